@@ -0,0 +1,153 @@
+// SPDX-FileCopyrightText: Copyright (C) 2024 Adaline Simonian
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// This file is part of gdvm.
+//
+// gdvm is free software: you can redistribute it and/or modify it under the
+// terms of the GNU General Public License as published by the Free Software
+// Foundation, either version 3 of the License, or (at your option) any later
+// version.
+//
+// gdvm is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR
+// A PARTICULAR PURPOSE. See the GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with
+// this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Startup of the `godot` shims: resolving a project's pin in full, as the
+//! first run does, against taking it from the resolution cache.
+//!
+//! Run with `cargo bench --features integration-tests --bench shim_startup`.
+
+use std::fs;
+use std::hint::black_box;
+use std::time::Duration;
+
+use criterion::{Criterion, criterion_group, criterion_main};
+use gdvm::app::{Gdvm, find_godot_executable};
+use gdvm::config::ConfigFile;
+use gdvm::registry::publish;
+use gdvm::resolution_cache::CachedResolution;
+use gdvm::run_version_resolver::{RunResolutionRequest, RunVersionResolver};
+use gdvm::version::{Variant, VersionQuery};
+use tempfile::TempDir;
+
+#[path = "../tests/common/mod.rs"]
+mod common;
+use common::{TestHome, host_platform, make_zip};
+
+/// Publish a local registry with a 4.4 build, pin the project to it and
+/// install it.
+fn set_up(env: &TestHome, rt: &tokio::runtime::Runtime) {
+    let reg = TempDir::new().unwrap().keep().join("reg");
+    publish::init(&reg, Some("local")).unwrap();
+    let archive = reg.parent().unwrap().join("godot.zip");
+    make_zip(&archive, "Godot_v4.4-stable.exe", b"godot");
+    publish::add_build(
+        &reg,
+        &publish::AddBuild {
+            version: "4.4-stable".to_string(),
+            variant: None,
+            platform: host_platform(),
+            file: Some(archive),
+            store: true,
+            url: None,
+            sha512: None,
+            size: None,
+        },
+    )
+    .unwrap();
+
+    let mut config = ConfigFile::load().unwrap();
+    config
+        .add_registry("localreg", &format!("file://{}", reg.display()))
+        .unwrap();
+    config.save().unwrap();
+    fs::write(
+        env.project_dir().join("gdvm.toml"),
+        "[godot]\nversion = \"localreg/4.4\"\n",
+    )
+    .unwrap();
+
+    rt.block_on(async {
+        let gdvm = Gdvm::new().await.unwrap();
+        let gv = VersionQuery::from_install_str("4.4-stable")
+            .unwrap()
+            .to_resolved();
+        gdvm.installer()
+            .install(&gv, &Variant::default(), Some("localreg"), false, false)
+            .await
+            .unwrap();
+    });
+}
+
+fn request() -> RunResolutionRequest<'static> {
+    RunResolutionRequest {
+        explicit: None,
+        variant: None,
+        registry: None,
+        include_pre: false,
+        possible_paths: &[],
+        force_on_mismatch: false,
+        install_if_missing: true,
+    }
+}
+
+fn shim_startup(c: &mut Criterion) {
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    let env = TestHome::with_project();
+    set_up(&env, &rt);
+
+    let cwd = std::env::current_dir().unwrap();
+    let light = Gdvm::for_shim().unwrap().unwrap();
+    let stamps = light.defaults().resolution_stamps(&[cwd]).unwrap();
+    // Files changed within the last couple of seconds aren't trusted yet.
+    std::thread::sleep(Duration::from_secs(3));
+    light
+        .resolution_cache()
+        .insert(
+            "bench",
+            CachedResolution {
+                version: "4.4-stable".to_string(),
+                variant: "default".to_string(),
+                registry: Some("localreg".to_string()),
+                project_version: None,
+            },
+            stamps,
+        )
+        .unwrap();
+
+    let mut group = c.benchmark_group("shim_startup");
+    group.bench_function("full_resolution", |b| {
+        b.iter(|| {
+            rt.block_on(async {
+                let gdvm = Gdvm::new().await.unwrap();
+                black_box(
+                    RunVersionResolver::new(&gdvm)
+                        .resolve(request())
+                        .await
+                        .unwrap(),
+                )
+            })
+        })
+    });
+    group.bench_function("cached_resolution", |b| {
+        b.iter(|| {
+            let gdvm = Gdvm::for_shim().unwrap().unwrap();
+            let cached = gdvm.resolution_cache().get("bench").unwrap();
+            let gv = VersionQuery::from_install_str(&cached.version)
+                .unwrap()
+                .to_resolved();
+            let (_, dir) = gdvm
+                .library()
+                .install_dir(&gv, &Variant::default(), cached.registry.as_deref())
+                .unwrap();
+            black_box(find_godot_executable(&dir, false).unwrap().unwrap())
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, shim_startup);
+criterion_main!(benches);
@@ -34,7 +34,23 @@ help-gdvm-version = Display the version of { -godot } Version Manager
 
 help-install = Install a new { -godot } version
 help-run = Run a specific { -godot } version
+help-exec = Run a command with the resolved { -godot } on PATH
+help-exec-long = { help-exec }
+
+    The version is chosen the same way as for "{ -gdvm } run" and installed if needed. The command runs with a directory holding a "godot" link to that version first on PATH, with GODOT and GODOT_BIN set to the executable's path and GODOT_VERSION to its version, and with the variables from a .env file in the current directory. Its exit code is passed on.
+
+    Example: { -gdvm } exec 4.3 -- ./addons/gdUnit4/runtest.sh -a test
+help-exec-command = The command to run and its arguments, after "--"
 help-show = Show the path to the executable for the given version of { -godot }
+help-show-explain = Also print how the version was chosen: every source checked, the file each came from, and any mismatch
+help-env = Print a shell hook that exports the current directory's { -godot }
+help-env-long = { help-env }
+
+    Add the hook to your shell's profile, e.g. eval "$({ -gdvm } env --shell bash)" in ~/.bashrc. At every prompt, it sets GODOT to the path of the { -godot } executable the directory uses, GODOT_VERSION to its version, and GDVM_RESOLVED_SOURCE to where the version came from: pin, project or default. The version is found the same way as for "{ -gdvm } run", but nothing is installed, so the variables are unset when that version isn't installed yet.
+
+    The variables are cached until one of the files they were read from changes, such as a pin, so the prompt stays fast.
+help-env-shell = The shell to print the hook for
+help-env-export = Print the variables for the current directory instead of the hook
 help-cache-path = Show the path to the cached download archive for the given version of { -godot }
 help-link = Link the executable of a { -godot } version to a specified path
 help-list = List all installed { -godot } versions
@@ -73,8 +89,35 @@ help-prune-long = { help-prune }
 
     By default, prune removes installs that have not been used in a while and cached download archives that have aged out, while preserving any install that still has a link pointing into it. The install set as the default is never removed, whatever flags are given. The age threshold is configurable with "{ -gdvm } config set prune.max-age-days <days>" (default { $default_days } days).
 help-prune-all = Remove all installs and cached archives regardless of age. Installs that still have a live link are kept unless --force is also given.
-help-prune-force = Ignore links, so installs referenced only by a link may also be removed.
+help-prune-force = Ignore links, so installs referenced only by a link may also be removed. Self-contained installs are also removed along with their editor data.
 help-prune-dry-run = Show what would be removed without deleting anything.
+help-logs = Show the output of detached { -godot } launches
+help-logs-long = { help-logs }
+
+    When { -godot } runs detached from the console, its output is written to a new log file for each launch, named after the version and the project. Logs are removed after { $default_days } days, which can be changed with "{ -gdvm } config set logs.max-age-days <days>".
+help-logs-follow = Keep printing output as it is written, moving on to newer launches as they start.
+help-logs-last = Show the logs of this many of the most recent launches.
+logs-none = No detached launches have been logged yet.
+help-ps = List the { -godot } processes started by { -gdvm } that are still running
+help-stop = Stop a { -godot } process started by { -gdvm }
+help-stop-pid = The process ID shown by "{ -gdvm } ps"
+ps-none = No { -godot } processes started by { -gdvm } are running.
+ps-process = { $pid }: { $version }, started { $started }
+ps-process-project = { $pid }: { $version } for { $project }, started { $started }
+ps-stopped-detail = { $pid } ({ $version })
+help-crashes = List the crash records saved for attached { -godot } runs
+help-crashes-long = { help-crashes }
+
+    When { -godot } runs attached to the console and ends on a crash, { -gdvm } prints a summary and saves a crash record with the exact version, variant, registry, arguments and project, ready to attach to a bug report. To also save the last lines { -godot } printed, turn on output capture with "{ -gdvm } config set crashes.capture-output true".
+crashes-none = No crashes have been recorded.
+crashes-entry = { $time }: { $version } ({ $variant }), { $reason }, saved to { $path }
+godot-crashed = { -godot } { $version } crashed ({ $reason }).
+crash-label-version = Version
+crash-label-variant = Variant
+crash-label-registry = Registry
+crash-label-args = Arguments
+crash-label-project = Project
+crash-saved = A crash record was saved to { $path }.
 prune-nothing-dry-run = Nothing would be removed.
 prune-nothing-removed = Nothing to remove; everything is in use or within the age threshold.
 prune-preserved-by-link =
@@ -82,12 +125,80 @@ prune-preserved-by-link =
         [one] Kept { $count } install still referenced by a link.
        *[other] Kept { $count } installs still referenced by a link.
     }
+help-dedupe = Share identical files between installs to save disk space
+help-dedupe-long = { help-dedupe }
+
+    Files with the same contents in different installs are replaced with copy-on-write clones where the filesystem supports them, and with hard links otherwise. Set "{ -gdvm } config set install.dedupe true" to do this automatically after each install.
+help-dedupe-dry-run = Show how much space would be saved without changing anything.
+dedupe-nothing = No identical files left to share.
+dedupe-shared-files =
+    { $count ->
+        [one] Shared { $count } identical file between installs, saving { size-display }.
+       *[other] Shared { $count } identical files between installs, saving { size-display }.
+    }
+dedupe-would-share-files =
+    { $count ->
+        [one] Would share { $count } identical file between installs, saving { size-display }.
+       *[other] Would share { $count } identical files between installs, saving { size-display }.
+    }
+warning-dedupe-failed = Couldn't share identical files between installs: { $error }
+warning-shims-sync-failed = Couldn't update the versioned { -godot } shims: { $error }
+warning-cache-trim-failed = Couldn't shrink the download cache to cache.max-size: { $error }
+warning-launch-log-failed = Couldn't create a log for this launch, so its output will be discarded: { $error }
+warning-crash-save-failed = Couldn't save the crash record: { $error }
+warning-self-contained-project-unsupported = { -godot } can't keep editor data per project on this platform, so it uses its shared editor data. Install the version with --self-contained to keep its data apart instead.
+warning-self-contained-link-failed = Couldn't link your own config, data and cache folders into the project's, so programs { -godot } starts may not find their settings: { $error }
+warning-carry-settings-failed = Couldn't carry the editor settings forward: { $error }
 warning-broken-install-reinstalling = The installed { $version } is missing its executable, reinstalling it.
 
 help-force = Force reinstall even if the version is already installed.
+help-install-system = Install into the system-wide install store instead of your own. Requires write access to the store.
+help-install-self-contained = Make the install self-contained, so its { -godot } keeps its own editor settings, caches and export templates inside the install.
+help-locked = Require gdvm.lock to agree with the pin and archives to match its hashes. Set GDVM_LOCKED=1 to do the same for the godot shims.
 help-redownload = Redownload the version even if it's already downloaded in the cache.
 help-yes = Skip confirmation prompt for removal
 help-remove-yes-deprecated = [deprecated] This flag is a no-op and will be removed in a future release.
+help-remove-system = Remove from the system-wide install store. Requires write access to the store.
+help-verify = Check installs for modified, missing, or added files
+help-verify-long = { help-verify }
+
+    Every install made by { -gdvm } records a receipt with the hash of each of its files. This command hashes the installed files again and compares them to the receipt. When an install has changed, { -gdvm } offers to reinstall it from the cached archive.
+help-verify-version = The installed version to check (e.g. 4.2 or 4.2-stable). Checks every install when omitted.
+help-verify-yes = Reinstall changed installs from the cached archive without asking.
+verify-no-receipt = { $version } has no install receipt to check against. Reinstall it to create one.
+verify-install-changed = { $version } differs from its install receipt ({ $modified } modified, { $missing } missing, { $added } added):
+verify-file-modified = modified: { $path }
+verify-file-missing = missing:  { $path }
+verify-file-added = added:    { $path }
+verify-archive-not-cached = The archive { $version } was installed from is no longer cached. Reinstall it with "{ -gdvm } install --force".
+verify-reinstall-prompt = Reinstall { $version } from the cached archive? (yes/no):
+verify-reinstall-tip = Run this command again with --yes to reinstall from the cached archive.
+carry-settings-prompt = This is the first time { $version } runs. Carry the editor settings, themes and script templates of { $from } forward to it? (yes/no):
+carry-settings-tip = To carry the editor settings of { $from } forward to new versions without asking, run "{ -gdvm } config set godot.carry-settings always".
+carry-settings-detail = editor settings from { $from }
+carry-settings-backup = The files this replaced were backed up to { $path }.
+help-outdated = Report newer builds of installed, default, and pinned versions
+help-outdated-long = { help-outdated }
+
+    For each installed version, the default version, and the current project's pin, shows the newest patch release, the newest minor release, and the newest prerelease. Exits with a non-zero code when a patch or minor update is available, or a newer prerelease of a prerelease.
+outdated-source-installed = installed
+outdated-source-default = default
+outdated-source-pinned = pinned
+outdated-patch = Patch:
+outdated-minor = Minor:
+outdated-prerelease = Prerelease:
+outdated-up-to-date = Everything is up to date.
+warning-outdated-check-failed = Couldn't check { $version } for newer builds: { $error }
+help-update = Install the newest patch release of each installed series
+help-update-long = { help-update }
+
+    For each installed major.minor series and variant, installs the newest stable patch release and points recorded links at it. Use --default to move the default version over as well, and --remove to remove the installs it replaces.
+help-update-version = Only update installs matching this version
+help-update-default = Move the default version to the updated install
+help-update-remove = Remove the installs replaced by the update
+update-up-to-date = All installed series are on their newest patch release.
+update-kept-default = Keeping { $version } because it is the default version. Pass --default to move it.
+warning-update-remove-failed = Couldn't remove { $version }: { $error }
 help-link-version = The version to link. If not provided, resolves the version based on the current directory or default version.
 help-link-path = The path where the link or copy will be created, e.g. "{ $platform ->
     [windows] godot.exe
@@ -123,6 +234,7 @@ help-console = Run { -godot } with the console attached. Defaults to false on Wi
 
 help-default = Manage the default version
 help-default-version = The version to set as default (e.g. 4.2 or 4.2-stable).
+help-default-float = Track the version spec instead of one build, following the newest installed match
 no-default-set = No default version set. Run "{ -gdvm } use <version>" to set a default version system-wide, or "{ -gdvm } pin <version>" to set a default version for the current directory.
 
 warning-prerelease = You are installing a pre-release version ({$branch}).
@@ -149,6 +261,9 @@ status-would-free = Would free
 status-would-prune = Would prune
 status-removing = Removing
 status-running = Running
+status-carried-settings = Carried forward
+status-log = Log
+status-stopped = Stopped
 status-cleared = Cleared
 status-refreshed = Refreshed
 status-skipped = Skipped
@@ -177,6 +292,8 @@ progress-eta =
 unsupported-platform = Unsupported platform
 unsupported-architecture = Unsupported architecture
 error-checksum-mismatch = Checksum mismatch for file { $file }
+error-lock-hash-mismatch = The archive for { $version } doesn't match the hash recorded in { $path }. Run `{ -gdvm } pin --update-lock` if the new build is expected.
+error-lock-platform-missing = { $path } records no hash for { $version } on { $platform }, so the archive can't be checked. Run `{ -gdvm } pin --update-lock` to add it.
 error-invalid-sha-length = Invalid SHA length { $length }
 error-size-mismatch = Size mismatch for file { $file }: expected { $expected } bytes, got { $actual } bytes.
 error-insecure-url = Refusing to fetch { $url } over an unencrypted connection. Only https:// and file:// URLs are allowed. Set the GDVM_ALLOW_INSECURE_URLS environment variable to allow unencrypted http:// URLs.
@@ -186,6 +303,10 @@ error-response-too-large = The response from { $url } exceeds the maximum allowe
 error-too-many-redirects = Too many redirects.
 error-config-invalid-number = Invalid value for { $key }: { $value } (expected a number)
 error-config-invalid-enum = Invalid value for { $key }: { $value } (expected one of: { $expected })
+error-config-invalid-bool = Invalid value for { $key }: { $value } (expected true or false)
+error-config-invalid-size = Invalid value for { $key }: { $value } (expected a size such as 512MiB or 10GiB)
+error-config-invalid-path = Invalid value for { $key }: { $value } (expected an absolute path)
+error-alias-invalid-name = Invalid alias name: { $name }. Alias names start with a letter, contain only letters, digits, "-" and "_", and can't be "stable", "latest" or "unset".
 error-config-unknown-key = Unknown configuration key: { $key }
 error-invalid-path = Invalid path: { $path }
 error-publish-missing-manifest = missing registry.json
@@ -213,6 +334,11 @@ error-spec-empty-variant = Empty variant name in '{ $input }'
 error-spec-empty-version = Empty version in '{ $input }'
 error-system-time = System time before UNIX EPOCH
 error-unrecognized-version-format = Unrecognized version format: { $input }
+error-invalid-version-range = Invalid version range: { $input }. Use comparators such as >=4.2, <4.5, ~4.3 or ^4.
+error-lock-missing = { $path } is required but doesn't exist. Run `{ -gdvm } pin --update-lock` to create it.
+error-lock-out-of-date = { $path } doesn't match the pin in { -gdvm-toml }. Run `{ -gdvm } pin --update-lock` to refresh it.
+error-no-pinned-version = No version is pinned in { -gdvm-toml } here or in a parent directory.
+error-alias-unknown = There is no alias named { $name }. Run "{ -gdvm } alias list" to see the configured aliases.
 error-diagnose-problems =
     { $count ->
         [one] { $count } problem found.
@@ -229,13 +355,19 @@ download-retrying = Download interrupted, retrying (attempt { $attempt } of { $m
 download-resuming = Resuming interrupted download ({ size-display } already downloaded).
 warning-resume-verification-failed = The resumed download did not match the expected checksum, downloading it again from scratch.
 lock-waiting = Waiting for another { -gdvm } process to finish (lock: { $resource })...
+relocated-to-xdg = Moved { -gdvm }'s files from { $from } into the XDG base directories. Replace { $old_bin } with { $bin } in your PATH.
+warning-relocate-leftovers = Some files in { $path } were not moved because the new location already has them. Remove them once you've checked them.
 prune-skipped-error = Skipping { $item }: { $error }
 prune-skipped-in-use = Skipping { $item }: it is in use by another { -gdvm } process.
+prune-skipped-running = Skipping { $item }: { -godot } is running from it. Use "{ -gdvm } ps" to see which processes.
+prune-skipped-self-contained = Skipping { $item }: it is self-contained and keeps editor settings. Use "{ -gdvm } remove" or "{ -gdvm } prune --force" to remove it.
 
 error-find-user-dirs = Failed to find user directories.
 warning-fetching-releases-using-cache = Error fetching releases: { $error }. Using cached releases instead.
 
 error-version-not-found = Version not found.
+error-system-store-not-set = No system-wide install store is set. Set one with "{ -gdvm } config set install.system-store <path>" or the GDVM_SYSTEM_STORE environment variable.
+error-system-install-read-only = { $version } is in the system-wide install store. Use "{ -gdvm } remove --system" to remove it from there.
 error-archive-not-cached = No cached archive found for {$version}. Install it first to populate the cache.
 error-multiple-versions-found = Multiple versions match your request:
     {$list}
@@ -245,6 +377,8 @@ no-matching-releases = No matching releases found.
 available-releases = Available releases:
 
 version-already-installed = Version {$version} already installed.
+install-self-contained = { $version } is self-contained. Its editor settings and data are kept in { $path }.
+remove-self-contained = The editor settings and data it kept in { $path } were removed with it.
 godot-executable-not-found = { -godot } executable not found for version {$version}.
 error-link-exists = Path {$path} already exists. Use --force to overwrite.
 error-link-symlink = Failed to create link from {$link} to {$target}.
@@ -253,9 +387,18 @@ error-link-copy = Failed to copy file.
 error-no-stable-releases-found = No stable releases found.
 
 error-starting-godot = Failed to start { -godot }.
+error-exec-failed = Failed to run { $command }.
+error-profile-not-found = No launch profile named { $profile } was found in { -gdvm-toml }.
+error-env-profile-not-found = No { $profile } environment profile was found at { $path }.
+error-install-running = { $version } can't be removed while { -godot } is running from it (process { $pids }). Close it or use "{ -gdvm } stop" first.
+error-self-contained-read-only = { $version } is in the system-wide install store, which { -godot } can't keep editor data in.
+error-process-not-tracked = No running { -godot } process with ID { $pid } was started by { -gdvm }.
+error-process-stop-failed = Failed to stop process { $pid }.
+error-process-unconfirmed = Can't confirm that process { $pid } is still the { -godot } that { -gdvm } started, so it was left running.
 confirm-yes = yes
 
 default-set-success = Successfully set {$version} as the default { -godot } version.
+default-float-success = The default { -godot } version now follows {$spec}, currently {$version}.
 default-unset-success = Successfully unset the default { -godot } version.
 provide-version-or-unset = Please provide a version to set as default or 'unset' to remove the default version.
 
@@ -295,6 +438,8 @@ help-pin-long = { help-pin }
 
     This will create a { -gdvm-toml } file in the current directory with the pinned version. When you run "{ -gdvm } run" in this directory or any of its subdirectories, the pinned version will be used instead of the default version.
 
+    Versions pinned for other version managers are also used: a .godot-version file, a godot line in .tool-versions, or godot under [tools] in mise.toml. In each directory { -gdvm-toml } takes precedence over these, then { -gdvmrc }, .godot-version, .tool-versions and mise.toml, in that order. The nearest directory with any pin wins. Turn this off with "{ -gdvm } config set pin.tool-files false".
+
     This is useful when you want to use a specific version of { -godot } for a project without changing the default version system-wide.
 
     This currently also writes the legacy { -gdvmrc } file for compatibility with older versions of { -gdvm }. This will be removed in a future release, so it is recommended to update to the new { -gdvm-toml } format and remove the { -gdvmrc } file if it exists.
@@ -302,7 +447,9 @@ help-pin-long = { help-pin }
     You can disable writing a { -gdvmrc } file using the --no-legacy flag.
 help-pin-version = The version to pin
 help-no-legacy = Do not write the legacy { -gdvmrc } compatibility file
+help-update-lock = Resolve the pin to an exact build and record it in gdvm.lock
 pinned-success = Successfully pinned version {$version} in { -gdvm-toml }
+lock-updated = Locked { $version } in { $path }
 error-pin-version-not-found = Could not pin version {$version}
 
 error-file-not-found = File not found. It may not exist on the server.
@@ -315,6 +462,24 @@ error-post-upgrade-action-failed = Step { $id } failed after upgrade.
 error-failed-reading-project-godot = Failed reading project.godot, cannot automatically determine project version.
 warning-using-project-version = Using version { $version } defined in project.godot.
 warning-gdvmrc-detected = A custom { -gdvmrc } file was detected. Support for { -gdvmrc } files is deprecated and will be removed in a coming release. Please switch to the new pin file used by `{ -gdvm } pin`.
+warning-lock-out-of-date = { $path } no longer matches the pin in { -gdvm-toml } and is being ignored. Run `{ -gdvm } pin --update-lock` to refresh it.
+warning-lock-hash-mismatch = The archive for { $version } doesn't match the hash recorded in { $path }.
+lock-platform-missing = { $path } records no hash for { $version } on { $platform }, so the archive wasn't checked against it.
+
+explain-header = How the { -godot } version was chosen:
+explain-source-explicit = command line
+explain-source-pin = pin
+explain-source-project = project
+explain-source-default = default
+explain-not-set = not set
+explain-offer-from-file = { $spec } from { $path }
+explain-selected = (selected)
+explain-variant-inherited = The { $variant } variant was inherited from the { $source }.
+explain-registry-inherited = The { $registry } registry was inherited from the { $source }.
+explain-project-mismatch = The project asks for { $project_version }, which conflicts with { $version }.
+explain-lock = The pinned version was narrowed by { $path }.
+explain-stale-lock = { $path } was ignored because it no longer agrees with the pin.
+explain-executable = Executable: { $path }
 
 warning-project-version-mismatch =
     {"\u001b"}[33mWarning: The version defined in project.godot does not match the { $pinned ->
@@ -344,6 +509,16 @@ help-run-force-long =
     { help-run-force }
 
     If you do this, the requested or pinned version of { -godot } may overwrite the project file. If pinning versions, it is instead recommended to update the pinned version in { -gdvmrc } to match the project version, or remove the { -gdvmrc } file to use the project version.
+help-run-profile = Use the named launch profile from { -gdvm-toml }
+help-run-profile-long =
+    { help-run-profile }
+
+    A profile is a [profiles.<name>] table in { -gdvm-toml } with args, env, launch-mode and working-dir keys. It builds on the [run] table, which applies whenever the project's { -godot } runs, including through the godot command. Arguments given after "--" follow the profile's arguments.
+help-env-profile = Layer the named .env.<profile> file over .env
+help-env-profile-long =
+    { help-env-profile }
+
+    The .env file is read from the project directory, or from the current directory when there is no project. Variables already set in the environment are kept. The [dotenv] table in { -gdvm-toml } can list which variables pass through to { -godot } and which are blocked. The GDVM_ENV_PROFILE environment variable picks a profile when this option isn't given.
 
 help-config = Manage { -gdvm } configuration
 help-format = Output format: text (default) or json
@@ -412,6 +587,17 @@ registry-removed = Removed registry { $registry }.
 registry-list-header = Configured registries:
 registry-tag-official = official
 
+help-alias = Manage version aliases
+help-alias-set = Add or change an alias
+help-alias-remove = Remove an alias
+help-alias-list = List configured aliases
+help-alias-name = The alias name. Use it in place of a version, optionally as @name.
+help-alias-spec = The version the alias stands for, e.g. studio/csharp:4.3.1-rc2
+alias-set = Alias { $name } now stands for { $spec }.
+alias-removed = Removed alias { $name }.
+alias-list-header = Configured aliases:
+alias-list-empty = No aliases are configured.
+
 error-invalid-registry-subcommand = Invalid registry subcommand. Use "add", "remove", "list", or "refresh".
 registry-trust-warning = { $registry } ({ $url }) is a custom registry, not the official one. { -gdvm } makes sure downloads match what the registry says to expect, but it can't tell whether they are safe to run. Only install from it if you trust whoever runs it.
 registry-trust-prompt = Do you trust this registry and want to continue? (yes/no):
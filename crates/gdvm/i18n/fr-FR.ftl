@@ -34,7 +34,23 @@ help-gdvm-version = Afficher la version du Gestionnaire de Versions { -godot }
 
 help-install = Installer une nouvelle version de { -godot }
 help-run = Exécuter une version spécifique de { -godot }
+help-exec = Exécuter une commande avec le { -godot } résolu dans le PATH
+help-exec-long = { help-exec }
+
+    La version est choisie de la même manière que pour « { -gdvm } run » et installée si nécessaire. La commande s'exécute avec, en tête du PATH, un répertoire contenant un lien « godot » vers cette version, avec GODOT et GODOT_BIN définis sur le chemin de l'exécutable et GODOT_VERSION sur sa version, ainsi qu'avec les variables d'un fichier .env du répertoire courant. Son code de sortie est transmis.
+
+    Exemple : { -gdvm } exec 4.3 -- ./addons/gdUnit4/runtest.sh -a test
+help-exec-command = La commande à exécuter et ses arguments, après « -- »
 help-show = Afficher le chemin de l'exécutable pour la version de { -godot } indiquée
+help-show-explain = Afficher aussi comment la version a été choisie : chaque source consultée, le fichier d'où elle provient et toute incompatibilité
+help-env = Afficher un hook de shell qui exporte le { -godot } du répertoire courant
+help-env-long = { help-env }
+
+    Ajoutez le hook au profil de votre shell, par exemple eval "$({ -gdvm } env --shell bash)" dans ~/.bashrc. À chaque invite, il définit GODOT sur le chemin de l'exécutable { -godot } utilisé par le répertoire, GODOT_VERSION sur sa version et GDVM_RESOLVED_SOURCE sur l'origine de la version : pin, project ou default. La version est trouvée de la même manière que pour « { -gdvm } run », mais rien n'est installé : les variables sont donc supprimées tant que cette version n'est pas installée.
+
+    Les variables sont mises en cache jusqu'à ce que l'un des fichiers dont elles proviennent change, comme un épinglage, afin que l'invite reste rapide.
+help-env-shell = Le shell pour lequel afficher le hook
+help-env-export = Afficher les variables du répertoire courant au lieu du hook
 help-cache-path = Afficher le chemin de l'archive de téléchargement en cache pour la version de { -godot } indiquée
 help-link = Lier l'exécutable d'une version de { -godot } à un chemin spécifié
 help-list = Lister toutes les versions installées de { -godot }
@@ -71,8 +87,35 @@ help-prune-long = { help-prune }
 
     Par défaut, prune supprime les installations qui n'ont pas été utilisées depuis un certain temps ainsi que les archives de téléchargement en cache devenues trop anciennes, tout en préservant toute installation encore référencée par un lien. L'installation définie comme défaut n'est jamais supprimée, quels que soient les drapeaux fournis. Le seuil d'ancienneté est configurable avec « { -gdvm } config set prune.max-age-days <jours> » (par défaut { $default_days } jours).
 help-prune-all = Supprimer toutes les installations et archives en cache quel que soit leur âge. Les installations encore référencées par un lien actif sont conservées sauf si --force est également fourni.
-help-prune-force = Ignorer les liens, afin que les installations référencées uniquement par un lien puissent aussi être supprimées.
+help-prune-force = Ignorer les liens, afin que les installations référencées uniquement par un lien puissent aussi être supprimées. Les installations autonomes sont aussi supprimées avec leurs données d'éditeur.
 help-prune-dry-run = Afficher ce qui serait supprimé sans rien supprimer.
+help-logs = Afficher la sortie des lancements détachés de { -godot }
+help-logs-long = { help-logs }
+
+    Lorsque { -godot } est lancé détaché de la console, sa sortie est écrite dans un nouveau fichier journal à chaque lancement, nommé d'après la version et le projet. Les journaux sont supprimés après { $default_days } jours, ce qui peut être modifié avec « { -gdvm } config set logs.max-age-days <jours> ».
+help-logs-follow = Continuer d'afficher la sortie au fur et à mesure, en passant aux lancements plus récents lorsqu'ils démarrent.
+help-logs-last = Afficher les journaux de ce nombre de lancements parmi les plus récents.
+logs-none = Aucun lancement détaché n'a encore été journalisé.
+help-ps = Lister les processus { -godot } lancés par { -gdvm } qui sont toujours en cours d'exécution
+help-stop = Arrêter un processus { -godot } lancé par { -gdvm }
+help-stop-pid = L'identifiant de processus affiché par « { -gdvm } ps »
+ps-none = Aucun processus { -godot } lancé par { -gdvm } n'est en cours d'exécution.
+ps-process = { $pid } : { $version }, lancé le { $started }
+ps-process-project = { $pid } : { $version } pour { $project }, lancé le { $started }
+ps-stopped-detail = { $pid } ({ $version })
+help-crashes = Lister les rapports de plantage enregistrés pour les exécutions de { -godot } attachées à la console
+help-crashes-long = { help-crashes }
+
+    Lorsque { -godot } est lancé attaché à la console et se termine par un plantage, { -gdvm } affiche un résumé et enregistre un rapport de plantage avec la version exacte, la variante, le registre, les arguments et le projet, prêt à être joint à un rapport de bogue. Pour enregistrer aussi les dernières lignes affichées par { -godot }, activez la capture de la sortie avec « { -gdvm } config set crashes.capture-output true ».
+crashes-none = Aucun plantage n'a été enregistré.
+crashes-entry = { $time } : { $version } ({ $variant }), { $reason }, enregistré dans { $path }
+godot-crashed = { -godot } { $version } a planté ({ $reason }).
+crash-label-version = Version
+crash-label-variant = Variante
+crash-label-registry = Registre
+crash-label-args = Arguments
+crash-label-project = Projet
+crash-saved = Un rapport de plantage a été enregistré dans { $path }.
 prune-nothing-dry-run = Rien ne serait supprimé.
 prune-nothing-removed = Rien à supprimer ; tout est utilisé ou dans le seuil d'ancienneté.
 prune-preserved-by-link =
@@ -80,12 +123,80 @@ prune-preserved-by-link =
         [one] { $count } installation conservée car encore référencée par un lien.
        *[other] { $count } installations conservées car encore référencées par un lien.
     }
+help-dedupe = Partager les fichiers identiques entre les installations pour économiser de l'espace disque
+help-dedupe-long = { help-dedupe }
+
+    Les fichiers au contenu identique dans différentes installations sont remplacés par des clones en copie sur écriture lorsque le système de fichiers le permet, et par des liens physiques sinon. Utilisez « { -gdvm } config set install.dedupe true » pour le faire automatiquement après chaque installation.
+help-dedupe-dry-run = Afficher l'espace qui serait économisé sans rien modifier.
+dedupe-nothing = Aucun fichier identique à partager.
+dedupe-shared-files =
+    { $count ->
+        [one] { $count } fichier identique partagé entre les installations, { size-display } économisés.
+       *[other] { $count } fichiers identiques partagés entre les installations, { size-display } économisés.
+    }
+dedupe-would-share-files =
+    { $count ->
+        [one] { $count } fichier identique serait partagé entre les installations, { size-display } économisés.
+       *[other] { $count } fichiers identiques seraient partagés entre les installations, { size-display } économisés.
+    }
+warning-dedupe-failed = Impossible de partager les fichiers identiques entre les installations : { $error }
+warning-shims-sync-failed = Impossible de mettre à jour les shims versionnés de { -godot } : { $error }
+warning-cache-trim-failed = Impossible de réduire le cache de téléchargement à cache.max-size : { $error }
+warning-launch-log-failed = Impossible de créer un journal pour ce lancement, sa sortie sera donc ignorée : { $error }
+warning-crash-save-failed = Impossible d'enregistrer le rapport de plantage : { $error }
+warning-self-contained-project-unsupported = { -godot } ne peut pas conserver des données d'éditeur par projet sur cette plateforme ; il utilise donc ses données d'éditeur partagées. Installez plutôt la version avec --self-contained pour garder ses données à part.
+warning-self-contained-link-failed = Impossible de lier vos propres dossiers de configuration, de données et de cache à ceux du projet ; les programmes lancés par { -godot } risquent donc de ne pas trouver leurs paramètres : { $error }
+warning-carry-settings-failed = Impossible de reprendre les paramètres d'éditeur : { $error }
 warning-broken-install-reinstalling = L'installation de { $version } n'a pas d'exécutable, réinstallation en cours.
 
 help-force = Forcer la réinstallation même si la version est déjà installée.
+help-install-system = Installer dans le dépôt d'installations partagé du système plutôt que dans le vôtre. Nécessite un accès en écriture au dépôt.
+help-install-self-contained = Rendre l'installation autonome, afin que son { -godot } garde ses propres paramètres d'éditeur, caches et modèles d'exportation dans l'installation.
+help-locked = Exiger que gdvm.lock corresponde à l'épinglage et que les archives correspondent à ses empreintes. Définissez GDVM_LOCKED=1 pour faire de même avec les raccourcis godot.
 help-redownload = Retélécharger la version même si elle est déjà présente dans le cache.
 help-yes = Ignorer la confirmation de suppression
 help-remove-yes-deprecated = [obsolète] Cette option est sans effet et sera supprimée dans une version ultérieure.
+help-remove-system = Supprimer du dépôt d'installations partagé du système. Nécessite un accès en écriture au dépôt.
+help-verify = Rechercher les fichiers modifiés, manquants ou ajoutés dans les installations
+help-verify-long = { help-verify }
+
+    Chaque installation effectuée par { -gdvm } enregistre un reçu contenant l'empreinte de chacun de ses fichiers. Cette commande recalcule l'empreinte des fichiers installés et la compare au reçu. Lorsqu'une installation a changé, { -gdvm } propose de la réinstaller depuis l'archive en cache.
+help-verify-version = La version installée à vérifier (ex. 4.2 ou 4.2-stable). Vérifie toutes les installations si omise.
+help-verify-yes = Réinstaller les installations modifiées depuis l'archive en cache sans demander.
+verify-no-receipt = { $version } n'a pas de reçu d'installation à comparer. Réinstallez-la pour en créer un.
+verify-install-changed = { $version } diffère de son reçu d'installation ({ $modified } modifié(s), { $missing } manquant(s), { $added } ajouté(s)) :
+verify-file-modified = modifié : { $path }
+verify-file-missing = manquant : { $path }
+verify-file-added = ajouté : { $path }
+verify-archive-not-cached = L'archive depuis laquelle { $version } a été installée n'est plus en cache. Réinstallez-la avec « { -gdvm } install --force ».
+verify-reinstall-prompt = Réinstaller { $version } depuis l'archive en cache ? (oui/non) :
+verify-reinstall-tip = Relancez cette commande avec --yes pour réinstaller depuis l'archive en cache.
+carry-settings-prompt = C'est la première exécution de { $version }. Reprendre les paramètres d'éditeur, thèmes et modèles de script de { $from } ? (oui/non) :
+carry-settings-tip = Pour reprendre les paramètres d'éditeur de { $from } dans les nouvelles versions sans confirmation, exécutez « { -gdvm } config set godot.carry-settings always ».
+carry-settings-detail = paramètres d'éditeur de { $from }
+carry-settings-backup = Les fichiers remplacés ont été sauvegardés dans { $path }.
+help-outdated = Signaler les versions plus récentes des versions installées, par défaut et épinglées
+help-outdated-long = { help-outdated }
+
+    Pour chaque version installée, la version par défaut et l'épinglage du projet actuel, affiche la version corrective la plus récente, la version mineure la plus récente et la préversion la plus récente. Se termine avec un code non nul lorsqu'une mise à jour corrective ou mineure est disponible, ou une préversion plus récente d'une préversion.
+outdated-source-installed = installée
+outdated-source-default = par défaut
+outdated-source-pinned = épinglée
+outdated-patch = Corrective :
+outdated-minor = Mineure :
+outdated-prerelease = Préversion :
+outdated-up-to-date = Tout est à jour.
+warning-outdated-check-failed = Impossible de rechercher des versions plus récentes de { $version } : { $error }
+help-update = Installer la dernière version corrective de chaque série installée
+help-update-long = { help-update }
+
+    Pour chaque série majeure.mineure et variante installée, installe la dernière version corrective stable et y redirige les liens enregistrés. Utilisez --default pour y déplacer également la version par défaut, et --remove pour supprimer les installations qu'elle remplace.
+help-update-version = Ne mettre à jour que les installations correspondant à cette version
+help-update-default = Déplacer la version par défaut vers l'installation mise à jour
+help-update-remove = Supprimer les installations remplacées par la mise à jour
+update-up-to-date = Toutes les séries installées sont à leur dernière version corrective.
+update-kept-default = { $version } est conservée car c'est la version par défaut. Utilisez --default pour la déplacer.
+warning-update-remove-failed = Impossible de supprimer { $version } : { $error }
 help-link-version = La version à lier. Si elle n'est pas fournie, la version est résolue en fonction du répertoire courant ou de la version par défaut.
 help-link-path = Le chemin où le lien ou la copie sera créé, par exemple «{ $platform ->
     [windows] godot.exe
@@ -121,6 +232,7 @@ help-console = Exécuter { -godot } avec la console attachée. Par défaut false
 
 help-default = Gérer la version par défaut
 help-default-version = La version à définir par défaut (ex. 4.2 ou 4.2-stable).
+help-default-float = Suivre la spécification de version plutôt qu'une seule compilation, en utilisant la plus récente installation correspondante
 no-default-set = Aucune version par défaut définie. Exécutez « { -gdvm } use <version> » pour définir une version par défaut système, ou « { -gdvm } pin <version> » pour définir une version par défaut pour le répertoire courant.
 
 warning-prerelease = Vous installez une version de pré-publication ({$branch}).
@@ -147,6 +259,9 @@ status-would-free = Libérerait
 status-would-prune = Purgerait
 status-removing = Suppression
 status-running = Exécution
+status-carried-settings = Repris
+status-log = Journal
+status-stopped = Arrêté
 status-cleared = Vidé
 status-refreshed = Actualisé
 status-skipped = Ignoré
@@ -175,6 +290,8 @@ progress-eta =
 unsupported-platform = Plateforme non prise en charge
 unsupported-architecture = Architecture non prise en charge
 error-checksum-mismatch = Incompatibilité de somme de contrôle pour le fichier { $file }
+error-lock-hash-mismatch = L'archive de { $version } ne correspond pas à l'empreinte enregistrée dans { $path }. Exécutez `{ -gdvm } pin --update-lock` si la nouvelle version est attendue.
+error-lock-platform-missing = { $path } n'enregistre aucune empreinte de { $version } pour { $platform } ; l'archive ne peut donc pas être vérifiée. Exécutez `{ -gdvm } pin --update-lock` pour l'ajouter.
 error-invalid-sha-length = Longueur SHA invalide { $length }
 error-size-mismatch = Taille incorrecte pour le fichier { $file } : { $expected } octets attendus, { $actual } octets reçus.
 error-insecure-url = Refus de récupérer { $url } via une connexion non chiffrée. Seules les URL https:// et file:// sont autorisées. Définissez la variable d'environnement GDVM_ALLOW_INSECURE_URLS pour autoriser les URL http:// non chiffrées.
@@ -184,6 +301,10 @@ error-response-too-large = La réponse de { $url } dépasse la taille maximale a
 error-too-many-redirects = Trop de redirections.
 error-config-invalid-number = Valeur non valide pour { $key } : { $value } (nombre attendu)
 error-config-invalid-enum = Valeur non valide pour { $key } : { $value } (valeurs possibles : { $expected })
+error-config-invalid-bool = Valeur non valide pour { $key } : { $value } (valeurs possibles : true ou false)
+error-config-invalid-size = Valeur non valide pour { $key } : { $value } (une taille est attendue, comme 512MiB ou 10GiB)
+error-config-invalid-path = Valeur non valide pour { $key } : { $value } (un chemin absolu est attendu)
+error-alias-invalid-name = Nom d'alias invalide : { $name }. Les noms d'alias commencent par une lettre, ne contiennent que des lettres, des chiffres, « - » et « _ », et ne peuvent pas être « stable », « latest » ou « unset ».
 error-config-unknown-key = Clé de configuration inconnue : { $key }
 error-invalid-path = Chemin non valide : { $path }
 error-publish-missing-manifest = registry.json manquant
@@ -211,6 +332,11 @@ error-spec-empty-variant = Nom de variante vide dans « { $input } »
 error-spec-empty-version = Version vide dans « { $input } »
 error-system-time = Heure système antérieure à l'époque UNIX
 error-unrecognized-version-format = Format de version non reconnu : { $input }
+error-invalid-version-range = Plage de versions invalide : { $input }. Utilisez des comparateurs comme >=4.2, <4.5, ~4.3 ou ^4.
+error-lock-missing = { $path } est requis mais n'existe pas. Exécutez `{ -gdvm } pin --update-lock` pour le créer.
+error-lock-out-of-date = { $path } ne correspond pas à l'épinglage de { -gdvm-toml }. Exécutez `{ -gdvm } pin --update-lock` pour le mettre à jour.
+error-no-pinned-version = Aucune version n'est épinglée dans { -gdvm-toml } ici ou dans un répertoire parent.
+error-alias-unknown = Il n'existe aucun alias nommé { $name }. Exécutez « { -gdvm } alias list » pour voir les alias configurés.
 error-diagnose-problems =
     { $count ->
         [one] { $count } problème détecté.
@@ -227,13 +353,19 @@ download-retrying = Téléchargement interrompu, nouvelle tentative ({ $attempt
 download-resuming = Reprise du téléchargement interrompu ({ size-display } déjà téléchargés).
 warning-resume-verification-failed = Le téléchargement repris ne correspond pas à la somme de contrôle attendue, nouveau téléchargement complet en cours.
 lock-waiting = En attente de la fin d'un autre processus { -gdvm } (verrou : { $resource })...
+relocated-to-xdg = Les fichiers de { -gdvm } ont été déplacés de { $from } vers les répertoires de base XDG. Remplacez { $old_bin } par { $bin } dans votre PATH.
+warning-relocate-leftovers = Certains fichiers de { $path } n'ont pas été déplacés, car ils existent déjà au nouvel emplacement. Supprimez-les après les avoir vérifiés.
 prune-skipped-error = { $item } ignoré : { $error }
 prune-skipped-in-use = { $item } ignoré : il est en cours d'utilisation par un autre processus { -gdvm }.
+prune-skipped-running = { $item } ignoré : { -godot } est en cours d'exécution depuis cette installation. Utilisez « { -gdvm } ps » pour voir quels processus.
+prune-skipped-self-contained = { $item } ignoré : cette installation est autonome et conserve des paramètres d'éditeur. Utilisez « { -gdvm } remove » ou « { -gdvm } prune --force » pour la supprimer.
 
 error-find-user-dirs = Échec de la recherche des répertoires utilisateur.
 warning-fetching-releases-using-cache = Erreur lors de la récupération des versions : { $error }. Utilisation des versions en cache à la place.
 
 error-version-not-found = Version introuvable.
+error-system-store-not-set = Aucun dépôt d'installations partagé du système n'est défini. Définissez-en un avec "{ -gdvm } config set install.system-store <chemin>" ou la variable d'environnement GDVM_SYSTEM_STORE.
+error-system-install-read-only = { $version } se trouve dans le dépôt d'installations partagé du système. Utilisez "{ -gdvm } remove --system" pour l'en supprimer.
 error-archive-not-cached = Aucune archive en cache trouvée pour {$version}. Installez-la d'abord pour remplir le cache.
 error-multiple-versions-found = Plusieurs versions correspondent à votre demande :
     {$list}
@@ -243,6 +375,8 @@ no-matching-releases = Aucune version correspondante trouvée.
 available-releases = Versions disponibles :
 
 version-already-installed = Version {$version} déjà installée.
+install-self-contained = { $version } est autonome. Ses paramètres et données d'éditeur sont conservés dans { $path }.
+remove-self-contained = Les paramètres et données d'éditeur conservés dans { $path } ont été supprimés avec elle.
 godot-executable-not-found = Exécutable { -godot } introuvable pour la version {$version}.
 error-link-exists = Le chemin {$path} existe déjà. Utilisez --force pour écraser.
 error-link-symlink = Échec de la création du lien de {$link} vers {$target}.
@@ -251,9 +385,18 @@ error-link-copy = Échec de la copie du fichier.
 error-no-stable-releases-found = Aucune version stable trouvée.
 
 error-starting-godot = Échec du démarrage de { -godot }.
+error-exec-failed = Échec de l'exécution de { $command }.
+error-profile-not-found = Aucun profil de lancement nommé { $profile } n'a été trouvé dans { -gdvm-toml }.
+error-env-profile-not-found = Aucun profil d'environnement { $profile } n'a été trouvé à l'emplacement { $path }.
+error-install-running = { $version } ne peut pas être supprimé tant que { -godot } est en cours d'exécution depuis cette installation (processus { $pids }). Fermez-le ou utilisez d'abord « { -gdvm } stop ».
+error-self-contained-read-only = { $version } se trouve dans le dépôt d'installations partagé du système, où { -godot } ne peut pas conserver de données d'éditeur.
+error-process-not-tracked = Aucun processus { -godot } en cours d'exécution avec l'identifiant { $pid } n'a été lancé par { -gdvm }.
+error-process-stop-failed = Échec de l'arrêt du processus { $pid }.
+error-process-unconfirmed = Impossible de confirmer que le processus { $pid } est toujours le { -godot } lancé par { -gdvm } ; il n'a donc pas été arrêté.
 confirm-yes = oui
 
 default-set-success = {$version} définie avec succès comme version par défaut de { -godot }.
+default-float-success = La version par défaut de { -godot } suit désormais {$spec}, actuellement {$version}.
 default-unset-success = Version par défaut de { -godot } supprimée avec succès.
 provide-version-or-unset = Veuillez fournir une version à définir par défaut ou 'unset' pour supprimer la version par défaut.
 
@@ -293,6 +436,8 @@ help-pin-long = { help-pin }
 
     Cela créera un fichier { -gdvm-toml } dans le répertoire courant avec la version épinglée. Lorsque vous exécutez « { -gdvm } run » dans ce répertoire ou dans l'un de ses sous-répertoires, la version épinglée sera utilisée au lieu de la version par défaut.
 
+    Les versions épinglées pour d'autres gestionnaires de versions sont aussi utilisées : un fichier .godot-version, une ligne godot dans .tool-versions, ou godot sous [tools] dans mise.toml. Dans chaque répertoire, { -gdvm-toml } a la priorité, puis { -gdvmrc }, .godot-version, .tool-versions et mise.toml, dans cet ordre. Le répertoire le plus proche contenant un épinglage l'emporte. Désactivez cela avec « { -gdvm } config set pin.tool-files false ».
+
     Ceci est utile lorsque vous voulez utiliser une version spécifique de { -godot } pour un projet sans changer la version par défaut du système.
 
     Actuellement, cela écrit aussi le fichier { -gdvmrc } hérité pour la compatibilité avec les anciennes versions de { -gdvm }. Cela sera supprimé dans une future version, il est donc recommandé de passer au nouveau format { -gdvm-toml } et de supprimer le fichier { -gdvmrc } s'il existe.
@@ -300,7 +445,9 @@ help-pin-long = { help-pin }
     Vous pouvez désactiver l'écriture du fichier { -gdvmrc } avec le drapeau --no-legacy.
 help-pin-version = La version à épingler
 help-no-legacy = Ne pas écrire le fichier de compatibilité hérité { -gdvmrc }
+help-update-lock = Résoudre l'épinglage en une version exacte et l'enregistrer dans gdvm.lock
 pinned-success = Version {$version} épinglée avec succès dans { -gdvm-toml }
+lock-updated = { $version } verrouillée dans { $path }
 error-pin-version-not-found = Impossible d'épingler la version {$version}
 
 error-file-not-found = Fichier introuvable. Il peut ne pas exister sur le serveur.
@@ -313,6 +460,24 @@ error-post-upgrade-action-failed = L'étape { $id } a échoué après la mise à
 error-failed-reading-project-godot = Échec de la lecture de project.godot, impossible de déterminer automatiquement la version du projet.
 warning-using-project-version = Utilisation de la version { $version } définie dans project.godot.
 warning-gdvmrc-detected = Un fichier { -gdvmrc } personnalisé a été détecté. Le support des fichiers { -gdvmrc } est obsolète et sera supprimé dans une future version. Veuillez passer au nouveau fichier d'épinglage utilisé par `{ -gdvm } pin`.
+warning-lock-out-of-date = { $path } ne correspond plus à l'épinglage de { -gdvm-toml } et est ignoré. Exécutez `{ -gdvm } pin --update-lock` pour le mettre à jour.
+warning-lock-hash-mismatch = L'archive de { $version } ne correspond pas à l'empreinte enregistrée dans { $path }.
+lock-platform-missing = { $path } n'enregistre aucune empreinte de { $version } pour { $platform } ; l'archive n'a donc pas été vérifiée.
+
+explain-header = Comment la version de { -godot } a été choisie :
+explain-source-explicit = ligne de commande
+explain-source-pin = épinglage
+explain-source-project = projet
+explain-source-default = par défaut
+explain-not-set = non défini
+explain-offer-from-file = { $spec } depuis { $path }
+explain-selected = (choisie)
+explain-variant-inherited = La variante { $variant } provient de la source « { $source } ».
+explain-registry-inherited = Le registre { $registry } provient de la source « { $source } ».
+explain-project-mismatch = Le projet demande { $project_version }, ce qui est incompatible avec { $version }.
+explain-lock = La version épinglée a été précisée par { $path }.
+explain-stale-lock = { $path } a été ignoré, car il ne correspond plus à l'épinglage.
+explain-executable = Exécutable : { $path }
 
 warning-project-version-mismatch =
     {"\u001b"}[33mAvertissement : La version définie dans project.godot ne correspond pas à la version { $pinned ->
@@ -342,6 +507,16 @@ help-run-force-long =
     { help-run-force }
 
     Si vous faites cela, la version demandée ou épinglée de { -godot } peut écraser le fichier de projet. Si vous épinglez des versions, il est plutôt recommandé de mettre à jour la version épinglée dans { -gdvmrc } pour correspondre à la version du projet, ou de supprimer le fichier { -gdvmrc } pour utiliser la version du projet.
+help-run-profile = Utiliser le profil de lancement indiqué dans { -gdvm-toml }
+help-run-profile-long =
+    { help-run-profile }
+
+    Un profil est une table [profiles.<nom>] dans { -gdvm-toml } avec les clés args, env, launch-mode et working-dir. Il complète la table [run], qui s'applique chaque fois que le { -godot } du projet est lancé, y compris avec la commande godot. Les arguments donnés après « -- » suivent ceux du profil.
+help-env-profile = Superposer le fichier .env.<profil> indiqué à .env
+help-env-profile-long =
+    { help-env-profile }
+
+    Le fichier .env est lu dans le répertoire du projet, ou dans le répertoire courant en l'absence de projet. Les variables déjà définies dans l'environnement sont conservées. La table [dotenv] de { -gdvm-toml } peut indiquer quelles variables sont transmises à { -godot } et lesquelles sont bloquées. La variable d'environnement GDVM_ENV_PROFILE choisit un profil lorsque cette option n'est pas donnée.
 
 help-config = Gérer la configuration { -gdvm }
 help-format = Format de sortie : text (par défaut) ou json
@@ -410,6 +585,17 @@ registry-removed = Registre { $registry } supprimé.
 registry-list-header = Registres configurés :
 registry-tag-official = officiel
 
+help-alias = Gérer les alias de version
+help-alias-set = Ajouter ou modifier un alias
+help-alias-remove = Supprimer un alias
+help-alias-list = Lister les alias configurés
+help-alias-name = Le nom de l'alias. Utilisez-le à la place d'une version, éventuellement sous la forme @nom.
+help-alias-spec = La version que représente l'alias, ex. studio/csharp:4.3.1-rc2
+alias-set = L'alias { $name } représente désormais { $spec }.
+alias-removed = Alias { $name } supprimé.
+alias-list-header = Alias configurés :
+alias-list-empty = Aucun alias n'est configuré.
+
 error-invalid-registry-subcommand = Sous-commande de registre invalide. Utilisez « add », « remove », « list » ou « refresh ».
 registry-trust-warning = { $registry } ({ $url }) est un registre personnalisé, pas le registre officiel. { -gdvm } vérifie que les téléchargements correspondent à ce que le registre annonce, mais il ne peut pas savoir s'ils sont sûrs à exécuter. Ne l'utilisez que si vous faites confiance à la personne qui le gère.
 registry-trust-prompt = Faites-vous confiance à ce registre et voulez-vous continuer ? (oui/non) :
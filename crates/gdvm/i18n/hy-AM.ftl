@@ -54,7 +54,23 @@ help-gdvm-version = Ցուցադրել { -godot } տարբերակների կա
 
 help-install = Տեղադրել նոր { -godot } տարբերակ
 help-run = Գործարկել որոշակի { -godot } տարբերակ
+help-exec = Գործարկել հրաման՝ PATH-ում ընտրված { -godot }-ով
+help-exec-long = { help-exec }
+
+    Տարբերակն ընտրվում է նույն կերպ, ինչպես "{ -gdvm } run"-ի համար, և անհրաժեշտության դեպքում տեղադրվում է։ Հրամանը գործարկվում է այնպես, որ PATH-ի սկզբում լինի այդ տարբերակի "godot" հղումը պարունակող պանակ, GODOT-ն ու GODOT_BIN-ը սահմանված լինեն գործարկվողի ուղով, GODOT_VERSION-ը՝ դրա տարբերակով, և ընթացիկ պանակի .env ֆայլի փոփոխականներով։ Դրա ելքի կոդը փոխանցվում է։
+
+    Օրինակ՝ { -gdvm } exec 4.3 -- ./addons/gdUnit4/runtest.sh -a test
+help-exec-command = Գործարկվող հրամանը և դրա արգումենտները՝ "--"-ից հետո
 help-show = Ցույց տալ { -godot(case: "genitive") } նշված տարբերակի գործարկվողի ուղին
+help-show-explain = Նաև տպել, թե ինչպես է ընտրվել տարբերակը՝ ստուգված յուրաքանչյուր աղբյուր, ֆայլը, որտեղից այն վերցվել է, և ցանկացած անհամապատասխանություն
+help-env = Տպել shell-ի hook, որն արտահանում է ընթացիկ պանակի { -godot }-ը
+help-env-long = { help-env }
+
+    Ավելացրեք hook-ը ձեր shell-ի պրոֆիլին, օրինակ՝ eval "$({ -gdvm } env --shell bash)" ~/.bashrc-ում։ Յուրաքանչյուր հուշման ժամանակ այն GODOT-ին վերագրում է պանակի կողմից օգտագործվող { -godot(case: "genitive") } գործարկվողի ուղին, GODOT_VERSION-ին՝ դրա տարբերակը, իսկ GDVM_RESOLVED_SOURCE-ին՝ թե որտեղից է վերցվել տարբերակը՝ pin, project կամ default։ Տարբերակը գտնվում է նույն կերպ, ինչպես "{ -gdvm } run"-ի համար, բայց ոչինչ չի տեղադրվում, ուստի փոփոխականները հեռացվում են, քանի դեռ այդ տարբերակը տեղադրված չէ։
+
+    Փոփոխականները քեշավորվում են, մինչև փոխվի այն ֆայլերից որևէ մեկը, որոնցից դրանք կարդացվել են, օրինակ՝ գամումը, որպեսզի հուշումը արագ մնա։
+help-env-shell = Shell-ը, որի համար տպել hook-ը
+help-env-export = Տպել ընթացիկ պանակի փոփոխականները hook-ի փոխարեն
 help-cache-path = Ցույց տալ նշված { -godot } տարբերակի ներբեռնման պահված արխիվի ուղին
 help-link = Կապել { -godot(case: "genitive") } որոշակի տարբերակի գործարկվողը նշված ուղու հետ
 help-list = Ցուցադրել բոլոր տեղադրված { -godot } տարբերակները
@@ -91,8 +107,35 @@ help-prune-long = { help-prune }
 
     Լռելյայն prune-ը հեռացնում է այն տեղադրումները, որոնք երկար ժամանակ չեն օգտագործվել, ինչպես նաև հնացած քեշավորված ներբեռնման արխիվները՝ պահպանելով յուրաքանչյուր տեղադրում, որին դեռ ցույց է տալիս ակտիվ հղում։ Որպես լռելյայն սահմանված տեղադրումը երբեք չի հեռացվում՝ անկախ տրված դրոշակներից։ Հնության շեմը կարգավորելի է « { -gdvm } config set prune.max-age-days <օրեր> » հրամանով (լռելյայն՝ { $default_days } օր)։
 help-prune-all = Հեռացնել բոլոր տեղադրումներն ու քեշավորված արխիվները՝ անկախ հնությունից։ Ակտիվ հղում ունեցող տեղադրումները պահպանվում են, եթե նաև --force տրված չէ։
-help-prune-force = Անտեսել հղումները, որպեսզի միայն հղումով հղվող տեղադրումները նույնպես հնարավոր լինի հեռացնել։
+help-prune-force = Անտեսել հղումները, որպեսզի միայն հղումով հղվող տեղադրումները նույնպես հնարավոր լինի հեռացնել։ Ինքնաբավ տեղադրումները նույնպես հեռացվում են իրենց խմբագրիչի տվյալների հետ։
 help-prune-dry-run = Ցույց տալ, թե ինչ կհեռացվեր՝ առանց որևէ բան ջնջելու։
+help-logs = Ցույց տալ { -godot(case: "genitive") } անջատված գործարկումների ելքը
+help-logs-long = { help-logs }
+
+    Երբ { -godot(case: "definite") } գործարկվում է կոնսոլից անջատված, դրա ելքը յուրաքանչյուր գործարկման համար գրվում է նոր մատյանի ֆայլում, որն անվանվում է ըստ տարբերակի և նախագծի։ Մատյանները հեռացվում են { $default_days } օր հետո, ինչը կարելի է փոխել « { -gdvm } config set logs.max-age-days <օրեր> » հրամանով։
+help-logs-follow = Շարունակել տպել ելքը գրվելուն զուգընթաց՝ անցնելով ավելի նոր գործարկումներին, երբ դրանք սկսվում են։
+help-logs-last = Ցույց տալ վերջին գործարկումներից այսքանի մատյանները։
+logs-none = Անջատված գործարկումներ դեռ չեն գրանցվել։
+help-ps = Ցուցակել { -gdvm(case: "genitive") } կողմից գործարկված { -godot(case: "genitive") } գործընթացները, որոնք դեռ աշխատում են
+help-stop = Կանգնեցնել { -gdvm(case: "genitive") } կողմից գործարկված { -godot(case: "genitive") } գործընթացը
+help-stop-pid = "{ -gdvm } ps"-ի ցույց տված գործընթացի ID-ն
+ps-none = { -gdvm(case: "genitive") } կողմից գործարկված { -godot(case: "genitive") } գործընթացներ չեն աշխատում։
+ps-process = { $pid }. { $version }, գործարկվել է { $started }
+ps-process-project = { $pid }. { $version }՝ { $project }-ի համար, գործարկվել է { $started }
+ps-stopped-detail = { $pid } ({ $version })
+help-crashes = Ցուցակել կոնսոլին կցված { -godot(case: "genitive") } գործարկումների համար պահված վթարների գրառումները
+help-crashes-long = { help-crashes }
+
+    Երբ { -godot(case: "definite") } գործարկվում է կոնսոլին կցված և ավարտվում է վթարով, { -gdvm(case: "definite") } տպում է ամփոփում և պահում է վթարի գրառում՝ ճշգրիտ տարբերակով, տարբերակի տեսակով, ռեեստրով, արգումենտներով և նախագծով, որը պատրաստ է կցվելու սխալի հաշվետվությանը։ { -godot(case: "genitive") } տպած վերջին տողերը նույնպես պահելու համար միացրեք ելքի գրանցումը « { -gdvm } config set crashes.capture-output true » հրամանով։
+crashes-none = Վթարներ չեն գրանցվել։
+crashes-entry = { $time }. { $version } ({ $variant }), { $reason }, պահված է { $path }-ում
+godot-crashed = { -godot } { $version }-ը վթարի ենթարկվեց ({ $reason })։
+crash-label-version = Տարբերակ
+crash-label-variant = Տեսակ
+crash-label-registry = Ռեեստր
+crash-label-args = Արգումենտներ
+crash-label-project = Նախագիծ
+crash-saved = Վթարի գրառումը պահվեց { $path }-ում։
 prune-nothing-dry-run = Ոչինչ չէր հեռացվի։
 prune-nothing-removed = Հեռացնելու բան չկա. ամեն ինչ օգտագործվում է կամ հնության շեմի սահմաններում է։
 prune-preserved-by-link =
@@ -100,12 +143,80 @@ prune-preserved-by-link =
         [one] Պահպանվեց { $count } տեղադրում, որը դեռ հղվում է հղումով։
        *[other] Պահպանվեց { $count } տեղադրում, որոնք դեռ հղվում են հղումով։
     }
+help-dedupe = Կիսել նույնական ֆայլերը տեղադրումների միջև՝ սկավառակի տարածք խնայելու համար
+help-dedupe-long = { help-dedupe }
+
+    Տարբեր տեղադրումներում նույն բովանդակությամբ ֆայլերը փոխարինվում են copy-on-write կլոններով, եթե ֆայլային համակարգը դա աջակցում է, իսկ հակառակ դեպքում՝ կոշտ հղումներով։ Օգտագործեք «{ -gdvm } config set install.dedupe true»՝ յուրաքանչյուր տեղադրումից հետո դա ավտոմատ անելու համար։
+help-dedupe-dry-run = Ցույց տալ, թե որքան տարածք կխնայվեր՝ առանց որևէ բան փոխելու։
+dedupe-nothing = Կիսելու համար նույնական ֆայլեր չկան։
+dedupe-shared-files =
+    { $count ->
+        [one] Տեղադրումների միջև կիսվեց { $count } նույնական ֆայլ, խնայվեց { size-display }։
+       *[other] Տեղադրումների միջև կիսվեց { $count } նույնական ֆայլ, խնայվեց { size-display }։
+    }
+dedupe-would-share-files =
+    { $count ->
+        [one] Տեղադրումների միջև կկիսվեր { $count } նույնական ֆայլ, կխնայվեր { size-display }։
+       *[other] Տեղադրումների միջև կկիսվեր { $count } նույնական ֆայլ, կխնայվեր { size-display }։
+    }
+warning-dedupe-failed = Չհաջողվեց կիսել նույնական ֆայլերը տեղադրումների միջև. { $error }
+warning-shims-sync-failed = Չհաջողվեց թարմացնել { -godot(case: "genitive") } տարբերակային շիմերը. { $error }
+warning-cache-trim-failed = Չհաջողվեց ներբեռնումների քեշը փոքրացնել մինչև cache.max-size. { $error }
+warning-launch-log-failed = Չհաջողվեց ստեղծել մատյան այս գործարկման համար, ուստի դրա ելքը կանտեսվի. { $error }
+warning-crash-save-failed = Չհաջողվեց պահել վթարի գրառումը. { $error }
+warning-self-contained-project-unsupported = Այս հարթակում { -godot }-ը չի կարող խմբագրիչի տվյալները պահել ըստ նախագծի, ուստի օգտագործում է իր ընդհանուր տվյալները։ Փոխարենը տեղադրեք տարբերակը --self-contained-ով՝ դրա տվյալները առանձին պահելու համար։
+warning-self-contained-link-failed = Չհաջողվեց ձեր սեփական կարգավորումների, տվյալների և քեշի պանակները կապել նախագծի պանակներին, ուստի { -godot(case: "genitive") } գործարկած ծրագրերը կարող են չգտնել իրենց կարգավորումները. { $error }
+warning-carry-settings-failed = Չհաջողվեց տեղափոխել խմբագրիչի կարգավորումները. { $error }
 warning-broken-install-reinstalling = Տեղադրված { $version }-ը չունի իր գործարկվող ֆայլը. այն վերատեղադրվում է։
 
 help-force = Ստիպել վերատեղադրումը, նույնիսկ եթե տարբերակը արդեն տեղադրված է:
+help-install-system = Տեղադրել համակարգային ընդհանուր պահոցում՝ ձեր սեփականի փոխարեն: Պահանջում է պահոցում գրելու իրավունք:
+help-install-self-contained = Դարձնել տեղադրումը ինքնաբավ, որպեսզի դրա { -godot(case: "definite") } պահի իր սեփական խմբագրիչի կարգավորումները, քեշերը և արտահանման ձևանմուշները տեղադրման ներսում։
+help-locked = Պահանջել, որ gdvm.lock-ը համապատասխանի ամրակցմանը, իսկ արխիվները՝ նրա հեշերին: Սահմանեք GDVM_LOCKED=1՝ նույնը godot շիմերի համար անելու համար:
 help-redownload = Նորից ներբեռնել տարբերակը, նույնիսկ եթե այն արդեն տեղադրված է:
 help-yes = Բաց թողնել հեռացման հաստատման հուշումը
 help-remove-yes-deprecated = [հնացած] Այս դրոշակը անօգուտ է և կհեռացվի ապագա թողարկումներում:
+help-remove-system = Հեռացնել համակարգային ընդհանուր պահոցից: Պահանջում է պահոցում գրելու իրավունք:
+help-verify = Ստուգել տեղադրումները՝ փոփոխված, բացակայող կամ ավելացված ֆայլերի համար
+help-verify-long = { help-verify }
+
+    { -gdvm }-ի կատարած յուրաքանչյուր տեղադրում պահում է անդորրագիր՝ իր յուրաքանչյուր ֆայլի հեշով։ Այս հրամանը կրկին հեշավորում է տեղադրված ֆայլերը և համեմատում անդորրագրի հետ։ Երբ տեղադրումը փոխվել է, { -gdvm }-ն առաջարկում է այն վերատեղադրել քեշավորված արխիվից։
+help-verify-version = Ստուգվող տեղադրված տարբերակը (օրինակ՝ 4.2 կամ 4.2-stable)։ Բաց թողնելու դեպքում ստուգվում են բոլոր տեղադրումները։
+help-verify-yes = Վերատեղադրել փոխված տեղադրումները քեշավորված արխիվից՝ առանց հարցնելու։
+verify-no-receipt = { $version }-ը չունի տեղադրման անդորրագիր՝ համեմատելու համար։ Վերատեղադրեք այն՝ անդորրագիր ստեղծելու համար։
+verify-install-changed = { $version }-ը տարբերվում է իր տեղադրման անդորրագրից ({ $modified } փոփոխված, { $missing } բացակայող, { $added } ավելացված).
+verify-file-modified = փոփոխված. { $path }
+verify-file-missing = բացակայող. { $path }
+verify-file-added = ավելացված. { $path }
+verify-archive-not-cached = Արխիվը, որից տեղադրվել է { $version }-ը, այլևս քեշում չէ։ Վերատեղադրեք այն «{ -gdvm } install --force» հրամանով։
+verify-reinstall-prompt = Վերատեղադրե՞լ { $version }-ը քեշավորված արխիվից։ (այո/ոչ).
+verify-reinstall-tip = Կրկին գործարկեք այս հրամանը --yes-ով՝ քեշավորված արխիվից վերատեղադրելու համար։
+carry-settings-prompt = { $version }-ը գործարկվում է առաջին անգամ։ Տեղափոխե՞լ { $from }-ի խմբագրիչի կարգավորումները, թեմաները և սկրիպտների ձևանմուշները դրան։ (այո/ոչ):
+carry-settings-tip = { $from }-ի խմբագրիչի կարգավորումները նոր տարբերակներին առանց հարցնելու տեղափոխելու համար գործարկեք "{ -gdvm } config set godot.carry-settings always"։
+carry-settings-detail = խմբագրիչի կարգավորումներ { $from }-ից
+carry-settings-backup = Փոխարինված ֆայլերի պահուստային պատճենները պահվեցին { $path }-ում։
+help-outdated = Ցույց տալ տեղադրված, լռելյայն և ամրակցված տարբերակների ավելի նոր կառուցումները
+help-outdated-long = { help-outdated }
+
+    Յուրաքանչյուր տեղադրված տարբերակի, լռելյայն տարբերակի և ընթացիկ նախագծի ամրակցման համար ցույց է տալիս ամենանոր ուղղիչ թողարկումը, ամենանոր փոքր թողարկումը և ամենանոր նախաթողարկումը։ Ավարտվում է ոչ զրոյական կոդով, երբ հասանելի է ուղղիչ կամ փոքր թարմացում, կամ նախաթողարկման ավելի նոր նախաթողարկում։
+outdated-source-installed = տեղադրված
+outdated-source-default = լռելյայն
+outdated-source-pinned = ամրակցված
+outdated-patch = Ուղղիչ՝
+outdated-minor = Փոքր՝
+outdated-prerelease = Նախաթողարկում՝
+outdated-up-to-date = Ամեն ինչ թարմացված է։
+warning-outdated-check-failed = Չհաջողվեց ստուգել { $version }-ի ավելի նոր կառուցումները. { $error }
+help-update = Տեղադրել յուրաքանչյուր տեղադրված շարքի ամենանոր ուղղիչ թողարկումը
+help-update-long = { help-update }
+
+    Յուրաքանչյուր տեղադրված major.minor շարքի և տարբերակի համար տեղադրում է ամենանոր կայուն ուղղիչ թողարկումը և գրանցված հղումներն ուղղում դրան։ Օգտագործեք --default՝ լռելյայն տարբերակը նույնպես տեղափոխելու համար, և --remove՝ փոխարինված տեղադրումները հեռացնելու համար։
+help-update-version = Թարմացնել միայն այս տարբերակին համապատասխանող տեղադրումները
+help-update-default = Տեղափոխել լռելյայն տարբերակը թարմացված տեղադրմանը
+help-update-remove = Հեռացնել թարմացմամբ փոխարինված տեղադրումները
+update-up-to-date = Բոլոր տեղադրված շարքերն իրենց ամենանոր ուղղիչ թողարկման վրա են։
+update-kept-default = { $version }-ը պահպանվում է, քանի որ այն լռելյայն տարբերակն է։ Փոխանցեք --default՝ այն տեղափոխելու համար։
+warning-update-remove-failed = Չհաջողվեց հեռացնել { $version }-ը. { $error }
 help-link-version = Այն տարբերակը, որը պետք է կապվի։ Եթե այն չի տրվում, տարբերակը որոշվում է ընթացիկ պանակի կամ լռելյայն տարբերակի հիման վրա։
 help-link-path = Ուղին, որտեղ կստեղծվի հղումը կամ պատճենը, օրինակ «{ $platform ->
     [windows] godot.exe
@@ -141,6 +252,7 @@ help-console = { -godot(case: "definite") } կատարողական կոնսոլ
 
 help-default = Կառավարել լռելյայն տարբերակը
 help-default-version = Տարբերակը, որը պետք է տեղադրվի լռելյայն (օրինակ՝ 4.2 կամ 4.2-stable):
+help-default-float = Հետևել տարբերակի նկարագրին մեկ կառուցման փոխարեն՝ օգտագործելով ամենանոր համապատասխան տեղադրումը
 no-default-set = Լռելյայն տարբերակը սահմանված չէ: Գործարկեք "{ -gdvm } use <version>"՝ լռելյայն տարբերակը համակարգային մակարդակով սահմանելու համար, կամ "{ -gdvm } pin <version>"՝ լռելյայն տարբերակը ընթացիկ պանակի համար սահմանելու համար:
 
 warning-prerelease = Դուք տեղադրում եք նախնական թողարկում ({$branch}).
@@ -167,6 +279,9 @@ status-would-free = Կազատվի
 status-would-prune = Կմաքրվի
 status-removing = Հեռացվում է
 status-running = Գործարկում
+status-carried-settings = Տեղափոխված է
+status-log = Մատյան
+status-stopped = Կանգնեցված է
 status-cleared = Մաքրվեց
 status-refreshed = Թարմացվեց
 status-skipped = Բաց թողնվեց
@@ -195,6 +310,8 @@ progress-eta =
 unsupported-platform = Չաջակցվող համակարգ
 unsupported-architecture = Չաջակցվող ստեղծածություն
 error-checksum-mismatch = Ստորակետի չափազանելը չհամընկեց ֆայլի { $file }
+error-lock-hash-mismatch = { $version }-ի արխիվը չի համապատասխանում { $path }-ում գրանցված հեշին: Գործարկեք `{ -gdvm } pin --update-lock`, եթե նոր կառուցումը սպասված է:
+error-lock-platform-missing = { $path }-ում { $version }-ի համար { $platform } հարթակի հեշ գրանցված չէ, ուստի արխիվը հնարավոր չէ ստուգել: Գործարկեք `{ -gdvm } pin --update-lock`՝ այն ավելացնելու համար:
 error-invalid-sha-length = Անվավեր SHA երկարություն { $length }
 error-size-mismatch = Չափի անհամապատասխանություն { $file } ֆայլի համար. սպասվում էր { $expected } բայթ, ստացվել է { $actual } բայթ։
 error-insecure-url = Մերժվում է { $url }-ի բեռնումը չգաղտնագրված կապով։ Թույլատրվում են միայն https:// և file:// URL-ները։ Սահմանեք GDVM_ALLOW_INSECURE_URLS միջավայրի փոփոխականը՝ չգաղտնագրված http:// URL-ները թույլատրելու համար։
@@ -204,6 +321,10 @@ error-response-too-large = { $url }-ի պատասխանը գերազանցում
 error-too-many-redirects = Չափազանց շատ վերաուղղորդումներ։
 error-config-invalid-number = Անվավեր արժեք { $key }-ի համար. { $value } (սպասվում էր թիվ)
 error-config-invalid-enum = Անվավեր արժեք { $key }-ի համար. { $value } (հնարավոր արժեքներ՝ { $expected })
+error-config-invalid-bool = Անվավեր արժեք { $key }-ի համար. { $value } (հնարավոր արժեքներ՝ true կամ false)
+error-config-invalid-size = Անվավեր արժեք { $key }-ի համար. { $value } (սպասվում է չափ, օրինակ՝ 512MiB կամ 10GiB)
+error-config-invalid-path = Անվավեր արժեք { $key }-ի համար. { $value } (սպասվում է բացարձակ ուղի)
+error-alias-invalid-name = Անվավեր այլանուն. { $name }։ Այլանունները սկսվում են տառով, պարունակում են միայն տառեր, թվանշաններ, «-» և «_», և չեն կարող լինել «stable», «latest» կամ «unset»։
 error-config-unknown-key = Անհայտ կարգավորման բանալի. { $key }
 error-invalid-path = Անվավեր ուղի. { $path }
 error-publish-missing-manifest = registry.json-ը բացակայում է
@@ -231,6 +352,11 @@ error-spec-empty-variant = Դատարկ տարբերակի անուն « { $inpu
 error-spec-empty-version = Դատարկ տարբերակ « { $input } »-ում
 error-system-time = Համակարգի ժամանակը UNIX դարաշրջանից առաջ է
 error-unrecognized-version-format = Տարբերակի չճանաչված ձևաչափ. { $input }
+error-invalid-version-range = Տարբերակների անվավեր միջակայք. { $input }: Օգտագործեք համեմատիչներ, օրինակ՝ >=4.2, <4.5, ~4.3 կամ ^4:
+error-lock-missing = { $path }-ը պարտադիր է, բայց գոյություն չունի: Գործարկեք `{ -gdvm } pin --update-lock`՝ այն ստեղծելու համար:
+error-lock-out-of-date = { $path }-ը չի համապատասխանում { -gdvm-toml(case: "locative") } ամրակցմանը: Գործարկեք `{ -gdvm } pin --update-lock`՝ այն թարմացնելու համար:
+error-no-pinned-version = Այստեղ կամ ծնող պանակում { -gdvm-toml(case: "locative") } ամրակցված տարբերակ չկա:
+error-alias-unknown = { $name } անունով այլանուն չկա։ Գործարկեք «{ -gdvm } alias list»՝ կարգավորված այլանունները տեսնելու համար։
 error-diagnose-problems =
     { $count ->
         [one] Հայտնաբերվել է { $count } խնդիր։
@@ -247,13 +373,19 @@ download-retrying = Ներբեռնումն ընդհատվեց, կրկին փոր
 download-resuming = Ընդհատված ներբեռնումը վերսկսվում է (արդեն ներբեռնված է { size-display }).
 warning-resume-verification-failed = Վերսկսված ներբեռնումը չի համապատասխանում սպասվող ստուգիչ գումարին. այն նորից ամբողջությամբ ներբեռնվում է։
 lock-waiting = Սպասում է { -gdvm(case: "genitive") } մեկ այլ գործընթացի ավարտին (կողպեք՝ { $resource })...
+relocated-to-xdg = { -gdvm }-ի ֆայլերը { $from }-ից տեղափոխվեցին XDG բազային պանակներ: Ձեր PATH-ում { $old_bin }-ը փոխարինեք { $bin }-ով:
+warning-relocate-leftovers = { $path }-ի որոշ ֆայլեր չտեղափոխվեցին, քանի որ նոր վայրում դրանք արդեն կան: Ստուգելուց հետո հեռացրեք դրանք:
 prune-skipped-error = { $item }-ը բաց է թողնվում. { $error }
 prune-skipped-in-use = { $item }-ը բաց է թողնվում. այն օգտագործվում է { -gdvm(case: "genitive") } մեկ այլ գործընթացի կողմից։
+prune-skipped-running = { $item }-ը բաց է թողնվում. { -godot(case: "definite") } աշխատում է դրանից։ Գործընթացները տեսնելու համար օգտագործեք "{ -gdvm } ps"։
+prune-skipped-self-contained = { $item }-ը բաց է թողնվում. այն ինքնաբավ է և պահում է խմբագրիչի կարգավորումներ։ Այն հեռացնելու համար օգտագործեք "{ -gdvm } remove" կամ "{ -gdvm } prune --force"։
 
 error-find-user-dirs = Չհաջողվեց գտնել օգտագործողի պանակները։
 warning-fetching-releases-using-cache = Սխալ թողարկումների ստացման ժամանակ՝ { $error }։ Օգտագործվում են պահված թողարկումները։
 
 error-version-not-found = Տարբերակը չի գտնվել:
+error-system-store-not-set = Համակարգային ընդհանուր պահոց սահմանված չէ: Սահմանեք այն "{ -gdvm } config set install.system-store <ուղի>" հրամանով կամ GDVM_SYSTEM_STORE միջավայրի փոփոխականով:
+error-system-install-read-only = { $version }-ը գտնվում է համակարգային ընդհանուր պահոցում: Այնտեղից հեռացնելու համար օգտագործեք "{ -gdvm } remove --system":
 error-archive-not-cached = {$version}-ի համար պահված արխիվ չի գտնվել։ Նախ տեղադրեք այն՝ քեշը լրացնելու համար։
 error-multiple-versions-found = Մի քանի տարբերակներ են համընկնում ձեր հարցմանը:
     {$list}
@@ -263,6 +395,8 @@ no-matching-releases = Համապատասխան թողարկումներ չեն
 available-releases = Հասանելի թողարկումներ:
 
 version-already-installed = Տարբերակը արդեն տեղադրված է։ օգտագործեք {$version}:
+install-self-contained = { $version }-ը ինքնաբավ է։ Դրա խմբագրիչի կարգավորումները և տվյալները պահվում են { $path }-ում։
+remove-self-contained = { $path }-ում պահված խմբագրիչի կարգավորումները և տվյալները հեռացվեցին դրա հետ։
 godot-executable-not-found = { -godot(case: "genitive") } գործարկվող ֆայլը չի գտնվել {$version} տարբերակի համար:
 error-link-exists = {$path} ուղին արդեն գոյություն ունի։ Օգտագործեք --force՝ վերագրելու համար։
 error-link-symlink = Չհաջողվեց ստեղծել հղումը {$link}-ից դեպի {$target}։
@@ -271,9 +405,18 @@ error-link-copy = Չհաջողվեց պատճենել ֆայլը։
 error-no-stable-releases-found = Կայուն թողարկումներ չեն գտնվել:
 
 error-starting-godot = Չհաջողվեց գործարկել { -godot(case: "definite") }։
+error-exec-failed = Չհաջողվեց գործարկել { $command }-ը։
+error-profile-not-found = { $profile } անունով գործարկման պրոֆիլ { -gdvm-toml(case: "locative") } չի գտնվել:
+error-env-profile-not-found = { $profile } միջավայրի պրոֆիլ { $path } հասցեում չի գտնվել:
+error-install-running = { $version }-ը հնարավոր չէ հեռացնել, քանի դեռ { -godot(case: "definite") } աշխատում է դրանից (գործընթաց { $pids }): Փակեք այն կամ նախ օգտագործեք "{ -gdvm } stop":
+error-self-contained-read-only = { $version }-ը գտնվում է համակարգային ընդհանուր պահոցում, որտեղ { -godot(case: "definite") } չի կարող պահել խմբագրիչի տվյալներ:
+error-process-not-tracked = { $pid } ID-ով աշխատող { -godot } գործընթաց { -gdvm(case: "genitive") } կողմից չի գործարկվել:
+error-process-stop-failed = Չհաջողվեց կանգնեցնել { $pid } գործընթացը:
+error-process-unconfirmed = Հնարավոր չէ հաստատել, որ { $pid } գործընթացը դեռ { -gdvm(case: "genitive") } գործարկած { -godot }-ն է, ուստի այն չի կանգնեցվել:
 confirm-yes = այո
 
 default-set-success = Հաջողությամբ սահմանվել է {$version} որպես լռելյայն { -godot } տարբերակը։
+default-float-success = Լռելյայն { -godot } տարբերակն այժմ հետևում է {$spec}-ին, ներկայումս՝ {$version}։
 default-unset-success = Հաջողությամբ հեռացվեց լռելյայն { -godot } տարբերակը։
 provide-version-or-unset = Խնդրում ենք տրամադրել տարբերակ՝ սահմանելու համար լռելյայն կամ 'unset'՝ լռելյայն տարբերակը հեռացնելու համար։
 
@@ -313,6 +456,8 @@ help-pin-long = { help-pin }
 
     Սա կստեղծի { -gdvm-toml } ֆայլ ընթացիկ պանակում գամված տարբերակով: Երբ դուք կգործարկեք "{ -gdvm } run" այս պանակում կամ դրա ենթապանակներում, կօգտագործվի գամված տարբերակը լռելյայն տարբերակի փոխարեն:
 
+    Օգտագործվում են նաև այլ տարբերակների կառավարիչների համար գամված տարբերակները՝ .godot-version ֆայլը, godot տողը .tool-versions-ում կամ godot-ը [tools]-ի տակ mise.toml-ում։ Յուրաքանչյուր պանակում առաջնահերթությունն ունի { -gdvm-toml }-ը, ապա { -gdvmrc }-ը, .godot-version-ը, .tool-versions-ը և mise.toml-ը՝ այդ հերթականությամբ։ Հաղթում է գամում պարունակող ամենամոտ պանակը։ Անջատեք սա "{ -gdvm } config set pin.tool-files false" հրամանով։
+
     Սա օգտակար է, երբ ցանկանում եք օգտագործել որոշակի { -godot } տարբերակ նախագծի համար, առանց փոխելու լռելյայն տարբերակը ամբողջ համակարգում:
 
     Ներկայումս սա գրում է նաև հնացած { -gdvmrc } ֆայլը։ { -gdvm(case: "genitive") } հին տարբերակների հետ համատեղելիության համար: Այն կհեռացվի ապագա թողարկումում, ուստի խորհուրդ է տրվում անցնել նոր { -gdvm-toml } ձևաչափին և հեռացնել { -gdvmrc } ֆայլը, եթե այն կա:
@@ -320,7 +465,9 @@ help-pin-long = { help-pin }
     Դուք կարող եք անջատել { -gdvmrc } ֆայլի գրումը --no-legacy դրոշակով:
 help-pin-version = Տարբերակը, որը պետք է գամել
 help-no-legacy = Չգրել հնացած { -gdvmrc } համատեղելիության ֆայլը
+help-update-lock = Ամրակցումը լուծել ճշգրիտ կառուցման և գրանցել այն gdvm.lock-ում
 pinned-success = {$version} տարբերակը հաջողությամբ գամվեց { -gdvm-toml(case: "locative") }
+lock-updated = { $version }-ը կողպվեց { $path }-ում
 error-pin-version-not-found = Չհաջողվեց գամել {$version} տարբերակը
 
 error-file-not-found = Ֆայլը չի գտնվել։ Հնարավոր է, որ այն գոյություն չունի սերվերի վրա։
@@ -333,6 +480,24 @@ error-post-upgrade-action-failed = { $id } քայլը ձախողվեց թարմ
 error-failed-reading-project-godot = Չհաջողվեց կարդալ project.godot ֆայլը, հնարավոր չէ ինքնուրույն որոշել նախագծի տարբերակը:
 warning-using-project-version = Օգտագործվում է project.godot-ում սահմանված տարբերակը ({ $version }).
 warning-gdvmrc-detected = Հայտնաբերվել է անհատական  { -gdvmrc } ֆայլ:  { -gdvmrc } ֆայլերի աջակցությունը հնացած է և կհեռացվի ապագա թողարկումում: Խնդրում ենք անցնել նոր գամման ֆայլին, որն օգտագործվում է `{ -gdvm } pin`-ի կողմից:
+warning-lock-out-of-date = { $path }-ն այլևս չի համապատասխանում { -gdvm-toml(case: "locative") } ամրակցմանը և անտեսվում է: Գործարկեք `{ -gdvm } pin --update-lock`՝ այն թարմացնելու համար:
+warning-lock-hash-mismatch = { $version }-ի արխիվը չի համապատասխանում { $path }-ում գրանցված հեշին:
+lock-platform-missing = { $path }-ում { $version }-ի համար { $platform } հարթակի հեշ գրանցված չէ, ուստի արխիվը չի ստուգվել:
+
+explain-header = Ինչպես է ընտրվել { -godot(case: "genitive") } տարբերակը։
+explain-source-explicit = հրամանի տող
+explain-source-pin = գամում
+explain-source-project = նախագիծ
+explain-source-default = լռելյայն
+explain-not-set = սահմանված չէ
+explain-offer-from-file = { $spec }՝ { $path }-ից
+explain-selected = (ընտրված)
+explain-variant-inherited = { $variant } տարբերակը ժառանգվել է «{ $source }» աղբյուրից։
+explain-registry-inherited = { $registry } ռեեստրը ժառանգվել է «{ $source }» աղբյուրից։
+explain-project-mismatch = Նախագիծը պահանջում է { $project_version }, որը հակասում է { $version }-ին։
+explain-lock = Ամրակցված տարբերակը ճշգրտվել է { $path }-ով։
+explain-stale-lock = { $path }-ն անտեսվել է, քանի որ այլևս չի համապատասխանում ամրակցմանը։
+explain-executable = Գործարկվող ֆայլ՝ { $path }
 
 warning-project-version-mismatch =
     {"\u001b"}[33mԶգուշացում. project.godot-ում սահմանված տարբերակը չի համընկնում { $pinned ->
@@ -362,6 +527,16 @@ help-run-force-long =
     { help-run-force }
 
     Եթե դուք այս գործողությունը կատարեք, { -godot(case: "genitive") } պահանջված կամ սահմանված տարբերակը կարող է վերագրանցել նախագծի ֆայլը։ Եթե տարբերակը սահմանված է { -gdvmrc(case: "locative") }, խորհուրդ է տրվում թարմացնել այն, որպեսզի այն համապատասխանի նախագծի տարբերակին, կամ հեռացնել { -gdvmrc } ֆայլը, որպեսզի օգտագործվի նախագծի տարբերակը:
+help-run-profile = Օգտագործել { -gdvm-toml(case: "locative") } նշված գործարկման պրոֆիլը
+help-run-profile-long =
+    { help-run-profile }
+
+    Պրոֆիլը { -gdvm-toml(case: "locative") } [profiles.<անուն>] աղյուսակ է՝ args, env, launch-mode և working-dir բանալիներով: Այն հիմնվում է [run] աղյուսակի վրա, որը կիրառվում է ամեն անգամ, երբ գործարկվում է նախագծի { -godot(case: "definite") }, այդ թվում՝ godot հրամանով: "--"-ից հետո տրված արգումենտները հաջորդում են պրոֆիլի արգումենտներին:
+help-env-profile = Նշված .env.<պրոֆիլ> ֆայլը դնել .env-ի վրա
+help-env-profile-long =
+    { help-env-profile }
+
+    Ֆայլը (.env) կարդացվում է նախագծի թղթապանակից, իսկ նախագծի բացակայության դեպքում՝ ընթացիկ թղթապանակից: Միջավայրում արդեն սահմանված փոփոխականները պահպանվում են: { -gdvm-toml(case: "locative") } [dotenv] աղյուսակը կարող է նշել, թե որ փոփոխականներն են փոխանցվում { -godot }-ին և որոնք են արգելափակվում: GDVM_ENV_PROFILE միջավայրի փոփոխականը ընտրում է պրոֆիլը, երբ այս տարբերակը տրված չէ:
 
 help-config = Կառավարել { -gdvm(case: "genitive") } կարգավորումները
 help-format = Արտածման ձևաչափ. text (լռելյայն) կամ json
@@ -430,6 +605,17 @@ registry-removed = Հեռացվեց { $registry } ռեեստրը:
 registry-list-header = Կարգավորված ռեեստրներ.
 registry-tag-official = պաշտոնական
 
+help-alias = Կառավարել տարբերակների այլանունները
+help-alias-set = Ավելացնել կամ փոխել այլանուն
+help-alias-remove = Հեռացնել այլանունը
+help-alias-list = Ցուցադրել կարգավորված այլանունները
+help-alias-name = Այլանվան անունը։ Օգտագործեք այն տարբերակի փոխարեն, ըստ ցանկության՝ @անուն տեսքով։
+help-alias-spec = Տարբերակը, որը ներկայացնում է այլանունը, օրինակ՝ studio/csharp:4.3.1-rc2
+alias-set = { $name } այլանունն այժմ ներկայացնում է { $spec }-ը։
+alias-removed = Հեռացվեց { $name } այլանունը։
+alias-list-header = Կարգավորված այլանուններ.
+alias-list-empty = Այլանուններ կարգավորված չեն։
+
 error-invalid-registry-subcommand = Անվավեր ռեեստրի ենթահրաման: Օգտագործեք «add», «remove», «list» կամ «refresh»:
 registry-trust-warning = { $registry } ({ $url })-ը հատուկ ռեեստր է, ոչ թե պաշտոնականը: { -gdvm(case: "definite") } ստուգում է, որ ներբեռնումները համապատասխանում են ռեեստրի նշածին, բայց չի կարող ջանաչել, թե արդյոք դրանք անվտանգ են գործարկելու համար: Տեղադրեք դրանից միայն այն դեպքում, եթե վստահում եք նրան, ով այն կառավարում է:
 registry-trust-prompt = Վստահո՞ւմ եք այս ռեեստրին և ցանկանում եք շարունակել: (այո/ոչ).
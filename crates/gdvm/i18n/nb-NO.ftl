@@ -34,7 +34,23 @@ help-gdvm-version = Vis versjonen av { -godot }-versjonsbehandleren
 
 help-install = Installer en ny { -godot }-versjon
 help-run = Kjør en spesifikk { -godot }-versjon
+help-exec = Kjør en kommando med den valgte { -godot } i PATH
+help-exec-long = { help-exec }
+
+    Versjonen velges på samme måte som for «{ -gdvm } run» og installeres ved behov. Kommandoen kjøres med en mappe som inneholder en «godot»-lenke til den versjonen først i PATH, med GODOT og GODOT_BIN satt til stien til den kjørbare fila og GODOT_VERSION til versjonen, og med variablene fra en .env-fil i gjeldende mappe. Avslutningskoden sendes videre.
+
+    Eksempel: { -gdvm } exec 4.3 -- ./addons/gdUnit4/runtest.sh -a test
+help-exec-command = Kommandoen som skal kjøres og argumentene, etter «--»
 help-show = Vis stien til den kjørbare fila for den angitte { -godot }-versjonen
+help-show-explain = Vis også hvordan versjonen ble valgt: hver kilde som ble sjekket, fila den kom fra, og eventuelle avvik
+help-env = Skriv ut en skallkrok som eksporterer { -godot } for gjeldende mappe
+help-env-long = { help-env }
+
+    Legg kroken til i skallprofilen din, f.eks. eval "$({ -gdvm } env --shell bash)" i ~/.bashrc. Ved hver ledetekst setter den GODOT til stien til den kjørbare { -godot }-fila mappa bruker, GODOT_VERSION til versjonen, og GDVM_RESOLVED_SOURCE til hvor versjonen kom fra: pin, project eller default. Versjonen finnes på samme måte som for «{ -gdvm } run», men ingenting installeres, så variablene fjernes så lenge den versjonen ikke er installert.
+
+    Variablene mellomlagres til en av filene de ble lest fra endres, for eksempel et feste, slik at ledeteksten forblir rask.
+help-env-shell = Skallet kroken skal skrives ut for
+help-env-export = Skriv ut variablene for gjeldende mappe i stedet for kroken
 help-cache-path = Vis stien til nedlastingsarkivet i cachen for den oppgitte { -godot }-versjonen
 help-link = Opprett ei lenke frå ein { -godot }-versjon si kjørbar fil til  til en angitt sti
 help-list = List alle installerte { -godot }-versjoner
@@ -71,8 +87,35 @@ help-prune-long = { help-prune }
 
     Som standard fjerner prune installasjoner som ikke har vært brukt på en stund, og cacha nedlastingsarkiv som har blitt for gamle, mens installasjoner som fortsatt har en aktiv lenke inn i seg blir bevart. Installasjonen som er satt som standard blir aldri fjerna, uansett hvilke flagg som gis. Aldersgrensa kan settes med «{ -gdvm } config set prune.max-age-days <dager>» (standard { $default_days } dager).
 help-prune-all = Fjern alle installasjoner og cacha arkiv uavhengig av alder. Installasjoner som fortsatt har en aktiv lenke beholdes med mindre --force også er gitt.
-help-prune-force = Ignorer lenker, slik at installasjoner som bare er referert av en lenke også kan fjernes.
+help-prune-force = Ignorer lenker, slik at installasjoner som bare er referert av en lenke også kan fjernes. Selvstendige installasjoner fjernes også, sammen med redigeringsdataene sine.
 help-prune-dry-run = Vis hva som ville blitt fjerna uten å slette noe.
+help-logs = Vis utdataene fra frakoblede { -godot }-oppstarter
+help-logs-long = { help-logs }
+
+    Når { -godot } kjører frakoblet fra konsollen, skrives utdataene til en ny loggfil for hver oppstart, oppkalt etter versjonen og prosjektet. Logger fjernes etter { $default_days } dager, noe som kan endres med «{ -gdvm } config set logs.max-age-days <dager>».
+help-logs-follow = Fortsett å skrive ut utdata etter hvert som de skrives, og gå videre til nyere oppstarter når de starter.
+help-logs-last = Vis loggene fra så mange av de siste oppstartene.
+logs-none = Ingen frakoblede oppstarter er logget ennå.
+help-ps = List opp { -godot }-prosessene startet av { -gdvm } som fortsatt kjører
+help-stop = Stopp en { -godot }-prosess startet av { -gdvm }
+help-stop-pid = Prosess-ID-en vist av «{ -gdvm } ps»
+ps-none = Ingen { -godot }-prosesser startet av { -gdvm } kjører.
+ps-process = { $pid }: { $version }, startet { $started }
+ps-process-project = { $pid }: { $version } for { $project }, startet { $started }
+ps-stopped-detail = { $pid } ({ $version })
+help-crashes = List opp krasjrapportene som er lagret for { -godot }-kjøringer knyttet til konsollen
+help-crashes-long = { help-crashes }
+
+    Når { -godot } kjører knyttet til konsollen og avsluttes med et krasj, skriver { -gdvm } ut et sammendrag og lagrer en krasjrapport med nøyaktig versjon, variant, register, argumenter og prosjekt, klar til å legges ved en feilrapport. For også å lagre de siste linjene { -godot } skrev ut, slå på oppfanging av utdata med «{ -gdvm } config set crashes.capture-output true».
+crashes-none = Ingen krasj er registrert.
+crashes-entry = { $time }: { $version } ({ $variant }), { $reason }, lagret i { $path }
+godot-crashed = { -godot } { $version } krasjet ({ $reason }).
+crash-label-version = Versjon
+crash-label-variant = Variant
+crash-label-registry = Register
+crash-label-args = Argumenter
+crash-label-project = Prosjekt
+crash-saved = En krasjrapport ble lagret i { $path }.
 prune-nothing-dry-run = Ingenting ville blitt fjerna.
 prune-nothing-removed = Ingenting å fjerne; alt er i bruk eller innenfor aldersgrensa.
 prune-preserved-by-link =
@@ -80,12 +123,80 @@ prune-preserved-by-link =
         [one] Beholdt { $count } installasjon som fortsatt er referert av en lenke.
        *[other] Beholdt { $count } installasjoner som fortsatt er refererte av en lenke.
     }
+help-dedupe = Del identiske filer mellom installasjoner for å spare diskplass
+help-dedupe-long = { help-dedupe }
+
+    Filer med samme innhold i ulike installasjoner erstattes med kopier-ved-skriving-kloner der filsystemet støtter det, og med harde lenker ellers. Bruk «{ -gdvm } config set install.dedupe true» for å gjøre dette automatisk etter hver installasjon.
+help-dedupe-dry-run = Vis hvor mye plass som ville blitt spart uten å endre noe.
+dedupe-nothing = Ingen identiske filer igjen å dele.
+dedupe-shared-files =
+    { $count ->
+        [one] Delte { $count } identisk fil mellom installasjoner og sparte { size-display }.
+       *[other] Delte { $count } identiske filer mellom installasjoner og sparte { size-display }.
+    }
+dedupe-would-share-files =
+    { $count ->
+        [one] Ville delt { $count } identisk fil mellom installasjoner og spart { size-display }.
+       *[other] Ville delt { $count } identiske filer mellom installasjoner og spart { size-display }.
+    }
+warning-dedupe-failed = Kunne ikke dele identiske filer mellom installasjoner: { $error }
+warning-shims-sync-failed = Kunne ikke oppdatere de versjonerte { -godot }-shimene: { $error }
+warning-cache-trim-failed = Kunne ikke krympe nedlastingscachen til cache.max-size: { $error }
+warning-launch-log-failed = Kunne ikke opprette en logg for denne oppstarten, så utdataene blir forkastet: { $error }
+warning-crash-save-failed = Kunne ikke lagre krasjrapporten: { $error }
+warning-self-contained-project-unsupported = { -godot } kan ikke ha redigeringsdata per prosjekt på denne plattformen, så den bruker de delte redigeringsdataene. Installer heller versjonen med --self-contained for å holde dataene dens adskilt.
+warning-self-contained-link-failed = Kunne ikke lenke dine egne konfigurasjons-, data- og hurtigbuffermapper inn i prosjektets, så programmer { -godot } starter finner kanskje ikke innstillingene sine: { $error }
+warning-carry-settings-failed = Kunne ikke ta med redigeringsinnstillingene: { $error }
 warning-broken-install-reinstalling = Den installerte { $version } mangler den kjørbare fila, installerer den på nytt.
 
 help-force = Tving installasjon på nytt selv om versjonen allerede er installert.
+help-install-system = Installer i det systemomfattende installasjonslageret i stedet for ditt eget. Krever skrivetilgang til lageret.
+help-install-self-contained = Gjør installasjonen selvstendig, slik at { -godot } i den beholder sine egne redigeringsinnstillinger, hurtigbuffere og eksportmaler inne i installasjonen.
+help-locked = Krev at gdvm.lock stemmer med festingen og at arkivene samsvarer med hashene i den. Sett GDVM_LOCKED=1 for å gjøre det samme for godot-snarveiene.
 help-redownload = Last ned versjonen på nytt selv om den allerede er lasta ned i cachen.
 help-yes = Hopp over bekreftelsesprompt for fjerning
 help-remove-yes-deprecated = [avvikla] Dette flagget gjør ingenting og vil bli fjerna i ei fremtidig utgave.
+help-remove-system = Fjern fra det systemomfattende installasjonslageret. Krever skrivetilgang til lageret.
+help-verify = Sjekk installasjoner for endrede, manglende eller tillagte filer
+help-verify-long = { help-verify }
+
+    Hver installasjon { -gdvm } gjør lagrer en kvittering med hashen til hver av filene. Denne kommandoen hasher de installerte filene på nytt og sammenligner dem med kvitteringen. Når en installasjon er endret, tilbyr { -gdvm } å installere den på nytt fra det mellomlagrede arkivet.
+help-verify-version = Den installerte versjonen som skal sjekkes (f.eks. 4.2 eller 4.2-stable). Sjekker alle installasjoner når den utelates.
+help-verify-yes = Installer endrede installasjoner på nytt fra det mellomlagrede arkivet uten å spørre.
+verify-no-receipt = { $version } har ingen installasjonskvittering å sammenligne med. Installer den på nytt for å lage en.
+verify-install-changed = { $version } avviker fra installasjonskvitteringen ({ $modified } endret, { $missing } mangler, { $added } lagt til):
+verify-file-modified = endret:   { $path }
+verify-file-missing = mangler:  { $path }
+verify-file-added = lagt til: { $path }
+verify-archive-not-cached = Arkivet { $version } ble installert fra er ikke lenger mellomlagret. Installer den på nytt med «{ -gdvm } install --force».
+verify-reinstall-prompt = Installere { $version } på nytt fra det mellomlagrede arkivet? (ja/nei):
+verify-reinstall-tip = Kjør denne kommandoen igjen med --yes for å installere på nytt fra det mellomlagrede arkivet.
+carry-settings-prompt = Dette er første gang { $version } kjører. Vil du ta med redigeringsinnstillingene, temaene og skriptmalene fra { $from }? (ja/nei):
+carry-settings-tip = For å ta med redigeringsinnstillingene fra { $from } til nye versjoner uten å spørre, kjør «{ -gdvm } config set godot.carry-settings always».
+carry-settings-detail = redigeringsinnstillinger fra { $from }
+carry-settings-backup = Filene som ble erstattet, ble sikkerhetskopiert til { $path }.
+help-outdated = Vis nyere bygg av installerte, standard og festede versjoner
+help-outdated-long = { help-outdated }
+
+    For hver installerte versjon, standardversjonen og festingen i det gjeldende prosjektet vises den nyeste feilrettingsutgivelsen, den nyeste mindre utgivelsen og den nyeste forhåndsutgivelsen. Avslutter med en kode som ikke er null når en feilretting eller mindre oppdatering er tilgjengelig, eller en nyere forhåndsutgivelse av en forhåndsutgivelse.
+outdated-source-installed = installert
+outdated-source-default = standard
+outdated-source-pinned = festet
+outdated-patch = Feilretting:
+outdated-minor = Mindre:
+outdated-prerelease = Forhåndsutgivelse:
+outdated-up-to-date = Alt er oppdatert.
+warning-outdated-check-failed = Kunne ikke se etter nyere bygg av { $version }: { $error }
+help-update = Installer den nyeste feilrettingsutgivelsen av hver installerte serie
+help-update-long = { help-update }
+
+    For hver installerte major.minor-serie og variant installeres den nyeste stabile feilrettingsutgivelsen, og registrerte lenker pekes til den. Bruk --default for også å flytte standardversjonen, og --remove for å fjerne installasjonene den erstatter.
+help-update-version = Oppdater bare installasjoner som samsvarer med denne versjonen
+help-update-default = Flytt standardversjonen til den oppdaterte installasjonen
+help-update-remove = Fjern installasjonene som erstattes av oppdateringen
+update-up-to-date = Alle installerte serier er på sin nyeste feilrettingsutgivelse.
+update-kept-default = Beholder { $version } fordi den er standardversjonen. Bruk --default for å flytte den.
+warning-update-remove-failed = Kunne ikke fjerne { $version }: { $error }
 help-link-version = Versjonen som skal lenkes. Hvis den ikke oppgis, blir versjonen løst basert på gjeldende mappe eller standardversjonen.
 help-link-path = Stien der lenka eller kopien skal opprettes, f.eks. «{ $platform ->
     [windows] godot.exe
@@ -121,6 +232,7 @@ help-console = Kjør { -godot } med konsoll tilkobla. Standard er false på Wind
 
 help-default = Administrer standardversjonen
 help-default-version = Versjonen som skal settes som standard (f.eks. 4.2 eller 4.2-stable).
+help-default-float = Følg versjonsspesifikasjonen i stedet for ett bygg, og bruk den nyeste installerte som samsvarer
 no-default-set = Ingen standardversjon er satt. Kjør «{ -gdvm } use <version>» for å sette en standardversjon systemomfattende, eller «{ -gdvm } pin <version>» for å sette en standardversjon for den gjeldende mappa.
 
 warning-prerelease = Du installerer en forhåndsversjon ({$branch}).
@@ -147,6 +259,9 @@ status-would-free = Ville frigjort
 status-would-prune = Ville rydda
 status-removing = Fjerner
 status-running = Kjører
+status-carried-settings = Tatt med
+status-log = Logg
+status-stopped = Stoppet
 status-cleared = Tømt
 status-refreshed = Oppdatert
 status-skipped = Hoppa over
@@ -175,6 +290,8 @@ progress-eta =
 unsupported-platform = Plattforma støttes ikke
 unsupported-architecture = Arkitekturen støttes ikke
 error-checksum-mismatch = Sjekksumfeil for fila { $file }
+error-lock-hash-mismatch = Arkivet for { $version } samsvarer ikke med hashen lagret i { $path }. Kjør `{ -gdvm } pin --update-lock` hvis det nye bygget er forventet.
+error-lock-platform-missing = { $path } har ingen hash for { $version } på { $platform }, så arkivet kan ikke kontrolleres. Kjør `{ -gdvm } pin --update-lock` for å legge den til.
 error-invalid-sha-length = Ugyldig SHA-lengde { $length }
 error-size-mismatch = Størrelsesavvik for fila { $file }: forventa { $expected } byte, fikk { $actual } byte.
 error-insecure-url = Nekter å hente { $url } over ei ukryptert tilkobling. Bare https://- og file://-URL-er er tillatte. Sett miljøvariabelen GDVM_ALLOW_INSECURE_URLS for å tillate ukrypterte http://-URL-er.
@@ -184,6 +301,10 @@ error-response-too-large = Svaret fra { $url } overskrider den maksimale tillatt
 error-too-many-redirects = For mange omdirigeringer.
 error-config-invalid-number = Ugyldig verdi for { $key }: { $value } (forventet et tall)
 error-config-invalid-enum = Ugyldig verdi for { $key }: { $value } (mulige verdier: { $expected })
+error-config-invalid-bool = Ugyldig verdi for { $key }: { $value } (mulige verdier: true eller false)
+error-config-invalid-size = Ugyldig verdi for { $key }: { $value } (forventet en størrelse som 512MiB eller 10GiB)
+error-config-invalid-path = Ugyldig verdi for { $key }: { $value } (forventet en absolutt sti)
+error-alias-invalid-name = Ugyldig aliasnavn: { $name }. Aliasnavn begynner med en bokstav, inneholder bare bokstaver, sifre, «-» og «_», og kan ikke være «stable», «latest» eller «unset».
 error-config-unknown-key = Ukjent konfigurasjonsnøkkel: { $key }
 error-invalid-path = Ugyldig sti: { $path }
 error-publish-missing-manifest = registry.json mangler
@@ -211,6 +332,11 @@ error-spec-empty-variant = Tomt variantnavn i «{ $input }»
 error-spec-empty-version = Tom versjon i «{ $input }»
 error-system-time = Systemtiden er før UNIX-epoken
 error-unrecognized-version-format = Ukjent versjonsformat: { $input }
+error-invalid-version-range = Ugyldig versjonsområde: { $input }. Bruk sammenligninger som >=4.2, <4.5, ~4.3 eller ^4.
+error-lock-missing = { $path } kreves, men finnes ikke. Kjør `{ -gdvm } pin --update-lock` for å opprette den.
+error-lock-out-of-date = { $path } stemmer ikke med festingen i { -gdvm-toml }. Kjør `{ -gdvm } pin --update-lock` for å oppdatere den.
+error-no-pinned-version = Ingen versjon er festet i { -gdvm-toml } her eller i en overordnet mappe.
+error-alias-unknown = Det finnes ikke noe alias med navnet { $name }. Kjør «{ -gdvm } alias list» for å se de konfigurerte aliasene.
 error-diagnose-problems =
     { $count ->
         [one] { $count } problem funnet.
@@ -227,13 +353,19 @@ download-retrying = Nedlastinga ble avbrutt, prøver på nytt (forsøk { $attemp
 download-resuming = Gjenopptar avbrutt nedlasting ({ size-display } allerede lasta ned).
 warning-resume-verification-failed = Den gjenopptatte nedlastinga samsvarte ikke med forventa kontrollsum, laster den ned på nytt fra bunnen av.
 lock-waiting = Venter på at en annen { -gdvm }-prosess skal bli ferdig (lås: { $resource })...
+relocated-to-xdg = Flyttet filene til { -gdvm } fra { $from } til XDG-basismappene. Bytt ut { $old_bin } med { $bin } i PATH.
+warning-relocate-leftovers = Noen filer i { $path } ble ikke flyttet fordi de allerede finnes på det nye stedet. Fjern dem når du har sjekket dem.
 prune-skipped-error = Hopper over { $item }: { $error }
 prune-skipped-in-use = Hopper over { $item }: den er i bruk av en annen { -gdvm }-prosess.
+prune-skipped-running = Hopper over { $item }: { -godot } kjører fra den. Bruk «{ -gdvm } ps» for å se hvilke prosesser.
+prune-skipped-self-contained = Hopper over { $item }: den er selvstendig og har redigeringsinnstillinger. Bruk «{ -gdvm } remove» eller «{ -gdvm } prune --force» for å fjerne den.
 
 error-find-user-dirs = Klarte ikke å finne brukermappene.
 warning-fetching-releases-using-cache = Feil ved henting av utgivelser: { $error }. Bruker hurtigbuffer i stedet.
 
 error-version-not-found = Versjonen ble ikke funnet.
+error-system-store-not-set = Ingen systemomfattende installasjonslager er satt. Sett et med "{ -gdvm } config set install.system-store <sti>" eller miljøvariabelen GDVM_SYSTEM_STORE.
+error-system-install-read-only = { $version } ligger i det systemomfattende installasjonslageret. Bruk "{ -gdvm } remove --system" for å fjerne den derfra.
 error-archive-not-cached = Fant ingen arkiv i cachen for {$version}. Installer den først for å fylle cachen.
 error-multiple-versions-found = Flere versjoner samsvarer med forespørselen:
     {$list}
@@ -243,6 +375,8 @@ no-matching-releases = Ingen samsvarende utgivelser funna.
 available-releases = Tilgjengelige utgivelser:
 
 version-already-installed = Versjon {$version} er allerede installert.
+install-self-contained = { $version } er selvstendig. Redigeringsinnstillingene og -dataene lagres i { $path }.
+remove-self-contained = Redigeringsinnstillingene og -dataene den hadde i { $path } ble fjernet sammen med den.
 godot-executable-not-found = { -godot }-kjørbar fil ble ikke funnet for versjon {$version}.
 error-link-exists = Stien {$path} finnes allerede. Bruk --force for å overskrive.
 error-link-symlink = Klarte ikke å opprette lenke fra {$link} til {$target}.
@@ -251,9 +385,18 @@ error-link-copy = Klarte ikke å kopiere fil.
 error-no-stable-releases-found = Ingen stabile versjoner funnet.
 
 error-starting-godot = Kunne ikke starte { -godot }.
+error-exec-failed = Kunne ikke kjøre { $command }.
+error-profile-not-found = Fant ingen oppstartsprofil med navnet { $profile } i { -gdvm-toml }.
+error-env-profile-not-found = Fant ingen miljøprofil { $profile } på { $path }.
+error-install-running = { $version } kan ikke fjernes mens { -godot } kjører fra den (prosess { $pids }). Lukk den eller bruk «{ -gdvm } stop» først.
+error-self-contained-read-only = { $version } ligger i det systemomfattende installasjonslageret, der { -godot } ikke kan lagre redigeringsdata.
+error-process-not-tracked = Ingen kjørende { -godot }-prosess med ID { $pid } ble startet av { -gdvm }.
+error-process-stop-failed = Kunne ikke stoppe prosess { $pid }.
+error-process-unconfirmed = Kan ikke bekrefte at prosess { $pid } fortsatt er { -godot } som { -gdvm } startet, så den ble ikke stoppet.
 confirm-yes = ja
 
 default-set-success = Standardversjon {$version} er satt.
+default-float-success = Standardversjonen av { -godot } følger nå {$spec}, for øyeblikket {$version}.
 default-unset-success = Standardversjonen er fjerna.
 provide-version-or-unset = Vennligst oppgi en versjon for å sette som standard eller «unset» for å fjerne standardversjonen.
 
@@ -293,6 +436,8 @@ help-pin-long = { help-pin }
 
     Dette vil opprette en { -gdvm-toml }-fil i gjeldende mappe med den festa versjonen. Når du kjører «{ -gdvm } run» i denne katalogen eller noen av underkatalogene, vil den festa versjonen brukes i stedet for standardversjonen.
 
+    Versjoner som er festet for andre versjonsbehandlere brukes også: en .godot-version-fil, en godot-linje i .tool-versions, eller godot under [tools] i mise.toml. I hver mappe går { -gdvm-toml } foran disse, deretter { -gdvmrc }, .godot-version, .tool-versions og mise.toml, i den rekkefølgen. Den nærmeste mappa med et feste vinner. Slå dette av med «{ -gdvm } config set pin.tool-files false».
+
     Dette er nyttig når du vil bruke en spesifikk versjon av { -godot } for et prosjekt uten å endre standardversjonen systemomfattende.
 
     Dette skriver foreløpig også den eldre { -gdvmrc }-fila for kompatibilitet med eldre versjoner av { -gdvm }. Dette vil bli fjerna i en framtidig utgivelse, så det anbefales å gå over til det nye { -gdvm-toml }-formatet og fjerne { -gdvmrc }-fila hvis den finnes.
@@ -300,7 +445,9 @@ help-pin-long = { help-pin }
     Du kan deaktivere skriving av en { -gdvmrc }-fil med --no-legacy-flagget.
 help-pin-version = Versjonen som skal festes
 help-no-legacy = Ikke skriv den eldre { -gdvmrc }-kompatibilitetsfila
+help-update-lock = Løs opp festingen til en eksakt bygg og lagre den i gdvm.lock
 pinned-success = Versjon {$version} ble festet i { -gdvm-toml }
+lock-updated = Låste { $version } i { $path }
 error-pin-version-not-found = Kan ikke feste versjon {$version}
 
 error-file-not-found = Fil ble ikke funnet. Den finnes kanskje ikke på serveren.
@@ -313,6 +460,24 @@ error-post-upgrade-action-failed = Trinnet { $id } mislyktes etter oppgraderinga
 error-failed-reading-project-godot = Kunne ikke lese project.godot, kan ikke automatisk bestemme prosjektversjonen.
 warning-using-project-version = Bruker versjon { $version } definert i project.godot.
 warning-gdvmrc-detected = Ei egendefinert { -gdvmrc }-fil ble oppdaga. Støtte for { -gdvmrc }-filer er forelda og vil bli fjerna i en fremtidig utgivelse. Vennligst bytt til den nye festefila som brukes av `{ -gdvm } pin`.
+warning-lock-out-of-date = { $path } stemmer ikke lenger med festingen i { -gdvm-toml } og blir ignorert. Kjør `{ -gdvm } pin --update-lock` for å oppdatere den.
+warning-lock-hash-mismatch = Arkivet for { $version } samsvarer ikke med hashen lagret i { $path }.
+lock-platform-missing = { $path } har ingen hash for { $version } på { $platform }, så arkivet ble ikke kontrollert mot den.
+
+explain-header = Slik ble { -godot }-versjonen valgt:
+explain-source-explicit = kommandolinje
+explain-source-pin = feste
+explain-source-project = prosjekt
+explain-source-default = standard
+explain-not-set = ikke satt
+explain-offer-from-file = { $spec } fra { $path }
+explain-selected = (valgt)
+explain-variant-inherited = Varianten { $variant } ble arvet fra kilden «{ $source }».
+explain-registry-inherited = Registeret { $registry } ble arvet fra kilden «{ $source }».
+explain-project-mismatch = Prosjektet ber om { $project_version }, som er i konflikt med { $version }.
+explain-lock = Den festede versjonen ble innsnevret av { $path }.
+explain-stale-lock = { $path } ble ignorert fordi den ikke lenger stemmer med festingen.
+explain-executable = Kjørbar fil: { $path }
 
 warning-project-version-mismatch =
     {"\u001b"}[33mAdvarsel: Versjonen definert i project.godot samsvarer ikke med den { $pinned ->
@@ -342,6 +507,16 @@ help-run-force-long =
     Tving kjøring av prosjektet med den forespurte eller festa versjonen selv om den ikke samsvarer med prosjektversjonen.
 
     Hvis du gjør dette, kan den forespurte eller festa versjonen av { -godot } overskrive prosjektfila. Hvis du fester versjoner, anbefales det i stedet å oppdatere den festa versjonen i { -gdvmrc } for å samsvare med prosjektversjonen, eller fjerne { -gdvmrc }-fila for å bruke prosjektversjonen.
+help-run-profile = Bruk den navngitte oppstartsprofilen fra { -gdvm-toml }
+help-run-profile-long =
+    { help-run-profile }
+
+    En profil er en [profiles.<navn>]-tabell i { -gdvm-toml } med nøklene args, env, launch-mode og working-dir. Den bygger på [run]-tabellen, som gjelder hver gang prosjektets { -godot } kjøres, også gjennom godot-kommandoen. Argumenter gitt etter «--» kommer etter profilens argumenter.
+help-env-profile = Legg den navngitte .env.<profil>-filen over .env
+help-env-profile-long =
+    { help-env-profile }
+
+    Filen .env leses fra prosjektmappen, eller fra gjeldende mappe når det ikke finnes noe prosjekt. Variabler som allerede er satt i miljøet, beholdes. [dotenv]-tabellen i { -gdvm-toml } kan angi hvilke variabler som sendes videre til { -godot }, og hvilke som blokkeres. Miljøvariabelen GDVM_ENV_PROFILE velger en profil når dette valget ikke er gitt.
 
 help-config = Administrer { -gdvm }-konfigurasjon
 help-format = Utdataformat: text (standard) eller json
@@ -410,6 +585,17 @@ registry-removed = Fjernet registeret { $registry }.
 registry-list-header = Konfigurerte registre:
 registry-tag-official = offisielt
 
+help-alias = Administrer versjonsaliaser
+help-alias-set = Legg til eller endre et alias
+help-alias-remove = Fjern et alias
+help-alias-list = List opp konfigurerte aliaser
+help-alias-name = Navnet på aliaset. Bruk det i stedet for en versjon, eventuelt som @navn.
+help-alias-spec = Versjonen aliaset står for, f.eks. studio/csharp:4.3.1-rc2
+alias-set = Aliaset { $name } står nå for { $spec }.
+alias-removed = Fjernet aliaset { $name }.
+alias-list-header = Konfigurerte aliaser:
+alias-list-empty = Ingen aliaser er konfigurert.
+
 error-invalid-registry-subcommand = Ugyldig register-underkommando. Bruk «add», «remove», «list» eller «refresh».
 registry-trust-warning = { $registry } ({ $url }) er et egendefinert register, ikke det offisielle. { -gdvm } sjekker at nedlastinger stemmer med det registeret oppgir, men kan ikke vite om de er trygge å kjøre. Installer fra det bare hvis du stoler på de som driver det.
 registry-trust-prompt = Stoler du på dette registeret og vil fortsette? (ja/nei):
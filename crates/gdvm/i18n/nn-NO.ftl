@@ -34,7 +34,23 @@ help-gdvm-version = Vis versjonen av { -godot }-versjonsbehandlaren
 
 help-install = Installer ein ny { -godot }-versjon
 help-run = Køyr ein spesifikk { -godot }-versjon
+help-exec = Køyr ein kommando med den valde { -godot } i PATH
+help-exec-long = { help-exec }
+
+    Versjonen vert vald på same måte som for «{ -gdvm } run» og installert ved behov. Kommandoen køyrer med ei mappe som inneheld ei «godot»-lenkje til den versjonen fyrst i PATH, med GODOT og GODOT_BIN sett til stigen til den køyrbare fila og GODOT_VERSION til versjonen, og med variablane frå ei .env-fil i gjeldande mappe. Avslutningskoden vert send vidare.
+
+    Døme: { -gdvm } exec 4.3 -- ./addons/gdUnit4/runtest.sh -a test
+help-exec-command = Kommandoen som skal køyrast og argumenta, etter «--»
 help-show = Vis stien til den køyrberre fila for den gjevne { -godot }-versjonen
+help-show-explain = Vis òg korleis versjonen vart vald: kvar kjelde som vart sjekka, fila ho kom frå, og eventuelle avvik
+help-env = Skriv ut ein skalkrok som eksporterer { -godot } for gjeldande mappe
+help-env-long = { help-env }
+
+    Legg kroken til i skalprofilen din, t.d. eval "$({ -gdvm } env --shell bash)" i ~/.bashrc. Ved kvar ledetekst set han GODOT til stigen til den køyrbare { -godot }-fila mappa brukar, GODOT_VERSION til versjonen, og GDVM_RESOLVED_SOURCE til kvar versjonen kom frå: pin, project eller default. Versjonen vert funnen på same måte som for «{ -gdvm } run», men ingenting vert installert, så variablane vert fjerna så lenge den versjonen ikkje er installert.
+
+    Variablane vert mellomlagra til ei av filene dei vart lesne frå endrar seg, til dømes eit feste, slik at ledeteksten held seg rask.
+help-env-shell = Skalet kroken skal skrivast ut for
+help-env-export = Skriv ut variablane for gjeldande mappe i staden for kroken
 help-cache-path = Vis stigen til nedlastingsarkivet i cachen for den oppgjevne { -godot }-versjonen
 help-link = Opprett ei lenkje frå ein { -godot }-versjon si køyrbare fil til ein oppgjeven stig
 help-list = List alle installerte { -godot }-versjonar
@@ -71,8 +87,35 @@ help-prune-long = { help-prune }
 
     Som standard fjernar prune installasjonar som ikkje har vore bruka på ei stund, og cacha nedlastingsarkiv som har vorte for gamle, medan installasjonar som framleis har ei lenkje inn i seg vert tekne vare på. Installasjonen som er sett som standard vert aldri fjerna, uansett kva flagg som vert gjeve. Aldersgrensa kan setjast med «{ -gdvm } config set prune.max-age-days <dagar>» (standard { $default_days } dagar).
 help-prune-all = Fjern alle installasjonar og cacha arkiv uavhengig av alder. Installasjonar som framleis har ei aktiv lenkje vert tekne vare på med mindre --force òg er gjeve.
-help-prune-force = Ignorer lenkjer, slik at installasjonar som berre er refererte av ei lenkje òg kan fjernast.
+help-prune-force = Ignorer lenkjer, slik at installasjonar som berre er refererte av ei lenkje òg kan fjernast. Sjølvstendige installasjonar vert òg fjerna, saman med redigeringsdataa sine.
 help-prune-dry-run = Vis kva som ville vorte fjerna utan å sletta noko.
+help-logs = Vis utdata frå fråkopla { -godot }-oppstartar
+help-logs-long = { help-logs }
+
+    Når { -godot } køyrer fråkopla frå konsollen, vert utdata skrivne til ei ny loggfil for kvar oppstart, kalla opp etter versjonen og prosjektet. Loggar vert fjerna etter { $default_days } dagar, noko som kan endrast med «{ -gdvm } config set logs.max-age-days <dagar>».
+help-logs-follow = Hald fram med å skrive ut utdata etter kvart som dei vert skrivne, og gå vidare til nyare oppstartar når dei startar.
+help-logs-last = Vis loggane frå så mange av dei siste oppstartane.
+logs-none = Ingen fråkopla oppstartar er logga enno.
+help-ps = List opp { -godot }-prosessane starta av { -gdvm } som framleis køyrer
+help-stop = Stopp ein { -godot }-prosess starta av { -gdvm }
+help-stop-pid = Prosess-ID-en vist av «{ -gdvm } ps»
+ps-none = Ingen { -godot }-prosessar starta av { -gdvm } køyrer.
+ps-process = { $pid }: { $version }, starta { $started }
+ps-process-project = { $pid }: { $version } for { $project }, starta { $started }
+ps-stopped-detail = { $pid } ({ $version })
+help-crashes = List opp krasjrapportane som er lagra for { -godot }-køyringar knytte til konsollen
+help-crashes-long = { help-crashes }
+
+    Når { -godot } køyrer knytt til konsollen og vert avslutta med eit krasj, skriv { -gdvm } ut eit samandrag og lagrar ein krasjrapport med nøyaktig versjon, variant, register, argument og prosjekt, klar til å leggjast ved ein feilrapport. For òg å lagre dei siste linjene { -godot } skreiv ut, slå på oppfanging av utdata med «{ -gdvm } config set crashes.capture-output true».
+crashes-none = Ingen krasj er registrerte.
+crashes-entry = { $time }: { $version } ({ $variant }), { $reason }, lagra i { $path }
+godot-crashed = { -godot } { $version } krasja ({ $reason }).
+crash-label-version = Versjon
+crash-label-variant = Variant
+crash-label-registry = Register
+crash-label-args = Argument
+crash-label-project = Prosjekt
+crash-saved = Ein krasjrapport vart lagra i { $path }.
 prune-nothing-dry-run = Ingenting ville vorte fjerna.
 prune-nothing-removed = Ingenting å fjerna; alt er i bruk eller innanfor aldersgrensa.
 prune-preserved-by-link =
@@ -80,12 +123,80 @@ prune-preserved-by-link =
         [one] Tok vare på { $count } installasjon som framleis er referert av ei lenkje.
        *[other] Tok vare på { $count } installasjonar som framleis er refererte av ei lenkje.
     }
+help-dedupe = Del identiske filer mellom installasjonar for å spare diskplass
+help-dedupe-long = { help-dedupe }
+
+    Filer med same innhald i ulike installasjonar vert bytte ut med kopier-ved-skriving-klonar der filsystemet støttar det, og med harde lenkjer elles. Bruk «{ -gdvm } config set install.dedupe true» for å gjere dette automatisk etter kvar installasjon.
+help-dedupe-dry-run = Vis kor mykje plass som ville vorte spart utan å endre noko.
+dedupe-nothing = Ingen identiske filer att å dele.
+dedupe-shared-files =
+    { $count ->
+        [one] Delte { $count } identisk fil mellom installasjonar og sparte { size-display }.
+       *[other] Delte { $count } identiske filer mellom installasjonar og sparte { size-display }.
+    }
+dedupe-would-share-files =
+    { $count ->
+        [one] Ville delt { $count } identisk fil mellom installasjonar og spart { size-display }.
+       *[other] Ville delt { $count } identiske filer mellom installasjonar og spart { size-display }.
+    }
+warning-dedupe-failed = Kunne ikkje dele identiske filer mellom installasjonar: { $error }
+warning-shims-sync-failed = Kunne ikkje oppdatere dei versjonerte { -godot }-shimane: { $error }
+warning-cache-trim-failed = Kunne ikkje krympe nedlastingscachen til cache.max-size: { $error }
+warning-launch-log-failed = Kunne ikkje opprette ein logg for denne oppstarten, så utdata vert forkasta: { $error }
+warning-crash-save-failed = Kunne ikkje lagre krasjrapporten: { $error }
+warning-self-contained-project-unsupported = { -godot } kan ikkje ha redigeringsdata per prosjekt på denne plattforma, så han brukar dei delte redigeringsdataa. Installer heller versjonen med --self-contained for å halde dataa hans åtskilde.
+warning-self-contained-link-failed = Kunne ikkje lenke dine eigne konfigurasjons-, data- og mellomlagermapper inn i prosjektet sine, så program { -godot } startar finn kanskje ikkje innstillingane sine: { $error }
+warning-carry-settings-failed = Kunne ikkje ta med redigeringsinnstillingane: { $error }
 warning-broken-install-reinstalling = Den installerte { $version } manglar den køyrberre fila, installerer han på nytt.
 
 help-force = Tving installasjon på nytt sjølv om versjonen alt er installert.
+help-install-system = Installer i det systemomfattande installasjonslageret i staden for ditt eige. Krev skrivetilgang til lageret.
+help-install-self-contained = Gjer installasjonen sjølvstendig, slik at { -godot } i han held på sine eigne redigeringsinnstillingar, snøggbuffrar og eksportmalar inne i installasjonen.
+help-locked = Krev at gdvm.lock stemmer med festinga og at arkiva samsvarar med hashane i han. Set GDVM_LOCKED=1 for å gjere det same for godot-snarvegane.
 help-redownload = Last ned versjonen på nytt sjølv om han alt er lasta ned i cachen.
 help-yes = Hopp over stadfestingsprompt for fjerning
 help-remove-yes-deprecated = [avvikla] Dette flagget gjer ingenting og vil verta fjerna i ei framtidig utgåve.
+help-remove-system = Fjern frå det systemomfattande installasjonslageret. Krev skrivetilgang til lageret.
+help-verify = Sjekk installasjonar for endra, manglande eller tillagde filer
+help-verify-long = { help-verify }
+
+    Kvar installasjon { -gdvm } gjer lagrar ei kvittering med hashen til kvar av filene. Denne kommandoen hashar dei installerte filene på nytt og samanliknar dei med kvitteringa. Når ein installasjon er endra, tilbyr { -gdvm } å installere han på nytt frå det mellomlagra arkivet.
+help-verify-version = Den installerte versjonen som skal sjekkast (t.d. 4.2 eller 4.2-stable). Sjekkar alle installasjonar når han er utelaten.
+help-verify-yes = Installer endra installasjonar på nytt frå det mellomlagra arkivet utan å spørje.
+verify-no-receipt = { $version } har inga installasjonskvittering å samanlikne med. Installer han på nytt for å lage ei.
+verify-install-changed = { $version } avvik frå installasjonskvitteringa ({ $modified } endra, { $missing } manglar, { $added } lagt til):
+verify-file-modified = endra:    { $path }
+verify-file-missing = manglar:  { $path }
+verify-file-added = lagt til: { $path }
+verify-archive-not-cached = Arkivet { $version } vart installert frå er ikkje lenger mellomlagra. Installer han på nytt med «{ -gdvm } install --force».
+verify-reinstall-prompt = Installere { $version } på nytt frå det mellomlagra arkivet? (ja/nei):
+verify-reinstall-tip = Køyr denne kommandoen att med --yes for å installere på nytt frå det mellomlagra arkivet.
+carry-settings-prompt = Dette er første gong { $version } køyrer. Vil du ta med redigeringsinnstillingane, temaa og skriptmalane frå { $from }? (ja/nei):
+carry-settings-tip = For å ta med redigeringsinnstillingane frå { $from } til nye versjonar utan å spørje, køyr «{ -gdvm } config set godot.carry-settings always».
+carry-settings-detail = redigeringsinnstillingar frå { $from }
+carry-settings-backup = Filene som vart erstatta, vart tryggingskopierte til { $path }.
+help-outdated = Vis nyare bygg av installerte, standard og festa versjonar
+help-outdated-long = { help-outdated }
+
+    For kvar installerte versjon, standardversjonen og festinga i det gjeldande prosjektet blir den nyaste feilrettingsutgjevinga, den nyaste mindre utgjevinga og den nyaste førehandsutgjevinga viste. Avsluttar med ein kode som ikkje er null når ei feilretting eller mindre oppdatering er tilgjengeleg, eller ei nyare førehandsutgjeving av ei førehandsutgjeving.
+outdated-source-installed = installert
+outdated-source-default = standard
+outdated-source-pinned = festa
+outdated-patch = Feilretting:
+outdated-minor = Mindre:
+outdated-prerelease = Førehandsutgjeving:
+outdated-up-to-date = Alt er oppdatert.
+warning-outdated-check-failed = Kunne ikkje sjå etter nyare bygg av { $version }: { $error }
+help-update = Installer den nyaste feilrettingsutgjevinga av kvar installerte serie
+help-update-long = { help-update }
+
+    For kvar installerte major.minor-serie og variant blir den nyaste stabile feilrettingsutgjevinga installert, og registrerte lenkjer blir peika til henne. Bruk --default for òg å flytte standardversjonen, og --remove for å fjerne installasjonane ho erstattar.
+help-update-version = Oppdater berre installasjonar som samsvarar med denne versjonen
+help-update-default = Flytt standardversjonen til den oppdaterte installasjonen
+help-update-remove = Fjern installasjonane som blir erstatta av oppdateringa
+update-up-to-date = Alle installerte seriar er på den nyaste feilrettingsutgjevinga si.
+update-kept-default = Beheld { $version } fordi han er standardversjonen. Bruk --default for å flytte han.
+warning-update-remove-failed = Kunne ikkje fjerne { $version }: { $error }
 help-link-version = Versjonen som skal lenkjast. Viss ho ikkje vert oppgjeven, vert versjonen løyst basert på gjeldande mappe eller standardversjonen.
 help-link-path = Stien der lenkja eller kopien skal opprettast, t.d. «{ $platform ->
     [windows] godot.exe
@@ -121,6 +232,7 @@ help-console = Køyr { -godot } med konsoll tilkopla. Standard er false på Wind
 
 help-default = Administrer standardversjonen
 help-default-version = Versjonen som skal setjast som standard (t.d. 4.2 eller 4.2-stable).
+help-default-float = Følg versjonsspesifikasjonen i staden for eitt bygg, og bruk den nyaste installerte som samsvarar
 no-default-set = Ingen standardversjon er sett. Køyr «{ -gdvm } use <version>» for å setja ein standardversjon systemomfattande, eller «{ -gdvm } pin <version>» for å setja ein standardversjon for den gjeldende mappa.
 
 warning-prerelease = Du installerer ein førehandsversjon ({$branch}).
@@ -147,6 +259,9 @@ status-would-free = Ville frigjort
 status-would-prune = Ville rydda
 status-removing = Fjernar
 status-running = Køyrer
+status-carried-settings = Teke med
+status-log = Logg
+status-stopped = Stoppa
 status-cleared = Tømd
 status-refreshed = Oppdatert
 status-skipped = Hoppa over
@@ -175,6 +290,8 @@ progress-eta =
 unsupported-platform = Plattforma er ikkje støtta
 unsupported-architecture = Arkitekturen er ikkje støtta
 error-checksum-mismatch = Sjekksumfeil for fila { $file }
+error-lock-hash-mismatch = Arkivet for { $version } samsvarar ikkje med hashen lagra i { $path }. Køyr `{ -gdvm } pin --update-lock` viss det nye bygget er venta.
+error-lock-platform-missing = { $path } har ingen hash for { $version } på { $platform }, så arkivet kan ikkje kontrollerast. Køyr `{ -gdvm } pin --update-lock` for å leggje han til.
 error-invalid-sha-length = Ugyldig SHA-lengde { $length }
 error-size-mismatch = Storleiksavvik for fila { $file }: forventa { $expected } byte, fekk { $actual } byte.
 error-insecure-url = Nektar å hente { $url } over ei ukryptert tilkopling. Berre https://- og file://-URL-ar er tillatne. Set miljøvariabelen GDVM_ALLOW_INSECURE_URLS for å tillate ukrypterte http://-URL-ar.
@@ -184,6 +301,10 @@ error-response-too-large = Svaret frå { $url } overskrid den maksimale tillatne
 error-too-many-redirects = For mange omdirigeringar.
 error-config-invalid-number = Ugyldig verdi for { $key }: { $value } (venta eit tal)
 error-config-invalid-enum = Ugyldig verdi for { $key }: { $value } (moglege verdiar: { $expected })
+error-config-invalid-bool = Ugyldig verdi for { $key }: { $value } (moglege verdiar: true eller false)
+error-config-invalid-size = Ugyldig verdi for { $key }: { $value } (venta ein storleik som 512MiB eller 10GiB)
+error-config-invalid-path = Ugyldig verdi for { $key }: { $value } (venta ein absolutt sti)
+error-alias-invalid-name = Ugyldig aliasnamn: { $name }. Aliasnamn byrjar med ein bokstav, inneheld berre bokstavar, siffer, «-» og «_», og kan ikkje vere «stable», «latest» eller «unset».
 error-config-unknown-key = Ukjend konfigurasjonsnøkkel: { $key }
 error-invalid-path = Ugyldig sti: { $path }
 error-publish-missing-manifest = registry.json manglar
@@ -211,6 +332,11 @@ error-spec-empty-variant = Tomt variantnamn i «{ $input }»
 error-spec-empty-version = Tom versjon i «{ $input }»
 error-system-time = Systemtida er før UNIX-epoken
 error-unrecognized-version-format = Ukjent versjonsformat: { $input }
+error-invalid-version-range = Ugyldig versjonsområde: { $input }. Bruk samanlikningar som >=4.2, <4.5, ~4.3 eller ^4.
+error-lock-missing = { $path } er påkravd, men finst ikkje. Køyr `{ -gdvm } pin --update-lock` for å opprette han.
+error-lock-out-of-date = { $path } stemmer ikkje med festinga i { -gdvm-toml }. Køyr `{ -gdvm } pin --update-lock` for å oppdatere han.
+error-no-pinned-version = Ingen versjon er festa i { -gdvm-toml } her eller i ei overordna mappe.
+error-alias-unknown = Det finst ikkje noko alias med namnet { $name }. Køyr «{ -gdvm } alias list» for å sjå dei konfigurerte aliasa.
 error-diagnose-problems = { $count } problem funne.
 error-non-interactive-trust = Kan ikkje spørja om å stole på registeret «{ $registry }» ({ $url }) i ei økt som ikkje er interaktiv. Send --yes for å stole på det eksplisitt.
 error-non-interactive-value = Kan ikkje bede om ein verdi for «{ $key }» i ei økt som ikkje er interaktiv. Send verdien som eit argument i staden.
@@ -223,13 +349,19 @@ download-retrying = Nedlastinga vart avbroten, prøver på nytt (forsøk { $atte
 download-resuming = Tek opp att avbroten nedlasting ({ size-display } alt lasta ned).
 warning-resume-verification-failed = Den oppattekne nedlastinga samsvarte ikkje med venta kontrollsum, lastar ho ned på nytt frå botnen av.
 lock-waiting = Ventar på at ein annan { -gdvm }-prosess skal verta ferdig (lås: { $resource })...
+relocated-to-xdg = Flytte filene til { -gdvm } frå { $from } til XDG-basismappene. Byt ut { $old_bin } med { $bin } i PATH.
+warning-relocate-leftovers = Nokre filer i { $path } vart ikkje flytte fordi dei alt finst på den nye staden. Fjern dei når du har sjekka dei.
 prune-skipped-error = Hoppar over { $item }: { $error }
 prune-skipped-in-use = Hoppar over { $item }: han er i bruk av ein annan { -gdvm }-prosess.
+prune-skipped-running = Hoppar over { $item }: { -godot } køyrer frå han. Bruk «{ -gdvm } ps» for å sjå kva prosessar.
+prune-skipped-self-contained = Hoppar over { $item }: han er sjølvstendig og har redigeringsinnstillingar. Bruk «{ -gdvm } remove» eller «{ -gdvm } prune --force» for å fjerna han.
 
 error-find-user-dirs = Klarte ikkje å finne brukarmappene.
 warning-fetching-releases-using-cache = Feil ved henting av utgjevingar: { $error }. Brukar hurtigbuffer i staden.
 
 error-version-not-found = Versjonen vart ikkje funnen.
+error-system-store-not-set = Ingen systemomfattande installasjonslager er sett. Set eit med "{ -gdvm } config set install.system-store <sti>" eller miljøvariabelen GDVM_SYSTEM_STORE.
+error-system-install-read-only = { $version } ligg i det systemomfattande installasjonslageret. Bruk "{ -gdvm } remove --system" for å fjerna han derifrå.
 error-archive-not-cached = Fann ikkje noko arkiv i cachen for {$version}. Installer han fyrst for å fylle cachen.
 error-multiple-versions-found = Fleire versjonar samsvarar med førespurnaden:
     {$list}
@@ -239,6 +371,8 @@ no-matching-releases = Ingen samsvarande utgjevingar funne.
 available-releases = Tilgjengelege utgjevingar:
 
 version-already-installed = Versjon {$version} er alt installert.
+install-self-contained = { $version } er sjølvstendig. Redigeringsinnstillingane og -dataa vert lagra i { $path }.
+remove-self-contained = Redigeringsinnstillingane og -dataa han hadde i { $path } vart fjerna saman med han.
 godot-executable-not-found = { -godot }-køyrberr fil vart ikkje funnen for versjon {$version}.
 error-link-exists = Stigen {$path} finst allereie. Bruk --force for å overskrive.
 error-link-symlink = Klarte ikkje å opprette lenkje frå {$link} til {$target}.
@@ -247,9 +381,18 @@ error-link-copy = Klarte ikkje å kopiere fil.
 error-no-stable-releases-found = Ingen stabile utgivelser funne.
 
 error-starting-godot = Kunne ikkje starte { -godot }.
+error-exec-failed = Kunne ikkje køyre { $command }.
+error-profile-not-found = Fann ingen oppstartsprofil med namnet { $profile } i { -gdvm-toml }.
+error-env-profile-not-found = Fann ingen miljøprofil { $profile } på { $path }.
+error-install-running = { $version } kan ikkje fjernast medan { -godot } køyrer frå han (prosess { $pids }). Lukk han eller bruk «{ -gdvm } stop» først.
+error-self-contained-read-only = { $version } ligg i det systemomfattande installasjonslageret, der { -godot } ikkje kan lagre redigeringsdata.
+error-process-not-tracked = Ingen køyrande { -godot }-prosess med ID { $pid } vart starta av { -gdvm }.
+error-process-stop-failed = Kunne ikkje stoppe prosess { $pid }.
+error-process-unconfirmed = Kan ikkje stadfeste at prosess { $pid } framleis er { -godot } som { -gdvm } starta, så han vart ikkje stoppa.
 confirm-yes = ja
 
 default-set-success = Standardversjon {$version} er sett.
+default-float-success = Standardversjonen av { -godot } følgjer no {$spec}, for augneblinken {$version}.
 default-unset-success = Standardversjonen er fjerna.
 provide-version-or-unset = Ver venleg og oppgjev ein versjon for å setja som standard eller «unset» for å fjerne standardversjonen.
 
@@ -289,6 +432,8 @@ help-pin-long = { help-pin }
 
     Dette vil opprette ei { -gdvm-toml }-fil i gjeldande mappe med den festa versjonen. Når du køyrer «{ -gdvm } run» i denne katalogen eller nokre av underkatalogane, vil den festa versjonen verta bruka i staden for standardversjonen.
 
+    Versjonar som er festa for andre versjonshandsamarar vert òg bruka: ei .godot-version-fil, ei godot-linje i .tool-versions, eller godot under [tools] i mise.toml. I kvar mappe går { -gdvm-toml } føre desse, deretter { -gdvmrc }, .godot-version, .tool-versions og mise.toml, i den rekkjefølgja. Den næraste mappa med eit feste vinn. Slå dette av med «{ -gdvm } config set pin.tool-files false».
+
     Dette er nyttig når du vil bruke ein spesifikk versjon av { -godot } for eit prosjekt utan å endre standardversjonen systemomfattande.
 
     Dette skriv førebels òg den eldre { -gdvmrc }-fila for bakoverkompatibilitet med eldre versjonar av { -gdvm }. Dette vil verta fjerna i ei framtidig utgjeving, so det er tilrådd å gå over til det nye { -gdvm-toml }-formatet og fjerne { -gdvmrc }-fila om ho finst.
@@ -296,7 +441,9 @@ help-pin-long = { help-pin }
     Du kan deaktivere skriving av ei { -gdvmrc }-fil med --no-legacy-flagget.
 help-pin-version = Versjonen som skal festast
 help-no-legacy = Ikkje skriv den eldre { -gdvmrc }-kompatibilitetsfila
+help-update-lock = Løys opp festinga til eit eksakt bygg og lagre det i gdvm.lock
 pinned-success = Versjon {$version} vart festa i { -gdvm-toml }
+lock-updated = Låste { $version } i { $path }
 error-pin-version-not-found = Kan ikkje feste versjon {$version}
 
 error-file-not-found = Fil vart ikkje funnen. Ho finst kanskje ikkje på tenaren.
@@ -309,6 +456,24 @@ error-post-upgrade-action-failed = Trinnet { $id } mislukkast etter oppgradering
 error-failed-reading-project-godot = Kunne ikkje lesa project.godot, kan ikkje automatisk bestemme prosjektversjonen.
 warning-using-project-version = Brukar versjon { $version } definert i project.godot.
 warning-gdvmrc-detected = Ei eigendefinert { -gdvmrc }-fil vart oppdaga. Støtte for { -gdvmrc }-filer er forelda og vil verta fjerna i ei framtidig utgjeving. Ver venleg og byt til den nye festefila som vert bruka av `{ -gdvm } pin`.
+warning-lock-out-of-date = { $path } stemmer ikkje lenger med festinga i { -gdvm-toml } og blir ignorert. Køyr `{ -gdvm } pin --update-lock` for å oppdatere han.
+warning-lock-hash-mismatch = Arkivet for { $version } samsvarar ikkje med hashen lagra i { $path }.
+lock-platform-missing = { $path } har ingen hash for { $version } på { $platform }, så arkivet vart ikkje kontrollert mot han.
+
+explain-header = Slik vart { -godot }-versjonen vald:
+explain-source-explicit = kommandolinje
+explain-source-pin = feste
+explain-source-project = prosjekt
+explain-source-default = standard
+explain-not-set = ikkje sett
+explain-offer-from-file = { $spec } frå { $path }
+explain-selected = (vald)
+explain-variant-inherited = Varianten { $variant } vart arva frå kjelda «{ $source }».
+explain-registry-inherited = Registeret { $registry } vart arva frå kjelda «{ $source }».
+explain-project-mismatch = Prosjektet ber om { $project_version }, som er i konflikt med { $version }.
+explain-lock = Den festa versjonen vart innsnevra av { $path }.
+explain-stale-lock = { $path } vart ignorert fordi han ikkje lenger stemmer med festinga.
+explain-executable = Køyrbar fil: { $path }
 
 warning-project-version-mismatch =
     {"\u001b"}[33mÅtvaring: Versjonen definert i project.godot samsvarar ikkje med den { $pinned ->
@@ -339,6 +504,16 @@ help-run-force-long =
     { help-run-force }
 
     Viss du gjer dette, kan den ynskte eller festa versjonen av { -godot } overskrive prosjektfila. Viss du festar versjonar, er det tilrådd i staden å oppdatere den festa versjonen i { -gdvmrc } for å samsvara med prosjektversjonen, eller fjerne { -gdvmrc }-fila for å bruke prosjektversjonen.
+help-run-profile = Bruk den namngjevne oppstartsprofilen frå { -gdvm-toml }
+help-run-profile-long =
+    { help-run-profile }
+
+    Ein profil er ein [profiles.<namn>]-tabell i { -gdvm-toml } med nøklane args, env, launch-mode og working-dir. Han byggjer på [run]-tabellen, som gjeld kvar gong { -godot } for prosjektet køyrer, òg gjennom godot-kommandoen. Argument gjevne etter «--» kjem etter argumenta til profilen.
+help-env-profile = Legg den namngjevne .env.<profil>-fila over .env
+help-env-profile-long =
+    { help-env-profile }
+
+    Fila .env vert lesen frå prosjektmappa, eller frå gjeldande mappe når det ikkje finst noko prosjekt. Variablar som alt er sette i miljøet, vert haldne. [dotenv]-tabellen i { -gdvm-toml } kan seie kva variablar som vert sende vidare til { -godot }, og kva som vert blokkerte. Miljøvariabelen GDVM_ENV_PROFILE vel ein profil når dette valet ikkje er gjeve.
 
 help-config = Administrer { -gdvm }-konfigurasjon
 help-format = Utdataformat: text (standard) eller json
@@ -407,6 +582,17 @@ registry-removed = Fjerna registeret { $registry }.
 registry-list-header = Konfigurerte register:
 registry-tag-official = offisielt
 
+help-alias = Administrer versjonsalias
+help-alias-set = Legg til eller endre eit alias
+help-alias-remove = Fjern eit alias
+help-alias-list = List opp konfigurerte alias
+help-alias-name = Namnet på aliaset. Bruk det i staden for ein versjon, eventuelt som @namn.
+help-alias-spec = Versjonen aliaset står for, t.d. studio/csharp:4.3.1-rc2
+alias-set = Aliaset { $name } står no for { $spec }.
+alias-removed = Fjerna aliaset { $name }.
+alias-list-header = Konfigurerte alias:
+alias-list-empty = Ingen alias er konfigurerte.
+
 error-invalid-registry-subcommand = Ugyldig register-underkommando. Bruk «add», «remove», «list» eller «refresh».
 registry-trust-warning = { $registry } ({ $url }) er eit eigendefinert register, ikkje det offisielle. { -gdvm } sjekkar at nedlastingar stemmer med det registeret oppgjev, men kan ikkje vite om dei er trygge å køyre. Installer frå det berre om du stolar på dei som driv det.
 registry-trust-prompt = Stoler du på dette registeret og vil halde fram? (ja/nei):
@@ -34,7 +34,23 @@ help-gdvm-version = Показать версию менеджера верси
 
 help-install = Установить новую версию { -godot }
 help-run = Запустить определенную версию { -godot }
+help-exec = Выполнить команду с выбранной версией { -godot } в PATH
+help-exec-long = { help-exec }
+
+    Версия выбирается так же, как для "{ -gdvm } run", и при необходимости устанавливается. Команда запускается с каталогом, содержащим ссылку "godot" на эту версию, в начале PATH, с GODOT и GODOT_BIN, указывающими на путь к исполняемому файлу, GODOT_VERSION — на его версию, а также с переменными из файла .env в текущем каталоге. Код выхода команды передаётся дальше.
+
+    Пример: { -gdvm } exec 4.3 -- ./addons/gdUnit4/runtest.sh -a test
+help-exec-command = Команда для выполнения и её аргументы, после "--"
 help-show = Показать путь к исполняемому файлу указанной версии { -godot }
+help-show-explain = Также показать, как была выбрана версия: каждый проверенный источник, файл, из которого он взят, и любые несоответствия
+help-env = Вывести хук оболочки, экспортирующий { -godot } текущего каталога
+help-env-long = { help-env }
+
+    Добавьте хук в профиль оболочки, например eval "$({ -gdvm } env --shell bash)" в ~/.bashrc. При каждом выводе приглашения он задаёт GODOT — путь к исполняемому файлу { -godot }, который использует каталог, GODOT_VERSION — его версию, и GDVM_RESOLVED_SOURCE — откуда взята версия: pin, project или default. Версия определяется так же, как для "{ -gdvm } run", но ничего не устанавливается, поэтому переменные удаляются, пока эта версия не установлена.
+
+    Переменные кешируются, пока не изменится один из файлов, из которых они прочитаны, например закрепление, поэтому приглашение остаётся быстрым.
+help-env-shell = Оболочка, для которой вывести хук
+help-env-export = Вывести переменные для текущего каталога вместо хука
 help-cache-path = Показать путь к кэшированному архиву загрузки для указанной версии { -godot }
 help-link = Создать ссылку на исполняемый файл версии { -godot } по указанному пути
 help-list = Список всех установленных версий { -godot }
@@ -71,8 +87,35 @@ help-prune-long = { help-prune }
 
     По умолчанию prune удаляет установки, которые давно не использовались, и устаревшие кэшированные архивы загрузок, сохраняя при этом любую установку, на которую всё ещё указывает ссылка. Установка, заданная как стандартная, никогда не удаляется, независимо от переданных флагов. Порог давности настраивается командой «{ -gdvm } config set prune.max-age-days <дни>» (по умолчанию { $default_days } дн.).
 help-prune-all = Удалить все установки и кэшированные архивы независимо от давности. Установки, на которые всё ещё указывает активная ссылка, сохраняются, если не указан также --force.
-help-prune-force = Игнорировать ссылки, чтобы установки, на которые ссылается только ссылка, тоже могли быть удалены.
+help-prune-force = Игнорировать ссылки, чтобы установки, на которые ссылается только ссылка, тоже могли быть удалены. Автономные установки также удаляются вместе с данными редактора.
 help-prune-dry-run = Показать, что было бы удалено, ничего не удаляя.
+help-logs = Показать вывод запусков { -godot } в отсоединённом режиме
+help-logs-long = { help-logs }
+
+    Когда { -godot } запускается отсоединённым от консоли, его вывод записывается в новый файл журнала для каждого запуска, названный по версии и проекту. Журналы удаляются через { $default_days } дн., это можно изменить командой «{ -gdvm } config set logs.max-age-days <дни>».
+help-logs-follow = Продолжать выводить новые строки по мере записи, переходя к более новым запускам, когда они начинаются.
+help-logs-last = Показать журналы указанного числа последних запусков.
+logs-none = Запусков в отсоединённом режиме ещё не было записано в журнал.
+help-ps = Показать запущенные через { -gdvm } процессы { -godot }, которые ещё работают
+help-stop = Остановить процесс { -godot }, запущенный через { -gdvm }
+help-stop-pid = Идентификатор процесса, показанный "{ -gdvm } ps"
+ps-none = Нет работающих процессов { -godot }, запущенных через { -gdvm }.
+ps-process = { $pid }: { $version }, запущен { $started }
+ps-process-project = { $pid }: { $version } для { $project }, запущен { $started }
+ps-stopped-detail = { $pid } ({ $version })
+help-crashes = Показать отчёты о сбоях, сохранённые для запусков { -godot }, привязанных к консоли
+help-crashes-long = { help-crashes }
+
+    Когда { -godot } запускается с привязкой к консоли и завершается сбоем, { -gdvm } выводит сводку и сохраняет отчёт о сбое с точной версией, вариантом, реестром, аргументами и проектом, готовый для приложения к отчёту об ошибке. Чтобы сохранять также последние строки вывода { -godot }, включите перехват вывода командой «{ -gdvm } config set crashes.capture-output true».
+crashes-none = Сбоев не зарегистрировано.
+crashes-entry = { $time }: { $version } ({ $variant }), { $reason }, сохранён в { $path }
+godot-crashed = { -godot } { $version } завершился сбоем ({ $reason }).
+crash-label-version = Версия
+crash-label-variant = Вариант
+crash-label-registry = Реестр
+crash-label-args = Аргументы
+crash-label-project = Проект
+crash-saved = Отчёт о сбое сохранён в { $path }.
 prune-nothing-dry-run = Ничего не было бы удалено.
 prune-nothing-removed = Удалять нечего; всё используется или в пределах порога давности.
 prune-preserved-by-link =
@@ -81,12 +124,82 @@ prune-preserved-by-link =
         [few] Сохранены { $count } установки, на которые всё ещё ссылается ссылка.
        *[many] Сохранено { $count } установок, на которые всё ещё ссылается ссылка.
     }
+help-dedupe = Объединить одинаковые файлы разных установок для экономии места на диске
+help-dedupe-long = { help-dedupe }
+
+    Файлы с одинаковым содержимым в разных установках заменяются клонами с копированием при записи, если файловая система их поддерживает, а иначе — жёсткими ссылками. Выполните «{ -gdvm } config set install.dedupe true», чтобы делать это автоматически после каждой установки.
+help-dedupe-dry-run = Показать, сколько места было бы сэкономлено, ничего не меняя.
+dedupe-nothing = Одинаковых файлов для объединения не осталось.
+dedupe-shared-files =
+    { $count ->
+        [one] Объединён { $count } одинаковый файл разных установок, сэкономлено { size-display }.
+        [few] Объединены { $count } одинаковых файла разных установок, сэкономлено { size-display }.
+       *[many] Объединено { $count } одинаковых файлов разных установок, сэкономлено { size-display }.
+    }
+dedupe-would-share-files =
+    { $count ->
+        [one] Был бы объединён { $count } одинаковый файл разных установок, экономия { size-display }.
+        [few] Были бы объединены { $count } одинаковых файла разных установок, экономия { size-display }.
+       *[many] Было бы объединено { $count } одинаковых файлов разных установок, экономия { size-display }.
+    }
+warning-dedupe-failed = Не удалось объединить одинаковые файлы разных установок: { $error }
+warning-shims-sync-failed = Не удалось обновить версионные шимы { -godot }: { $error }
+warning-cache-trim-failed = Не удалось уменьшить кэш загрузок до cache.max-size: { $error }
+warning-launch-log-failed = Не удалось создать журнал для этого запуска, поэтому его вывод будет отброшен: { $error }
+warning-crash-save-failed = Не удалось сохранить отчёт о сбое: { $error }
+warning-self-contained-project-unsupported = На этой платформе { -godot } не может хранить данные редактора отдельно для проекта, поэтому использует общие данные редактора. Вместо этого установите версию с --self-contained, чтобы держать её данные отдельно.
+warning-self-contained-link-failed = Не удалось связать ваши собственные папки конфигурации, данных и кеша с папками проекта, поэтому программы, запускаемые { -godot }, могут не найти свои настройки: { $error }
+warning-carry-settings-failed = Не удалось перенести настройки редактора: { $error }
 warning-broken-install-reinstalling = У установленной версии { $version } отсутствует исполняемый файл, она переустанавливается.
 
 help-force = Принудительная переустановка, даже если версия уже установлена.
+help-install-system = Установить в общесистемное хранилище установок вместо вашего собственного. Требуется доступ на запись к хранилищу.
+help-install-self-contained = Сделать установку автономной, чтобы её { -godot } хранил собственные настройки редактора, кэши и шаблоны экспорта внутри установки.
+help-locked = Требовать, чтобы gdvm.lock соответствовал закреплению, а архивы — его хешам. Установите GDVM_LOCKED=1, чтобы так же работали ярлыки godot.
 help-redownload = Повторно загрузить версию, даже если она уже загружена в кэше.
 help-yes = Пропустить подтверждение удаления
 help-remove-yes-deprecated = [устарело] Этот флаг не выполняет никаких действий и будет удален в будущем выпуске.
+help-remove-system = Удалить из общесистемного хранилища установок. Требуется доступ на запись к хранилищу.
+help-verify = Проверить установки на изменённые, отсутствующие или добавленные файлы
+help-verify-long = { help-verify }
+
+    Каждая установка, выполненная { -gdvm }, сохраняет квитанцию с хешем каждого своего файла. Эта команда заново хеширует установленные файлы и сравнивает их с квитанцией. Если установка изменилась, { -gdvm } предлагает переустановить её из кэшированного архива.
+help-verify-version = Проверяемая установленная версия (например, 4.2 или 4.2-stable). Если не указана, проверяются все установки.
+help-verify-yes = Переустанавливать изменённые установки из кэшированного архива без подтверждения.
+verify-no-receipt = У { $version } нет квитанции установки для сравнения. Переустановите её, чтобы создать квитанцию.
+verify-install-changed = { $version } отличается от своей квитанции установки (изменено: { $modified }, отсутствует: { $missing }, добавлено: { $added }):
+verify-file-modified = изменён:   { $path }
+verify-file-missing = отсутствует: { $path }
+verify-file-added = добавлен:  { $path }
+verify-archive-not-cached = Архив, из которого была установлена { $version }, больше не находится в кэше. Переустановите её командой «{ -gdvm } install --force».
+verify-reinstall-prompt = Переустановить { $version } из кэшированного архива? (да/нет):
+verify-reinstall-tip = Запустите эту команду снова с --yes, чтобы переустановить из кэшированного архива.
+carry-settings-prompt = { $version } запускается впервые. Перенести в неё настройки редактора, темы и шаблоны скриптов из { $from }? (да/нет):
+carry-settings-tip = Чтобы переносить настройки редактора из { $from } в новые версии без вопросов, выполните "{ -gdvm } config set godot.carry-settings always".
+carry-settings-detail = настройки редактора из { $from }
+carry-settings-backup = Заменённые файлы сохранены в { $path }.
+help-outdated = Показать более новые сборки установленных, используемой по умолчанию и закреплённой версий
+help-outdated-long = { help-outdated }
+
+    Для каждой установленной версии, версии по умолчанию и закрепления текущего проекта показывает новейший патч-релиз, новейший минорный релиз и новейший предварительный релиз. Завершается с ненулевым кодом, если доступно патч- или минорное обновление либо более новый предварительный релиз для предварительного релиза.
+outdated-source-installed = установлена
+outdated-source-default = по умолчанию
+outdated-source-pinned = закреплена
+outdated-patch = Патч:
+outdated-minor = Минорный:
+outdated-prerelease = Предварительный:
+outdated-up-to-date = Всё обновлено.
+warning-outdated-check-failed = Не удалось проверить наличие более новых сборок { $version }: { $error }
+help-update = Установить новейший патч-релиз каждой установленной серии
+help-update-long = { help-update }
+
+    Для каждой установленной серии major.minor и варианта устанавливает новейший стабильный патч-релиз и перенаправляет на него записанные ссылки. Используйте --default, чтобы также перенести версию по умолчанию, и --remove, чтобы удалить заменённые установки.
+help-update-version = Обновлять только установки, соответствующие этой версии
+help-update-default = Перенести версию по умолчанию на обновлённую установку
+help-update-remove = Удалить установки, заменённые обновлением
+update-up-to-date = Все установленные серии используют новейший патч-релиз.
+update-kept-default = { $version } сохранена, так как это версия по умолчанию. Укажите --default, чтобы перенести её.
+warning-update-remove-failed = Не удалось удалить { $version }: { $error }
 help-link-version = Версия для ссылки. Если не указана, версия определяется на основе текущего каталога или версии по умолчанию.
 help-link-path = Путь, по которому будет создана ссылка или копия, например «{ $platform ->
     [windows] godot.exe
@@ -123,6 +236,7 @@ help-console = Запустить { -godot } с консольным окном.
 
 help-default = Управление версией по умолчанию
 help-default-version = Версия для установки по умолчанию (например, 4.2 или 4.2-stable).
+help-default-float = Следовать спецификации версии вместо одной сборки, используя новейшую подходящую установку
 no-default-set =Локальная версия не установлена. Запустите "{ -gdvm } use <version>", чтобы установить
     локальную версию для всей системы, или "{ -gdvm } pin <version>", чтобы установить
     локальную версию для текущего каталога.
@@ -151,6 +265,9 @@ status-would-free = Будет освобождено
 status-would-prune = Будет очищено
 status-removing = Удаление
 status-running = Запуск
+status-carried-settings = Перенесено
+status-log = Журнал
+status-stopped = Остановлено
 status-cleared = Очищен
 status-refreshed = Обновлен
 status-skipped = Пропущено
@@ -179,6 +296,8 @@ progress-eta =
 unsupported-platform = Неподдерживаемая платформа
 unsupported-architecture = Неподдерживаемая архитектура
 error-checksum-mismatch = Несоответствие контрольной суммы для файла { $file }
+error-lock-hash-mismatch = Архив { $version } не соответствует хешу, записанному в { $path }. Выполните `{ -gdvm } pin --update-lock`, если новая сборка ожидаема.
+error-lock-platform-missing = В { $path } нет хеша { $version } для { $platform }, поэтому архив нельзя проверить. Выполните `{ -gdvm } pin --update-lock`, чтобы добавить его.
 error-invalid-sha-length = Неверная длина SHA { $length }
 error-size-mismatch = Несоответствие размера для файла { $file }: ожидалось { $expected } байт, получено { $actual } байт.
 error-insecure-url = Отказ в получении { $url } через незашифрованное соединение. Разрешены только URL-адреса https:// и file://. Установите переменную окружения GDVM_ALLOW_INSECURE_URLS, чтобы разрешить незашифрованные URL-адреса http://.
@@ -188,6 +307,10 @@ error-response-too-large = Ответ от { $url } превышает макс
 error-too-many-redirects = Слишком много перенаправлений.
 error-config-invalid-number = Недопустимое значение для { $key }: { $value } (ожидалось число)
 error-config-invalid-enum = Недопустимое значение для { $key }: { $value } (допустимые значения: { $expected })
+error-config-invalid-bool = Недопустимое значение для { $key }: { $value } (допустимые значения: true или false)
+error-config-invalid-size = Недопустимое значение для { $key }: { $value } (ожидается размер, например 512MiB или 10GiB)
+error-config-invalid-path = Недопустимое значение для { $key }: { $value } (ожидается абсолютный путь)
+error-alias-invalid-name = Недопустимое имя псевдонима: { $name }. Имена псевдонимов начинаются с буквы, содержат только буквы, цифры, «-» и «_» и не могут быть «stable», «latest» или «unset».
 error-config-unknown-key = Неизвестный ключ конфигурации: { $key }
 error-invalid-path = Недопустимый путь: { $path }
 error-publish-missing-manifest = отсутствует registry.json
@@ -215,6 +338,11 @@ error-spec-empty-variant = Пустое имя варианта в «{ $input }
 error-spec-empty-version = Пустая версия в «{ $input }»
 error-system-time = Системное время раньше эпохи UNIX
 error-unrecognized-version-format = Нераспознанный формат версии: { $input }
+error-invalid-version-range = Недопустимый диапазон версий: { $input }. Используйте операторы сравнения, например >=4.2, <4.5, ~4.3 или ^4.
+error-lock-missing = Требуется { $path }, но он не существует. Выполните `{ -gdvm } pin --update-lock`, чтобы создать его.
+error-lock-out-of-date = { $path } не соответствует закреплению в { -gdvm-toml }. Выполните `{ -gdvm } pin --update-lock`, чтобы обновить его.
+error-no-pinned-version = В { -gdvm-toml } здесь или в родительском каталоге не закреплена ни одна версия.
+error-alias-unknown = Псевдонима с именем { $name } нет. Выполните «{ -gdvm } alias list», чтобы увидеть настроенные псевдонимы.
 error-diagnose-problems =
     { $count ->
         [one] Обнаружена { $count } проблема.
@@ -232,13 +360,19 @@ download-retrying = Загрузка прервана, повторная поп
 download-resuming = Возобновляется прерванная загрузка (уже загружено { size-display }).
 warning-resume-verification-failed = Возобновлённая загрузка не совпала с ожидаемой контрольной суммой, она загружается заново целиком.
 lock-waiting = Ожидание завершения другого процесса { -gdvm } (блокировка: { $resource })...
+relocated-to-xdg = Файлы { -gdvm } перемещены из { $from } в базовые каталоги XDG. Замените { $old_bin } на { $bin } в вашем PATH.
+warning-relocate-leftovers = Некоторые файлы в { $path } не были перемещены, так как они уже есть в новом расположении. Удалите их после проверки.
 prune-skipped-error = Пропуск { $item }: { $error }
 prune-skipped-in-use = Пропуск { $item }: он используется другим процессом { -gdvm }.
+prune-skipped-running = Пропуск { $item }: из этой установки запущен { -godot }. Используйте "{ -gdvm } ps", чтобы увидеть процессы.
+prune-skipped-self-contained = Пропуск { $item }: установка автономна и хранит настройки редактора. Используйте "{ -gdvm } remove" или "{ -gdvm } prune --force", чтобы удалить её.
 
 error-find-user-dirs = Не удалось найти пользовательские каталоги.
 warning-fetching-releases-using-cache = Ошибка при получении релизов: { $error }. Используются кэшированные релизы.
 
 error-version-not-found = Версия не найдена.
+error-system-store-not-set = Общесистемное хранилище установок не задано. Задайте его с помощью "{ -gdvm } config set install.system-store <путь>" или переменной окружения GDVM_SYSTEM_STORE.
+error-system-install-read-only = { $version } находится в общесистемном хранилище установок. Используйте "{ -gdvm } remove --system", чтобы удалить её оттуда.
 error-archive-not-cached = Кэшированный архив для {$version} не найден. Сначала установите его, чтобы заполнить кэш.
 error-multiple-versions-found = Найдено несколько версий, соответствующих запросу:
     {$list}
@@ -248,6 +382,8 @@ no-matching-releases = Соответствующие релизы не найд
 available-releases = Доступные релизы:
 
 version-already-installed = Версия {$version} уже установлена.
+install-self-contained = { $version } теперь автономна. Её настройки и данные редактора хранятся в { $path }.
+remove-self-contained = Настройки и данные редактора, хранившиеся в { $path }, удалены вместе с ней.
 godot-executable-not-found = Исполняемый файл { -godot } не найден для версии {$version}.
 error-link-exists = Путь {$path} уже существует. Используйте --force для перезаписи.
 error-link-symlink = Не удалось создать ссылку из {$link} в {$target}.
@@ -256,9 +392,18 @@ error-link-copy = Не удалось скопировать файл.
 error-no-stable-releases-found = Стабильные версии не найдены.
 
 error-starting-godot = Не удалось запустить { -godot }.
+error-exec-failed = Не удалось выполнить { $command }.
+error-profile-not-found = Профиль запуска с именем { $profile } не найден в { -gdvm-toml }.
+error-env-profile-not-found = Профиль окружения { $profile } не найден по пути { $path }.
+error-install-running = { $version } нельзя удалить, пока из этой установки запущен { -godot } (процесс { $pids }). Закройте его или сначала используйте "{ -gdvm } stop".
+error-self-contained-read-only = { $version } находится в общесистемном хранилище установок, где { -godot } не может хранить данные редактора.
+error-process-not-tracked = Работающий процесс { -godot } с идентификатором { $pid } не запускался через { -gdvm }.
+error-process-stop-failed = Не удалось остановить процесс { $pid }.
+error-process-unconfirmed = Не удалось подтвердить, что процесс { $pid } — всё ещё { -godot }, запущенный через { -gdvm }, поэтому он не был остановлен.
 confirm-yes = да
 
 default-set-success = Успешно установлено {$version} как версия { -godot } по умолчанию.
+default-float-success = Версия { -godot } по умолчанию теперь следует {$spec}, сейчас это {$version}.
 default-unset-success = Успешно удалено значение версии { -godot } по умолчанию.
 provide-version-or-unset = Пожалуйста, укажите версию для установки по умолчанию или 'unset' для удаления версии по умолчанию.
 
@@ -298,6 +443,8 @@ help-pin-long = { help-pin }
 
     Это создаст файл { -gdvm-toml } в текущем каталоге с закреплённой версией. Когда вы запустите "{ -gdvm } run" в этом каталоге или любом из его подкаталогов, будет использоваться закреплённая версия вместо версии по умолчанию.
 
+    Также используются версии, закреплённые для других менеджеров версий: файл .godot-version, строка godot в .tool-versions или godot в разделе [tools] файла mise.toml. В каждом каталоге приоритет у { -gdvm-toml }, затем { -gdvmrc }, .godot-version, .tool-versions и mise.toml, в этом порядке. Побеждает ближайший каталог с закреплением. Отключить это можно командой "{ -gdvm } config set pin.tool-files false".
+
     Это полезно, когда вы хотите использовать определённую версию { -godot } для проекта, не изменяя версию по умолчанию для всей системы.
 
     В настоящее время также записывается устаревший файл { -gdvmrc } для совместимости со старыми версиями { -gdvm }. Он будет удалён в будущем выпуске, поэтому рекомендуется перейти на новый формат { -gdvm-toml } и удалить файл { -gdvmrc }, если он существует.
@@ -305,7 +452,9 @@ help-pin-long = { help-pin }
     Вы можете отключить запись файла { -gdvmrc } с помощью флага --no-legacy.
 help-pin-version = Укажите версию для закрепления
 help-no-legacy = Не записывать устаревший файл совместимости { -gdvmrc }
+help-update-lock = Разрешить закрепление до точной сборки и записать её в gdvm.lock
 pinned-success = Версия {$version} успешно закреплена в { -gdvm-toml }
+lock-updated = Версия { $version } зафиксирована в { $path }
 error-pin-version-not-found = Невозможно закрепить версию {$version}
 
 error-file-not-found = Файл не найден. Возможно, он не существует на сервере.
@@ -318,6 +467,24 @@ error-post-upgrade-action-failed = Шаг { $id } завершился ошиб
 error-failed-reading-project-godot = Не удалось прочитать project.godot, невозможно автоматически определить версию проекта.
 warning-using-project-version = Используется версия { $version }, указанная в project.godot.
 warning-gdvmrc-detected = Был обнаружен пользовательский файл { -gdvmrc }. Поддержка файлов { -gdvmrc } устарела и будет удалена в будущем выпуске. Пожалуйста, перейдите на новый формат закрепления файлов, используемый командой `{ -gdvm } pin`.
+warning-lock-out-of-date = { $path } больше не соответствует закреплению в { -gdvm-toml } и игнорируется. Выполните `{ -gdvm } pin --update-lock`, чтобы обновить его.
+warning-lock-hash-mismatch = Архив { $version } не соответствует хешу, записанному в { $path }.
+lock-platform-missing = В { $path } нет хеша { $version } для { $platform }, поэтому архив не был с ним сверен.
+
+explain-header = Как была выбрана версия { -godot }:
+explain-source-explicit = командная строка
+explain-source-pin = закрепление
+explain-source-project = проект
+explain-source-default = по умолчанию
+explain-not-set = не задано
+explain-offer-from-file = { $spec } из { $path }
+explain-selected = (выбрано)
+explain-variant-inherited = Вариант { $variant } унаследован из источника «{ $source }».
+explain-registry-inherited = Реестр { $registry } унаследован из источника «{ $source }».
+explain-project-mismatch = Проект требует { $project_version }, что противоречит { $version }.
+explain-lock = Закреплённая версия уточнена файлом { $path }.
+explain-stale-lock = { $path } проигнорирован, так как больше не соответствует закреплению.
+explain-executable = Исполняемый файл: { $path }
 
 warning-project-version-mismatch =
     {"\u001b"}[33mПредупреждение: версия, указанная в project.godot, не совпадает с { $pinned ->
@@ -347,6 +514,16 @@ help-run-force-long =
     { help-run-force }
 
     Если вы сделаете это, запрошенная или закреплённая версия { -godot } может перезаписать файл проекта. Если вы закрепляете версии, рекомендуется вместо этого обновить закреплённую версию в { -gdvmrc }, чтобы она соответствовала версии проекта, или удалить файл { -gdvmrc }, чтобы использовать версию проекта.
+help-run-profile = Использовать указанный профиль запуска из { -gdvm-toml }
+help-run-profile-long =
+    { help-run-profile }
+
+    Профиль — это таблица [profiles.<имя>] в { -gdvm-toml } с ключами args, env, launch-mode и working-dir. Он дополняет таблицу [run], которая применяется при каждом запуске { -godot } проекта, в том числе через команду godot. Аргументы, указанные после "--", следуют за аргументами профиля.
+help-env-profile = Наложить указанный файл .env.<профиль> поверх .env
+help-env-profile-long =
+    { help-env-profile }
+
+    Файл .env читается из каталога проекта, а при отсутствии проекта — из текущего каталога. Переменные, уже заданные в окружении, сохраняются. Таблица [dotenv] в { -gdvm-toml } может указать, какие переменные передаются в { -godot }, а какие блокируются. Переменная окружения GDVM_ENV_PROFILE выбирает профиль, когда этот параметр не указан.
 
 help-config = Управление конфигурацией { -gdvm }
 help-format = Формат вывода: text (по умолчанию) или json
@@ -416,6 +593,17 @@ registry-removed = Реестр { $registry } удалён.
 registry-list-header = Настроенные реестры:
 registry-tag-official = официальный
 
+help-alias = Управление псевдонимами версий
+help-alias-set = Добавить или изменить псевдоним
+help-alias-remove = Удалить псевдоним
+help-alias-list = Показать настроенные псевдонимы
+help-alias-name = Имя псевдонима. Используйте его вместо версии, при желании в виде @имя.
+help-alias-spec = Версия, которую обозначает псевдоним, например studio/csharp:4.3.1-rc2
+alias-set = Псевдоним { $name } теперь обозначает { $spec }.
+alias-removed = Псевдоним { $name } удалён.
+alias-list-header = Настроенные псевдонимы:
+alias-list-empty = Псевдонимы не настроены.
+
 error-invalid-registry-subcommand = Недопустимая подкоманда реестра. Используйте «add», «remove», «list» или «refresh».
 registry-trust-warning = { $registry } ({ $url }) — это пользовательский реестр, а не официальный. { -gdvm } проверяет, что загрузки соответствуют тому, что указывает реестр, но не может определить, безопасно ли их запускать. Устанавливайте из него, только если доверяете тому, кто им управляет.
 registry-trust-prompt = Доверяете ли вы этому реестру и хотите продолжить? (да/нет):
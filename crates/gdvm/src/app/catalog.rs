@@ -19,7 +19,7 @@ use anyhow::Result;
 
 use crate::host::HostPlatform;
 use crate::registry::{self, BinarySelectionError};
-use crate::registry_version_resolver::RegistryVersionResolver;
+use crate::registry_version_resolver::{NewerBuilds, RegistryVersionResolver};
 use crate::releases::{CatalogSet, ReleaseCatalog};
 use crate::terr;
 use crate::version::{ResolvedVersion, Variant, VersionQuery};
@@ -141,6 +141,19 @@ impl<'a> Catalogs<'a> {
         }
     }
 
+    /// Find newer releases of a version in its registry.
+    pub async fn newer_builds(
+        &self,
+        current: &ResolvedVersion,
+        variant: &Variant,
+        registry: Option<&str>,
+        use_cache_only: bool,
+    ) -> Result<NewerBuilds> {
+        RegistryVersionResolver::new(self.catalog(registry)?, *self.host)
+            .newer_builds(current, Some(variant.as_str()), use_cache_only)
+            .await
+    }
+
     /// Get a suggestion for the user to try using a wildcard.
     pub async fn wildcard_suggestion(
         &self,
@@ -0,0 +1,243 @@
+// SPDX-FileCopyrightText: Copyright (C) 2024 Adaline Simonian
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// This file is part of gdvm.
+//
+// gdvm is free software: you can redistribute it and/or modify it under the
+// terms of the GNU General Public License as published by the Free Software
+// Foundation, either version 3 of the License, or (at your option) any later
+// version.
+//
+// gdvm is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR
+// A PARTICULAR PURPOSE. See the GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with
+// this program. If not, see <https://www.gnu.org/licenses/>.
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+use serde::Serialize;
+
+use super::*;
+use crate::dedupe_index::{DedupeIndex, SharedGroup};
+use crate::fs_utils::{dir_size_excluding, same_file, share_file};
+use crate::hash_utils::{ShaType, hash_reader};
+use crate::paths::GdvmPaths;
+use crate::usage_tracker::UsageTracker;
+
+/// Files smaller than this are not worth sharing.
+const MIN_SHARED_SIZE: u64 = 4096;
+
+/// The outcome of a dedupe operation.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct DedupeReport {
+    /// Number of files that now share storage with an identical file.
+    pub files: usize,
+    /// Approximate bytes saved.
+    pub saved_bytes: u64,
+    /// Whether this was a dry run.
+    pub dry_run: bool,
+}
+
+#[derive(Clone, Copy)]
+pub struct Deduper<'a> {
+    pub(super) paths: &'a GdvmPaths,
+    pub(super) usage_tracker: &'a UsageTracker,
+    pub(super) catalogs: Catalogs<'a>,
+}
+
+impl<'a> Deduper<'a> {
+    fn library(&self) -> Library<'a> {
+        Library {
+            paths: self.paths,
+            usage_tracker: self.usage_tracker,
+            catalogs: self.catalogs,
+        }
+    }
+
+    fn index(&self) -> DedupeIndex {
+        DedupeIndex::new(self.paths.dedupe_index(), self.paths.locks())
+    }
+
+    /// Replace identical files across installs with hard links or reflinks.
+    pub fn dedupe(&self, dry_run: bool) -> Result<DedupeReport> {
+        let installs_dir = self.paths.installs();
+        let index = self.index();
+        let previous = index.load()?;
+
+        // Keep every install locked until the end, so nothing we share is
+        // removed or replaced halfway through.
+        let mut locks = Vec::new();
+        let mut by_size: HashMap<u64, Vec<PathBuf>> = HashMap::new();
+        for (key, path) in self.library().install_dirs() {
+            let Some(lock) = crate::locks::Lock::try_acquire(
+                &self.paths.locks(),
+                crate::locks::Resource::Install(&key),
+            )?
+            else {
+                eprintln_i18n!(
+                    "prune-skipped-in-use",
+                    item = self.library().install_label(&key)
+                );
+                continue;
+            };
+            locks.push(lock);
+            collect_files(&path, &mut by_size);
+        }
+
+        let mut report = DedupeReport {
+            dry_run,
+            ..Default::default()
+        };
+        let mut groups: HashMap<String, SharedGroup> = HashMap::new();
+
+        for (size, paths) in by_size.into_iter().filter(|(_, paths)| paths.len() > 1) {
+            let mut by_digest: HashMap<String, Vec<PathBuf>> = HashMap::new();
+            for path in paths {
+                let Ok(digest) = fs::File::open(&path)
+                    .map_err(anyhow::Error::from)
+                    .and_then(|mut file| hash_reader(ShaType::Sha256, &mut file))
+                else {
+                    continue;
+                };
+                by_digest.entry(digest).or_default().push(path);
+            }
+
+            for (digest, mut paths) in by_digest.into_iter().filter(|(_, p)| p.len() > 1) {
+                let relative = |path: &Path| {
+                    path.strip_prefix(installs_dir)
+                        .unwrap_or(path)
+                        .to_string_lossy()
+                        .replace('\\', "/")
+                };
+                let known: Vec<String> = previous
+                    .groups
+                    .get(&digest)
+                    .map(|group| group.members.clone())
+                    .unwrap_or_default();
+
+                // Files already recorded keep their order, so the file owning
+                // the storage stays the same between runs.
+                paths.sort_by_key(|path| {
+                    let member = relative(path);
+                    (
+                        known
+                            .iter()
+                            .position(|k| *k == member)
+                            .unwrap_or(usize::MAX),
+                        member,
+                    )
+                });
+
+                let primary = paths.remove(0);
+                let primary_member = relative(&primary);
+                let permissions = fs::metadata(&primary)?.permissions();
+                let recorded: HashSet<String> = known.iter().cloned().collect();
+                let mut members = known;
+                if !members.contains(&primary_member) {
+                    members.insert(0, primary_member.clone());
+                }
+
+                for path in paths {
+                    let member = relative(&path);
+                    let already_shared = same_file(&primary, &path)
+                        || (recorded.contains(&member) && recorded.contains(&primary_member));
+                    if already_shared {
+                        if !members.contains(&member) {
+                            members.push(member);
+                        }
+                        continue;
+                    }
+                    if fs::metadata(&path).map(|m| m.permissions()).ok()
+                        != Some(permissions.clone())
+                    {
+                        continue;
+                    }
+
+                    if !dry_run && let Err(error) = share_file(&primary, &path) {
+                        eprintln_i18n!(
+                            "prune-skipped-error",
+                            item = member.as_str(),
+                            error = error.to_string()
+                        );
+                        continue;
+                    }
+
+                    report.files += 1;
+                    report.saved_bytes += size;
+                    if !members.contains(&member) {
+                        members.push(member);
+                    }
+                }
+
+                if members.len() > 1 {
+                    groups.insert(digest, SharedGroup { size, members });
+                }
+            }
+        }
+
+        if !dry_run {
+            index.update(|state| {
+                state.groups.extend(groups);
+                state.retain_existing(installs_dir);
+            })?;
+        }
+
+        drop(locks);
+        Ok(report)
+    }
+
+    /// The disk space used by an install, not counting files whose storage is
+    /// owned by another install.
+    pub fn install_size(&self, path: &Path) -> u64 {
+        let shared = self
+            .index()
+            .load()
+            .map(|state| state.shared_paths(self.paths.installs()))
+            .unwrap_or_default();
+        dir_size_excluding(path, &shared)
+    }
+
+    /// Forget the shared files of a removed install.
+    pub(super) fn forget_install(&self, install_key: &str) -> Result<()> {
+        self.index().forget_install(install_key)
+    }
+
+    /// Drop shared files that no longer exist from the index.
+    pub(super) fn retain_existing(&self) -> Result<()> {
+        let installs_dir = self.paths.installs();
+        if !self.paths.dedupe_index().exists() {
+            return Ok(());
+        }
+        self.index()
+            .update(|state| state.retain_existing(installs_dir))
+    }
+}
+
+/// Collect the regular files under `dir` worth sharing, grouped by size. The
+/// data of a self-contained install changes as Godot runs, so it is skipped.
+fn collect_files(dir: &Path, by_size: &mut HashMap<u64, Vec<PathBuf>>) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+    let self_contained = crate::self_contained::is_enabled(dir);
+    for entry in entries.flatten() {
+        let Ok(file_type) = entry.file_type() else {
+            continue;
+        };
+        if file_type.is_dir() {
+            if self_contained && entry.file_name() == crate::self_contained::EDITOR_DATA_DIR {
+                continue;
+            }
+            collect_files(&entry.path(), by_size);
+        } else if file_type.is_file()
+            && let Ok(meta) = entry.metadata()
+            && meta.len() >= MIN_SHARED_SIZE
+        {
+            by_size.entry(meta.len()).or_default().push(entry.path());
+        }
+    }
+}
@@ -15,22 +15,35 @@
 // You should have received a copy of the GNU General Public License along with
 // this program. If not, see <https://www.gnu.org/licenses/>.
 
+use std::collections::BTreeMap;
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 use anyhow::Result;
 
 use super::*;
+use crate::config::Config;
+use crate::gdvm_lock::{self, GdvmLock, GdvmLockGodot};
+use crate::gdvm_toml::{GdvmToml, GdvmTomlLaunch};
 use crate::paths::GdvmPaths;
+use crate::resolution_cache::Stamp;
 use crate::usage_tracker::UsageTracker;
-use crate::version::{QuerySelection, ResolvedSelection, ResolvedVersion, Variant, VersionQuery};
-use crate::{project_version_detector, terr};
+use crate::version::{
+    QuerySelection, ResolvedSelection, ResolvedVersion, Variant, VersionQuery, VersionSpec,
+    VersionTarget,
+};
+use crate::{pin_files, project_version_detector, terr};
+
+/// Marks a floating default in the `default` file. The pinned-format spec it
+/// tracks follows.
+const FLOATING_PREFIX: &str = "float ";
 
 #[derive(Clone, Copy)]
 pub struct Defaults<'a> {
     pub(super) paths: &'a GdvmPaths,
     pub(super) usage_tracker: &'a UsageTracker,
     pub(super) catalogs: Catalogs<'a>,
+    pub(super) config: &'a Config,
 }
 
 impl<'a> Defaults<'a> {
@@ -51,13 +64,8 @@ impl<'a> Defaults<'a> {
         let _lock =
             crate::locks::Lock::acquire(&self.paths.locks(), crate::locks::Resource::Defaults)?;
         // Check if the version exists
-        let install_name = crate::version::install_dir_subpath(
-            &self.library().install_store_key(registry)?,
-            &gv.to_remote_str(),
-            variant,
-        );
-        let version_path = self.paths.installs().join(&install_name);
-        if !version_path.exists() {
+        let (install_name, target_dir) = self.library().install_dir(gv, variant, registry)?;
+        if !target_dir.exists() {
             return Err(terr!("error-version-not-found").into());
         }
 
@@ -68,20 +76,86 @@ impl<'a> Defaults<'a> {
         let default_str = crate::version::pinned_str(registry, &gv.to_pinned_str(), variant);
         fs::write(&default_path, &default_str)?;
 
-        // Create directory symlink .gdvm/bin/current_godot -> .gdvm/<install_name>/
+        self.link_current_godot(&target_dir)
+    }
+
+    /// Make the default track a spec rather than an exact build. It resolves
+    /// to the newest installed build matching the spec, so it follows new
+    /// installs. Returns the build it currently resolves to.
+    pub fn set_floating_default(
+        &self,
+        target: &VersionTarget,
+        variant: &Variant,
+        registry: Option<&str>,
+    ) -> Result<ResolvedSelection> {
+        let _lock =
+            crate::locks::Lock::acquire(&self.paths.locks(), crate::locks::Resource::Defaults)?;
+        let Some(newest) = self.newest_match(&target.to_query(), variant, registry)? else {
+            return Err(terr!("error-version-not-found").into());
+        };
+        let (install_name, target_dir) =
+            self.library()
+                .install_dir(&newest.version, variant, registry)?;
+        self.library().track_install_use(&install_name)?;
+
+        let default_str = crate::version::pinned_str(registry, &target.to_string(), variant);
+        fs::write(
+            self.paths.default_file(),
+            format!("{FLOATING_PREFIX}{default_str}"),
+        )?;
+
+        self.link_current_godot(&target_dir)?;
+        Ok(ResolvedSelection {
+            version: newest.version,
+            variant: variant.clone(),
+            registry: registry.map(str::to_string),
+        })
+    }
+
+    /// Point `current_godot` at the newest build a floating default matches,
+    /// after installs were added or removed. Does nothing when the default
+    /// is an exact build.
+    pub fn refresh_floating_default(&self) -> Result<()> {
+        let _lock =
+            crate::locks::Lock::acquire(&self.paths.locks(), crate::locks::Resource::Defaults)?;
+        if self.get_floating_default()?.is_none() {
+            return Ok(());
+        }
+
+        match self.get_default()? {
+            Some(default) => {
+                let (_, target_dir) = self.library().install_dir(
+                    &default.version,
+                    &default.variant,
+                    default.registry.as_deref(),
+                )?;
+                self.link_current_godot(&target_dir)
+            }
+            None => {
+                let symlink_dir = self.paths.current_godot_symlink();
+                if symlink_dir.symlink_metadata().is_ok() {
+                    fs::remove_dir_all(symlink_dir)?;
+                }
+                Ok(())
+            }
+        }
+    }
+
+    /// Create directory symlink .gdvm/bin/current_godot -> .gdvm/<install_name>/
+    fn link_current_godot(&self, target_dir: &Path) -> Result<()> {
         let symlink_dir = self.paths.current_godot_symlink();
-        let target_dir = self.paths.installs().join(&install_name);
 
         // Make sure bin directory exists
         fs::create_dir_all(symlink_dir.parent().unwrap())?;
 
-        if symlink_dir.exists() {
+        // The link may dangle if its install was removed.
+        if symlink_dir.symlink_metadata().is_ok() {
             fs::remove_dir_all(&symlink_dir)?;
         }
         #[cfg(target_family = "unix")]
-        std::os::unix::fs::symlink(&target_dir, &symlink_dir)?;
+        std::os::unix::fs::symlink(target_dir, &symlink_dir)?;
         #[cfg(target_family = "windows")]
-        if let Err(e) = std::os::windows::fs::symlink_dir(&target_dir, &symlink_dir) {
+        if let Err(e) = std::os::windows::fs::symlink_dir(target_dir, &symlink_dir) {
             if e.raw_os_error() == Some(1314) {
                 return Err(terr!("error-create-symlink-windows").into());
             }
@@ -108,7 +182,20 @@ impl<'a> Defaults<'a> {
         Ok(())
     }
 
+    /// The default version. A floating default resolves to the newest
+    /// installed build matching its spec, or `None` when nothing matches.
     pub fn get_default(&self) -> Result<Option<ResolvedSelection>> {
+        if let Some(floating) = self.get_floating_default()? {
+            let variant = Variant::from_option(floating.variant.as_deref());
+            let newest =
+                self.newest_match(&floating.version, &variant, floating.registry.as_deref())?;
+            return Ok(newest.map(|installed| ResolvedSelection {
+                version: installed.version,
+                variant,
+                registry: floating.registry,
+            }));
+        }
+
         let default_file = self.paths.default_file();
         if default_file.exists() {
             let contents = fs::read_to_string(&default_file)?;
@@ -125,50 +212,185 @@ impl<'a> Defaults<'a> {
         }
     }
 
-    /// Recursively search upward for gdvm.toml, return the pinned version if found.
+    /// The spec a floating default tracks, or `None` when the default is an
+    /// exact build or unset.
+    pub fn get_floating_default(&self) -> Result<Option<QuerySelection>> {
+        let default_file = self.paths.default_file();
+        if !default_file.exists() {
+            return Ok(None);
+        }
+        let contents = fs::read_to_string(&default_file)?;
+        let Some(spec) = contents.trim().strip_prefix(FLOATING_PREFIX) else {
+            return Ok(None);
+        };
+
+        let (registry, variant, target) = crate::version::parse_pinned_str(spec);
+        Ok(Some(QuerySelection {
+            version: VersionSpec::parse(&target)?.target.to_query(),
+            variant,
+            registry,
+            gdvmrc_fallback: false,
+            stale_lock: None,
+            lock_file: None,
+            pin_file: None,
+        }))
+    }
+
+    /// The newest installed build matching a query.
+    fn newest_match(
+        &self,
+        query: &VersionQuery,
+        variant: &Variant,
+        registry: Option<&str>,
+    ) -> Result<Option<InstalledVersion>> {
+        Ok(self
+            .library()
+            .installed_matches(query, variant, registry)?
+            .into_iter()
+            .max_by(|a, b| a.version.cmp(&b.version)))
+    }
+
+    /// Recursively search upward for a pin file, return the pinned version if
+    /// found.
     pub fn get_pinned_version(&self) -> Option<QuerySelection> {
-        let mut current = std::env::current_dir().ok()?;
-        loop {
-            let toml_candidate = current.join("gdvm.toml");
-            if toml_candidate.is_file()
-                && let Ok(contents) = fs::read_to_string(&toml_candidate)
-                && let Ok(gdvm_toml) = crate::gdvm_toml::deserialize_gdvm_toml(&contents)
-                && let Some(godot) = gdvm_toml.godot
-            {
-                let (registry, variant, version_str) =
-                    crate::version::parse_pinned_str(godot.version.trim());
-                if let Ok(version) = VersionQuery::from_install_str(&version_str) {
-                    return Some(QuerySelection {
-                        version,
-                        variant,
-                        registry,
-                        gdvmrc_fallback: false,
-                    });
-                }
-            }
+        self.find_pin().map(|pin| pin.selection)
+    }
 
-            // Fall back to deprecated .gdvmrc.
-            let rc_candidate = current.join(".gdvmrc");
-            if rc_candidate.is_file()
-                && let Ok(contents) = fs::read_to_string(&rc_candidate)
-            {
-                let (registry, variant, version_str) =
-                    crate::version::parse_pinned_str(contents.trim());
-                if let Ok(version) = VersionQuery::from_install_str(&version_str) {
-                    return Some(QuerySelection {
-                        version,
-                        variant,
-                        registry,
-                        gdvmrc_fallback: true,
-                    });
-                }
-            }
+    fn find_pin(&self) -> Option<PinFile> {
+        find_pin(self.config.pin.tool_files())
+    }
 
-            if !current.pop() {
-                break;
-            }
+    /// The pinned version, narrowed to the exact build recorded in `gdvm.lock`
+    /// when the lock agrees with the pin. With `locked`, a missing or
+    /// disagreeing lock is an error.
+    pub fn get_locked_pin(&self, locked: bool) -> Result<Option<QuerySelection>> {
+        let Some(pin) = self.find_pin() else {
+            return Ok(None);
+        };
+        // Only gdvm.toml pins have a lock.
+        if pin.source != PinSource::GdvmToml {
+            return Ok(Some(pin.selection));
         }
-        None
+
+        let lock_path = pin.dir.join(gdvm_lock::LOCK_FILE);
+        let lock = match gdvm_lock::read(&pin.dir) {
+            Ok(None) if locked => {
+                return Err(
+                    terr!("error-lock-missing", path = lock_path.display().to_string()).into(),
+                );
+            }
+            Ok(None) => return Ok(Some(pin.selection)),
+            Ok(Some(lock)) if self.lock_agrees(&lock.godot, &pin)? => lock.godot,
+            _ if locked => {
+                return Err(terr!(
+                    "error-lock-out-of-date",
+                    path = lock_path.display().to_string()
+                )
+                .into());
+            }
+            _ => {
+                return Ok(Some(QuerySelection {
+                    stale_lock: Some(lock_path),
+                    ..pin.selection
+                }));
+            }
+        };
+
+        Ok(Some(QuerySelection {
+            version: VersionQuery::from_install_str(&lock.version)?,
+            lock_file: Some(lock_path),
+            ..pin.selection
+        }))
+    }
+
+    /// The project's `gdvm.lock` entry, if it locks this exact build.
+    pub(super) fn project_lock(
+        &self,
+        gv: &ResolvedVersion,
+        variant: &Variant,
+        registry: Option<&str>,
+    ) -> Result<Option<(PathBuf, GdvmLockGodot)>> {
+        let Some(pin) = self.find_pin() else {
+            return Ok(None);
+        };
+        let Ok(Some(lock)) = gdvm_lock::read(&pin.dir) else {
+            return Ok(None);
+        };
+        let locks_build = lock.godot.version == gv.to_remote_str()
+            && lock.godot.variant == variant.as_str()
+            && lock.godot.registry == self.registry_url(registry)?;
+        Ok(locks_build.then(|| (pin.dir.join(gdvm_lock::LOCK_FILE), lock.godot)))
+    }
+
+    /// Resolve the nearest `gdvm.toml` pin to an exact build and record it in
+    /// the `gdvm.lock` next to it. Returns the lock file's path and the build.
+    pub async fn update_lock(&self, include_pre: bool) -> Result<(PathBuf, ResolvedSelection)> {
+        let pin = self
+            .find_pin()
+            .filter(|pin| pin.source == PinSource::GdvmToml)
+            .ok_or_else(|| terr!("error-no-pinned-version"))?;
+        let registry = pin.selection.registry.as_deref();
+        let variant = Variant::from_option(pin.selection.variant.as_deref());
+
+        let version = self
+            .catalogs
+            .resolve_available_or_not_found(
+                &pin.selection.version,
+                pin.selection.variant.as_deref(),
+                registry,
+                include_pre,
+                false,
+            )
+            .await?;
+        let meta = self
+            .catalogs
+            .catalog(registry)?
+            .metadata_for(&version)
+            .await?;
+        let platforms = meta
+            .variants
+            .get(variant.as_str())
+            .ok_or_else(|| terr!("unsupported-platform"))?;
+
+        let lock = GdvmLock {
+            godot: GdvmLockGodot {
+                constraint: pin.specifier,
+                version: version.to_remote_str(),
+                variant: variant.as_str().to_string(),
+                registry: self.registry_url(registry)?,
+                sha512: platforms
+                    .iter()
+                    .map(|(platform, binary)| (platform.clone(), binary.sha512.to_lowercase()))
+                    .collect(),
+            },
+            extra: BTreeMap::new(),
+        };
+        gdvm_lock::write(&pin.dir, &lock)?;
+
+        Ok((
+            pin.dir.join(gdvm_lock::LOCK_FILE),
+            ResolvedSelection {
+                version,
+                variant,
+                registry: pin.selection.registry,
+            },
+        ))
+    }
+
+    /// Whether a lock was resolved from the pin as it currently reads.
+    fn lock_agrees(&self, lock: &GdvmLockGodot, pin: &PinFile) -> Result<bool> {
+        let variant = Variant::from_option(pin.selection.variant.as_deref());
+        Ok(lock.constraint == pin.specifier
+            && lock.variant == variant.as_str()
+            && lock.registry == self.registry_url(pin.selection.registry.as_deref())?
+            && VersionQuery::from_install_str(&lock.version)
+                .is_ok_and(|locked| pin.selection.version.matches(&locked)))
+    }
+
+    /// The normalized base URL of a registry, as recorded in lock files.
+    fn registry_url(&self, registry: Option<&str>) -> Result<String> {
+        let base_url = self.catalogs.catalog(registry)?.registry_base_url();
+        Ok(crate::registry::normalize_url(&base_url))
     }
 
     /// Try to determine the version to use based on the current Godot project
@@ -184,6 +406,133 @@ impl<'a> Defaults<'a> {
         project_version_detector::detect_godot_version_in_path(&current_dir)
     }
 
+    /// The `project.godot` file `determine_version` reads for the same path.
+    pub fn project_file<P: AsRef<Path>>(&self, path: Option<P>) -> Option<PathBuf> {
+        let current_dir = match path {
+            Some(p) => p.as_ref().to_path_buf(),
+            None => std::env::current_dir().ok()?,
+        };
+
+        project_version_detector::find_project_file(&current_dir)
+    }
+
+    /// Stamps of everything resolving a version to run from `start_dirs` reads:
+    /// pin files, `gdvm.lock`, `project.godot` and a project's `.csproj` files
+    /// in each directory up to the root, as well as the default, the
+    /// configuration, the release metadata and the install directories.
+    pub fn resolution_stamps(&self, start_dirs: &[PathBuf]) -> Result<Vec<Stamp>> {
+        let mut paths = vec![
+            crate::config::ConfigFile::path()?,
+            self.paths.default_file(),
+            self.paths.cache_index().to_path_buf(),
+        ];
+        // Installs come and go in `<store>/<variant>`, which changes the
+        // variant's directory, and a new store or variant changes the one above.
+        let subdirs = |dir: &Path| -> Vec<PathBuf> {
+            fs::read_dir(dir)
+                .map(|entries| {
+                    entries
+                        .filter_map(|entry| entry.ok())
+                        .filter(|entry| entry.file_type().is_ok_and(|ft| ft.is_dir()))
+                        .map(|entry| entry.path())
+                        .collect()
+                })
+                .unwrap_or_default()
+        };
+        for installs in [Some(self.paths.installs()), self.paths.system_installs()]
+            .into_iter()
+            .flatten()
+        {
+            paths.push(installs.to_path_buf());
+            for store in subdirs(installs) {
+                paths.extend(subdirs(&store));
+                paths.push(store);
+            }
+        }
+
+        let mut seen = std::collections::HashSet::new();
+        for dir in start_dirs.iter().flat_map(|dir| dir.ancestors()) {
+            if !seen.insert(dir.to_path_buf()) {
+                continue;
+            }
+            paths.extend(
+                PinSource::PRECEDENCE
+                    .iter()
+                    .map(|source| source.file_name())
+                    .chain([gdvm_lock::LOCK_FILE, "project.godot"])
+                    .map(|name| dir.join(name)),
+            );
+            if dir.join("project.godot").is_file()
+                && let Ok(entries) = fs::read_dir(dir)
+            {
+                // The directory changes when `.csproj` files come and go.
+                paths.push(dir.to_path_buf());
+                paths.extend(
+                    entries
+                        .filter_map(|entry| entry.ok())
+                        .map(|entry| entry.path())
+                        .filter(|path| path.extension().is_some_and(|ext| ext == "csproj")),
+                );
+            }
+        }
+
+        Ok(paths.iter().map(|path| Stamp::of(path)).collect())
+    }
+
+    /// The launch settings of the nearest `gdvm.toml` from the project in
+    /// `project`, or from the current directory without one, from `[run]`
+    /// layered with `profile` if given. The working directory is made
+    /// absolute. Without a `gdvm.toml`, only a missing `profile` is an error.
+    pub fn launch_profile(
+        &self,
+        project: Option<&Path>,
+        profile: Option<&str>,
+    ) -> Result<GdvmTomlLaunch> {
+        let not_found = || terr!("error-profile-not-found", profile = profile.unwrap_or(""));
+        let found = match project {
+            Some(project) => find_gdvm_toml_from(project.to_path_buf()),
+            None => find_gdvm_toml(),
+        };
+        let Some((dir, toml)) = found else {
+            return match profile {
+                Some(_) => Err(not_found().into()),
+                None => Ok(GdvmTomlLaunch::default()),
+            };
+        };
+
+        let mut launch = toml.launch(profile).ok_or_else(not_found)?;
+        launch.working_dir = launch.working_dir.map(|working_dir| dir.join(working_dir));
+        Ok(launch)
+    }
+
+    /// Variables for Godot from the `.env` files of the project in `project`,
+    /// or from the nearest `.env` without a project. `profile` layers
+    /// `.env.<profile>` over `.env`. The `[dotenv]` table of the nearest
+    /// `gdvm.toml` decides which variables are passed.
+    pub fn dotenv_vars(
+        &self,
+        project: Option<&Path>,
+        profile: Option<&crate::dotenv::EnvProfile>,
+    ) -> Result<Vec<(String, String)>> {
+        let dir = match project {
+            Some(project) => project.to_path_buf(),
+            None => {
+                let cwd = std::env::current_dir()?;
+                cwd.ancestors()
+                    .find(|dir| dir.join(crate::dotenv::DOTENV_FILE).is_file())
+                    .map_or_else(|| cwd.clone(), Path::to_path_buf)
+            }
+        };
+        let rules = find_gdvm_toml_from(dir.clone())
+            .and_then(|(_, toml)| toml.dotenv)
+            .unwrap_or_default();
+
+        Ok(crate::dotenv::load(&dir, profile)?
+            .into_iter()
+            .filter(|(name, _)| rules.allows(name))
+            .collect())
+    }
+
     /// Pin a version to gdvm.toml in the current directory.
     pub fn pin_version(
         &self,
@@ -195,8 +544,12 @@ impl<'a> Defaults<'a> {
         let path = std::env::current_dir()?;
 
         let specifier = crate::version::pinned_str(registry, &gv.to_pinned_str(), variant);
-        let toml_content = crate::gdvm_toml::serialize_gdvm_toml(&specifier);
-        crate::fs_utils::atomic_write(&path.join("gdvm.toml"), &toml_content)?;
+        let toml_path = path.join("gdvm.toml");
+        let toml_content = match fs::read_to_string(&toml_path) {
+            Ok(existing) => crate::gdvm_toml::update_gdvm_toml_pin(&existing, &specifier),
+            Err(_) => crate::gdvm_toml::serialize_gdvm_toml(&specifier),
+        };
+        crate::fs_utils::atomic_write(&toml_path, &toml_content)?;
 
         // Write deprecated .gdvmrc for backward compatibility with older versions of gdvm.
         // The legacy format predates registries, so we skip writing it for builds from custom
@@ -209,3 +562,141 @@ impl<'a> Defaults<'a> {
         Ok(())
     }
 }
+
+/// A file a pin can be read from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PinSource {
+    GdvmToml,
+    /// The deprecated `.gdvmrc`.
+    Gdvmrc,
+    GodotVersion,
+    ToolVersions,
+    MiseToml,
+}
+
+impl PinSource {
+    /// Sources in order of precedence within one directory. The nearest
+    /// directory with any pin wins before precedence is considered.
+    const PRECEDENCE: [PinSource; 5] = [
+        PinSource::GdvmToml,
+        PinSource::Gdvmrc,
+        PinSource::GodotVersion,
+        PinSource::ToolVersions,
+        PinSource::MiseToml,
+    ];
+
+    fn file_name(self) -> &'static str {
+        match self {
+            PinSource::GdvmToml => "gdvm.toml",
+            PinSource::Gdvmrc => ".gdvmrc",
+            PinSource::GodotVersion => pin_files::GODOT_VERSION_FILE,
+            PinSource::ToolVersions => pin_files::TOOL_VERSIONS_FILE,
+            PinSource::MiseToml => pin_files::MISE_TOML_FILE,
+        }
+    }
+
+    /// Whether the file is written for another version manager.
+    fn is_tool_file(self) -> bool {
+        !matches!(self, PinSource::GdvmToml | PinSource::Gdvmrc)
+    }
+
+    /// The version specifier pinned in the file's contents.
+    fn specifier(self, contents: &str) -> Option<String> {
+        match self {
+            PinSource::GdvmToml => crate::gdvm_toml::deserialize_gdvm_toml(contents)
+                .ok()?
+                .godot
+                .map(|godot| godot.version.trim().to_string()),
+            PinSource::Gdvmrc => Some(contents.trim().to_string()),
+            PinSource::GodotVersion => pin_files::parse_godot_version(contents).map(str::to_string),
+            PinSource::ToolVersions => pin_files::parse_tool_versions(contents).map(str::to_string),
+            PinSource::MiseToml => pin_files::parse_mise_toml(contents),
+        }
+    }
+
+    /// Parse the version out of a specifier. `.gdvmrc` uses the legacy install
+    /// format.
+    fn parse_version(self, version: &str) -> Result<VersionQuery> {
+        match self {
+            PinSource::Gdvmrc => VersionQuery::from_install_str(version),
+            _ => VersionQuery::from_remote_str(version),
+        }
+    }
+}
+
+/// A pin and the file it was read from.
+struct PinFile {
+    dir: PathBuf,
+    source: PinSource,
+    /// The version specifier as written in the pin file.
+    specifier: String,
+    selection: QuerySelection,
+}
+
+/// Recursively search upward for a `gdvm.toml` that can be parsed. Returns it
+/// with the directory it's in.
+fn find_gdvm_toml() -> Option<(PathBuf, GdvmToml)> {
+    find_gdvm_toml_from(std::env::current_dir().ok()?)
+}
+
+/// Search upward from `current` for a `gdvm.toml` that can be parsed.
+fn find_gdvm_toml_from(mut current: PathBuf) -> Option<(PathBuf, GdvmToml)> {
+    loop {
+        if let Some(toml) = fs::read_to_string(current.join("gdvm.toml"))
+            .ok()
+            .and_then(|contents| crate::gdvm_toml::deserialize_gdvm_toml(&contents).ok())
+        {
+            return Some((current, toml));
+        }
+
+        if !current.pop() {
+            return None;
+        }
+    }
+}
+
+/// Recursively search upward for a pin. In each directory, `gdvm.toml` comes
+/// first, then the deprecated `.gdvmrc`, then the files of other version
+/// managers unless `tool_files` is off.
+fn find_pin(tool_files: bool) -> Option<PinFile> {
+    let mut current = std::env::current_dir().ok()?;
+    loop {
+        for source in PinSource::PRECEDENCE {
+            if source.is_tool_file() && !tool_files {
+                continue;
+            }
+            let candidate = current.join(source.file_name());
+            if !candidate.is_file() {
+                continue;
+            }
+            let Some(specifier) = fs::read_to_string(&candidate)
+                .ok()
+                .and_then(|contents| source.specifier(&contents))
+            else {
+                continue;
+            };
+            let (registry, variant, version_str) = crate::version::parse_pinned_str(&specifier);
+            if let Ok(version) = source.parse_version(&version_str) {
+                return Some(PinFile {
+                    specifier,
+                    dir: current,
+                    source,
+                    selection: QuerySelection {
+                        version,
+                        variant,
+                        registry,
+                        gdvmrc_fallback: source == PinSource::Gdvmrc,
+                        stale_lock: None,
+                        lock_file: None,
+                        pin_file: Some(candidate),
+                    },
+                });
+            }
+        }
+
+        if !current.pop() {
+            break;
+        }
+    }
+    None
+}
@@ -23,7 +23,9 @@ use anyhow::Result;
 
 use super::*;
 use crate::artifact_cache::ArtifactCache;
+use crate::config::Config;
 use crate::hash_utils::{self, ShaType};
+use crate::install_receipt::InstallReceipt;
 use crate::paths::GdvmPaths;
 use crate::registry_version_resolver::RegistryVersionResolver;
 use crate::usage_tracker::UsageTracker;
@@ -103,6 +105,8 @@ pub struct Installer<'a> {
     pub(super) artifact_cache: &'a ArtifactCache,
     pub(super) usage_tracker: &'a UsageTracker,
     pub(super) catalogs: Catalogs<'a>,
+    pub(super) config: &'a Config,
+    pub(super) locked: bool,
 }
 
 impl<'a> Installer<'a> {
@@ -118,6 +122,72 @@ impl<'a> Installer<'a> {
         }
     }
 
+    fn pruner(&self) -> Pruner<'a> {
+        Pruner {
+            paths: self.paths,
+            artifact_cache: self.artifact_cache,
+            usage_tracker: self.usage_tracker,
+            catalogs: self.catalogs,
+            defaults: self.defaults(),
+        }
+    }
+
+    fn defaults(&self) -> Defaults<'a> {
+        Defaults {
+            paths: self.paths,
+            usage_tracker: self.usage_tracker,
+            catalogs: self.catalogs,
+            config: self.config,
+        }
+    }
+
+    fn deduper(&self) -> Deduper<'a> {
+        Deduper {
+            paths: self.paths,
+            usage_tracker: self.usage_tracker,
+            catalogs: self.catalogs,
+        }
+    }
+
+    /// Remove a specified Godot version. A default pointing at it is unset,
+    /// and a floating default moves on to another match.
+    pub fn remove(
+        &self,
+        gv: &ResolvedVersion,
+        variant: &Variant,
+        registry: Option<&str>,
+    ) -> Result<()> {
+        let install_name = crate::version::install_dir_subpath(
+            &self.library().install_store_key(registry)?,
+            &gv.to_remote_str(),
+            variant,
+        );
+
+        let _lock = crate::locks::Lock::acquire(
+            &self.paths.locks(),
+            crate::locks::Resource::Install(&install_name),
+        )?;
+
+        let floating = self.defaults().get_floating_default()?.is_some();
+        let was_default = self.defaults().get_default()?.is_some_and(|def| {
+            def.version.to_remote_str() == gv.to_remote_str()
+                && def.variant == *variant
+                && crate::registry::normalize_registry(def.registry.as_deref())
+                    == crate::registry::normalize_registry(registry)
+        });
+
+        self.library()
+            .remove_locked(gv, variant, registry, &install_name)?;
+
+        if floating {
+            self.defaults().refresh_floating_default()?;
+        } else if was_default {
+            self.defaults().unset_default()?;
+        }
+        self.library().sync_version_shims();
+        Ok(())
+    }
+
     /// Install a specified Godot version
     ///
     /// - `variant`: Optional variant, e.g. `Some("csharp")`.
@@ -137,13 +207,23 @@ impl<'a> Installer<'a> {
             crate::version::install_dir_subpath(&store_key, &gv.to_remote_str(), variant);
         let version_path = self.paths.installs().join(&install_str);
 
-        let _lock = crate::locks::Lock::acquire(
+        let lock = crate::locks::Lock::acquire(
             &self.paths.locks(),
             crate::locks::Resource::Install(&install_str),
         )?;
 
         let display = crate::version::display_version(gv, variant, registry);
 
+        // A working copy in the system-wide store is used instead of a user
+        // install.
+        if !force
+            && let Some(system) = self.paths.system_installs()
+            && system.join(&install_str).is_dir()
+            && crate::app::find_godot_executable(&system.join(&install_str), false)?.is_some()
+        {
+            return Ok(InstallOutcome::AlreadyInstalled);
+        }
+
         if version_path.exists() {
             let healthy = crate::app::find_godot_executable(&version_path, false)?.is_some();
 
@@ -154,8 +234,15 @@ impl<'a> Installer<'a> {
                         version = &display
                     ));
                 }
-                self.library()
-                    .remove_locked(gv, variant, registry, &install_str)?;
+                // A self-contained install keeps its editor data.
+                crate::self_contained::set_aside(&version_path)?;
+                if let Err(error) =
+                    self.library()
+                        .remove_locked(gv, variant, registry, &install_str)
+                {
+                    crate::self_contained::restore(&version_path, &version_path)?;
+                    return Err(error);
+                }
             } else {
                 return Ok(InstallOutcome::AlreadyInstalled);
             }
@@ -172,6 +259,7 @@ impl<'a> Installer<'a> {
         let meta = self.catalogs().catalog(registry)?.metadata_for(gv).await?;
 
         let binary = self.catalogs().select_platform_binary(&meta, variant)?;
+        self.check_locked_hash(gv, variant, registry, &binary.sha512)?;
 
         let download_url = binary.urls.first().unwrap();
         let cache_zip_path = self.artifact_cache.cached_zip_path(&binary.sha512);
@@ -224,19 +312,124 @@ impl<'a> Installer<'a> {
         // Extract from cache_zip_path
         zip_utils::extract_zip_from_file(&mut zip_file, &cache_zip_path, staging.path(), &display)?;
 
+        let base_url = self.catalogs().catalog(registry)?.registry_base_url();
+        InstallReceipt::create(
+            staging.path(),
+            &base_url,
+            &gv.to_remote_str(),
+            variant.as_str(),
+            &binary.sha512,
+        )?
+        .write(staging.path())?;
+
         staging.commit(&version_path)?;
+        // Only moved back once the install is in place. Until then, a failed
+        // install leaves it set aside for the next one.
+        crate::self_contained::restore(&version_path, &version_path)?;
 
-        let base_url = self.catalogs().catalog(registry)?.registry_base_url();
         let store_dir = self.paths.installs().join(&store_key);
         crate::registry_store::upsert(&store_dir, &base_url, registry, None)?;
 
         self.library().track_install_use(&install_str)?;
+        self.defaults().refresh_floating_default()?;
 
         ui::milestone(t!("status-installed"), &display);
+        self.library().sync_version_shims();
+
+        if let Some(max_size) = self.config.cache.max_size
+            && let Err(error) = self.pruner().trim_cache(max_size.bytes(), false)
+        {
+            ui::warn(t!("warning-cache-trim-failed", error = error.to_string()));
+        }
+
+        if self.config.install.dedupe() {
+            // Dedupe locks every install itself, including this one.
+            drop(lock);
+            self.dedupe_after_install();
+        }
 
         Ok(InstallOutcome::Installed)
     }
 
+    /// Compare the archive's hash with the one the project's `gdvm.lock`
+    /// records, when installing the build it locks.
+    fn check_locked_hash(
+        &self,
+        gv: &ResolvedVersion,
+        variant: &Variant,
+        registry: Option<&str>,
+        sha512: &str,
+    ) -> Result<()> {
+        let Some((lock_path, lock)) = self.defaults().project_lock(gv, variant, registry)? else {
+            return Ok(());
+        };
+        let candidates = crate::registry::platform_candidates(*self.catalogs.host);
+        let expected = candidates
+            .iter()
+            .find_map(|platform| lock.sha512.get(platform));
+
+        let version = crate::version::display_version(gv, variant, registry);
+        let path = lock_path.display().to_string();
+        let Some(expected) = expected else {
+            // The build for this platform may have been published after the
+            // lock was written. There's nothing to compare, which isn't a
+            // mismatch.
+            let platform = candidates[0].as_str();
+            if self.locked {
+                return Err(terr!(
+                    "error-lock-platform-missing",
+                    version = version,
+                    platform = platform,
+                    path = path
+                )
+                .into());
+            }
+            ui::note(t!(
+                "lock-platform-missing",
+                version = version,
+                platform = platform,
+                path = path
+            ));
+            return Ok(());
+        };
+        if expected.eq_ignore_ascii_case(sha512) {
+            return Ok(());
+        }
+
+        if self.locked {
+            return Err(terr!("error-lock-hash-mismatch", version = version, path = path).into());
+        }
+        ui::warn(t!(
+            "warning-lock-hash-mismatch",
+            version = version,
+            path = path
+        ));
+        Ok(())
+    }
+
+    /// Share files of the new install with identical files in other installs.
+    /// Failing to do so doesn't fail the install.
+    fn dedupe_after_install(&self) {
+        match self.deduper().dedupe(false) {
+            Ok(report) if report.files > 0 => {
+                let (value, unit) = crate::fs_utils::byte_display_args(report.saved_bytes);
+                ui::note(t!(
+                    "dedupe-shared-files",
+                    count = report.files,
+                    value = value,
+                    unit = unit
+                ));
+            }
+            Ok(_) => {}
+            Err(error) => ui::warn(t!("warning-dedupe-failed", error = error.to_string())),
+        }
+    }
+
+    /// Whether the archive with the given SHA-512 is in the cache.
+    pub fn has_cached_archive(&self, sha512: &str) -> bool {
+        self.artifact_cache.cached_zip_path(sha512).is_file()
+    }
+
     /// Resolve the path to the cached download archive for a release.
     pub async fn cached_archive_path(
         &self,
@@ -269,6 +462,18 @@ impl<'a> Installer<'a> {
         T: Into<VersionQuery> + Clone,
     {
         let gv: VersionQuery = gv.clone().into();
+
+        // Prefer an installed version that satisfies a range over downloading
+        // a newer one.
+        if gv.range.is_some()
+            && let Ok(installed) = self
+                .library()
+                .resolve_installed_newest(&gv, variant, registry)
+                .await
+        {
+            return Ok(installed.version);
+        }
+
         let resolver =
             RegistryVersionResolver::new(self.catalogs().catalog(registry)?, *self.catalogs.host);
 
@@ -15,6 +15,7 @@
 // You should have received a copy of the GNU General Public License along with
 // this program. If not, see <https://www.gnu.org/licenses/>.
 
+use std::collections::BTreeMap;
 use std::fs;
 use std::path::{Path, PathBuf};
 
@@ -23,9 +24,11 @@ use anyhow::Result;
 use daemonize::Daemonize;
 
 use super::*;
+use crate::config::Config;
+use crate::crash_reports::{CrashRecord, OutputTail};
 use crate::paths::GdvmPaths;
-use crate::usage_tracker::UsageTracker;
-use crate::version::{ResolvedVersion, Variant};
+use crate::usage_tracker::{ProcessRecord, UsageTracker};
+use crate::version::{ResolvedVersion, Variant, VersionQuery};
 
 /// Searches for the Godot executable within the given directory.
 ///
@@ -177,12 +180,54 @@ fn find_macos_app_executable(app_path: &Path) -> Option<PathBuf> {
     None
 }
 
+/// The project directory of a launch. Without a project, the current
+/// directory is used as one.
+fn launch_project(options: &LaunchOptions) -> Result<PathBuf> {
+    match &options.project {
+        Some(project) => Ok(project.clone()),
+        None => Ok(std::env::current_dir()?),
+    }
+}
+
+/// Editor settings of an older version that can be carried forward.
+#[derive(Debug, Clone)]
+pub struct SettingsCarry {
+    /// The older version the settings come from.
+    pub from: String,
+    /// Where the older version keeps its settings.
+    pub source: PathBuf,
+    pub source_file: String,
+    /// Where the new version will look for its settings.
+    pub target: PathBuf,
+    pub target_file: String,
+}
+
+/// How `Launcher::run` starts Godot.
+#[derive(Debug, Default)]
+pub struct LaunchOptions {
+    /// Attach Godot to the console and wait for it to exit.
+    pub console: bool,
+    /// Arguments passed to Godot.
+    pub args: Vec<String>,
+    /// Environment variables set for Godot, over those from `dotenv`.
+    pub env: BTreeMap<String, String>,
+    /// Variables from the project's `.env` files.
+    pub dotenv: Vec<(String, String)>,
+    /// The directory Godot starts in, instead of the current one.
+    pub working_dir: Option<PathBuf>,
+    /// The directory of the project being run.
+    pub project: Option<PathBuf>,
+    /// Give the project its own editor settings, caches and export templates,
+    /// apart from those of every other project.
+    pub self_contained: bool,
+}
+
 #[derive(Clone, Copy)]
 pub struct Launcher<'a> {
     pub(super) paths: &'a GdvmPaths,
     pub(super) usage_tracker: &'a UsageTracker,
     pub(super) catalogs: Catalogs<'a>,
-    pub(super) dotenv_vars: &'a [(String, String)],
+    pub(super) config: &'a Config,
 }
 
 impl<'a> Launcher<'a> {
@@ -200,52 +245,356 @@ impl<'a> Launcher<'a> {
         gv: &ResolvedVersion,
         variant: &Variant,
         registry: Option<&str>,
-        console: bool,
-        godot_args: &[String],
+        options: &LaunchOptions,
     ) -> Result<i32> {
         let path = self
             .library()
-            .get_executable_path(gv, variant, registry, console)?;
+            .get_executable_path(gv, variant, registry, options.console)?;
 
-        let dotenv_vars = self
-            .dotenv_vars
-            .iter()
-            .map(|(k, v)| (k.as_str(), v.as_str()));
+        let mut command = std::process::Command::new(&path);
+        command
+            .args(&options.args)
+            .envs(options.dotenv.iter().map(|(k, v)| (k.as_str(), v.as_str())))
+            .envs(self.project_data_env(options)?)
+            .envs(&options.env);
+        if let Some(working_dir) = &options.working_dir {
+            command.current_dir(working_dir);
+        }
 
-        if console {
+        let install_key = self.library().install_key(gv, variant, registry)?;
+        // Best effort, like tracking the process below.
+        self.usage_tracker.record_launch(&install_key).ok();
+        let mut record = ProcessRecord {
+            install_key,
+            executable: path.clone(),
+            project: options.project.clone(),
+            started: crate::date_utils::now_unix_secs(),
+            start_time: None,
+        };
+
+        if options.console {
             // Run the process attached to the terminal and wait for it to exit
-            let status = std::process::Command::new(&path)
-                .args(godot_args)
-                .envs(dotenv_vars)
+            // Godot's output only goes through gdvm when it's kept for crash
+            // records.
+            let capture = self.config.crashes.capture_output();
+            let output = || {
+                if capture {
+                    std::process::Stdio::piped()
+                } else {
+                    std::process::Stdio::inherit()
+                }
+            };
+            // Looked up before Godot runs, so that an error here can't replace
+            // its exit code.
+            let registry_url = self.catalogs.catalog(registry)?.registry_base_url();
+            let mut child = command
                 .stdin(std::process::Stdio::inherit())
-                .stdout(std::process::Stdio::inherit())
-                .stderr(std::process::Stdio::inherit())
-                .status()?;
+                .stdout(output())
+                .stderr(output())
+                .spawn()?;
+            let tail = capture.then(|| OutputTail::attach(&mut child));
+            record.start_time = crate::process_utils::process_start_time(child.id());
+            // Tracking is best effort and mustn't stop Godot from running.
+            let tracked = self
+                .usage_tracker
+                .record_process(child.id(), record.clone())
+                .is_ok();
+            let status = child.wait()?;
+            let output = tail.map(OutputTail::finish).unwrap_or_default();
+            if tracked {
+                self.usage_tracker.forget_process(child.id()).ok();
+            }
 
-            return Ok(crate::process_utils::child_exit_code(status));
+            let exit_code = crate::process_utils::child_exit_code(status);
+            if let Some(reason) = crate::process_utils::crash_reason(status) {
+                let crash = CrashRecord {
+                    time: crate::date_utils::now_iso8601(),
+                    version: gv.to_remote_str(),
+                    variant: variant.as_str().to_string(),
+                    registry: registry_url,
+                    reason,
+                    exit_code,
+                    executable: record.executable,
+                    args: options.args.clone(),
+                    project: record.project,
+                    gdvm_version: env!("CARGO_PKG_VERSION").to_string(),
+                    os: std::env::consts::OS.to_string(),
+                    arch: std::env::consts::ARCH.to_string(),
+                    output,
+                };
+                let version = crate::version::display_version(gv, variant, registry);
+                self.report_crash(&version, &crash);
+            }
+
+            return Ok(exit_code);
         }
 
+        // Detached launches keep their output in a log, since there's no
+        // console to show it.
+        let version = crate::version::display_version(gv, variant, registry);
+        let project_name = options
+            .project
+            .as_deref()
+            .and_then(Path::file_name)
+            .map(|name| name.to_string_lossy());
+        let log = match self.create_log(&version, project_name.as_deref()) {
+            Ok(log) => Some(log),
+            Err(e) => {
+                crate::ui::warn(t!("warning-launch-log-failed", error = e.to_string()));
+                None
+            }
+        };
+
         // Detached process configuration
         #[cfg(target_family = "unix")]
-        {
-            Daemonize::new()
-                .start()
-                .map_err(|e| crate::terr!("error-starting-godot").with_source(e))?;
-            std::process::Command::new(&path)
-                .args(godot_args)
-                .envs(dotenv_vars)
-                .spawn()?;
+        Daemonize::new()
+            .start()
+            .map_err(|e| crate::terr!("error-starting-godot").with_source(e))?;
+
+        let child = crate::process_utils::spawn_detached(&mut command, log)?;
+        record.start_time = crate::process_utils::process_start_time(child.id());
+        self.usage_tracker.record_process(child.id(), record).ok();
+
+        Ok(0)
+    }
+
+    /// The variables pointing Godot at the project's own data directory, when
+    /// it runs self-contained. Without a project, the current directory is
+    /// used as one.
+    fn project_data_env(&self, options: &LaunchOptions) -> Result<Vec<(&'static str, PathBuf)>> {
+        if !options.self_contained {
+            return Ok(Vec::new());
+        }
+        if !crate::self_contained::PROJECT_DATA_SUPPORTED {
+            crate::ui::warn(t!("warning-self-contained-project-unsupported"));
+            return Ok(Vec::new());
         }
+        let data_dir = crate::self_contained::prepare_project(
+            &self.paths.editor_data(),
+            &launch_project(options)?,
+        )?;
+        #[cfg(target_family = "unix")]
+        if let Err(e) = crate::self_contained::link_user_dirs(&data_dir) {
+            crate::ui::warn(t!(
+                "warning-self-contained-link-failed",
+                error = e.to_string()
+            ));
+        }
+        Ok(crate::self_contained::project_env(&data_dir).to_vec())
+    }
 
-        #[cfg(target_family = "windows")]
-        {
-            crate::process_utils::spawn_detached(
-                std::process::Command::new(&path)
-                    .args(godot_args)
-                    .envs(dotenv_vars),
+    /// The editor settings of an older version to carry forward, when this is
+    /// the first time gdvm launches this major.minor version and it has no
+    /// settings yet. They come from the newest older install of the same major
+    /// version that has any.
+    pub fn settings_to_carry(
+        &self,
+        gv: &ResolvedVersion,
+        variant: &Variant,
+        registry: Option<&str>,
+        options: &LaunchOptions,
+    ) -> Result<Option<SettingsCarry>> {
+        let launched_before = self
+            .usage_tracker
+            .launched_installs()?
+            .iter()
+            .filter_map(|key| key.rsplit('/').next())
+            .filter_map(|leaf| VersionQuery::from_install_str(leaf).ok())
+            .any(|launched| launched.major == Some(gv.major) && launched.minor == Some(gv.minor));
+        if launched_before {
+            return Ok(None);
+        }
+
+        let project_data =
+            if options.self_contained && crate::self_contained::PROJECT_DATA_SUPPORTED {
+                Some(crate::self_contained::project_data_dir(
+                    &self.paths.editor_data(),
+                    &launch_project(options)?,
+                ))
+            } else {
+                None
+            };
+        let library = self.library();
+        let (_, install_dir) = library.install_dir(gv, variant, registry)?;
+        let target = crate::editor_settings::config_dir(&install_dir, project_data.as_deref())?;
+        let target_file = crate::editor_settings::settings_file_name(gv);
+        if target.join(&target_file).exists() {
+            return Ok(None);
+        }
+
+        let mut older: Vec<InstalledVersion> = library
+            .list_installed()?
+            .into_iter()
+            .filter(|installed| {
+                installed.version.major == gv.major && installed.version.minor < gv.minor
+            })
+            .collect();
+        older.sort_by(|a, b| b.version.cmp(&a.version));
+
+        for installed in older {
+            let (_, dir) = library.install_dir(
+                &installed.version,
+                &installed.variant,
+                installed.registry.as_deref(),
             )?;
+            let source = crate::editor_settings::config_dir(&dir, project_data.as_deref())?;
+            let source_file = crate::editor_settings::settings_file_name(&installed.version);
+            if source.join(&source_file).is_file() {
+                return Ok(Some(SettingsCarry {
+                    from: installed.display(),
+                    source,
+                    source_file,
+                    target,
+                    target_file,
+                }));
+            }
         }
+        Ok(None)
+    }
 
-        Ok(0)
+    /// Carry editor settings forward. Returns the directory that the files
+    /// it replaced were backed up to, if any were.
+    pub fn carry_settings(&self, carry: &SettingsCarry) -> Result<Option<PathBuf>> {
+        let timestamp = jiff::Timestamp::now()
+            .strftime("%Y%m%dT%H%M%S%3fZ")
+            .to_string();
+        let backup = self.paths.settings_backups().join(timestamp);
+        let backed_up = crate::editor_settings::carry(
+            (&carry.source, &carry.source_file),
+            (&carry.target, &carry.target_file),
+            &backup,
+        )?;
+        Ok(backed_up.then_some(backup))
     }
+
+    /// Print a summary of a crash and save its record.
+    fn report_crash(&self, version: &str, crash: &CrashRecord) {
+        crate::ui::error(t!(
+            "godot-crashed",
+            version = version,
+            reason = crash.reason.as_str()
+        ));
+        let mut details = vec![
+            (t!("crash-label-version"), crash.version.clone()),
+            (t!("crash-label-variant"), crash.variant.clone()),
+            (t!("crash-label-registry"), crash.registry.clone()),
+            (t!("crash-label-args"), crash.args.join(" ")),
+        ];
+        if let Some(project) = &crash.project {
+            details.push((t!("crash-label-project"), project.display().to_string()));
+        }
+        for (label, value) in details {
+            eprintln!("  {label}: {value}");
+        }
+
+        match crate::crash_reports::save(&self.paths.crashes(), crash) {
+            Ok(path) => crate::ui::note(t!("crash-saved", path = path.display().to_string())),
+            Err(e) => crate::ui::warn(t!("warning-crash-save-failed", error = e.to_string())),
+        }
+    }
+
+    /// The crash records saved for attached runs, oldest first.
+    pub fn crashes(&self) -> Vec<(PathBuf, CrashRecord)> {
+        crate::crash_reports::list(&self.paths.crashes())
+    }
+
+    /// The Godot processes gdvm started that are still running, oldest first.
+    pub fn running(&self) -> Result<Vec<(u32, ProcessRecord)>> {
+        self.usage_tracker.running_processes()
+    }
+
+    /// Stop a running Godot process that gdvm started. Returns its record.
+    pub fn stop(&self, pid: u32) -> Result<ProcessRecord> {
+        let Some((_, record)) = self
+            .running()?
+            .into_iter()
+            .find(|(running, _)| *running == pid)
+        else {
+            return Err(crate::terr!("error-process-not-tracked", pid = pid).into());
+        };
+        // Without the start time, the PID may belong to an unrelated process
+        // by now.
+        if !record.is_confirmed(pid) {
+            return Err(crate::terr!("error-process-unconfirmed", pid = pid).into());
+        }
+        crate::process_utils::terminate_process(pid)
+            .map_err(|e| crate::terr!("error-process-stop-failed", pid = pid).with_source(e))?;
+        self.usage_tracker.forget_process(pid)?;
+        Ok(record)
+    }
+
+    /// The logs of detached launches, oldest first.
+    pub fn logs(&self) -> Vec<PathBuf> {
+        crate::launch_logs::list(&self.paths.logs())
+    }
+
+    /// Remove logs older than `logs.max-age-days`, then create the log for a
+    /// detached launch.
+    fn create_log(&self, version: &str, project: Option<&str>) -> std::io::Result<fs::File> {
+        let dir = self.paths.logs();
+        let max_age_secs = self.config.logs.max_age_days().saturating_mul(24 * 60 * 60);
+        crate::launch_logs::remove_older_than(
+            &dir,
+            max_age_secs,
+            crate::date_utils::now_unix_secs(),
+        );
+        let (_, file) = crate::launch_logs::create(&dir, version, project)?;
+        Ok(file)
+    }
+
+    /// Run `command` with the given Godot first on `PATH` as `godot`, and with
+    /// `GODOT`, `GODOT_BIN` and `GODOT_VERSION` set, along with the `dotenv`
+    /// variables. Returns the command's exit code.
+    pub fn exec(
+        &self,
+        gv: &ResolvedVersion,
+        variant: &Variant,
+        registry: Option<&str>,
+        command: &[String],
+        dotenv: &[(String, String)],
+    ) -> Result<i32> {
+        let path = self
+            .library()
+            .get_executable_path(gv, variant, registry, true)?;
+        let Some((program, args)) = command.split_first() else {
+            return Ok(0);
+        };
+
+        // Removed when dropped, after the command exits.
+        let bin_dir = tempfile::Builder::new().prefix("gdvm-exec-").tempdir()?;
+        link_godot(&path, bin_dir.path())?;
+
+        let mut search_path = vec![bin_dir.path().to_path_buf()];
+        if let Some(existing) = std::env::var_os("PATH") {
+            search_path.extend(std::env::split_paths(&existing));
+        }
+
+        let version = crate::version::display_version(gv, variant, registry);
+        let status = std::process::Command::new(program)
+            .args(args)
+            .envs(dotenv.iter().map(|(k, v)| (k.as_str(), v.as_str())))
+            .env("PATH", std::env::join_paths(search_path)?)
+            .env("GODOT", &path)
+            .env("GODOT_BIN", &path)
+            .env("GODOT_VERSION", version)
+            .status()
+            .map_err(|e| crate::terr!("error-exec-failed", command = program).with_source(e))?;
+
+        Ok(crate::process_utils::child_exit_code(status))
+    }
+}
+
+/// Make `exe` available as `godot` in `dir`. Windows links can't be created
+/// without privileges, and Godot looks for its data next to the path it was
+/// started from, so a batch file that starts it is used there instead.
+fn link_godot(exe: &Path, dir: &Path) -> Result<()> {
+    #[cfg(target_family = "unix")]
+    std::os::unix::fs::symlink(exe, dir.join("godot"))?;
+    #[cfg(target_family = "windows")]
+    fs::write(
+        dir.join("godot.cmd"),
+        format!("@\"{}\" %*\r\n", exe.display()),
+    )?;
+
+    Ok(())
 }
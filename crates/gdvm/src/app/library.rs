@@ -16,15 +16,16 @@
 // this program. If not, see <https://www.gnu.org/licenses/>.
 
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 use anyhow::Result;
 
 use super::*;
+use crate::install_receipt::{InstallReceipt, ReceiptDiff};
 use crate::paths::GdvmPaths;
-use crate::terr;
 use crate::usage_tracker::UsageTracker;
 use crate::version::{ResolvedVersion, Variant, VersionQuery};
+use crate::{t, terr, ui};
 
 #[derive(Debug, Clone)]
 pub struct InstalledVersion {
@@ -39,6 +40,19 @@ impl InstalledVersion {
     }
 }
 
+/// The newest stable patch release of an installed major.minor series, and
+/// the older installs of the series it replaces.
+#[derive(Debug, Clone)]
+pub struct SeriesUpdate {
+    pub variant: Variant,
+    pub registry: Option<String>,
+    /// The newest stable release of the series.
+    pub latest: ResolvedVersion,
+    /// The installs of the series that the newest release replaces, oldest
+    /// first.
+    pub replaced: Vec<ResolvedVersion>,
+}
+
 #[derive(Clone, Copy)]
 pub struct Library<'a> {
     pub(super) paths: &'a GdvmPaths,
@@ -51,8 +65,8 @@ impl<'a> Library<'a> {
         self.catalogs
     }
 
-    fn defaults(&self) -> Defaults<'a> {
-        Defaults {
+    pub(super) fn deduper(&self) -> Deduper<'a> {
+        Deduper {
             paths: self.paths,
             usage_tracker: self.usage_tracker,
             catalogs: self.catalogs,
@@ -91,10 +105,21 @@ impl<'a> Library<'a> {
             &gv.to_remote_str(),
             variant,
         );
-        let path = self.paths.installs().join(&subpath);
+        let path = self.locate(&subpath);
         Ok((subpath, path))
     }
 
+    /// The directory of an install, looking in the system-wide store first.
+    fn locate(&self, install_key: &str) -> PathBuf {
+        if let Some(system) = self.paths.system_installs() {
+            let path = system.join(install_key);
+            if path.exists() {
+                return path;
+            }
+        }
+        self.paths.installs().join(install_key)
+    }
+
     /// Get when a version was last used.
     pub fn last_used(&self, install_key: &str) -> Result<Option<u64>> {
         Ok(self
@@ -111,6 +136,26 @@ impl<'a> Library<'a> {
         self.usage_tracker.record_link(link_path, install_key)
     }
 
+    /// Paths of the links recorded against an install.
+    pub fn recorded_links(
+        &self,
+        gv: &ResolvedVersion,
+        variant: &Variant,
+        registry: Option<&str>,
+    ) -> Result<Vec<PathBuf>> {
+        let install_key = self.install_key(gv, variant, registry)?;
+        let mut links: Vec<PathBuf> = self
+            .usage_tracker
+            .load()?
+            .links
+            .into_iter()
+            .filter(|(_, record)| record.install_key == install_key)
+            .map(|(path, _)| PathBuf::from(path))
+            .collect();
+        links.sort();
+        Ok(links)
+    }
+
     /// Record that an install was run or referenced.
     pub(super) fn track_install_use(&self, install_key: &str) -> Result<()> {
         self.usage_tracker.record_install(install_key)
@@ -139,31 +184,31 @@ impl<'a> Library<'a> {
         Some(display_name.unwrap_or(url).to_string())
     }
 
-    /// List all installed Godot versions.
+    /// List all installed Godot versions, including those in the system-wide
+    /// store.
     pub fn list_installed(&self) -> Result<Vec<InstalledVersion>> {
         let mut versions = vec![];
-        let installs_dir = self.paths.installs();
-
-        for entry in fs::read_dir(installs_dir)? {
-            let entry = entry?;
-            let file_type = entry.file_type()?;
-            if file_type.is_symlink() || !file_type.is_dir() {
-                continue;
-            }
-            let top_dir = entry.path();
 
-            match crate::registry_store::read(&top_dir)? {
-                Some(meta) => {
-                    let registry =
-                        self.display_registry_for_url(&meta.url, meta.display_name.as_deref());
-                    self.collect_store_installs(&top_dir, &registry, &mut versions);
-                }
-                None => {
-                    // Legacy layout.
-                    self.collect_legacy_installs(&entry, &mut versions);
-                }
+        if let Some(system) = self.paths.system_installs()
+            && system.is_dir()
+        {
+            self.collect_installs(system, &mut versions)?;
+        }
+        let mut user_versions = vec![];
+        if self.paths.installs().is_dir() {
+            self.collect_installs(self.paths.installs(), &mut user_versions)?;
+        }
+        for installed in user_versions {
+            let duplicate = versions.iter().any(|v| {
+                v.version == installed.version
+                    && v.variant == installed.variant
+                    && v.registry == installed.registry
+            });
+            if !duplicate {
+                versions.push(installed);
             }
         }
+
         versions.sort_by(|a, b| {
             let mut v = [a.version.clone(), b.version.clone()];
             v.sort_by(|a, b| b.cmp(a));
@@ -176,38 +221,29 @@ impl<'a> Library<'a> {
         Ok(versions)
     }
 
-    /// Collect installs from a URL-keyed store directory, whose layout is
-    /// `{store}/{variant}/{version}`.
-    fn collect_store_installs(
-        &self,
-        store_dir: &Path,
-        registry: &Option<String>,
-        out: &mut Vec<InstalledVersion>,
-    ) {
-        let Ok(variants) = fs::read_dir(store_dir) else {
-            return;
-        };
-        for variant_entry in variants.flatten() {
-            if !variant_entry.file_type().is_ok_and(|ft| ft.is_dir()) {
+    /// Collect the installs in an installs directory.
+    fn collect_installs(&self, installs_dir: &Path, out: &mut Vec<InstalledVersion>) -> Result<()> {
+        for entry in fs::read_dir(installs_dir)? {
+            let entry = entry?;
+            let file_type = entry.file_type()?;
+            if file_type.is_symlink() || !file_type.is_dir() {
                 continue;
             }
-            let variant_name = variant_entry.file_name().to_string_lossy().to_string();
-            let Ok(leaves) = fs::read_dir(variant_entry.path()) else {
-                continue;
-            };
-            for leaf in leaves.flatten() {
-                if leaf.file_type().is_ok_and(|ft| ft.is_dir())
-                    && let Ok(gv) =
-                        VersionQuery::from_install_str(&leaf.file_name().to_string_lossy())
-                {
-                    out.push(InstalledVersion {
-                        version: gv.to_resolved(),
-                        variant: Variant::from_option(Some(variant_name.as_str())),
-                        registry: registry.clone(),
-                    });
+            let top_dir = entry.path();
+
+            match crate::registry_store::read(&top_dir)? {
+                Some(meta) => {
+                    let registry =
+                        self.display_registry_for_url(&meta.url, meta.display_name.as_deref());
+                    collect_store_installs(&top_dir, &registry, out);
+                }
+                None => {
+                    // Legacy layout.
+                    self.collect_legacy_installs(&entry, out);
                 }
             }
         }
+        Ok(())
     }
 
     /// Collect installs from a legacy layout.
@@ -250,25 +286,82 @@ impl<'a> Library<'a> {
         }
     }
 
-    /// Remove a specified Godot version
-    pub fn remove(
-        &self,
-        gv: &ResolvedVersion,
-        variant: &Variant,
-        registry: Option<&str>,
-    ) -> Result<()> {
-        let install_name = crate::version::install_dir_subpath(
-            &self.install_store_key(registry)?,
-            &gv.to_remote_str(),
-            variant,
-        );
+    /// Enumerate every install directory as `(install_key, path)`.
+    pub(super) fn install_dirs(&self) -> Vec<(String, PathBuf)> {
+        let installs = self.paths.installs();
+        let mut out = Vec::new();
+        let Ok(tops) = fs::read_dir(installs) else {
+            return out;
+        };
+        for top in tops.flatten() {
+            if !top.file_type().is_ok_and(|ft| ft.is_dir()) {
+                continue;
+            }
+            let top_name = top.file_name().to_string_lossy().to_string();
+            let Ok(mids) = fs::read_dir(top.path()) else {
+                continue;
+            };
+            for mid in mids.flatten() {
+                if !mid.file_type().is_ok_and(|ft| ft.is_dir()) {
+                    continue;
+                }
+                let mid_name = mid.file_name().to_string_lossy().to_string();
+                if VersionQuery::from_install_str(&mid_name).is_ok() {
+                    // Legacy variant/version layout.
+                    out.push((format!("{top_name}/{mid_name}"), mid.path()));
+                    continue;
+                }
+                let Ok(leaves) = fs::read_dir(mid.path()) else {
+                    continue;
+                };
+                for leaf in leaves.flatten() {
+                    if !leaf.file_type().is_ok_and(|ft| ft.is_dir()) {
+                        continue;
+                    }
+                    let leaf_name = leaf.file_name().to_string_lossy().to_string();
+                    if VersionQuery::from_install_str(&leaf_name).is_ok() {
+                        out.push((format!("{top_name}/{mid_name}/{leaf_name}"), leaf.path()));
+                    }
+                }
+            }
+        }
+        out
+    }
 
-        let _lock = crate::locks::Lock::acquire(
-            &self.paths.locks(),
-            crate::locks::Resource::Install(&install_name),
-        )?;
+    /// Get a user-friendly label for an install key.
+    pub fn install_label(&self, key: &str) -> String {
+        let parts: Vec<&str> = key.split('/').collect();
+        let (registry, variant, version) = match parts.as_slice() {
+            [store, variant, version] => {
+                let registry = crate::registry_store::read(&self.paths.installs().join(store))
+                    .ok()
+                    .flatten()
+                    .and_then(|m| self.display_registry_for_url(&m.url, m.display_name.as_deref()));
+                (registry, (*variant).to_string(), (*version).to_string())
+            }
+            [variant, version] => (None, (*variant).to_string(), (*version).to_string()),
+            _ => return key.to_string(),
+        };
 
-        self.remove_locked(gv, variant, registry, &install_name)
+        match VersionQuery::from_install_str(&version) {
+            Ok(gv) => crate::version::display_version(
+                &gv.to_resolved(),
+                &Variant::from_option(Some(&variant)),
+                registry.as_deref(),
+            ),
+            Err(_) => key.to_string(),
+        }
+    }
+
+    /// Bring the versioned shims such as `godot4.3` in line with the installs.
+    /// Failing to do so doesn't fail the caller.
+    pub fn sync_version_shims(&self) {
+        let result = self
+            .list_installed()
+            .and_then(|installed| crate::shims::sync_versioned(self.paths.bin_dir(), &installed));
+        if let Err(error) = result {
+            ui::warn(t!("warning-shims-sync-failed", error = error.to_string()));
+        }
     }
 
     /// Inner removal function that does not lock. Only use when a lock was
@@ -283,23 +376,104 @@ impl<'a> Library<'a> {
         let path = self.paths.installs().join(install_name);
 
         if path.exists() {
-            // If this version is the default, unset it
-            if let Some(def) = self.defaults().get_default()?
-                && def.version.to_remote_str() == gv.to_remote_str()
-                && def.variant == *variant
-                && crate::registry::normalize_registry(def.registry.as_deref())
-                    == crate::registry::normalize_registry(registry)
-            {
-                self.defaults().unset_default()?;
+            let running = self.usage_tracker.processes_using(install_name)?;
+            if !running.is_empty() {
+                let pids: Vec<String> = running.iter().map(u32::to_string).collect();
+                return Err(terr!(
+                    "error-install-running",
+                    version = &crate::version::display_version(gv, variant, registry),
+                    pids = pids.join(", "),
+                )
+                .into());
             }
+
             fs::remove_dir_all(path)?;
             self.usage_tracker.forget_install(install_name)?;
+            self.deduper().forget_install(install_name)?;
             Ok(())
+        } else if let Some(system) = self.paths.system_installs()
+            && system.join(install_name).exists()
+        {
+            Err(terr!(
+                "error-system-install-read-only",
+                version = &crate::version::display_version(gv, variant, registry),
+            )
+            .into())
         } else {
             Err(terr!("error-version-not-found").into())
         }
     }
 
+    /// Make an install self-contained, so Godot keeps its editor settings,
+    /// caches and export templates inside it. Returns where they are kept.
+    pub fn make_self_contained(
+        &self,
+        gv: &ResolvedVersion,
+        variant: &Variant,
+        registry: Option<&str>,
+    ) -> Result<PathBuf> {
+        let display = crate::version::display_version(gv, variant, registry);
+        let install_key = self.install_key(gv, variant, registry)?;
+        let path = self.paths.installs().join(&install_key);
+        if !path.exists() {
+            return Err(if self.locate(&install_key).exists() {
+                terr!("error-self-contained-read-only", version = &display)
+            } else {
+                terr!("error-version-not-found", version = &display)
+            }
+            .into());
+        }
+
+        let _lock = crate::locks::Lock::acquire(
+            &self.paths.locks(),
+            crate::locks::Resource::Install(&install_key),
+        )?;
+        crate::self_contained::enable(&path)?;
+        Ok(crate::self_contained::editor_data(&path))
+    }
+
+    /// The editor data kept inside an install, when it is self-contained and
+    /// Godot has run from it.
+    pub fn self_contained_data(
+        &self,
+        gv: &ResolvedVersion,
+        variant: &Variant,
+        registry: Option<&str>,
+    ) -> Result<Option<PathBuf>> {
+        let (_, path) = self.install_dir(gv, variant, registry)?;
+        let data = crate::self_contained::editor_data(&path);
+        Ok((crate::self_contained::is_enabled(&path) && data.is_dir()).then_some(data))
+    }
+
+    /// Compare an install against the receipt written when it was installed.
+    /// Returns `None` when the install has no receipt.
+    pub fn verify(
+        &self,
+        gv: &ResolvedVersion,
+        variant: &Variant,
+        registry: Option<&str>,
+    ) -> Result<Option<(InstallReceipt, ReceiptDiff)>> {
+        let (install_key, path) = self.install_dir(gv, variant, registry)?;
+        if !path.exists() {
+            return Err(terr!(
+                "error-version-not-found",
+                version = &crate::version::display_version(gv, variant, registry),
+            )
+            .into());
+        }
+
+        let _lock = crate::locks::Lock::acquire(
+            &self.paths.locks(),
+            crate::locks::Resource::Install(&install_key),
+        )?;
+
+        let Some(receipt) = InstallReceipt::read(&path)? else {
+            return Ok(None);
+        };
+        let diff = receipt.verify(&path)?;
+        Ok(Some((receipt, diff)))
+    }
+
     /// Resolve the path to the Godot executable for the given version and console preference.
     pub fn get_executable_path(
         &self,
@@ -313,7 +487,7 @@ impl<'a> Library<'a> {
             &gv.to_remote_str(),
             variant,
         );
-        let version_dir = self.paths.installs().join(&install_name);
+        let version_dir = self.locate(&install_name);
         if !version_dir.exists() {
             return Err(terr!(
                 "error-version-not-found",
@@ -384,6 +558,119 @@ impl<'a> Library<'a> {
         }
     }
 
+    /// Find the installed major.minor series, per variant and registry, that
+    /// have installs older than the newest stable patch release, whether that
+    /// release is installed yet or not. Only installs matching the given
+    /// filters are considered.
+    pub async fn plan_updates(
+        &self,
+        filter: Option<&VersionQuery>,
+        variant: Option<&str>,
+        registry: Option<&str>,
+    ) -> Result<Vec<SeriesUpdate>> {
+        let variant = variant.map(|v| Variant::from_option(Some(v)));
+        let registry = crate::registry::normalize_registry(registry);
+
+        let mut series: Vec<SeriesUpdate> = Vec::new();
+        for installed in self.list_installed()? {
+            let install_registry =
+                crate::registry::normalize_registry(installed.registry.as_deref());
+            if filter.is_some_and(|f| !f.matches(&installed.version))
+                || variant.as_ref().is_some_and(|v| *v != installed.variant)
+                || registry.is_some_and(|r| install_registry != Some(r))
+            {
+                continue;
+            }
+
+            let same_series = |update: &&mut SeriesUpdate| {
+                update.variant == installed.variant
+                    && update.registry.as_deref() == install_registry
+                    && update.replaced[0].major == installed.version.major
+                    && update.replaced[0].minor == installed.version.minor
+            };
+            match series.iter_mut().find(same_series) {
+                Some(update) => update.replaced.push(installed.version),
+                None => series.push(SeriesUpdate {
+                    registry: install_registry.map(str::to_string),
+                    variant: installed.variant,
+                    latest: installed.version.clone(),
+                    replaced: vec![installed.version],
+                }),
+            }
+        }
+
+        let mut updates = Vec::new();
+        for mut update in series {
+            let query = VersionQuery {
+                major: Some(update.latest.major),
+                minor: Some(update.latest.minor),
+                release_type: Some("stable".to_string()),
+                ..Default::default()
+            };
+            let latest = match self
+                .catalogs()
+                .resolve_available_version(
+                    &query,
+                    Some(update.variant.as_str()),
+                    update.registry.as_deref(),
+                    false,
+                    false,
+                )
+                .await
+            {
+                Ok(latest) => latest,
+                // Installs from registries that are no longer configured can't
+                // be updated.
+                Err(e)
+                    if e.downcast_ref::<crate::error::CodedError>()
+                        .and_then(crate::error::CodedError::code)
+                        == crate::error::code_for("error-registry-unknown") =>
+                {
+                    continue;
+                }
+                Err(e) => return Err(e),
+            };
+
+            // The latest release may be installed already, in which case the
+            // older installs still move over to it.
+            let newest_stable = update
+                .replaced
+                .iter()
+                .filter(|installed| installed.is_stable())
+                .max()
+                .cloned();
+            let Some(latest) = latest.into_iter().chain(newest_stable).max() else {
+                continue;
+            };
+
+            update.replaced.retain(|installed| *installed < latest);
+            update.replaced.sort();
+            if !update.replaced.is_empty() {
+                update.latest = latest;
+                updates.push(update);
+            }
+        }
+
+        Ok(updates)
+    }
+
+    /// Resolve a query against installed versions, taking the newest match.
+    pub async fn resolve_installed_newest<T>(
+        &self,
+        gv: &T,
+        variant: Option<&str>,
+        registry: Option<&str>,
+    ) -> Result<InstalledVersion>
+    where
+        T: Into<VersionQuery> + Clone,
+    {
+        self.resolve_installed_version(gv, variant, registry)
+            .await?
+            .into_iter()
+            .max_by(|a, b| a.version.cmp(&b.version))
+            .ok_or_else(|| crate::terr!("error-version-not-found").into())
+    }
+
     /// Resolve the Godot version from a string, for an installed version
     /// Returns a list of possible versions. If the input is ambiguous, the list
     /// will have more than one element. Otherwise, it will have one element,
@@ -400,13 +687,22 @@ impl<'a> Library<'a> {
         T: Into<VersionQuery> + Clone,
     {
         let gv: VersionQuery = gv.clone().into();
+        self.installed_matches(&gv, &Variant::from_option(variant), registry)
+    }
+
+    /// Installed versions of a variant and registry that match a query.
+    pub(super) fn installed_matches(
+        &self,
+        gv: &VersionQuery,
+        variant: &Variant,
+        registry: Option<&str>,
+    ) -> Result<Vec<InstalledVersion>> {
         let installed = self.list_installed()?;
         // Filter by registry and variant, then filter by version match.
-        let target_variant = Variant::from_option(variant);
         let matches: Vec<_> = installed
             .into_iter()
             .filter(|v| {
-                v.variant == target_variant
+                v.variant == *variant
                     && crate::registry::normalize_registry(v.registry.as_deref())
                         == crate::registry::normalize_registry(registry)
                     && gv.matches(&v.version)
@@ -415,3 +711,35 @@ impl<'a> Library<'a> {
         Ok(matches)
     }
 }
+
+/// Collect installs from a URL-keyed store directory, whose layout is
+/// `{store}/{variant}/{version}`.
+pub(crate) fn collect_store_installs(
+    store_dir: &Path,
+    registry: &Option<String>,
+    out: &mut Vec<InstalledVersion>,
+) {
+    let Ok(variants) = fs::read_dir(store_dir) else {
+        return;
+    };
+    for variant_entry in variants.flatten() {
+        if !variant_entry.file_type().is_ok_and(|ft| ft.is_dir()) {
+            continue;
+        }
+        let variant_name = variant_entry.file_name().to_string_lossy().to_string();
+        let Ok(leaves) = fs::read_dir(variant_entry.path()) else {
+            continue;
+        };
+        for leaf in leaves.flatten() {
+            if leaf.file_type().is_ok_and(|ft| ft.is_dir())
+                && let Ok(gv) = VersionQuery::from_install_str(&leaf.file_name().to_string_lossy())
+            {
+                out.push(InstalledVersion {
+                    version: gv.to_resolved(),
+                    variant: Variant::from_option(Some(variant_name.as_str())),
+                    registry: registry.clone(),
+                });
+            }
+        }
+    }
+}
@@ -16,9 +16,11 @@
 // this program. If not, see <https://www.gnu.org/licenses/>.
 
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 use anyhow::Result;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
 
 use crate::artifact_cache::ArtifactCache;
 use crate::config::{Config, ConfigFile};
@@ -26,14 +28,16 @@ use crate::host::{HostPlatform, detect_host};
 use crate::metadata_cache::CacheStore;
 #[cfg(test)]
 use crate::metadata_cache::{RegistryReleasesCache, ReleaseCache, filter_cached_releases};
-use crate::paths::GdvmPaths;
+use crate::paths::{GdvmPaths, Layout};
 use crate::releases::CatalogSet;
+use crate::resolution_cache::ResolutionCache;
 use crate::run_version_resolver::RunVersionSource;
 use crate::usage_tracker::UsageTracker;
 use crate::version::{QuerySelection, ResolvedSelection, ResolvedVersion, VersionQuery};
-use crate::{eprintln_i18n, post_upgrade, t};
+use crate::{eprintln_i18n, post_upgrade, t, terr};
 
 mod catalog;
+mod deduper;
 mod defaults;
 mod installer;
 mod launcher;
@@ -42,17 +46,40 @@ mod pruner;
 mod updater;
 
 pub use catalog::Catalogs;
+pub use deduper::{DedupeReport, Deduper};
 pub use defaults::Defaults;
 pub use installer::{InstallOutcome, Installer};
-pub use launcher::{Launcher, find_godot_executable};
-pub use library::{InstalledVersion, Library};
+pub use launcher::{LaunchOptions, Launcher, SettingsCarry, find_godot_executable};
+pub(crate) use library::collect_store_installs;
+pub use library::{InstalledVersion, Library, SeriesUpdate};
 pub use pruner::{PruneOptions, PruneReport, PrunedItem, Pruner};
 pub use updater::Updater;
 
+/// The system-wide install store. It keeps its own usage index and archive
+/// cache, so that installing into it leaves the installing user's alone.
+struct SystemStore {
+    paths: GdvmPaths,
+    artifact_cache: ArtifactCache,
+    usage_tracker: UsageTracker,
+}
+
+impl SystemStore {
+    fn new(base: PathBuf) -> Self {
+        let paths = GdvmPaths::system(base);
+        Self {
+            artifact_cache: ArtifactCache::new(paths.cache_dir().to_path_buf()),
+            usage_tracker: UsageTracker::new(paths.usage_index().to_path_buf(), paths.locks()),
+            paths,
+        }
+    }
+}
+
 /// App dependency container.
 pub struct Gdvm {
     /// Paths helper for GDVM directories
     paths: GdvmPaths,
+    /// The system-wide install store, if one is set.
+    system_store: Option<SystemStore>,
     /// Cache for downloaded artifacts
     artifact_cache: ArtifactCache,
     /// Metadata cache.
@@ -63,20 +90,10 @@ pub struct Gdvm {
     catalogs: CatalogSet,
     /// Host platform
     host: HostPlatform,
-    /// Env vars from `.env` for passing to Godot.
-    dotenv_vars: Vec<(String, String)>,
     /// Machine-level configuration.
     config: Config,
-}
-
-/// Get env vars from `.env` to pass to Godot.
-fn dotenv_vars() -> Vec<(String, String)> {
-    let Ok(iter) = dotenvy::dotenv_iter() else {
-        return Vec::new();
-    };
-    iter.filter_map(|item| item.ok())
-        .filter(|(key, _)| std::env::var_os(key).is_none())
-        .collect()
+    /// Whether a project's `gdvm.lock` must exist and agree with its pin.
+    locked: bool,
 }
 
 /// Get registry information from the nearest gdvm.toml in the current directory or its parents.
@@ -157,19 +174,45 @@ fn registry_override_conflicts(
 impl Gdvm {
     /// Create a new Gdvm instance and set up the installation and cache paths.
     pub async fn new() -> Result<Self> {
-        let paths = GdvmPaths::new()?;
-        let artifact_cache = ArtifactCache::new(paths.cache_dir().to_path_buf());
-        artifact_cache.ensure_dir()?;
+        let layout = Layout::detect()?;
+        let legacy = crate::paths::legacy_dir()?;
+        if layout.should_relocate(&legacy) {
+            // Bring the old directory up to date before moving it.
+            post_upgrade::run(&legacy)?;
+            crate::migrations::relocate(&legacy, &layout)?;
+        }
 
+        let paths = GdvmPaths::from_layout(&layout)?;
         post_upgrade::run(paths.base())?;
+        Self::assemble(paths)?.finish()
+    }
 
-        let config_file = ConfigFile::load()?;
-        config_file.report_problems();
+    /// A lighter instance for the `godot` shims, enough to launch a version
+    /// taken from the resolution cache. It leaves registry warnings and the
+    /// upgrade notice to `finish`, which makes it a full instance. `None`
+    /// while an upgrade or migration is pending.
+    pub fn for_shim() -> Result<Option<Self>> {
+        let layout = Layout::detect()?;
+        if layout.should_relocate(&crate::paths::legacy_dir()?) {
+            return Ok(None);
+        }
 
-        let config = config_file.into_config();
-        let mut registries = config.registry_pairs();
-        let project = project_registry_pairs();
-        for conflict in registry_override_conflicts(&registries, &project) {
+        let paths = GdvmPaths::from_layout(&layout)?;
+        if !post_upgrade::is_current(paths.base())? {
+            return Ok(None);
+        }
+        Self::assemble(paths).map(Some)
+    }
+
+    /// Finish setting up an instance whose data is up to date: make sure the
+    /// archive cache exists, warn about registries the project overrides, and
+    /// report any available upgrade from the last update check.
+    pub fn finish(self) -> Result<Self> {
+        self.artifact_cache.ensure_dir()?;
+
+        for conflict in
+            registry_override_conflicts(&self.config.registry_pairs(), &project_registry_pairs())
+        {
             crate::ui::warn(t!(
                 "registry-project-override-conflict",
                 registry = conflict.name.as_str(),
@@ -177,30 +220,54 @@ impl Gdvm {
                 project_url = conflict.project_url.as_str(),
             ));
         }
-        registries.extend(project);
+
+        if std::env::var_os(Updater::BACKGROUND_CHECK_ENV_VAR).is_none() {
+            self.updater().print_upgrade_notice().ok();
+            self.updater().spawn_background_check_if_due().ok();
+        }
+
+        Ok(self)
+    }
+
+    /// Load the configuration and set up everything else around `paths`.
+    fn assemble(paths: GdvmPaths) -> Result<Self> {
+        let artifact_cache = ArtifactCache::new(paths.cache_dir().to_path_buf());
+
+        let config_file = ConfigFile::load()?;
+        config_file.report_problems();
+
+        let config = config_file.into_config();
+        let system_store = crate::paths::system_store_dir(config.install.system_store.as_deref());
+        let system = system_store.clone().map(SystemStore::new);
+        let paths = paths.with_system_store(system_store);
+        let mut registries = config.registry_pairs();
+        registries.extend(project_registry_pairs());
         let catalogs = CatalogSet::new(paths.cache_index(), &registries)?;
         let cache_store = CacheStore::new(paths.cache_index().to_path_buf());
         let usage_tracker = UsageTracker::new(paths.usage_index().to_path_buf(), paths.locks());
         let host = detect_host()?;
 
-        let gdvm = Gdvm {
+        Ok(Gdvm {
             paths,
+            system_store: system,
             artifact_cache,
             cache_store,
             usage_tracker,
             catalogs,
             host,
-            dotenv_vars: dotenv_vars(),
             config,
-        };
+            locked: crate::gdvm_lock::locked_from_env(),
+        })
+    }
 
-        // Report any available upgrade from the last update check.
-        if std::env::var_os(Updater::BACKGROUND_CHECK_ENV_VAR).is_none() {
-            gdvm.updater().print_upgrade_notice().ok();
-            gdvm.updater().spawn_background_check_if_due().ok();
-        }
+    /// Resolutions of the `godot` shims from earlier runs.
+    pub fn resolution_cache(&self) -> ResolutionCache {
+        ResolutionCache::new(self.paths.resolution_cache())
+    }
 
-        Ok(gdvm)
+    /// Exports of `gdvm env` from earlier prompts.
+    pub fn env_cache<T: Clone + Serialize + DeserializeOwned>(&self) -> ResolutionCache<T> {
+        ResolutionCache::new(self.paths.env_cache())
     }
 
     /// Gets the path to gdvm's base directory
@@ -209,6 +276,18 @@ impl Gdvm {
         self.paths.base()
     }
 
+    /// Require the project's `gdvm.lock` to exist and agree with its pin, and
+    /// archives to match the hashes it records.
+    pub fn require_lock(&mut self) {
+        self.locked = true;
+    }
+
+    /// The project's pinned version, narrowed to the build in its
+    /// `gdvm.lock`.
+    pub fn locked_pin(&self) -> Result<Option<QuerySelection>> {
+        self.defaults().get_locked_pin(self.locked)
+    }
+
     /// Clears the release cache by deleting the cache file and all cached zip files
     pub fn clear_cache(&self) -> Result<()> {
         let cache_index = self.cache_store.index_path();
@@ -268,6 +347,16 @@ impl Gdvm {
             paths: &self.paths,
             usage_tracker: &self.usage_tracker,
             catalogs: self.catalogs(),
+            config: &self.config,
+        }
+    }
+
+    /// Sharing of identical files between installs.
+    pub fn deduper(&self) -> Deduper<'_> {
+        Deduper {
+            paths: &self.paths,
+            usage_tracker: &self.usage_tracker,
+            catalogs: self.catalogs(),
         }
     }
 
@@ -278,16 +367,48 @@ impl Gdvm {
             artifact_cache: &self.artifact_cache,
             usage_tracker: &self.usage_tracker,
             catalogs: self.catalogs(),
+            config: &self.config,
+            locked: self.locked,
         }
     }
 
+    /// The system-wide install store.
+    fn system_store(&self) -> Result<&SystemStore> {
+        self.system_store
+            .as_ref()
+            .ok_or_else(|| terr!("error-system-store-not-set").into())
+    }
+
+    /// Installed-version inventory of the system-wide install store.
+    pub fn system_library(&self) -> Result<Library<'_>> {
+        let store = self.system_store()?;
+        Ok(Library {
+            paths: &store.paths,
+            usage_tracker: &store.usage_tracker,
+            catalogs: self.catalogs(),
+        })
+    }
+
+    /// Godot installation into the system-wide install store.
+    pub fn system_installer(&self) -> Result<Installer<'_>> {
+        let store = self.system_store()?;
+        Ok(Installer {
+            paths: &store.paths,
+            artifact_cache: &store.artifact_cache,
+            usage_tracker: &store.usage_tracker,
+            catalogs: self.catalogs(),
+            config: &self.config,
+            locked: self.locked,
+        })
+    }
+
     /// Launching installed Godot versions.
     pub fn launcher(&self) -> Launcher<'_> {
         Launcher {
             paths: &self.paths,
             usage_tracker: &self.usage_tracker,
             catalogs: self.catalogs(),
-            dotenv_vars: &self.dotenv_vars,
+            config: &self.config,
         }
     }
 
@@ -298,6 +419,7 @@ impl Gdvm {
             artifact_cache: &self.artifact_cache,
             usage_tracker: &self.usage_tracker,
             catalogs: self.catalogs(),
+            defaults: self.defaults(),
         }
     }
 
@@ -312,8 +434,8 @@ impl Gdvm {
 
 #[async_trait::async_trait(?Send)]
 impl RunVersionSource for Gdvm {
-    async fn get_pinned_version(&self) -> Option<QuerySelection> {
-        self.defaults().get_pinned_version()
+    async fn get_pinned_version(&self) -> Result<Option<QuerySelection>> {
+        self.locked_pin()
     }
 
     async fn get_default(&self) -> Result<Option<ResolvedSelection>> {
@@ -327,6 +449,10 @@ impl RunVersionSource for Gdvm {
         self.defaults().determine_version(path)
     }
 
+    async fn project_file<P: AsRef<Path> + Send + Sync>(&self, path: Option<P>) -> Option<PathBuf> {
+        self.defaults().project_file(path)
+    }
+
     async fn auto_install_version<T>(
         &self,
         gv: &T,
@@ -351,11 +477,18 @@ impl RunVersionSource for Gdvm {
     where
         T: Into<VersionQuery> + Clone + Send + Sync,
     {
-        Ok(self
-            .library()
-            .resolve_installed_one(gv, variant, registry)
-            .await?
-            .version)
+        let gv: VersionQuery = gv.clone().into();
+        // Any installed version satisfies a range, so take the newest one.
+        let installed = if gv.range.is_some() {
+            self.library()
+                .resolve_installed_newest(&gv, variant, registry)
+                .await?
+        } else {
+            self.library()
+                .resolve_installed_one(&gv, variant, registry)
+                .await?
+        };
+        Ok(installed.version)
     }
 }
 
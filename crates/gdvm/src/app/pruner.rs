@@ -25,10 +25,10 @@ use serde::Serialize;
 use super::*;
 use crate::artifact_cache::ArtifactCache;
 use crate::date_utils::{modified_unix_secs, now_unix_secs};
-use crate::fs_utils::dir_size;
+use crate::install_receipt::InstallReceipt;
 use crate::paths::GdvmPaths;
 use crate::usage_tracker::{UsageState, UsageTracker};
-use crate::version::{Variant, VersionQuery};
+use crate::version::Variant;
 
 /// Options controlling how `Gdvm::prune` behaves.
 #[derive(Debug, Clone, Copy, Default)]
@@ -36,10 +36,14 @@ pub struct PruneOptions {
     /// Remove all installs and cached archives regardless of age. Installs that
     /// still have an active link are preserved unless `force` is also set.
     pub all: bool,
-    /// Ignore links entirely, allowing linked installs to be removed.
+    /// Ignore links entirely, allowing linked installs to be removed. Also
+    /// removes self-contained installs along with their editor data.
     pub force: bool,
     /// Report what would be removed without deleting anything.
     pub dry_run: bool,
+    /// After age-based pruning, also remove the least recently used archives
+    /// until the cache fits in this many bytes.
+    pub cache_max_bytes: Option<u64>,
 }
 
 /// A single asset removed by prune.
@@ -60,6 +64,8 @@ pub struct PruneReport {
     pub archives: Vec<PrunedItem>,
     /// Partial downloads and extracted Godots.
     pub interrupted: Vec<PrunedItem>,
+    /// Editor data of self-contained projects that no longer exist.
+    pub editor_data: Vec<PrunedItem>,
     /// Number of installs preserved because they still have an active link.
     pub preserved_by_link: usize,
     /// Total approximate bytes freed.
@@ -71,7 +77,10 @@ pub struct PruneReport {
 impl PruneReport {
     /// True when nothing was removed.
     pub fn is_empty(&self) -> bool {
-        self.installs.is_empty() && self.archives.is_empty() && self.interrupted.is_empty()
+        self.installs.is_empty()
+            && self.archives.is_empty()
+            && self.interrupted.is_empty()
+            && self.editor_data.is_empty()
     }
 
     /// Record an item that was or would be pruned.
@@ -109,19 +118,26 @@ pub struct Pruner<'a> {
     pub(super) artifact_cache: &'a ArtifactCache,
     pub(super) usage_tracker: &'a UsageTracker,
     pub(super) catalogs: Catalogs<'a>,
+    /// Tells which versions are the default and pinned ones, whose installs
+    /// and archives are kept.
+    pub(super) defaults: Defaults<'a>,
 }
 
 impl<'a> Pruner<'a> {
     fn defaults(&self) -> Defaults<'a> {
-        Defaults {
+        self.defaults
+    }
+
+    fn library(&self) -> Library<'a> {
+        Library {
             paths: self.paths,
             usage_tracker: self.usage_tracker,
             catalogs: self.catalogs,
         }
     }
 
-    fn library(&self) -> Library<'a> {
-        Library {
+    fn deduper(&self) -> Deduper<'a> {
+        Deduper {
             paths: self.paths,
             usage_tracker: self.usage_tracker,
             catalogs: self.catalogs,
@@ -150,12 +166,20 @@ impl<'a> Pruner<'a> {
             self.live_link_install_keys(&state)
         };
 
+        // Installs that Godot is running from are never removed.
+        let running: HashSet<String> = self
+            .usage_tracker
+            .running_processes()?
+            .into_iter()
+            .map(|(_, record)| record.install_key)
+            .collect();
+
         let mut report = PruneReport {
             dry_run: opts.dry_run,
             ..Default::default()
         };
 
-        for (key, path) in self.collect_prunable_installs() {
+        for (key, path) in self.library().install_dirs() {
             if default_install_key.as_deref() == Some(key.as_str()) {
                 continue;
             }
@@ -176,21 +200,45 @@ impl<'a> Pruner<'a> {
                 continue;
             }
 
+            if running.contains(&key) {
+                eprintln_i18n!(
+                    "prune-skipped-running",
+                    item = self.library().install_label(&key)
+                );
+                continue;
+            }
+
+            // Editor settings kept in a self-contained install aren't
+            // removed without asking for it.
+            if !opts.force
+                && crate::self_contained::is_enabled(&path)
+                && crate::self_contained::editor_data(&path).is_dir()
+            {
+                eprintln_i18n!(
+                    "prune-skipped-self-contained",
+                    item = self.library().install_label(&key)
+                );
+                continue;
+            }
+
             if !opts.dry_run {
                 let Some(_lock) = crate::locks::Lock::try_acquire(
                     &self.paths.locks(),
                     crate::locks::Resource::Install(&key),
                 )?
                 else {
-                    eprintln_i18n!("prune-skipped-in-use", item = self.install_label(&key));
+                    eprintln_i18n!(
+                        "prune-skipped-in-use",
+                        item = self.library().install_label(&key)
+                    );
                     continue;
                 };
 
-                let freed = dir_size(&path);
+                let freed = self.deduper().install_size(&path);
                 if let Err(error) = fs::remove_dir_all(&path) {
                     eprintln_i18n!(
                         "prune-skipped-error",
-                        item = self.install_label(&key),
+                        item = self.library().install_label(&key),
                         error = error.to_string()
                     );
                     continue;
@@ -199,21 +247,22 @@ impl<'a> Pruner<'a> {
                     &mut report.installs,
                     &mut report.freed_bytes,
                     opts.dry_run,
-                    self.install_label(&key),
+                    self.library().install_label(&key),
                     freed,
                 );
             } else {
-                let freed = dir_size(&path);
+                let freed = self.deduper().install_size(&path);
                 PruneReport::record_pruned(
                     &mut report.installs,
                     &mut report.freed_bytes,
                     opts.dry_run,
-                    self.install_label(&key),
+                    self.library().install_label(&key),
                     freed,
                 );
             }
         }
 
+        let mut pruned_archives = HashSet::new();
         for path in self.collect_cached_archives() {
             let should_remove = if opts.all {
                 true
@@ -257,6 +306,19 @@ impl<'a> Pruner<'a> {
                 label,
                 freed,
             );
+            pruned_archives.insert(path);
+        }
+
+        if let Some(max_bytes) = opts.cache_max_bytes
+            && !opts.all
+        {
+            self.evict_archives(
+                max_bytes,
+                &state,
+                opts.dry_run,
+                &pruned_archives,
+                &mut report,
+            )?;
         }
 
         const INTERRUPTED_MIN_AGE_SECS: u64 = 60 * 60;
@@ -293,12 +355,36 @@ impl<'a> Pruner<'a> {
             );
         }
 
+        for (dir, project) in crate::self_contained::orphaned_projects(&self.paths.editor_data()) {
+            let label = project.display().to_string();
+            let size = crate::fs_utils::dir_size(&dir);
+            if !opts.dry_run
+                && let Err(error) = fs::remove_dir_all(&dir)
+            {
+                eprintln_i18n!(
+                    "prune-skipped-error",
+                    item = label.as_str(),
+                    error = error.to_string()
+                );
+                continue;
+            }
+
+            PruneReport::record_pruned(
+                &mut report.editor_data,
+                &mut report.freed_bytes,
+                opts.dry_run,
+                label,
+                size,
+            );
+        }
+
         if opts.dry_run {
             return Ok(report);
         }
 
         let installs_dir = self.paths.installs().to_path_buf();
         let cache_dir = self.artifact_cache.dir().to_path_buf();
+        self.deduper().retain_existing()?;
         self.usage_tracker.update(|state| {
             state.installs.retain(|k, _| installs_dir.join(k).exists());
             state
@@ -308,10 +394,169 @@ impl<'a> Pruner<'a> {
                 .links
                 .retain(|path_str, rec| self.link_is_live(Path::new(path_str), &rec.install_key));
         })?;
+        if !report.installs.is_empty() {
+            self.library().sync_version_shims();
+        }
 
         Ok(report)
     }
 
+    /// Remove the least recently used cached archives until the cache fits in
+    /// `max_bytes`.
+    pub fn trim_cache(&self, max_bytes: u64, dry_run: bool) -> Result<PruneReport> {
+        let state = self.usage_tracker.load()?;
+        let mut report = PruneReport {
+            dry_run,
+            ..Default::default()
+        };
+
+        self.evict_archives(max_bytes, &state, dry_run, &HashSet::new(), &mut report)?;
+
+        if !dry_run && !report.archives.is_empty() {
+            let cache_dir = self.artifact_cache.dir().to_path_buf();
+            self.usage_tracker.update(|state| {
+                state
+                    .archives
+                    .retain(|name, _| cache_dir.join(name).exists());
+            })?;
+        }
+
+        Ok(report)
+    }
+
+    /// Evict archives, least recently used first, until the archives left,
+    /// excluding those in `already_pruned`, fit in `max_bytes`. Archives that
+    /// the default or pinned version was installed from are kept.
+    fn evict_archives(
+        &self,
+        max_bytes: u64,
+        state: &UsageState,
+        dry_run: bool,
+        already_pruned: &HashSet<PathBuf>,
+        report: &mut PruneReport,
+    ) -> Result<()> {
+        let mut archives: Vec<(PathBuf, u64, u64)> = self
+            .collect_cached_archives()
+            .into_iter()
+            .filter(|path| !already_pruned.contains(path))
+            .map(|path| {
+                let size = fs::metadata(&path).map(|m| m.len()).unwrap_or(0);
+                let last_used = self.effective_archive_last_used(&path, state);
+                (path, size, last_used)
+            })
+            .collect();
+        archives.sort_by_key(|(path, _, last_used)| (*last_used, path.clone()));
+
+        let mut total: u64 = archives.iter().map(|(_, size, _)| size).sum();
+        if total <= max_bytes {
+            return Ok(());
+        }
+
+        let protected = self.protected_archives();
+
+        for (path, size, _) in archives {
+            if total <= max_bytes {
+                break;
+            }
+            if protected.contains(&path) {
+                continue;
+            }
+
+            let label = path
+                .file_name()
+                .map(|n| n.to_string_lossy().to_string())
+                .unwrap_or_default();
+            if !dry_run {
+                let Some(_lock) = crate::locks::Lock::try_acquire(
+                    &self.paths.locks(),
+                    crate::locks::Resource::Archive(&label),
+                )?
+                else {
+                    eprintln_i18n!("prune-skipped-in-use", item = label.as_str());
+                    continue;
+                };
+
+                if let Err(error) = fs::remove_file(&path) {
+                    eprintln_i18n!(
+                        "prune-skipped-error",
+                        item = label.as_str(),
+                        error = error.to_string()
+                    );
+                    continue;
+                }
+            }
+
+            total = total.saturating_sub(size);
+            PruneReport::record_pruned(
+                &mut report.archives,
+                &mut report.freed_bytes,
+                dry_run,
+                label,
+                size,
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Cached archives that the default and pinned versions were installed
+    /// from, according to their install receipts. For installs made before
+    /// receipts existed, archives holding that version's files are protected.
+    fn protected_archives(&self) -> HashSet<PathBuf> {
+        let mut installs = Vec::new();
+
+        if let Ok(Some(def)) = self.defaults().get_default()
+            && let Ok((_, dir)) =
+                self.library()
+                    .install_dir(&def.version, &def.variant, def.registry.as_deref())
+        {
+            installs.push((def.version, def.variant, dir));
+        }
+
+        if let Some(pinned) = self.defaults().get_pinned_version() {
+            let variant = Variant::from_option(pinned.variant.as_deref());
+            for installed in self.library().list_installed().unwrap_or_default() {
+                if installed.variant == variant
+                    && crate::registry::normalize_registry(installed.registry.as_deref())
+                        == crate::registry::normalize_registry(pinned.registry.as_deref())
+                    && pinned.version.matches(&installed.version)
+                    && let Ok((_, dir)) = self.library().install_dir(
+                        &installed.version,
+                        &installed.variant,
+                        installed.registry.as_deref(),
+                    )
+                {
+                    installs.push((installed.version, installed.variant, dir));
+                }
+            }
+        }
+
+        let mut protected = HashSet::new();
+        let mut without_receipt = Vec::new();
+        for (version, variant, dir) in installs {
+            match InstallReceipt::read(&dir).ok().flatten() {
+                Some(receipt) => {
+                    protected.insert(self.artifact_cache.cached_zip_path(&receipt.archive_sha512));
+                }
+                None => without_receipt.push((version.to_remote_str(), variant)),
+            }
+        }
+
+        if !without_receipt.is_empty() {
+            protected.extend(
+                self.collect_cached_archives()
+                    .into_iter()
+                    .filter(|archive| {
+                        without_receipt
+                            .iter()
+                            .any(|(tag, variant)| archive_holds_version(archive, tag, variant))
+                    }),
+            );
+        }
+
+        protected
+    }
+
     /// The set of install keys that still have at least one symlink.
     fn live_link_install_keys(&self, state: &UsageState) -> HashSet<String> {
         let mut protected = HashSet::new();
@@ -341,48 +586,6 @@ impl<'a> Pruner<'a> {
         target.starts_with(install_canon)
     }
 
-    /// Enumerate every install directory as `(install_key, path)`.
-    fn collect_prunable_installs(&self) -> Vec<(String, PathBuf)> {
-        let installs = self.paths.installs();
-        let mut out = Vec::new();
-        let Ok(tops) = fs::read_dir(installs) else {
-            return out;
-        };
-        for top in tops.flatten() {
-            if !top.file_type().is_ok_and(|ft| ft.is_dir()) {
-                continue;
-            }
-            let top_name = top.file_name().to_string_lossy().to_string();
-            let Ok(mids) = fs::read_dir(top.path()) else {
-                continue;
-            };
-            for mid in mids.flatten() {
-                if !mid.file_type().is_ok_and(|ft| ft.is_dir()) {
-                    continue;
-                }
-                let mid_name = mid.file_name().to_string_lossy().to_string();
-                if VersionQuery::from_install_str(&mid_name).is_ok() {
-                    // Legacy variant/version layout.
-                    out.push((format!("{top_name}/{mid_name}"), mid.path()));
-                    continue;
-                }
-                let Ok(leaves) = fs::read_dir(mid.path()) else {
-                    continue;
-                };
-                for leaf in leaves.flatten() {
-                    if !leaf.file_type().is_ok_and(|ft| ft.is_dir()) {
-                        continue;
-                    }
-                    let leaf_name = leaf.file_name().to_string_lossy().to_string();
-                    if VersionQuery::from_install_str(&leaf_name).is_ok() {
-                        out.push((format!("{top_name}/{mid_name}/{leaf_name}"), leaf.path()));
-                    }
-                }
-            }
-        }
-        out
-    }
-
     /// Get a list of partial downloads and staging directories.
     fn collect_interrupted_leftovers(&self, min_age_secs: u64) -> Vec<(PathBuf, u64)> {
         let now = std::time::SystemTime::now();
@@ -473,32 +676,21 @@ impl<'a> Pruner<'a> {
             modified_unix_secs(path).unwrap_or(0)
         }
     }
+}
 
-    /// Get a user-friendly label for an install key.
-    fn install_label(&self, key: &str) -> String {
-        let parts: Vec<&str> = key.split('/').collect();
-        let (registry, variant, version) = match parts.as_slice() {
-            [store, variant, version] => {
-                let registry = crate::registry_store::read(&self.paths.installs().join(store))
-                    .ok()
-                    .flatten()
-                    .and_then(|m| {
-                        self.library()
-                            .display_registry_for_url(&m.url, m.display_name.as_deref())
-                    });
-                (registry, (*variant).to_string(), (*version).to_string())
-            }
-            [variant, version] => (None, (*variant).to_string(), (*version).to_string()),
-            _ => return key.to_string(),
-        };
-
-        match VersionQuery::from_install_str(&version) {
-            Ok(gv) => crate::version::display_version(
-                &gv.to_resolved(),
-                &Variant::from_option(Some(&variant)),
-                registry.as_deref(),
-            ),
-            Err(_) => key.to_string(),
-        }
-    }
+/// Whether the archive at `path` holds the files of the release `tag` (e.g.
+/// `4.3-stable`) for `variant`, going by the names of its entries. Official
+/// archives name their files after the release, such as
+/// `Godot_v4.3-stable_linux.x86_64`, and C# ones add `_mono`.
+fn archive_holds_version(path: &Path, tag: &str, variant: &Variant) -> bool {
+    let Ok(Ok(archive)) = fs::File::open(path).map(zip::ZipArchive::new) else {
+        return false;
+    };
+    let needle = format!("v{tag}");
+    archive.file_names().any(|name| {
+        let is_release = name.match_indices(&needle).any(|(index, _)| {
+            !name[index + needle.len()..].starts_with(|c: char| c.is_ascii_alphanumeric())
+        });
+        is_release && name.contains("_mono") != variant.is_default()
+    })
 }
@@ -81,6 +81,7 @@ impl<'a> Updater<'a> {
         crate::process_utils::spawn_detached(
             std::process::Command::new(std::env::current_exe()?)
                 .env(Self::BACKGROUND_CHECK_ENV_VAR, "1"),
+            None,
         )?;
 
         Ok(())
@@ -0,0 +1,67 @@
+// SPDX-FileCopyrightText: Copyright (C) 2024 Adaline Simonian
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// This file is part of gdvm.
+//
+// gdvm is free software: you can redistribute it and/or modify it under the
+// terms of the GNU General Public License as published by the Free Software
+// Foundation, either version 3 of the License, or (at your option) any later
+// version.
+//
+// gdvm is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR
+// A PARTICULAR PURPOSE. See the GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with
+// this program. If not, see <https://www.gnu.org/licenses/>.
+
+use anyhow::Result;
+use clap::ArgMatches;
+use gdvm::config::ConfigFile;
+use gdvm::println_i18n;
+
+/// Handle the 'alias' subcommand
+pub(crate) fn sub_alias(matches: &ArgMatches) -> Result<()> {
+    match matches.subcommand() {
+        Some(("set", sub_m)) => {
+            let name = sub_m.get_one::<String>("name").unwrap();
+            let spec = sub_m.get_one::<String>("spec").unwrap();
+            ConfigFile::modify(|file| file.set_alias(name, spec))?;
+            println_i18n!("alias-set", name = name.as_str(), spec = spec.as_str());
+        }
+        Some(("remove", sub_m)) => {
+            let name = sub_m.get_one::<String>("name").unwrap();
+            ConfigFile::modify(|file| file.remove_alias(name))?;
+            println_i18n!("alias-removed", name = name.as_str());
+        }
+        Some(("list", sub_m)) => {
+            let aliases = ConfigFile::load()?.config().alias_pairs();
+
+            if super::format::OutputFormat::is_json(sub_m) {
+                #[derive(serde::Serialize)]
+                struct AliasEntry {
+                    name: String,
+                    spec: String,
+                }
+                let entries: Vec<AliasEntry> = aliases
+                    .into_iter()
+                    .map(|(name, spec)| AliasEntry { name, spec })
+                    .collect();
+                return super::format::print_json(&entries);
+            }
+
+            if aliases.is_empty() {
+                println_i18n!("alias-list-empty");
+                return Ok(());
+            }
+
+            println_i18n!("alias-list-header");
+            for (name, spec) in aliases {
+                println!("- {name} ({spec})");
+            }
+        }
+        _ => {}
+    }
+
+    Ok(())
+}
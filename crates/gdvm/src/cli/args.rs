@@ -15,6 +15,9 @@
 // You should have received a copy of the GNU General Public License along with
 // this program. If not, see <https://www.gnu.org/licenses/>.
 
+use std::collections::HashMap;
+use std::sync::Arc;
+
 use clap::{Arg, ArgAction, Command, value_parser};
 use gdvm::config::{self};
 use gdvm::t;
@@ -52,6 +55,21 @@ fn include_pre_flag() -> Arg {
         .help(t!("help-include-pre"))
 }
 
+fn locked_flag() -> Arg {
+    Arg::new("locked")
+        .long("locked")
+        .num_args(0)
+        .help(t!("help-locked"))
+}
+
+fn env_profile_arg() -> Arg {
+    Arg::new("env-profile")
+        .long("env-profile")
+        .value_name("NAME")
+        .help(t!("help-env-profile"))
+        .long_help(t!("help-env-profile-long"))
+}
+
 fn deprecated_csharp_flag() -> Arg {
     Arg::new("csharp")
         .long("csharp")
@@ -82,8 +100,22 @@ fn config_key_arg(help: impl Into<clap::builder::StyledStr>) -> Arg {
         .help(help)
 }
 
-/// Build the gdvm command-line interface.
-pub(crate) fn build_cli() -> Command {
+/// Parse a version spec argument, expanding the names of version aliases so
+/// that commands get the spec an alias stands for.
+fn version_spec_parser(
+    aliases: &Arc<HashMap<String, String>>,
+) -> impl Fn(&str) -> Result<String, String> + Clone + Send + Sync + 'static {
+    let aliases = Arc::clone(aliases);
+    move |input| {
+        let spec = version::expand_alias(input, &aliases).map_err(|e| e.to_string())?;
+        version::validate_version_spec(spec)
+    }
+}
+
+/// Build the gdvm command-line interface. Version arguments accept the names
+/// in `aliases`.
+pub(crate) fn build_cli(aliases: &HashMap<String, String>) -> Command {
+    let aliases = Arc::new(aliases.clone());
     Command::new("gdvm")
         .version(env!("CARGO_PKG_VERSION"))
         .author("Adaline Simonian <adalinesimonian@gmail.com>")
@@ -114,8 +146,8 @@ pub(crate) fn build_cli() -> Command {
                 .about(t!("help-install"))
                 .arg(
                     Arg::new("version")
-                        .required(true)
-                        .value_parser(version::validate_version_spec)
+                        .required(false)
+                        .value_parser(version_spec_parser(&aliases))
                         .help(t!("help-version"))
                         .long_help(t!("help-version-long")),
                 )
@@ -132,6 +164,20 @@ pub(crate) fn build_cli() -> Command {
                         .num_args(0)
                         .help(t!("help-redownload")),
                 )
+                .arg(
+                    Arg::new("system")
+                        .long("system")
+                        .num_args(0)
+                        .help(t!("help-install-system")),
+                )
+                .arg(
+                    Arg::new("self-contained")
+                        .long("self-contained")
+                        .num_args(0)
+                        .conflicts_with("system")
+                        .help(t!("help-install-self-contained")),
+                )
+                .arg(locked_flag())
                 .arg(deprecated_csharp_flag())
                 .arg(include_pre_flag())
                 .arg(refresh_flag())
@@ -153,7 +199,7 @@ pub(crate) fn build_cli() -> Command {
                 .arg(
                     Arg::new("version")
                         .required(false)
-                        .value_parser(version::validate_version_spec)
+                        .value_parser(version_spec_parser(&aliases))
                         .help(t!("help-version-installed")),
                 )
                 .arg(
@@ -180,6 +226,14 @@ pub(crate) fn build_cli() -> Command {
                         .help(t!("help-run-force"))
                         .long_help(t!("help-run-force-long")),
                 )
+                .arg(
+                    Arg::new("profile")
+                        .long("profile")
+                        .value_name("NAME")
+                        .help(t!("help-run-profile"))
+                        .long_help(t!("help-run-profile-long")),
+                )
+                .arg(env_profile_arg())
                 // Allow any number of command line arguments to be passed to the Godot executable after "--"
                 .arg(
                     Arg::new("args")
@@ -187,11 +241,43 @@ pub(crate) fn build_cli() -> Command {
                         .last(true)
                         .help(t!("help-run-args")),
                 )
+                .arg(locked_flag())
                 .arg(deprecated_csharp_flag_with_value())
                 .arg(include_pre_flag())
                 .arg(refresh_flag())
                 .arg(yes_flag()),
         )
+        .subcommand(
+            Command::new("exec")
+                .about(t!("help-exec"))
+                .long_about(t!("help-exec-long"))
+                .arg(
+                    Arg::new("version")
+                        .required(false)
+                        .value_parser(version_spec_parser(&aliases))
+                        .help(t!("help-version-installed")),
+                )
+                .arg(
+                    Arg::new("force")
+                        .long("force")
+                        .short('f')
+                        .num_args(0)
+                        .help(t!("help-run-force"))
+                        .long_help(t!("help-run-force-long")),
+                )
+                .arg(
+                    Arg::new("command")
+                        .num_args(1..)
+                        .last(true)
+                        .required(true)
+                        .help(t!("help-exec-command")),
+                )
+                .arg(env_profile_arg())
+                .arg(locked_flag())
+                .arg(include_pre_flag())
+                .arg(refresh_flag())
+                .arg(yes_flag()),
+        )
         .subcommand(
             Command::new("show")
                 .about(t!("help-show"))
@@ -199,7 +285,7 @@ pub(crate) fn build_cli() -> Command {
                 .arg(
                     Arg::new("version")
                         .required(false)
-                        .value_parser(version::validate_version_spec)
+                        .value_parser(version_spec_parser(&aliases))
                         .help(t!("help-version-installed")),
                 )
                 .arg(
@@ -226,11 +312,35 @@ pub(crate) fn build_cli() -> Command {
                         .help(t!("help-run-force"))
                         .long_help(t!("help-run-force-long")),
                 )
+                .arg(
+                    Arg::new("explain")
+                        .long("explain")
+                        .action(ArgAction::SetTrue)
+                        .help(t!("help-show-explain")),
+                )
                 .arg(deprecated_csharp_flag_with_value())
                 .arg(include_pre_flag())
                 .arg(refresh_flag())
                 .arg(yes_flag()),
         )
+        .subcommand(
+            Command::new("env")
+                .about(t!("help-env"))
+                .long_about(t!("help-env-long"))
+                .arg(
+                    Arg::new("shell")
+                        .long("shell")
+                        .required(true)
+                        .value_parser(["bash", "zsh", "fish", "powershell"])
+                        .help(t!("help-env-shell")),
+                )
+                .arg(
+                    Arg::new("export")
+                        .long("export")
+                        .action(ArgAction::SetTrue)
+                        .help(t!("help-env-export")),
+                ),
+        )
         .subcommand(
             Command::new("info")
                 .about(t!("help-info"))
@@ -238,7 +348,7 @@ pub(crate) fn build_cli() -> Command {
                 .arg(
                     Arg::new("version")
                         .required(false)
-                        .value_parser(version::validate_version_spec)
+                        .value_parser(version_spec_parser(&aliases))
                         .help(t!("help-version-installed")),
                 )
                 .arg(include_pre_flag()),
@@ -250,7 +360,7 @@ pub(crate) fn build_cli() -> Command {
                 .arg(
                     Arg::new("version")
                         .required(true)
-                        .value_parser(version::validate_version_spec)
+                        .value_parser(version_spec_parser(&aliases))
                         .help(t!("help-version")),
                 )
                 .arg(deprecated_csharp_flag_with_value())
@@ -266,7 +376,7 @@ pub(crate) fn build_cli() -> Command {
                 .arg(
                     Arg::new("version")
                         .required(false)
-                        .value_parser(version::validate_version_spec)
+                        .value_parser(version_spec_parser(&aliases))
                         .help(t!("help-link-version")),
                 )
                 .arg({
@@ -312,7 +422,7 @@ pub(crate) fn build_cli() -> Command {
                 .arg(
                     Arg::new("version")
                         .required(true)
-                        .value_parser(version::validate_version_spec)
+                        .value_parser(version_spec_parser(&aliases))
                         .help(t!("help-version-installed")),
                 )
                 .arg(
@@ -322,8 +432,67 @@ pub(crate) fn build_cli() -> Command {
                         .num_args(0)
                         .help(t!("help-remove-yes-deprecated")),
                 )
+                .arg(
+                    Arg::new("system")
+                        .long("system")
+                        .num_args(0)
+                        .help(t!("help-remove-system")),
+                )
+                .arg(deprecated_csharp_flag()),
+        )
+        .subcommand(
+            Command::new("verify")
+                .about(t!("help-verify"))
+                .long_about(t!("help-verify-long"))
+                .arg(
+                    Arg::new("version")
+                        .required(false)
+                        .value_parser(version_spec_parser(&aliases))
+                        .help(t!("help-verify-version")),
+                )
+                .arg(format_flag())
+                .arg(
+                    Arg::new("yes")
+                        .short('y')
+                        .long("yes")
+                        .num_args(0)
+                        .help(t!("help-verify-yes")),
+                )
                 .arg(deprecated_csharp_flag()),
         )
+        .subcommand(
+            Command::new("outdated")
+                .about(t!("help-outdated"))
+                .long_about(t!("help-outdated-long"))
+                .arg(format_flag())
+                .arg(refresh_flag())
+                .arg(yes_flag()),
+        )
+        .subcommand(
+            Command::new("update")
+                .about(t!("help-update"))
+                .long_about(t!("help-update-long"))
+                .arg(
+                    Arg::new("version")
+                        .required(false)
+                        .value_parser(version_spec_parser(&aliases))
+                        .help(t!("help-update-version")),
+                )
+                .arg(
+                    Arg::new("default")
+                        .long("default")
+                        .num_args(0)
+                        .help(t!("help-update-default")),
+                )
+                .arg(
+                    Arg::new("remove")
+                        .long("remove")
+                        .num_args(0)
+                        .help(t!("help-update-remove")),
+                )
+                .arg(refresh_flag())
+                .arg(yes_flag()),
+        )
         .subcommand(
             Command::new("search")
                 .about(t!("help-search"))
@@ -362,6 +531,45 @@ pub(crate) fn build_cli() -> Command {
         )
         .subcommand(Command::new("clear-cache").about(t!("help-clear-cache")))
         .subcommand(Command::new("refresh").about(t!("help-refresh")))
+        .subcommand(
+            Command::new("logs")
+                .about(t!("help-logs"))
+                .long_about(t!(
+                    "help-logs-long",
+                    default_days = config::DEFAULT_LOGS_MAX_AGE_DAYS
+                ))
+                .arg(
+                    Arg::new("follow")
+                        .long("follow")
+                        .short('f')
+                        .num_args(0)
+                        .help(t!("help-logs-follow")),
+                )
+                .arg(
+                    Arg::new("last")
+                        .long("last")
+                        .short('n')
+                        .num_args(1)
+                        .default_value("1")
+                        .value_parser(clap::value_parser!(u64).range(1..))
+                        .help(t!("help-logs-last")),
+                ),
+        )
+        .subcommand(
+            Command::new("crashes")
+                .about(t!("help-crashes"))
+                .long_about(t!("help-crashes-long"))
+                .arg(format_flag()),
+        )
+        .subcommand(Command::new("ps").about(t!("help-ps")).arg(format_flag()))
+        .subcommand(
+            Command::new("stop").about(t!("help-stop")).arg(
+                Arg::new("pid")
+                    .required(true)
+                    .value_parser(clap::value_parser!(u32))
+                    .help(t!("help-stop-pid")),
+            ),
+        )
         .subcommand(
             Command::new("prune")
                 .about(t!("help-prune"))
@@ -391,6 +599,18 @@ pub(crate) fn build_cli() -> Command {
                         .help(t!("help-prune-dry-run")),
                 ),
         )
+        .subcommand(
+            Command::new("dedupe")
+                .about(t!("help-dedupe"))
+                .long_about(t!("help-dedupe-long"))
+                .arg(format_flag())
+                .arg(
+                    Arg::new("dry-run")
+                        .long("dry-run")
+                        .num_args(0)
+                        .help(t!("help-dedupe-dry-run")),
+                ),
+        )
         .subcommand(
             Command::new("use")
                 .about(t!("help-default"))
@@ -399,6 +619,12 @@ pub(crate) fn build_cli() -> Command {
                         .help(t!("help-default-version"))
                         .required(false),
                 )
+                .arg(
+                    Arg::new("float")
+                        .long("float")
+                        .num_args(0)
+                        .help(t!("help-default-float")),
+                )
                 .arg(deprecated_csharp_flag())
                 .arg(include_pre_flag())
                 .arg(refresh_flag())
@@ -429,7 +655,7 @@ pub(crate) fn build_cli() -> Command {
                 .arg(
                     Arg::new("version")
                         .help(t!("help-pin-version"))
-                        .required(true),
+                        .required_unless_present("update-lock"),
                 )
                 .arg(deprecated_csharp_flag())
                 .arg(include_pre_flag())
@@ -440,6 +666,12 @@ pub(crate) fn build_cli() -> Command {
                         .num_args(0)
                         .help(t!("help-no-legacy")),
                 )
+                .arg(
+                    Arg::new("update-lock")
+                        .long("update-lock")
+                        .num_args(0)
+                        .help(t!("help-update-lock")),
+                )
                 .arg(yes_flag()),
         )
         .subcommand(
@@ -484,6 +716,33 @@ pub(crate) fn build_cli() -> Command {
                         ),
                 ),
         )
+        .subcommand(
+            Command::new("alias")
+                .about(t!("help-alias"))
+                .subcommand_required(true)
+                .arg_required_else_help(true)
+                .subcommand(
+                    Command::new("set")
+                        .about(t!("help-alias-set"))
+                        .arg(Arg::new("name").required(true).help(t!("help-alias-name")))
+                        .arg(
+                            Arg::new("spec")
+                                .required(true)
+                                .value_parser(version::validate_version_spec)
+                                .help(t!("help-alias-spec")),
+                        ),
+                )
+                .subcommand(
+                    Command::new("remove")
+                        .about(t!("help-alias-remove"))
+                        .arg(Arg::new("name").required(true).help(t!("help-alias-name"))),
+                )
+                .subcommand(
+                    Command::new("list")
+                        .about(t!("help-alias-list"))
+                        .arg(format_flag()),
+                ),
+        )
         .subcommand(
             Command::new("registry")
                 .about(t!("help-registry"))
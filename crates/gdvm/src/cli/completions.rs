@@ -15,20 +15,32 @@
 // You should have received a copy of the GNU General Public License along with
 // this program. If not, see <https://www.gnu.org/licenses/>.
 
+use std::collections::HashMap;
+
 use anyhow::Result;
-use clap::ArgMatches;
+use clap::builder::PossibleValuesParser;
+use clap::{ArgMatches, Command};
 
 /// Handle the 'completions' subcommand.
-pub(crate) fn sub_completions(matches: &ArgMatches) -> Result<()> {
+pub(crate) fn sub_completions(
+    matches: &ArgMatches,
+    aliases: &HashMap<String, String>,
+) -> Result<()> {
     let shell = *matches
         .get_one::<clap_complete::Shell>("shell")
         .expect("shell is a required argument");
 
+    let mut cmd = super::build_cli(aliases);
+    let mut aliases: Vec<String> = aliases.keys().cloned().collect();
+    aliases.sort();
+    cmd = with_alias_candidates(cmd, &aliases);
+
     // Avoid panics in pipes by writing to a buffer first, then writing the
     // buffer to stdout.
     let mut script = Vec::new();
-    clap_complete::generate(shell, &mut super::build_cli(), "gdvm", &mut script);
+    clap_complete::generate(shell, &mut cmd, "gdvm", &mut script);
     let script = augment_shell_argument(shell, script);
+    let script = augment_positional_values(shell, script, &version_commands(&cmd), &aliases);
 
     use std::io::Write;
     match std::io::stdout().write_all(&script) {
@@ -37,65 +49,106 @@ pub(crate) fn sub_completions(matches: &ArgMatches) -> Result<()> {
     }
 }
 
+/// Subcommands that take a version spec as a positional argument.
+fn version_commands(cmd: &Command) -> Vec<String> {
+    cmd.get_subcommands()
+        .filter(|sub| {
+            sub.get_arguments()
+                .any(|arg| arg.get_id() == "version" && arg.is_positional())
+        })
+        .map(|sub| sub.get_name().to_string())
+        .collect()
+}
+
+/// Offer alias names wherever a version spec is accepted. Only used to
+/// generate the script, as it would otherwise restrict the accepted values.
+fn with_alias_candidates(mut cmd: Command, aliases: &[String]) -> Command {
+    if aliases.is_empty() {
+        return cmd;
+    }
+
+    for name in version_commands(&cmd) {
+        cmd = cmd.mut_subcommand(name, |sub| {
+            sub.mut_arg("version", |arg| {
+                arg.value_parser(PossibleValuesParser::new(aliases.to_vec()))
+            })
+        });
+    }
+    cmd
+}
+
 /// Work around clap_complete's lack of support for argument value candidates in
 /// PowerShell and fish.
 fn augment_shell_argument(shell: clap_complete::Shell, script: Vec<u8>) -> Vec<u8> {
     use clap::ValueEnum;
 
-    let names = || {
-        clap_complete::Shell::value_variants()
-            .iter()
-            .map(|s| s.to_string())
-            .collect::<Vec<_>>()
-    };
+    let names: Vec<String> = clap_complete::Shell::value_variants()
+        .iter()
+        .map(|s| s.to_string())
+        .collect();
 
-    let Ok(text) = String::from_utf8(script) else {
+    augment_positional_values(shell, script, &["completions".to_string()], &names)
+}
+
+/// Add value candidates for the positional arguments of `subcommands` to
+/// PowerShell and fish scripts.
+fn augment_positional_values(
+    shell: clap_complete::Shell,
+    script: Vec<u8>,
+    subcommands: &[String],
+    names: &[String],
+) -> Vec<u8> {
+    let Ok(mut text) = String::from_utf8(script) else {
         unreachable!("generated scripts are UTF-8");
     };
+    if names.is_empty() {
+        return text.into_bytes();
+    }
 
-    let text = match shell {
-        clap_complete::Shell::Fish => {
-            let reference = "complete -c gdvm -n \"__fish_gdvm_using_subcommand completions\"";
+    for subcommand in subcommands {
+        text = match shell {
+            clap_complete::Shell::Fish => {
+                let reference =
+                    format!("complete -c gdvm -n \"__fish_gdvm_using_subcommand {subcommand}\"");
 
-            if text.contains(reference) {
-                let line = format!(
-                    "complete -c gdvm -n \"__fish_gdvm_using_subcommand completions\" -f -a \"{}\"\n",
-                    names().join(" ")
-                );
-                let insert_at = text.find(reference).expect("checked above");
+                if let Some(insert_at) = text.find(&reference) {
+                    let line = format!("{reference} -f -a \"{}\"\n", names.join(" "));
 
-                format!("{}{}{}", &text[..insert_at], line, &text[insert_at..])
-            } else {
-                text
+                    format!("{}{}{}", &text[..insert_at], line, &text[insert_at..])
+                } else {
+                    text
+                }
             }
-        }
-        clap_complete::Shell::PowerShell => {
-            let reference = "'gdvm;completions' {";
-
-            if let Some(pos) = text.find(reference) {
-                let insert_at = pos + reference.len();
-                let entries: String = names()
-                    .iter()
-                    .map(|name| {
-                        format!(
-                            "\n            [CompletionResult]::new('{name}', '{name}', [CompletionResultType]::ParameterValue, '{name}')"
-                        )
-                    })
-                    .collect();
-
-                format!("{}{}{}", &text[..insert_at], entries, &text[insert_at..])
-            } else {
-                text
+            clap_complete::Shell::PowerShell => {
+                let reference = format!("'gdvm;{subcommand}' {{");
+
+                if let Some(pos) = text.find(&reference) {
+                    let insert_at = pos + reference.len();
+                    let entries: String = names
+                        .iter()
+                        .map(|name| {
+                            format!(
+                                "\n            [CompletionResult]::new('{name}', '{name}', [CompletionResultType]::ParameterValue, '{name}')"
+                            )
+                        })
+                        .collect();
+
+                    format!("{}{}{}", &text[..insert_at], entries, &text[insert_at..])
+                } else {
+                    text
+                }
             }
-        }
-        _ => text,
-    };
+            _ => text,
+        };
+    }
 
     text.into_bytes()
 }
 
 #[cfg(test)]
 mod tests {
+    use std::collections::HashMap;
+
     use clap::ValueEnum;
     use clap_complete::Shell;
 
@@ -103,7 +156,12 @@ mod tests {
     fn generates_scripts_for_all_shells() {
         for shell in Shell::value_variants() {
             let mut out = Vec::new();
-            clap_complete::generate(*shell, &mut crate::cli::build_cli(), "gdvm", &mut out);
+            clap_complete::generate(
+                *shell,
+                &mut crate::cli::build_cli(&HashMap::new()),
+                "gdvm",
+                &mut out,
+            );
             let out = super::augment_shell_argument(*shell, out);
             let script = String::from_utf8(out).expect("script is valid UTF-8");
             assert!(script.contains("gdvm"), "{shell}: mentions the binary");
@@ -120,4 +178,23 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn offers_alias_names_for_version_arguments() {
+        let aliases = vec!["work".to_string()];
+        for shell in [Shell::Bash, Shell::Fish, Shell::PowerShell] {
+            let mut cmd =
+                super::with_alias_candidates(crate::cli::build_cli(&HashMap::new()), &aliases);
+            let mut out = Vec::new();
+            clap_complete::generate(shell, &mut cmd, "gdvm", &mut out);
+            let out = super::augment_positional_values(
+                shell,
+                out,
+                &super::version_commands(&cmd),
+                &aliases,
+            );
+            let script = String::from_utf8(out).expect("script is valid UTF-8");
+            assert!(script.contains("work"), "{shell}: offers alias names");
+        }
+    }
 }
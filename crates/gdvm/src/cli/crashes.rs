@@ -0,0 +1,74 @@
+// SPDX-FileCopyrightText: Copyright (C) 2024 Adaline Simonian
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// This file is part of gdvm.
+//
+// gdvm is free software: you can redistribute it and/or modify it under the
+// terms of the GNU General Public License as published by the Free Software
+// Foundation, either version 3 of the License, or (at your option) any later
+// version.
+//
+// gdvm is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR
+// A PARTICULAR PURPOSE. See the GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with
+// this program. If not, see <https://www.gnu.org/licenses/>.
+
+use anyhow::Result;
+use clap::ArgMatches;
+use gdvm::app::Gdvm;
+use gdvm::crash_reports::CrashRecord;
+use gdvm::date_utils::format_unix_timestamp_local;
+use gdvm::println_i18n;
+
+use super::format::{OutputFormat, print_json};
+
+/// A saved crash record, as printed by `gdvm crashes --format json`.
+#[derive(serde::Serialize)]
+struct CrashEntry {
+    path: String,
+    #[serde(flatten)]
+    record: CrashRecord,
+}
+
+/// Handle the 'crashes' subcommand
+pub(crate) fn sub_crashes(gdvm: &Gdvm, matches: &ArgMatches) -> Result<()> {
+    let crashes = gdvm.launcher().crashes();
+
+    if OutputFormat::is_json(matches) {
+        let entries: Vec<CrashEntry> = crashes
+            .into_iter()
+            .map(|(path, record)| CrashEntry {
+                path: path.display().to_string(),
+                record,
+            })
+            .collect();
+        return print_json(&entries);
+    }
+
+    if crashes.is_empty() {
+        println_i18n!("crashes-none");
+        return Ok(());
+    }
+
+    for (path, record) in &crashes {
+        let time = record
+            .time
+            .parse::<jiff::Timestamp>()
+            .ok()
+            .and_then(|ts| u64::try_from(ts.as_second()).ok())
+            .map(format_unix_timestamp_local)
+            .unwrap_or_else(|| record.time.clone());
+        println_i18n!(
+            "crashes-entry",
+            time = time.as_str(),
+            version = record.version.as_str(),
+            variant = record.variant.as_str(),
+            reason = record.reason.as_str(),
+            path = path.display().to_string()
+        );
+    }
+
+    Ok(())
+}
@@ -0,0 +1,56 @@
+// SPDX-FileCopyrightText: Copyright (C) 2024 Adaline Simonian
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// This file is part of gdvm.
+//
+// gdvm is free software: you can redistribute it and/or modify it under the
+// terms of the GNU General Public License as published by the Free Software
+// Foundation, either version 3 of the License, or (at your option) any later
+// version.
+//
+// gdvm is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR
+// A PARTICULAR PURPOSE. See the GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with
+// this program. If not, see <https://www.gnu.org/licenses/>.
+
+use anyhow::Result;
+use clap::ArgMatches;
+use gdvm::app::Gdvm;
+use gdvm::t;
+
+use super::format::{OutputFormat, byte_display_args, print_json};
+
+/// Handle the 'dedupe' subcommand
+pub(crate) fn sub_dedupe(gdvm: &Gdvm, matches: &ArgMatches) -> Result<()> {
+    let report = gdvm.deduper().dedupe(matches.get_flag("dry-run"))?;
+
+    if OutputFormat::is_json(matches) {
+        return print_json(&report);
+    }
+
+    if report.files == 0 {
+        gdvm::ui::note(t!("dedupe-nothing"));
+        return Ok(());
+    }
+
+    let (value, unit) = byte_display_args(report.saved_bytes);
+    gdvm::ui::note(if report.dry_run {
+        t!(
+            "dedupe-would-share-files",
+            count = report.files,
+            value = value,
+            unit = unit
+        )
+    } else {
+        t!(
+            "dedupe-shared-files",
+            count = report.files,
+            value = value,
+            unit = unit
+        )
+    });
+
+    Ok(())
+}
@@ -0,0 +1,249 @@
+// SPDX-FileCopyrightText: Copyright (C) 2024 Adaline Simonian
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// This file is part of gdvm.
+//
+// gdvm is free software: you can redistribute it and/or modify it under the
+// terms of the GNU General Public License as published by the Free Software
+// Foundation, either version 3 of the License, or (at your option) any later
+// version.
+//
+// gdvm is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR
+// A PARTICULAR PURPOSE. See the GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with
+// this program. If not, see <https://www.gnu.org/licenses/>.
+
+use std::path::Path;
+
+use anyhow::Result;
+use clap::ArgMatches;
+use gdvm::app::Gdvm;
+use gdvm::run_version_resolver::{RunResolutionRequest, RunVersionResolver, RunVersionSource};
+use gdvm::version::Variant;
+
+/// Variables the hook exports for the current directory.
+const GODOT_VAR: &str = "GODOT";
+const VERSION_VAR: &str = "GODOT_VERSION";
+const SOURCE_VAR: &str = "GDVM_RESOLVED_SOURCE";
+
+/// The Godot that runs in the current directory.
+#[derive(Clone, serde::Serialize, serde::Deserialize)]
+struct ProjectGodot {
+    path: String,
+    version: String,
+    source: String,
+}
+
+/// Handle the 'env' subcommand. It sets up its own instance of gdvm, since the
+/// hooks call it at every prompt.
+pub(crate) async fn sub_env(matches: &ArgMatches) -> Result<()> {
+    let shell = matches
+        .get_one::<String>("shell")
+        .expect("shell is a required argument");
+
+    let script = if matches.get_flag("export") {
+        exports(shell, current_godot().await?.as_ref())
+    } else {
+        hook(shell).to_string()
+    };
+    print!("{script}");
+
+    Ok(())
+}
+
+/// The current directory's Godot. While none of the files it was resolved
+/// from changed, it's taken from the cache with a light instance of gdvm.
+async fn current_godot() -> Result<Option<ProjectGodot>> {
+    let Some(light) = Gdvm::for_shim()? else {
+        return Ok(resolve(&Gdvm::new().await?).await);
+    };
+
+    let cwd = std::env::current_dir()?;
+    let key = serde_json::json!([cwd, gdvm::gdvm_lock::locked_from_env()]).to_string();
+    let cache = light.env_cache::<Option<ProjectGodot>>();
+    if let Some(godot) = cache.get(&key)
+        && godot
+            .as_ref()
+            .is_none_or(|godot| Path::new(&godot.path).is_file())
+    {
+        return Ok(godot);
+    }
+
+    let stamps = light.defaults().resolution_stamps(&[cwd])?;
+    let godot = resolve(&Gdvm::new().await?).await;
+    // The cache only saves time, so failing to write it doesn't matter.
+    cache.insert(&key, godot.clone(), stamps).ok();
+    Ok(godot)
+}
+
+/// Resolve the current directory's Godot the way `godot` would, without
+/// installing anything or printing warnings. `None` when nothing is selected
+/// or the selected version isn't installed.
+async fn resolve(gdvm: &Gdvm) -> Option<ProjectGodot> {
+    let selection = RunVersionResolver::new(gdvm)
+        .select(&RunResolutionRequest {
+            explicit: None,
+            variant: None,
+            registry: None,
+            include_pre: false,
+            possible_paths: &[],
+            force_on_mismatch: true,
+            install_if_missing: false,
+        })
+        .await
+        .ok()??;
+
+    let variant = Variant::from_option(selection.variant.as_deref());
+    let registry = selection.registry.as_deref();
+    let version = gdvm
+        .ensure_installed_version(&selection.version, selection.variant.as_deref(), registry)
+        .await
+        .ok()?;
+    let path = gdvm
+        .library()
+        .get_executable_path(&version, &variant, registry, true)
+        .ok()?;
+
+    Some(ProjectGodot {
+        path: path.display().to_string(),
+        version: gdvm::version::display_version(&version, &variant, registry),
+        source: selection.source.name().to_string(),
+    })
+}
+
+/// Statements that set the variables for `godot`, or unset them without one.
+fn exports(shell: &str, godot: Option<&ProjectGodot>) -> String {
+    let vars = [GODOT_VAR, VERSION_VAR, SOURCE_VAR];
+    let Some(godot) = godot else {
+        return match shell {
+            "fish" => format!("set -e {}\n", vars.join(" ")),
+            "powershell" => vars
+                .map(|var| format!("Remove-Item Env:{var} -ErrorAction SilentlyContinue\n"))
+                .concat(),
+            _ => format!("unset {}\n", vars.join(" ")),
+        };
+    };
+
+    let values = [
+        godot.path.as_str(),
+        godot.version.as_str(),
+        godot.source.as_str(),
+    ];
+    vars.iter()
+        .zip(values)
+        .map(|(var, value)| match shell {
+            "fish" => format!(
+                "set -gx {var} '{}'\n",
+                value.replace('\\', "\\\\").replace('\'', "\\'")
+            ),
+            "powershell" => format!("$env:{var} = '{}'\n", value.replace('\'', "''")),
+            _ => format!("export {var}='{}'\n", value.replace('\'', "'\\''")),
+        })
+        .collect()
+}
+
+/// A hook that re-exports the variables whenever the directory changes.
+fn hook(shell: &str) -> &'static str {
+    match shell {
+        "zsh" => ZSH_HOOK,
+        "fish" => FISH_HOOK,
+        "powershell" => POWERSHELL_HOOK,
+        _ => BASH_HOOK,
+    }
+}
+
+// The hooks call gdvm at every prompt, so that edits to pin files show up
+// without changing directories. gdvm answers from its cache until one of the
+// files the answer came from changes, so the prompt stays fast.
+
+const BASH_HOOK: &str = r#"_gdvm_env_hook() {
+  eval "$(command gdvm env --shell bash --export 2>/dev/null)"
+}
+case ";${PROMPT_COMMAND-};" in
+  *";_gdvm_env_hook;"*) ;;
+  *) PROMPT_COMMAND="_gdvm_env_hook${PROMPT_COMMAND:+;$PROMPT_COMMAND}" ;;
+esac
+"#;
+
+const ZSH_HOOK: &str = r#"_gdvm_env_hook() {
+  eval "$(command gdvm env --shell zsh --export 2>/dev/null)"
+}
+autoload -Uz add-zsh-hook
+add-zsh-hook precmd _gdvm_env_hook
+"#;
+
+const FISH_HOOK: &str = r#"function _gdvm_env_hook --on-event fish_prompt
+    command gdvm env --shell fish --export 2>/dev/null | source
+end
+_gdvm_env_hook
+"#;
+
+const POWERSHELL_HOOK: &str = r#"function global:_GdvmEnvHook {
+    (& gdvm env --shell powershell --export 2>$null) -join "`n" | Invoke-Expression
+}
+if (-not $global:_GdvmPreviousPrompt) {
+    $global:_GdvmPreviousPrompt = $function:prompt
+}
+function global:prompt { _GdvmEnvHook; & $global:_GdvmPreviousPrompt }
+"#;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn godot(path: &str) -> ProjectGodot {
+        ProjectGodot {
+            path: path.to_string(),
+            version: "4.3-stable".to_string(),
+            source: "pin".to_string(),
+        }
+    }
+
+    #[test]
+    fn exports_quote_values_for_each_shell() {
+        let godot = godot("/home/o'neil/godot");
+
+        assert_eq!(
+            exports("bash", Some(&godot)),
+            "export GODOT='/home/o'\\''neil/godot'\n\
+             export GODOT_VERSION='4.3-stable'\n\
+             export GDVM_RESOLVED_SOURCE='pin'\n"
+        );
+        assert!(
+            exports("fish", Some(&godot)).starts_with("set -gx GODOT '/home/o\\'neil/godot'\n")
+        );
+        assert!(
+            exports("powershell", Some(&godot)).starts_with("$env:GODOT = '/home/o''neil/godot'\n")
+        );
+    }
+
+    #[test]
+    fn exports_unset_without_a_godot() {
+        assert_eq!(
+            exports("zsh", None),
+            "unset GODOT GODOT_VERSION GDVM_RESOLVED_SOURCE\n"
+        );
+        assert_eq!(
+            exports("fish", None),
+            "set -e GODOT GODOT_VERSION GDVM_RESOLVED_SOURCE\n"
+        );
+        assert_eq!(exports("powershell", None).lines().count(), 3);
+    }
+
+    #[test]
+    fn hooks_call_back_with_their_own_shell() {
+        for shell in ["bash", "zsh", "fish", "powershell"] {
+            assert!(hook(shell).contains(&format!("env --shell {shell} --export")));
+        }
+    }
+
+    #[test]
+    fn powershell_hook_wraps_the_prompt_once() {
+        let guard = "if (-not $global:_GdvmPreviousPrompt) {";
+        let wrap = "$global:_GdvmPreviousPrompt = $function:prompt";
+        let hook = hook("powershell");
+        assert!(hook.find(guard).unwrap() < hook.find(wrap).unwrap());
+    }
+}
@@ -0,0 +1,64 @@
+// SPDX-FileCopyrightText: Copyright (C) 2024 Adaline Simonian
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// This file is part of gdvm.
+//
+// gdvm is free software: you can redistribute it and/or modify it under the
+// terms of the GNU General Public License as published by the Free Software
+// Foundation, either version 3 of the License, or (at your option) any later
+// version.
+//
+// gdvm is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR
+// A PARTICULAR PURPOSE. See the GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with
+// this program. If not, see <https://www.gnu.org/licenses/>.
+
+use anyhow::Result;
+use clap::ArgMatches;
+use gdvm::app::Gdvm;
+
+use super::run::{env_profile, resolve_run_version};
+use super::{RunConfig, VersionRequest};
+
+/// Handle the 'exec' subcommand
+pub(crate) async fn sub_exec(gdvm: &Gdvm, matches: &ArgMatches) -> Result<i32> {
+    let command: Vec<String> = matches
+        .get_many::<String>("command")
+        .expect("command is a required argument")
+        .cloned()
+        .collect();
+
+    let request = VersionRequest::from_matches(matches)?;
+    request.prepare(gdvm, matches).await?;
+
+    let config = RunConfig {
+        gdvm,
+        version_input: matches.get_one::<String>("version"),
+        variant: request.variant_owned(),
+        console: Some(true),
+        profile: None,
+        env_profile: matches.get_one::<String>("env-profile").map(String::as_str),
+        raw_args: &command,
+        force_on_mismatch: matches.get_flag("force"),
+        include_pre: matches.get_flag("include-pre"),
+        assume_yes: matches.get_flag("yes"),
+    };
+    let resolved = resolve_run_version(&config, &[]).await?;
+    let project = gdvm
+        .defaults()
+        .project_file::<&str>(None)
+        .and_then(|file| file.parent()?.canonicalize().ok());
+    let dotenv = gdvm
+        .defaults()
+        .dotenv_vars(project.as_deref(), env_profile(&config).as_ref())?;
+
+    gdvm.launcher().exec(
+        &resolved.version,
+        &resolved.variant,
+        resolved.registry.as_deref(),
+        &command,
+        &dotenv,
+    )
+}
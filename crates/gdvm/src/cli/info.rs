@@ -18,7 +18,7 @@
 use anyhow::Result;
 use clap::ArgMatches;
 use gdvm::app::Gdvm;
-use gdvm::{fs_utils, t, t_attr};
+use gdvm::{t, t_attr};
 
 use super::VersionRequest;
 use super::format::{OutputFormat, byte_display_args, format_label_value_table, print_json};
@@ -66,7 +66,7 @@ pub(crate) async fn sub_info(gdvm: &Gdvm, matches: &ArgMatches) -> Result<()> {
         registry: registry.map(str::to_string),
         install_path: install_path.display().to_string(),
         executable: executable.display().to_string(),
-        size_bytes: fs_utils::dir_size(&install_path),
+        size_bytes: gdvm.deduper().install_size(&install_path),
         is_default,
         last_used: library.last_used(&install_key)?,
     };
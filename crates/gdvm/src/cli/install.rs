@@ -18,22 +18,44 @@
 use anyhow::Result;
 use clap::ArgMatches;
 use gdvm::app::{Gdvm, InstallOutcome};
-use gdvm::println_i18n;
 use gdvm::version::{self, Variant};
+use gdvm::{println_i18n, t, terr};
 
-use super::VersionRequest;
+use super::{VersionRequest, ensure_registry_trusted};
 
 /// Handle the 'install' subcommand
 pub(crate) async fn sub_install(gdvm: &Gdvm, matches: &ArgMatches) -> Result<()> {
     let force_reinstall = matches.get_flag("force");
     let redownload = matches.get_flag("redownload");
     let include_pre = matches.get_flag("include-pre");
+    let system = matches.get_flag("system");
+    let self_contained = matches.get_flag("self-contained");
 
     let request = VersionRequest::from_matches(matches)?;
     request.prepare(gdvm, matches).await?;
-    let variant = request.variant();
-    let registry = request.registry();
-    let requested_version = request.required_filter().clone();
+    // Without a version, install the project's pinned build.
+    let (requested_version, variant, registry) = match request.filter_owned() {
+        Some(filter) => (filter, request.variant_owned(), request.registry_owned()),
+        None => {
+            let pin = gdvm
+                .locked_pin()?
+                .ok_or_else(|| terr!("error-no-pinned-version"))?;
+            if let Some(path) = &pin.stale_lock {
+                gdvm::ui::warn(t!(
+                    "warning-lock-out-of-date",
+                    path = path.display().to_string()
+                ));
+            }
+            ensure_registry_trusted(gdvm, pin.registry.as_deref(), matches.get_flag("yes")).await?;
+            (
+                pin.version,
+                request.variant_owned().or(pin.variant),
+                pin.registry,
+            )
+        }
+    };
+    let variant = variant.as_deref();
+    let registry = registry.as_deref();
 
     let gv = gdvm
         .catalogs()
@@ -43,8 +65,13 @@ pub(crate) async fn sub_install(gdvm: &Gdvm, matches: &ArgMatches) -> Result<()>
     let resolved_variant = Variant::from_option(variant);
     let display = version::display_version(&gv, &resolved_variant, registry);
 
-    match gdvm
-        .installer()
+    let installer = if system {
+        gdvm.system_installer()?
+    } else {
+        gdvm.installer()
+    };
+
+    match installer
         .install(
             &gv,
             &resolved_variant,
@@ -60,9 +87,19 @@ pub(crate) async fn sub_install(gdvm: &Gdvm, matches: &ArgMatches) -> Result<()>
         InstallOutcome::AlreadyInstalled => {
             // Print a message indicating the version is already installed
             println_i18n!("version-already-installed", version = &display);
-            return Ok(());
         }
     }
 
+    if self_contained {
+        let data = gdvm
+            .library()
+            .make_self_contained(&gv, &resolved_variant, registry)?;
+        gdvm::ui::note(t!(
+            "install-self-contained",
+            version = &display,
+            path = data.display().to_string()
+        ));
+    }
+
     Ok(())
 }
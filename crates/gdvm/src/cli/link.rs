@@ -21,6 +21,7 @@ use std::path::{Path, PathBuf};
 use anyhow::Result;
 use clap::ArgMatches;
 use gdvm::app::Gdvm;
+use gdvm::run_version_resolver::RunResolutionResult;
 use gdvm::{println_i18n, terr};
 
 use super::VersionRequest;
@@ -37,6 +38,18 @@ pub(crate) async fn sub_link(gdvm: &Gdvm, matches: &ArgMatches) -> Result<()> {
     let request = VersionRequest::from_matches(matches)?;
     let resolved = request.resolve_selection(gdvm, false, false, force).await?;
 
+    link_install(gdvm, &resolved, Path::new(link_path_raw), force, copy)
+}
+
+/// Link or copy an install's executable to `link_path`, recording links so
+/// that prune and update know about them.
+pub(crate) fn link_install(
+    gdvm: &Gdvm,
+    resolved: &RunResolutionResult,
+    link_path: &Path,
+    force: bool,
+    copy: bool,
+) -> Result<()> {
     let primary_exe = gdvm.library().get_executable_path(
         &resolved.version,
         &resolved.variant,
@@ -69,7 +82,7 @@ pub(crate) async fn sub_link(gdvm: &Gdvm, matches: &ArgMatches) -> Result<()> {
         resolved.registry.as_deref(),
     )?;
 
-    let link_path = PathBuf::from(link_path_raw);
+    let link_path = link_path.to_path_buf();
     if let Some(parent) = link_path.parent().filter(|p| !p.as_os_str().is_empty()) {
         fs::create_dir_all(parent)?;
     }
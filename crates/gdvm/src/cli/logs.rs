@@ -0,0 +1,81 @@
+// SPDX-FileCopyrightText: Copyright (C) 2024 Adaline Simonian
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// This file is part of gdvm.
+//
+// gdvm is free software: you can redistribute it and/or modify it under the
+// terms of the GNU General Public License as published by the Free Software
+// Foundation, either version 3 of the License, or (at your option) any later
+// version.
+//
+// gdvm is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR
+// A PARTICULAR PURPOSE. See the GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with
+// this program. If not, see <https://www.gnu.org/licenses/>.
+
+use std::fs::File;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use anyhow::Result;
+use clap::ArgMatches;
+use gdvm::app::Gdvm;
+
+/// How often `--follow` checks the newest log for more output.
+const FOLLOW_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Handle the 'logs' subcommand
+pub(crate) fn sub_logs(gdvm: &Gdvm, matches: &ArgMatches) -> Result<()> {
+    let last = *matches
+        .get_one::<u64>("last")
+        .expect("last has a default value");
+    let last = usize::try_from(last).unwrap_or(usize::MAX);
+    let follow = matches.get_flag("follow");
+
+    let logs = gdvm.launcher().logs();
+    if logs.is_empty() && !follow {
+        gdvm::ui::note(gdvm::t!("logs-none"));
+        return Ok(());
+    }
+
+    let mut stdout = io::stdout().lock();
+    let mut current = None;
+    for path in &logs[logs.len().saturating_sub(last)..] {
+        current = Some(print_log(path, &mut stdout)?);
+    }
+
+    if !follow {
+        return Ok(());
+    }
+
+    // Keep printing what the newest log gains, moving on to newer logs as
+    // launches create them.
+    loop {
+        if let Some((_, file)) = current.as_mut() {
+            io::copy(file, &mut stdout)?;
+            stdout.flush()?;
+        }
+
+        if let Some(newest) = gdvm.launcher().logs().pop()
+            && current.as_ref().is_none_or(|(path, _)| *path != newest)
+        {
+            current = Some(print_log(&newest, &mut stdout)?);
+            continue;
+        }
+
+        std::thread::sleep(FOLLOW_INTERVAL);
+    }
+}
+
+/// Print a log's name and everything in it so far. Returns the open file to
+/// follow it from there.
+fn print_log(path: &Path, stdout: &mut impl Write) -> Result<(PathBuf, File)> {
+    gdvm::ui::milestone(gdvm::t!("status-log"), path.display().to_string());
+    let mut file = File::open(path)?;
+    io::copy(&mut file, stdout)?;
+    stdout.flush()?;
+    Ok((path.to_path_buf(), file))
+}
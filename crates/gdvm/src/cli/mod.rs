@@ -20,49 +20,68 @@ use std::io::{self, IsTerminal, Write};
 use anyhow::Result;
 use gdvm::app::Gdvm;
 use gdvm::config::ConfigFile;
-use gdvm::version::VersionQuery;
 use gdvm::{t, terr, ui};
 
+mod alias;
 mod args;
 mod cache;
 mod completions;
 mod config;
+mod crashes;
+mod dedupe;
 mod diagnose;
+mod env;
+mod exec;
 mod format;
 mod info;
 mod install;
 mod link;
 mod list;
+mod logs;
+mod outdated;
 mod pin;
 mod prune;
+mod ps;
 mod registry;
 mod remove;
 mod request;
 mod run;
 mod search;
 mod show;
+mod update;
 mod upgrade;
 mod use_cmd;
+mod verify;
 
+pub(crate) use alias::sub_alias;
 pub(crate) use args::build_cli;
 pub(crate) use cache::{sub_cache_path, sub_clear_cache, sub_refresh};
 pub(crate) use completions::sub_completions;
 pub(crate) use config::sub_config;
+pub(crate) use crashes::sub_crashes;
+pub(crate) use dedupe::sub_dedupe;
 pub(crate) use diagnose::sub_diagnose;
+pub(crate) use env::sub_env;
+pub(crate) use exec::sub_exec;
 pub(crate) use info::sub_info;
 pub(crate) use install::sub_install;
 pub(crate) use link::sub_link;
 pub(crate) use list::sub_list;
+pub(crate) use logs::sub_logs;
+pub(crate) use outdated::sub_outdated;
 pub(crate) use pin::sub_pin;
 pub(crate) use prune::sub_prune;
+pub(crate) use ps::{sub_ps, sub_stop};
 pub(crate) use registry::sub_registry;
 pub(crate) use remove::sub_remove;
 pub(crate) use request::VersionRequest;
-pub(crate) use run::{RunConfig, sub_run, sub_run_inner};
+pub(crate) use run::{RunConfig, sub_run, sub_run_shim};
 pub(crate) use search::sub_search;
 pub(crate) use show::sub_show;
+pub(crate) use update::sub_update;
 pub(crate) use upgrade::sub_upgrade;
 pub(crate) use use_cmd::sub_use;
+pub(crate) use verify::sub_verify;
 
 async fn refresh_cache_if_requested(gdvm: &Gdvm, refresh: bool) -> Result<()> {
     if refresh {
@@ -72,18 +91,6 @@ async fn refresh_cache_if_requested(gdvm: &Gdvm, refresh: bool) -> Result<()> {
     Ok(())
 }
 
-/// Convert a keyword to a `VersionQuery` filter for resolution.
-fn keyword_to_version_filter(keyword: &str) -> VersionQuery {
-    if keyword == "stable" {
-        VersionQuery {
-            release_type: Some("stable".to_string()),
-            ..Default::default()
-        }
-    } else {
-        VersionQuery::default()
-    }
-}
-
 /// Ensure the user has acknowledged the use of a third-party registry. If the
 /// registry is official, this function does nothing. If the registry is not
 /// trusted, it prompts the user to confirm that they trust it, unless
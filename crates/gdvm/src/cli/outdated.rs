@@ -0,0 +1,235 @@
+// SPDX-FileCopyrightText: Copyright (C) 2024 Adaline Simonian
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// This file is part of gdvm.
+//
+// gdvm is free software: you can redistribute it and/or modify it under the
+// terms of the GNU General Public License as published by the Free Software
+// Foundation, either version 3 of the License, or (at your option) any later
+// version.
+//
+// gdvm is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR
+// A PARTICULAR PURPOSE. See the GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with
+// this program. If not, see <https://www.gnu.org/licenses/>.
+
+use anyhow::Result;
+use clap::ArgMatches;
+use gdvm::app::Gdvm;
+use gdvm::registry::normalize_registry;
+use gdvm::registry_version_resolver::NewerBuilds;
+use gdvm::version::{self, QuerySelection, ResolvedVersion, Variant};
+use gdvm::{println_i18n, t, ui};
+
+use super::format::{OutputFormat, format_label_value_table, print_json};
+use super::{ensure_registry_trusted, refresh_cache_if_requested};
+
+/// Where a checked version is used.
+#[derive(Clone, Copy, serde::Serialize)]
+#[serde(rename_all = "lowercase")]
+enum Source {
+    Installed,
+    Default,
+    Pinned,
+}
+
+impl Source {
+    fn label(self) -> String {
+        match self {
+            Source::Installed => t!("outdated-source-installed"),
+            Source::Default => t!("outdated-source-default"),
+            Source::Pinned => t!("outdated-source-pinned"),
+        }
+    }
+}
+
+/// A version to check, and where it is used.
+struct Target {
+    version: ResolvedVersion,
+    variant: Variant,
+    registry: Option<String>,
+    sources: Vec<Source>,
+}
+
+/// The newer builds of one version.
+#[derive(serde::Serialize)]
+struct OutdatedEntry {
+    version: String,
+    variant: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    registry: Option<String>,
+    sources: Vec<Source>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    patch: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    minor: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    prerelease: Option<String>,
+    outdated: bool,
+}
+
+/// Handle the 'outdated' subcommand. Returns a non-zero exit code when an
+/// update is available.
+pub(crate) async fn sub_outdated(gdvm: &Gdvm, matches: &ArgMatches) -> Result<i32> {
+    let json = OutputFormat::is_json(matches);
+    refresh_cache_if_requested(gdvm, matches.get_flag("refresh")).await?;
+
+    let targets = collect_targets(gdvm).await?;
+
+    let mut trusted: Vec<Option<&str>> = Vec::new();
+    let mut entries = Vec::new();
+    let mut exit_code = 0;
+
+    for target in &targets {
+        let registry = target.registry.as_deref();
+        if !trusted.contains(&registry) {
+            ensure_registry_trusted(gdvm, registry, matches.get_flag("yes")).await?;
+            trusted.push(registry);
+        }
+
+        let newer = match gdvm
+            .catalogs()
+            .newer_builds(&target.version, &target.variant, registry, false)
+            .await
+        {
+            Ok(newer) => newer,
+            Err(error) => {
+                ui::warn(t!(
+                    "warning-outdated-check-failed",
+                    version = version::display_version(&target.version, &target.variant, registry),
+                    error = error.to_string()
+                ));
+                continue;
+            }
+        };
+
+        let outdated = newer.has_updates(&target.version);
+        if outdated {
+            exit_code = 1;
+        }
+        if !json && !newer.is_empty() {
+            print_target(target, &newer);
+        }
+        entries.push(OutdatedEntry {
+            version: target.version.to_display_str(),
+            variant: target.variant.as_str().to_string(),
+            registry: target.registry.clone(),
+            sources: target.sources.clone(),
+            patch: newer.patch.map(|gv| gv.to_display_str()),
+            minor: newer.minor.map(|gv| gv.to_display_str()),
+            prerelease: newer.prerelease.map(|gv| gv.to_display_str()),
+            outdated,
+        });
+    }
+
+    if json {
+        print_json(&entries)?;
+    } else if targets.is_empty() {
+        println_i18n!("no-versions-installed");
+    } else if exit_code == 0 {
+        println_i18n!("outdated-up-to-date");
+    }
+
+    Ok(exit_code)
+}
+
+/// Gather the installed versions, the default and the project's pin, merging
+/// the ones that refer to the same build.
+async fn collect_targets(gdvm: &Gdvm) -> Result<Vec<Target>> {
+    let mut targets: Vec<Target> = Vec::new();
+    let mut add =
+        |version: ResolvedVersion, variant: Variant, registry: Option<String>, source: Source| {
+            let registry = normalize_registry(registry.as_deref()).map(str::to_string);
+            match targets
+                .iter_mut()
+                .find(|t| t.version == version && t.variant == variant && t.registry == registry)
+            {
+                Some(target) => target.sources.push(source),
+                None => targets.push(Target {
+                    version,
+                    variant,
+                    registry,
+                    sources: vec![source],
+                }),
+            }
+        };
+
+    for installed in gdvm.library().list_installed()? {
+        add(
+            installed.version,
+            installed.variant,
+            installed.registry,
+            Source::Installed,
+        );
+    }
+
+    if let Some(default) = gdvm.defaults().get_default()? {
+        add(
+            default.version,
+            default.variant,
+            default.registry,
+            Source::Default,
+        );
+    }
+
+    if let Some(pin) = gdvm.defaults().get_locked_pin(false)?
+        && let Some(version) = resolve_pin(gdvm, &pin).await
+    {
+        let variant = Variant::from_option(pin.variant.as_deref());
+        add(version, variant, pin.registry, Source::Pinned);
+    }
+
+    Ok(targets)
+}
+
+/// The build a pin currently stands for: the pinned version itself when it
+/// is exact, otherwise the newest installed or available match.
+async fn resolve_pin(gdvm: &Gdvm, pin: &QuerySelection) -> Option<ResolvedVersion> {
+    let query = &pin.version;
+    if !query.is_incomplete() && !query.has_release_wildcard() && query.range.is_none() {
+        return Some(query.to_resolved());
+    }
+
+    let variant = pin.variant.as_deref();
+    let registry = pin.registry.as_deref();
+    if let Ok(installed) = gdvm
+        .library()
+        .resolve_installed_newest(query, variant, registry)
+        .await
+    {
+        return Some(installed.version);
+    }
+    gdvm.catalogs()
+        .resolve_available_version(query, variant, registry, false, false)
+        .await
+        .ok()
+        .flatten()
+}
+
+/// Print the newer builds of a version.
+fn print_target(target: &Target, newer: &NewerBuilds) {
+    let sources = target
+        .sources
+        .iter()
+        .map(|source| source.label())
+        .collect::<Vec<_>>()
+        .join(", ");
+    println!(
+        "- {} ({sources})",
+        version::display_version(&target.version, &target.variant, target.registry.as_deref())
+    );
+
+    let rows: Vec<(String, String)> = [
+        ("outdated-patch", &newer.patch),
+        ("outdated-minor", &newer.minor),
+        ("outdated-prerelease", &newer.prerelease),
+    ]
+    .into_iter()
+    .filter_map(|(label, gv)| Some((t!(label), gv.as_ref()?.to_display_str())))
+    .collect();
+    for line in format_label_value_table(&rows).lines() {
+        println!("    {line}");
+    }
+}
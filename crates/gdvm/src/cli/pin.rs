@@ -24,34 +24,54 @@ use gdvm::run_version_resolver::warn_project_version_mismatch;
 use gdvm::version::{self, Variant};
 use gdvm::{println_i18n, terr};
 
-use super::VersionRequest;
+use super::{VersionRequest, ensure_registry_trusted};
 
 /// Handle the 'pin' subcommand
 pub(crate) async fn sub_pin(gdvm: &Gdvm, matches: &ArgMatches) -> Result<()> {
     let request = VersionRequest::from_matches(matches)?;
     request.prepare(gdvm, matches).await?;
-    let variant = request.variant();
-    let registry = request.registry();
-    let version = request.required_filter().clone();
+    let include_pre = matches.get_flag("include-pre");
+    let update_lock = matches.get_flag("update-lock");
 
-    warn_project_version_mismatch::<_, &Path>(gdvm, &version, true, None).await;
+    if let Some(version) = request.filter_owned() {
+        let variant = request.variant();
+        let registry = request.registry();
 
-    let include_pre = matches.get_flag("include-pre");
+        warn_project_version_mismatch::<_, &Path>(gdvm, &version, true, None).await;
+
+        let resolved_version = gdvm
+            .installer()
+            .auto_install_version(&version, variant, registry, include_pre)
+            .await?;
+
+        let resolved_variant = Variant::from_option(variant);
+        let display = version::display_version(&resolved_version, &resolved_variant, registry);
 
-    let resolved_version = gdvm
-        .installer()
-        .auto_install_version(&version, variant, registry, include_pre)
-        .await?;
+        let skip_gdvmrc = matches.get_flag("no-legacy");
 
-    let resolved_variant = Variant::from_option(variant);
-    let display = version::display_version(&resolved_version, &resolved_variant, registry);
+        gdvm.defaults()
+            .pin_version(&resolved_version, &resolved_variant, registry, skip_gdvmrc)
+            .map_err(|_| terr!("error-pin-version-not-found", version = display.clone()))?;
 
-    let skip_gdvmrc = matches.get_flag("no-legacy");
+        println_i18n!("pinned-success", version = &display);
 
-    gdvm.defaults()
-        .pin_version(&resolved_version, &resolved_variant, registry, skip_gdvmrc)
-        .map_err(|_| terr!("error-pin-version-not-found", version = display.clone()))?;
+        // An existing lock would no longer agree with the new pin.
+        let has_lock = std::env::current_dir()?
+            .join(gdvm::gdvm_lock::LOCK_FILE)
+            .is_file();
+        if !update_lock && !has_lock {
+            return Ok(());
+        }
+    } else if let Some(pinned) = gdvm.defaults().get_pinned_version() {
+        ensure_registry_trusted(gdvm, pinned.registry.as_deref(), matches.get_flag("yes")).await?;
+    }
 
-    println_i18n!("pinned-success", version = &display);
+    let (path, locked) = gdvm.defaults().update_lock(include_pre).await?;
+    println_i18n!(
+        "lock-updated",
+        version =
+            version::display_version(&locked.version, &locked.variant, locked.registry.as_deref()),
+        path = path.display().to_string()
+    );
     Ok(())
 }
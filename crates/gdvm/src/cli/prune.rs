@@ -25,13 +25,15 @@ use super::format::{OutputFormat, byte_display_args, print_json};
 
 /// Handle the 'prune' subcommand
 pub(crate) fn sub_prune(gdvm: &Gdvm, matches: &ArgMatches) -> Result<()> {
+    let config = config::ConfigFile::load()?.into_config();
+
     let opts = PruneOptions {
         all: matches.get_flag("all"),
         force: matches.get_flag("force"),
         dry_run: matches.get_flag("dry-run"),
+        cache_max_bytes: config.cache.max_size.map(|size| size.bytes()),
     };
 
-    let config = config::ConfigFile::load()?.into_config();
     let max_age_secs = config.prune.max_age_days().saturating_mul(24 * 60 * 60);
 
     let report = gdvm.pruner().prune(max_age_secs, opts)?;
@@ -0,0 +1,99 @@
+// SPDX-FileCopyrightText: Copyright (C) 2024 Adaline Simonian
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// This file is part of gdvm.
+//
+// gdvm is free software: you can redistribute it and/or modify it under the
+// terms of the GNU General Public License as published by the Free Software
+// Foundation, either version 3 of the License, or (at your option) any later
+// version.
+//
+// gdvm is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR
+// A PARTICULAR PURPOSE. See the GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with
+// this program. If not, see <https://www.gnu.org/licenses/>.
+
+use anyhow::Result;
+use clap::ArgMatches;
+use gdvm::app::Gdvm;
+use gdvm::date_utils::format_unix_timestamp_local;
+use gdvm::{println_i18n, t};
+
+use super::format::{OutputFormat, print_json};
+
+/// A running Godot process, as printed by `gdvm ps --format json`.
+#[derive(serde::Serialize)]
+struct ProcessEntry {
+    pid: u32,
+    version: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    project: Option<String>,
+    /// Unix timestamp when the process was started.
+    started: u64,
+}
+
+/// Handle the 'ps' subcommand
+pub(crate) fn sub_ps(gdvm: &Gdvm, matches: &ArgMatches) -> Result<()> {
+    let entries: Vec<ProcessEntry> = gdvm
+        .launcher()
+        .running()?
+        .into_iter()
+        .map(|(pid, record)| ProcessEntry {
+            pid,
+            version: gdvm.library().install_label(&record.install_key),
+            project: record.project.map(|dir| dir.display().to_string()),
+            started: record.started,
+        })
+        .collect();
+
+    if OutputFormat::is_json(matches) {
+        return print_json(&entries);
+    }
+
+    if entries.is_empty() {
+        println_i18n!("ps-none");
+        return Ok(());
+    }
+
+    for entry in &entries {
+        let started = format_unix_timestamp_local(entry.started);
+        match &entry.project {
+            Some(project) => println_i18n!(
+                "ps-process-project",
+                pid = entry.pid,
+                version = entry.version.as_str(),
+                project = project.as_str(),
+                started = started.as_str()
+            ),
+            None => println_i18n!(
+                "ps-process",
+                pid = entry.pid,
+                version = entry.version.as_str(),
+                started = started.as_str()
+            ),
+        }
+    }
+
+    Ok(())
+}
+
+/// Handle the 'stop' subcommand
+pub(crate) fn sub_stop(gdvm: &Gdvm, matches: &ArgMatches) -> Result<()> {
+    let pid = *matches
+        .get_one::<u32>("pid")
+        .expect("pid is a required argument");
+
+    let record = gdvm.launcher().stop(pid)?;
+    gdvm::ui::milestone(
+        t!("status-stopped"),
+        t!(
+            "ps-stopped-detail",
+            pid = pid,
+            version = gdvm.library().install_label(&record.install_key)
+        ),
+    );
+
+    Ok(())
+}
@@ -28,21 +28,37 @@ pub(crate) async fn sub_remove(gdvm: &Gdvm, matches: &ArgMatches) -> Result<()>
     let variant = request.variant();
     let registry = request.registry();
     let requested_version = request.installed_filter()?;
+    let (library, installer) = if matches.get_flag("system") {
+        (gdvm.system_library()?, gdvm.system_installer()?)
+    } else {
+        (gdvm.library(), gdvm.installer())
+    };
 
-    let installed = gdvm
-        .library()
+    let installed = library
         .resolve_installed_one(requested_version, variant, registry)
         .await?;
 
     let display = installed.display();
 
+    let editor_data = library.self_contained_data(
+        &installed.version,
+        &installed.variant,
+        installed.registry.as_deref(),
+    )?;
+
     gdvm::ui::milestone(t!("status-removing"), &display);
-    gdvm.library().remove(
+    installer.remove(
         &installed.version,
         &installed.variant,
         installed.registry.as_deref(),
     )?;
     gdvm::ui::milestone(t!("status-removed"), &display);
+    if let Some(path) = editor_data {
+        gdvm::ui::note(t!(
+            "remove-self-contained",
+            path = path.display().to_string()
+        ));
+    }
 
     Ok(())
 }
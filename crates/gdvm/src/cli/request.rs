@@ -18,10 +18,13 @@
 use anyhow::Result;
 use clap::ArgMatches;
 use gdvm::app::Gdvm;
+use gdvm::run_version_resolver::{
+    ResolutionTrace, RunResolutionRequest, RunResolutionResult, RunVersionResolver,
+};
 use gdvm::version::{VersionQuery, VersionSpec, VersionTarget};
 use gdvm::{t, terr};
 
-use super::{ensure_registry_trusted, keyword_to_version_filter, refresh_cache_if_requested};
+use super::{ensure_registry_trusted, refresh_cache_if_requested};
 
 /// Version request from the CLI.
 pub(crate) struct VersionRequest {
@@ -46,10 +49,7 @@ impl VersionRequest {
             spec.as_ref().map(|s| &s.target),
             Some(VersionTarget::Keyword(_))
         );
-        let filter = spec.map(|s| match s.target {
-            VersionTarget::Keyword(kw) => keyword_to_version_filter(&kw),
-            VersionTarget::Pattern(gv) => gv,
-        });
+        let filter = spec.map(|s| s.target.to_query());
 
         Ok(Self {
             variant,
@@ -108,22 +108,37 @@ impl VersionRequest {
         include_pre: bool,
         install_if_missing: bool,
         force_on_mismatch: bool,
-    ) -> Result<gdvm::run_version_resolver::RunResolutionResult> {
-        use gdvm::run_version_resolver::{RunResolutionRequest, RunVersionResolver};
+    ) -> Result<RunResolutionResult> {
+        RunVersionResolver::new(gdvm)
+            .resolve(self.run_request(include_pre, install_if_missing, force_on_mismatch))
+            .await
+    }
 
+    /// Trace every source run resolution checks for this request, without
+    /// resolving it.
+    pub(crate) async fn explain(&self, gdvm: &Gdvm) -> Result<ResolutionTrace> {
         RunVersionResolver::new(gdvm)
-            .resolve(RunResolutionRequest {
-                explicit: self.filter_owned(),
-                variant: self.variant_owned(),
-                registry: self.registry_owned(),
-                include_pre,
-                possible_paths: &[],
-                force_on_mismatch,
-                install_if_missing,
-            })
+            .explain(&self.run_request(false, false, false))
             .await
     }
 
+    fn run_request(
+        &self,
+        include_pre: bool,
+        install_if_missing: bool,
+        force_on_mismatch: bool,
+    ) -> RunResolutionRequest<'static> {
+        RunResolutionRequest {
+            explicit: self.filter_owned(),
+            variant: self.variant_owned(),
+            registry: self.registry_owned(),
+            include_pre,
+            possible_paths: &[],
+            force_on_mismatch,
+            install_if_missing,
+        }
+    }
+
     /// Perform common preparation steps. Handles `--refresh` and `--yes` flags.
     /// Commands without these don't need to run this.
     pub(crate) async fn prepare(&self, gdvm: &Gdvm, matches: &ArgMatches) -> Result<()> {
@@ -15,14 +15,24 @@
 // You should have received a copy of the GNU General Public License along with
 // this program. If not, see <https://www.gnu.org/licenses/>.
 
+use std::io::{self, IsTerminal, Write};
+use std::path::PathBuf;
+
 use anyhow::Result;
 use clap::ArgMatches;
-use gdvm::app::Gdvm;
-use gdvm::run_version_resolver::{RunResolutionRequest, RunVersionResolver};
-use gdvm::version::{VersionSpec, VersionTarget};
+use clap::parser::ValueSource;
+use gdvm::app::{Gdvm, LaunchOptions, SettingsCarry, find_godot_executable};
+use gdvm::config::{CarrySettings, GodotLaunchMode};
+use gdvm::dotenv::EnvProfile;
+use gdvm::resolution_cache::{CachedResolution, ResolutionCache};
+use gdvm::run_version_resolver::{
+    RunResolutionRequest, RunResolutionResult, RunSelection, RunSource, RunVersionResolver,
+};
+use gdvm::t;
+use gdvm::version::{Variant, VersionQuery, VersionSpec};
 
 use super::link::collect_possible_paths;
-use super::{VersionRequest, ensure_registry_trusted, keyword_to_version_filter};
+use super::{VersionRequest, ensure_registry_trusted};
 
 /// Handle the 'run' subcommand
 pub(crate) async fn sub_run(gdvm: &Gdvm, matches: &ArgMatches) -> Result<i32> {
@@ -33,7 +43,9 @@ pub(crate) async fn sub_run(gdvm: &Gdvm, matches: &ArgMatches) -> Result<i32> {
     };
 
     let version_input = matches.get_one::<String>("version");
-    let console = matches.get_flag("console");
+    // Only a console mode given on the command line overrides the profile's.
+    let console = (matches.value_source("console") == Some(ValueSource::CommandLine))
+        .then(|| matches.get_flag("console"));
     let force_on_mismatch = matches.get_flag("force");
     let include_pre = matches.get_flag("include-pre");
 
@@ -46,6 +58,8 @@ pub(crate) async fn sub_run(gdvm: &Gdvm, matches: &ArgMatches) -> Result<i32> {
         version_input,
         variant,
         console,
+        profile: matches.get_one::<String>("profile").map(String::as_str),
+        env_profile: matches.get_one::<String>("env-profile").map(String::as_str),
         raw_args: &raw_args,
         force_on_mismatch,
         include_pre,
@@ -59,7 +73,13 @@ pub(crate) struct RunConfig<'a> {
     pub(crate) gdvm: &'a Gdvm,
     pub(crate) version_input: Option<&'a String>,
     pub(crate) variant: Option<String>,
-    pub(crate) console: bool,
+    /// Whether to attach the console. Without it, the launch profile's mode
+    /// is used, then the `godot.launch-mode` setting.
+    pub(crate) console: Option<bool>,
+    /// The `gdvm.toml` launch profile to layer over `[run]`.
+    pub(crate) profile: Option<&'a str>,
+    /// The `.env.<profile>` file to layer over `.env`.
+    pub(crate) env_profile: Option<&'a str>,
     pub(crate) raw_args: &'a Vec<String>,
     pub(crate) force_on_mismatch: bool,
     pub(crate) include_pre: bool,
@@ -68,33 +88,286 @@ pub(crate) struct RunConfig<'a> {
 
 /// Run the Godot executable
 pub(crate) async fn sub_run_inner(config: RunConfig<'_>) -> Result<i32> {
-    let RunConfig {
+    let options = launch_options(&config)?;
+    let resolved = {
+        let possible_paths = collect_possible_paths(&options.args);
+        resolve_run_version(&config, &possible_paths).await?
+    };
+    launch(&config, options, resolved)
+}
+
+/// Run Godot for one of the `godot` shims. While the resolution of an earlier
+/// run is still valid, Godot is launched with a light instance of gdvm.
+/// Otherwise the instance is finished into a full one that resolves the
+/// version, installing it or asking questions as needed, and the resolution is
+/// cached for next time.
+pub(crate) async fn sub_run_shim(
+    version_input: Option<&String>,
+    console: Option<bool>,
+    raw_args: &Vec<String>,
+) -> Result<i32> {
+    let Some(light) = Gdvm::for_shim()? else {
+        let gdvm = Gdvm::new().await?;
+        return sub_run_inner(shim_config(&gdvm, version_input, console, raw_args)).await;
+    };
+
+    let config = shim_config(&light, version_input, console, raw_args);
+    let options = launch_options(&config)?;
+    let possible_paths = collect_possible_paths(&options.args);
+    let cwd = std::env::current_dir()?;
+    let key = serde_json::json!([
+        cwd,
+        version_input,
+        possible_paths,
+        gdvm::gdvm_lock::locked_from_env()
+    ])
+    .to_string();
+
+    let cache = light.resolution_cache();
+    if let Some(resolved) = cached_resolution(&light, &cache, &key) {
+        return launch(&config, options, resolved);
+    }
+
+    let start_dirs: Vec<PathBuf> = possible_paths
+        .iter()
+        .map(|path| cwd.join(path))
+        .chain([cwd.clone()])
+        .collect();
+    let stamps = light.defaults().resolution_stamps(&start_dirs)?;
+
+    let gdvm = light.finish()?;
+    let config = shim_config(&gdvm, version_input, console, raw_args);
+    let (resolved, selection) = resolve_run_selection(&config, &possible_paths).await?;
+    // Pins that come with a warning to fix something keep resolving in full.
+    if let Some(selection) = selection
+        && selection.stale_lock.is_none()
+        && !matches!(
+            selection.source,
+            RunSource::Pin {
+                gdvmrc_fallback: true
+            }
+        )
+    {
+        let resolution = CachedResolution {
+            version: resolved.version.to_remote_str(),
+            variant: resolved.variant.as_str().to_string(),
+            registry: resolved.registry.clone(),
+            project_version: (selection.source == RunSource::Project)
+                .then(|| selection.version.to_display_str())
+                .flatten(),
+        };
+        // The cache only saves time, so failing to write it doesn't matter.
+        cache.insert(&key, resolution, stamps).ok();
+    }
+    launch(&config, options, resolved)
+}
+
+fn shim_config<'a>(
+    gdvm: &'a Gdvm,
+    version_input: Option<&'a String>,
+    console: Option<bool>,
+    raw_args: &'a Vec<String>,
+) -> RunConfig<'a> {
+    RunConfig {
         gdvm,
         version_input,
-        variant,
+        variant: None,
         console,
+        profile: None,
+        env_profile: None,
         raw_args,
+        force_on_mismatch: false,
+        include_pre: false,
+        assume_yes: false,
+    }
+}
+
+/// The resolution cached under `key`, as long as its install is still there.
+fn cached_resolution(
+    gdvm: &Gdvm,
+    cache: &ResolutionCache,
+    key: &str,
+) -> Option<RunResolutionResult> {
+    let cached = cache.get(key)?;
+    let resolved = RunResolutionResult {
+        version: VersionQuery::from_install_str(&cached.version).ok()?.into(),
+        variant: Variant::from_option(Some(&cached.variant)),
+        registry: cached.registry,
+    };
+    let (_, dir) = gdvm
+        .library()
+        .install_dir(
+            &resolved.version,
+            &resolved.variant,
+            resolved.registry.as_deref(),
+        )
+        .ok()?;
+    find_godot_executable(&dir, false).ok()??;
+
+    if let Some(version) = cached.project_version {
+        gdvm::ui::warn(t!("warning-using-project-version", version = version));
+    }
+    Some(resolved)
+}
+
+/// The launch settings of a run: the launch profile's, with the arguments
+/// given on the command line following its own.
+fn launch_options(config: &RunConfig<'_>) -> Result<LaunchOptions> {
+    // Try to see if a path was given in args. First, by checking if the --path flag was given
+    // and then by checking if the first argument is a path. Prefer the --path flag if both are
+    // given. The launch profile and `.env` both come from this project.
+    let project = config
+        .gdvm
+        .defaults()
+        .project_file(collect_possible_paths(config.raw_args).first())
+        .and_then(|file| file.parent()?.canonicalize().ok());
+    let launch = config
+        .gdvm
+        .defaults()
+        .launch_profile(project.as_deref(), config.profile)?;
+    let console = config
+        .console
+        .or(launch.launch_mode.map(GodotLaunchMode::attaches_console))
+        .unwrap_or_else(|| config.gdvm.config().godot.launch_mode().attaches_console());
+    let mut args = launch.args;
+    args.extend(config.raw_args.iter().cloned());
+
+    let dotenv = config
+        .gdvm
+        .defaults()
+        .dotenv_vars(project.as_deref(), env_profile(config).as_ref())?;
+
+    Ok(LaunchOptions {
+        console,
+        args,
+        env: launch.env,
+        dotenv,
+        working_dir: launch.working_dir,
+        project,
+        self_contained: launch.self_contained.unwrap_or(false),
+    })
+}
+
+/// The `.env` profile given on the command line, or else through the
+/// environment.
+pub(crate) fn env_profile(config: &RunConfig<'_>) -> Option<EnvProfile> {
+    config
+        .env_profile
+        .map(|name| EnvProfile::Given(name.to_string()))
+        .or_else(gdvm::dotenv::profile_from_env)
+}
+
+/// Launch the resolved version, offering to carry editor settings forward
+/// first.
+fn launch(
+    config: &RunConfig<'_>,
+    options: LaunchOptions,
+    resolved: RunResolutionResult,
+) -> Result<i32> {
+    let display = resolved.display();
+
+    let launcher = config.gdvm.launcher();
+    if let Some(carry) = launcher.settings_to_carry(
+        &resolved.version,
+        &resolved.variant,
+        resolved.registry.as_deref(),
+        &options,
+    )? {
+        offer_settings_carry(config, &carry, &display);
+    }
+
+    gdvm::ui::milestone(gdvm::t!("status-running"), &display);
+
+    launcher.run(
+        &resolved.version,
+        &resolved.variant,
+        resolved.registry.as_deref(),
+        &options,
+    )
+}
+
+/// Carry editor settings forward to the version about to run, asking first
+/// unless `godot.carry-settings` says otherwise. Failing to carry them doesn't
+/// stop Godot from running.
+fn offer_settings_carry(config: &RunConfig<'_>, carry: &SettingsCarry, display: &str) {
+    let carry_it = match config.gdvm.config().godot.carry_settings() {
+        CarrySettings::Always => true,
+        CarrySettings::Never => false,
+        CarrySettings::Ask if config.assume_yes => true,
+        CarrySettings::Ask if !io::stdin().is_terminal() => {
+            gdvm::ui::tip(t!("carry-settings-tip", from = carry.from.as_str()));
+            false
+        }
+        CarrySettings::Ask => {
+            eprint!(
+                "{} ",
+                t!(
+                    "carry-settings-prompt",
+                    from = carry.from.as_str(),
+                    version = display
+                )
+            );
+            io::stderr().flush().ok();
+            let mut input = String::new();
+            io::stdin().read_line(&mut input).is_ok()
+                && input.trim().to_lowercase() == t!("confirm-yes")
+        }
+    };
+    if !carry_it {
+        return;
+    }
+
+    match config.gdvm.launcher().carry_settings(carry) {
+        Ok(backup) => {
+            gdvm::ui::milestone(
+                t!("status-carried-settings"),
+                t!("carry-settings-detail", from = carry.from.as_str()),
+            );
+            if let Some(backup) = backup {
+                gdvm::ui::note(t!(
+                    "carry-settings-backup",
+                    path = backup.display().to_string()
+                ));
+            }
+        }
+        Err(error) => gdvm::ui::warn(t!(
+            "warning-carry-settings-failed",
+            error = error.to_string()
+        )),
+    }
+}
+
+/// Resolve the version to run, installing it if needed. `possible_paths` are
+/// checked for a project before the current directory.
+pub(crate) async fn resolve_run_version(
+    config: &RunConfig<'_>,
+    possible_paths: &[&str],
+) -> Result<RunResolutionResult> {
+    Ok(resolve_run_selection(config, possible_paths).await?.0)
+}
+
+/// Resolve the version to run like `resolve_run_version`, along with the
+/// source selected for it.
+async fn resolve_run_selection(
+    config: &RunConfig<'_>,
+    possible_paths: &[&str],
+) -> Result<(RunResolutionResult, Option<RunSelection>)> {
+    let RunConfig {
+        gdvm,
+        version_input,
         force_on_mismatch,
         include_pre,
         assume_yes,
-    } = config;
-
-    // Try to see if a path was given in raw_args. First, by checking if the --path flag was given
-    // and then by checking if the first argument is a path. Prefer the --path flag if both are
-    // given.
-    let possible_paths = collect_possible_paths(raw_args);
+        ..
+    } = *config;
 
     let (explicit_version, resolved_variant, resolved_registry) = if let Some(v) = version_input {
         let spec = VersionSpec::parse(v)?;
-        let var = spec.variant.or(variant);
+        let var = spec.variant.or(config.variant.clone());
         let reg = spec.registry;
-        let ver = match spec.target {
-            VersionTarget::Pattern(gv) => Some(gv),
-            VersionTarget::Keyword(ref kw) => Some(keyword_to_version_filter(kw)),
-        };
-        (ver, var, reg)
+        (Some(spec.target.to_query()), var, reg)
     } else {
-        (None, variant, None)
+        (None, config.variant.clone(), None)
     };
 
     let resolver = RunVersionResolver::new(gdvm);
@@ -103,25 +376,14 @@ pub(crate) async fn sub_run_inner(config: RunConfig<'_>) -> Result<i32> {
         variant: resolved_variant,
         registry: resolved_registry,
         include_pre,
-        possible_paths: &possible_paths,
+        possible_paths,
         force_on_mismatch,
         install_if_missing: true,
     };
 
-    let trust_registry = resolver.select(&request).await?.and_then(|s| s.registry);
-    ensure_registry_trusted(gdvm, trust_registry.as_deref(), assume_yes).await?;
-
-    let resolved = resolver.resolve(request).await?;
-
-    let display = resolved.display();
-
-    gdvm::ui::milestone(gdvm::t!("status-running"), &display);
+    let selection = resolver.select(&request).await?;
+    let trust_registry = selection.as_ref().and_then(|s| s.registry.as_deref());
+    ensure_registry_trusted(gdvm, trust_registry, assume_yes).await?;
 
-    gdvm.launcher().run(
-        &resolved.version,
-        &resolved.variant,
-        resolved.registry.as_deref(),
-        console,
-        raw_args,
-    )
+    Ok((resolver.resolve(request).await?, selection))
 }
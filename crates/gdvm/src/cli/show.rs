@@ -15,9 +15,14 @@
 // You should have received a copy of the GNU General Public License along with
 // this program. If not, see <https://www.gnu.org/licenses/>.
 
+use std::path::Path;
+
 use anyhow::Result;
 use clap::ArgMatches;
 use gdvm::app::Gdvm;
+use gdvm::run_version_resolver::{ResolutionTrace, RunSource, SourceOffer};
+use gdvm::version::{Variant, VersionQuery};
+use gdvm::{t, ui};
 
 use super::VersionRequest;
 use super::format::{OutputFormat, print_json};
@@ -27,13 +32,45 @@ pub(crate) async fn sub_show(gdvm: &Gdvm, matches: &ArgMatches) -> Result<()> {
     let console = matches.get_flag("console");
     let force_on_mismatch = matches.get_flag("force");
     let include_pre = matches.get_flag("include-pre");
+    let explain = matches.get_flag("explain");
 
     let request = VersionRequest::from_matches(matches)?;
     request.prepare(gdvm, matches).await?;
 
-    let resolved = request
+    // Traced before resolving, so the trace is complete even when resolution
+    // stops at a mismatch.
+    let trace = if explain {
+        Some(request.explain(gdvm).await?)
+    } else {
+        None
+    };
+    if let Some(trace) = &trace
+        && !OutputFormat::is_json(matches)
+    {
+        print_trace(trace);
+    }
+
+    let resolved = match request
         .resolve_selection(gdvm, include_pre, false, force_on_mismatch)
-        .await?;
+        .await
+    {
+        Ok(resolved) => resolved,
+        Err(err) => {
+            // Still emit the trace, since it shows why resolution failed.
+            if let Some(trace) = &trace
+                && OutputFormat::is_json(matches)
+            {
+                #[derive(serde::Serialize)]
+                struct Explained {
+                    explain: TraceJson,
+                }
+                print_json(&Explained {
+                    explain: TraceJson::from(trace),
+                })?;
+            }
+            return Err(err);
+        }
+    };
 
     let exe_path = gdvm.library().get_executable_path(
         &resolved.version,
@@ -50,16 +87,176 @@ pub(crate) async fn sub_show(gdvm: &Gdvm, matches: &ArgMatches) -> Result<()> {
             #[serde(skip_serializing_if = "Option::is_none")]
             registry: Option<String>,
             path: String,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            explain: Option<TraceJson>,
         }
         return print_json(&Shown {
             version: resolved.version.to_display_str(),
             variant: resolved.variant.as_str().to_string(),
             registry: resolved.registry.clone(),
             path: exe_path.display().to_string(),
+            explain: trace.as_ref().map(TraceJson::from),
         });
     }
 
+    if explain {
+        ui::note(t!(
+            "explain-executable",
+            path = exe_path.display().to_string()
+        ));
+    }
     println!("{}", exe_path.display());
 
     Ok(())
 }
+
+/// The localized name of a version source.
+fn source_label(source: RunSource) -> String {
+    match source {
+        RunSource::Explicit => t!("explain-source-explicit"),
+        RunSource::Pin { .. } => t!("explain-source-pin"),
+        RunSource::Project => t!("explain-source-project"),
+        RunSource::Default => t!("explain-source-default"),
+    }
+}
+
+/// A version query for display. Keywords like `latest` match any version.
+fn query_display(version: &VersionQuery) -> String {
+    version.to_display_str().unwrap_or_else(|| "*".to_string())
+}
+
+/// A source's offer, written as a version spec.
+fn offer_spec(offer: &SourceOffer) -> String {
+    let mut spec = query_display(&offer.version);
+    let variant = Variant::from_option(offer.variant.as_deref());
+    if !variant.is_default() {
+        spec = format!("{}:{spec}", variant.as_str());
+    }
+    if let Some(registry) = &offer.registry {
+        spec = format!("{registry}/{spec}");
+    }
+    spec
+}
+
+/// Print every source checked, then how the selected one was completed.
+fn print_trace(trace: &ResolutionTrace) {
+    let selected = trace.selection.as_ref().map(|s| s.source.name());
+
+    ui::milestone(t!("explain-header"), "");
+    for check in &trace.checks {
+        let found = match &check.offer {
+            None => t!("explain-not-set"),
+            Some(offer) => match &offer.file {
+                Some(file) => t!(
+                    "explain-offer-from-file",
+                    spec = offer_spec(offer),
+                    path = file.display().to_string()
+                ),
+                None => offer_spec(offer),
+            },
+        };
+        if selected == Some(check.source.name()) {
+            ui::step(
+                source_label(check.source),
+                format!("{found}  {}", t!("explain-selected")),
+            );
+        } else {
+            ui::step(source_label(check.source), found);
+        }
+    }
+
+    if let Some(path) = &trace.lock_file {
+        ui::note(t!("explain-lock", path = path.display().to_string()));
+    }
+    if let Some(path) = &trace.stale_lock {
+        ui::note(t!("explain-stale-lock", path = path.display().to_string()));
+    }
+
+    let Some(selection) = &trace.selection else {
+        return;
+    };
+    if let (Some(source), Some(variant)) = (trace.variant_inherited_from, &selection.variant) {
+        ui::note(t!(
+            "explain-variant-inherited",
+            variant = variant.as_str(),
+            source = source_label(source)
+        ));
+    }
+    if let (Some(source), Some(registry)) = (trace.registry_inherited_from, &selection.registry) {
+        ui::note(t!(
+            "explain-registry-inherited",
+            registry = registry.as_str(),
+            source = source_label(source)
+        ));
+    }
+    if let Some(project_version) = &trace.project_mismatch {
+        ui::note(t!(
+            "explain-project-mismatch",
+            project_version = query_display(project_version),
+            version = query_display(&selection.version)
+        ));
+    }
+}
+
+/// A resolution trace in JSON output.
+#[derive(serde::Serialize)]
+struct TraceJson {
+    sources: Vec<SourceCheckJson>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    selected: Option<&'static str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    variant_inherited_from: Option<&'static str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    registry_inherited_from: Option<&'static str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    project_mismatch: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    lock_file: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    stale_lock: Option<String>,
+}
+
+/// One checked source in JSON output.
+#[derive(serde::Serialize)]
+struct SourceCheckJson {
+    source: &'static str,
+    found: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    version: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    variant: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    registry: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    file: Option<String>,
+}
+
+impl From<&ResolutionTrace> for TraceJson {
+    fn from(trace: &ResolutionTrace) -> Self {
+        let path_string = |path: &Path| path.display().to_string();
+        Self {
+            sources: trace
+                .checks
+                .iter()
+                .map(|check| SourceCheckJson {
+                    source: check.source.name(),
+                    found: check.offer.is_some(),
+                    version: check.offer.as_ref().map(|o| query_display(&o.version)),
+                    variant: check.offer.as_ref().and_then(|o| o.variant.clone()),
+                    registry: check.offer.as_ref().and_then(|o| o.registry.clone()),
+                    file: check
+                        .offer
+                        .as_ref()
+                        .and_then(|o| o.file.as_deref())
+                        .map(path_string),
+                })
+                .collect(),
+            selected: trace.selection.as_ref().map(|s| s.source.name()),
+            variant_inherited_from: trace.variant_inherited_from.map(RunSource::name),
+            registry_inherited_from: trace.registry_inherited_from.map(RunSource::name),
+            project_mismatch: trace.project_mismatch.as_ref().map(query_display),
+            lock_file: trace.lock_file.as_deref().map(path_string),
+            stale_lock: trace.stale_lock.as_deref().map(path_string),
+        }
+    }
+}
@@ -0,0 +1,113 @@
+// SPDX-FileCopyrightText: Copyright (C) 2024 Adaline Simonian
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// This file is part of gdvm.
+//
+// gdvm is free software: you can redistribute it and/or modify it under the
+// terms of the GNU General Public License as published by the Free Software
+// Foundation, either version 3 of the License, or (at your option) any later
+// version.
+//
+// gdvm is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR
+// A PARTICULAR PURPOSE. See the GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with
+// this program. If not, see <https://www.gnu.org/licenses/>.
+
+use anyhow::Result;
+use clap::ArgMatches;
+use gdvm::app::Gdvm;
+use gdvm::registry::normalize_registry;
+use gdvm::run_version_resolver::RunResolutionResult;
+use gdvm::version;
+use gdvm::{println_i18n, t};
+
+use super::VersionRequest;
+use super::link::link_install;
+
+/// Handle the 'update' subcommand
+pub(crate) async fn sub_update(gdvm: &Gdvm, matches: &ArgMatches) -> Result<()> {
+    let move_default = matches.get_flag("default");
+    let remove_replaced = matches.get_flag("remove");
+
+    let request = VersionRequest::from_matches(matches)?;
+    request.prepare(gdvm, matches).await?;
+
+    let filter = request.filter_owned();
+    let updates = gdvm
+        .library()
+        .plan_updates(filter.as_ref(), request.variant(), request.registry())
+        .await?;
+
+    if updates.is_empty() {
+        println_i18n!("update-up-to-date");
+        return Ok(());
+    }
+
+    let default = gdvm.defaults().get_default()?;
+
+    for update in updates {
+        let registry = update.registry.as_deref();
+        gdvm.installer()
+            .install(&update.latest, &update.variant, registry, false, false)
+            .await?;
+
+        let latest_display = version::display_version(&update.latest, &update.variant, registry);
+
+        // The default points at one of the installs this update replaces.
+        let replaced_default = default.as_ref().and_then(|default| {
+            (default.variant == update.variant
+                && normalize_registry(default.registry.as_deref()) == registry
+                && update.replaced.contains(&default.version))
+            .then_some(&default.version)
+        });
+
+        if move_default && replaced_default.is_some() {
+            gdvm.defaults()
+                .set_default(&update.latest, &update.variant, registry)?;
+            println_i18n!("default-set-success", version = &latest_display);
+        }
+
+        let resolved = RunResolutionResult {
+            version: update.latest.clone(),
+            variant: update.variant.clone(),
+            registry: update.registry.clone(),
+        };
+        for old in &update.replaced {
+            for link in gdvm
+                .library()
+                .recorded_links(old, &update.variant, registry)?
+            {
+                // Links the user has since deleted are left alone.
+                if link.symlink_metadata().is_ok() {
+                    link_install(gdvm, &resolved, &link, true, false)?;
+                }
+            }
+        }
+
+        if !remove_replaced {
+            continue;
+        }
+
+        for old in &update.replaced {
+            let display = version::display_version(old, &update.variant, registry);
+            if !move_default && replaced_default == Some(old) {
+                println_i18n!("update-kept-default", version = &display);
+                continue;
+            }
+
+            gdvm::ui::milestone(t!("status-removing"), &display);
+            match gdvm.installer().remove(old, &update.variant, registry) {
+                Ok(()) => gdvm::ui::milestone(t!("status-removed"), &display),
+                Err(err) => gdvm::ui::warn(t!(
+                    "warning-update-remove-failed",
+                    version = &display,
+                    error = err.to_string()
+                )),
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -19,7 +19,7 @@ use anyhow::Result;
 use clap::ArgMatches;
 use gdvm::app::Gdvm;
 use gdvm::println_i18n;
-use gdvm::version::{self, Variant};
+use gdvm::version::{self, Variant, VersionSpec};
 
 use super::VersionRequest;
 
@@ -55,6 +55,20 @@ pub(crate) async fn sub_use(gdvm: &Gdvm, matches: &ArgMatches) -> Result<()> {
         .await?;
 
     let resolved_variant = Variant::from_option(variant);
+    if matches.get_flag("float") {
+        let target = VersionSpec::parse(version_input)?.target;
+        let current = gdvm
+            .defaults()
+            .set_floating_default(&target, &resolved_variant, registry)?;
+        let display = version::display_version(&current.version, &resolved_variant, registry);
+        println_i18n!(
+            "default-float-success",
+            spec = version_input.as_str(),
+            version = &display
+        );
+        return Ok(());
+    }
+
     gdvm.defaults()
         .set_default(&resolved_version, &resolved_variant, registry)?;
     let display = version::display_version(&resolved_version, &resolved_variant, registry);
@@ -0,0 +1,190 @@
+// SPDX-FileCopyrightText: Copyright (C) 2024 Adaline Simonian
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// This file is part of gdvm.
+//
+// gdvm is free software: you can redistribute it and/or modify it under the
+// terms of the GNU General Public License as published by the Free Software
+// Foundation, either version 3 of the License, or (at your option) any later
+// version.
+//
+// gdvm is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR
+// A PARTICULAR PURPOSE. See the GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with
+// this program. If not, see <https://www.gnu.org/licenses/>.
+
+use std::io::{self, IsTerminal, Write};
+
+use anyhow::Result;
+use clap::ArgMatches;
+use gdvm::app::{Gdvm, InstalledVersion};
+use gdvm::install_receipt::ReceiptDiff;
+use gdvm::{t, ui};
+
+use super::VersionRequest;
+use super::format::{OutputFormat, print_json};
+
+/// The result of verifying one install.
+#[derive(serde::Serialize)]
+struct VerifyEntry {
+    version: String,
+    variant: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    registry: Option<String>,
+    has_receipt: bool,
+    #[serde(flatten)]
+    diff: ReceiptDiff,
+    reinstalled: bool,
+}
+
+/// Handle the 'verify' subcommand. Returns a non-zero exit code when an
+/// install differs from its receipt and was not reinstalled.
+pub(crate) async fn sub_verify(gdvm: &Gdvm, matches: &ArgMatches) -> Result<i32> {
+    let json = OutputFormat::is_json(matches);
+    let assume_yes = matches.get_flag("yes");
+
+    let installs = if matches.get_one::<String>("version").is_some() {
+        let request = VersionRequest::from_matches(matches)?;
+        vec![
+            gdvm.library()
+                .resolve_installed_one(
+                    request.installed_filter()?,
+                    request.variant(),
+                    request.registry(),
+                )
+                .await?,
+        ]
+    } else {
+        gdvm.library().list_installed()?
+    };
+
+    let mut entries = Vec::new();
+    let mut exit_code = 0;
+
+    for installed in installs {
+        let display = installed.display();
+        let registry = installed.registry.as_deref();
+        let mut entry = VerifyEntry {
+            version: installed.version.to_display_str(),
+            variant: installed.variant.as_str().to_string(),
+            registry: installed.registry.clone(),
+            has_receipt: false,
+            diff: ReceiptDiff::default(),
+            reinstalled: false,
+        };
+
+        if !json {
+            ui::milestone(t!("status-verifying"), &display);
+        }
+
+        match gdvm
+            .library()
+            .verify(&installed.version, &installed.variant, registry)?
+        {
+            None => {
+                if !json {
+                    ui::note(t!("verify-no-receipt", version = &display));
+                }
+            }
+            Some((_, diff)) if diff.is_clean() => {
+                entry.has_receipt = true;
+                if !json {
+                    ui::step(t!("status-ok"), &display);
+                }
+            }
+            Some((receipt, diff)) => {
+                entry.has_receipt = true;
+                if !json {
+                    print_diff(&display, &diff);
+                }
+                entry.diff = diff;
+
+                if !gdvm.installer().has_cached_archive(&receipt.archive_sha512) {
+                    if !json {
+                        ui::note(t!("verify-archive-not-cached", version = &display));
+                    }
+                    exit_code = 1;
+                } else if confirm_reinstall(&display, assume_yes, json)? {
+                    reinstall(gdvm, &installed).await?;
+                    entry.reinstalled = true;
+                } else {
+                    exit_code = 1;
+                }
+            }
+        }
+
+        entries.push(entry);
+    }
+
+    if json {
+        print_json(&entries)?;
+    }
+
+    Ok(exit_code)
+}
+
+/// Print the files that differ from the receipt.
+fn print_diff(display: &str, diff: &ReceiptDiff) {
+    ui::warn(t!(
+        "verify-install-changed",
+        version = display,
+        modified = diff.modified.len(),
+        missing = diff.missing.len(),
+        added = diff.added.len()
+    ));
+    for path in &diff.modified {
+        eprintln!("  {}", t!("verify-file-modified", path = path.as_str()));
+    }
+    for path in &diff.missing {
+        eprintln!("  {}", t!("verify-file-missing", path = path.as_str()));
+    }
+    for path in &diff.added {
+        eprintln!("  {}", t!("verify-file-added", path = path.as_str()));
+    }
+}
+
+/// Ask whether to reinstall a changed install from the cached archive.
+fn confirm_reinstall(display: &str, assume_yes: bool, json: bool) -> Result<bool> {
+    if assume_yes {
+        return Ok(true);
+    }
+
+    if json || !io::stdin().is_terminal() {
+        ui::tip(t!("verify-reinstall-tip"));
+        return Ok(false);
+    }
+
+    eprint!("{} ", t!("verify-reinstall-prompt", version = display));
+    io::stderr().flush().ok();
+    let mut input = String::new();
+    io::stdin().read_line(&mut input)?;
+    Ok(input.trim().to_lowercase() == t!("confirm-yes"))
+}
+
+/// Reinstall from the cached archive, keeping it as the default if it was.
+async fn reinstall(gdvm: &Gdvm, installed: &InstalledVersion) -> Result<()> {
+    let registry = installed.registry.as_deref();
+    let was_default = gdvm.defaults().get_default()?.is_some_and(|default| {
+        default.version == installed.version
+            && default.variant == installed.variant
+            && default.registry.as_deref() == registry
+    });
+
+    gdvm.installer()
+        .install(
+            &installed.version,
+            &installed.variant,
+            registry,
+            true,
+            false,
+        )
+        .await?;
+
+    if was_default {
+        gdvm.defaults()
+            .set_default(&installed.version, &installed.variant, registry)?;
+    }
+    Ok(())
+}
@@ -0,0 +1,120 @@
+// SPDX-FileCopyrightText: Copyright (C) 2024 Adaline Simonian
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// This file is part of gdvm.
+//
+// gdvm is free software: you can redistribute it and/or modify it under the
+// terms of the GNU General Public License as published by the Free Software
+// Foundation, either version 3 of the License, or (at your option) any later
+// version.
+//
+// gdvm is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR
+// A PARTICULAR PURPOSE. See the GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with
+// this program. If not, see <https://www.gnu.org/licenses/>.
+
+use anyhow::Result;
+
+use super::Config;
+use crate::terr;
+use crate::version::VersionSpec;
+
+/// Validate a version alias name. Names start with a letter and can't be
+/// mistaken for a keyword or a version.
+pub fn validate_alias_name(name: &str) -> Result<()> {
+    let starts_with_letter = name.chars().next().is_some_and(|c| c.is_ascii_alphabetic());
+    if !starts_with_letter
+        || !name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+        || matches!(name.to_lowercase().as_str(), "stable" | "latest" | "unset")
+    {
+        return Err(terr!("error-alias-invalid-name", name = name).into());
+    }
+    Ok(())
+}
+
+impl Config {
+    /// Get aliases as `(name, spec)` pairs, sorted by name.
+    pub fn alias_pairs(&self) -> Vec<(String, String)> {
+        let mut pairs: Vec<(String, String)> = self
+            .aliases
+            .iter()
+            .map(|(name, spec)| (name.clone(), spec.clone()))
+            .collect();
+        pairs.sort();
+        pairs
+    }
+
+    /// Store a version alias. The spec can't refer to another alias.
+    pub fn set_alias(&mut self, name: &str, spec: &str) -> Result<()> {
+        validate_alias_name(name)?;
+        VersionSpec::parse(spec)?;
+        self.aliases.insert(name.to_string(), spec.to_string());
+        Ok(())
+    }
+
+    /// Remove a version alias.
+    pub fn remove_alias(&mut self, name: &str) -> Result<()> {
+        if self.aliases.remove(name).is_none() {
+            return Err(terr!("error-alias-unknown", name = name).into());
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_alias_set_remove() {
+        let mut cfg = Config::default();
+
+        cfg.set_alias("work", "studio/csharp:4.3.1-rc2").unwrap();
+        assert_eq!(
+            cfg.aliases.get("work").map(String::as_str),
+            Some("studio/csharp:4.3.1-rc2")
+        );
+
+        cfg.set_alias("old", "3.6").unwrap();
+        assert_eq!(
+            cfg.alias_pairs(),
+            vec![
+                ("old".to_string(), "3.6".to_string()),
+                ("work".to_string(), "studio/csharp:4.3.1-rc2".to_string()),
+            ]
+        );
+
+        cfg.remove_alias("work").unwrap();
+        assert!(cfg.aliases.get("work").map(String::as_str).is_none());
+        assert!(cfg.remove_alias("work").is_err());
+    }
+
+    #[test]
+    fn test_alias_validation() {
+        let mut cfg = Config::default();
+        assert!(cfg.set_alias("4x", "4.3").is_err());
+        assert!(cfg.set_alias("stable", "4.3").is_err());
+        assert!(cfg.set_alias("a/b", "4.3").is_err());
+        assert!(cfg.set_alias("@work", "4.3").is_err());
+        assert!(cfg.set_alias("work", "not-a-version").is_err());
+        assert!(cfg.set_alias("work", "@other").is_err());
+        assert!(cfg.set_alias("my_work-2", "csharp:stable").is_ok());
+    }
+
+    #[test]
+    fn test_aliases_toml_roundtrip() {
+        let mut cfg = Config::default();
+        cfg.set_alias("work", "csharp:4.3").unwrap();
+
+        let parsed: Config = toml::from_str(&toml::to_string(&cfg).unwrap()).unwrap();
+
+        assert_eq!(
+            parsed.aliases.get("work").map(String::as_str),
+            Some("csharp:4.3")
+        );
+    }
+}
@@ -26,7 +26,7 @@ use toml_edit::DocumentMut;
 use super::document::{document_get, document_remove, document_set};
 use super::schema::ManagedKey;
 use super::{Config, ConfigKey};
-use crate::paths::gdvm_dir;
+use crate::paths::{config_dir, gdvm_dir};
 use crate::{t, terr};
 
 /// The table registries live in.
@@ -82,7 +82,7 @@ pub struct ConfigFile {
 }
 
 impl ConfigFile {
-    /// Load configuration from ~/.gdvm/config.toml.
+    /// Load configuration from config.toml in the config directory.
     pub fn load() -> Result<Self> {
         Self::load_from(Self::path()?)
     }
@@ -148,7 +148,7 @@ impl ConfigFile {
         Ok(file)
     }
 
-    /// Modify configuration at ~/.gdvm/config.toml.
+    /// Modify configuration in config.toml in the config directory.
     pub fn modify<T>(f: impl FnOnce(&mut ConfigFile) -> Result<T>) -> Result<T> {
         let _lock = crate::locks::Lock::acquire(
             &gdvm_dir()?.join("locks"),
@@ -162,7 +162,7 @@ impl ConfigFile {
 
     /// Get the path to the configuration file.
     pub fn path() -> Result<PathBuf> {
-        Ok(gdvm_dir()?.join("config.toml"))
+        Ok(config_dir()?.join("config.toml"))
     }
 
     /// Get the configuration object.
@@ -222,6 +222,20 @@ impl ConfigFile {
         Ok(())
     }
 
+    /// Store a version alias in config.
+    pub fn set_alias(&mut self, name: &str, spec: &str) -> Result<()> {
+        self.config.set_alias(name, spec)?;
+        self.mark(ChangedKey::Managed(ManagedKey::Aliases));
+        Ok(())
+    }
+
+    /// Remove a version alias from config.
+    pub fn remove_alias(&mut self, name: &str) -> Result<()> {
+        self.config.remove_alias(name)?;
+        self.mark(ChangedKey::Managed(ManagedKey::Aliases));
+        Ok(())
+    }
+
     /// Trust the registry at `url`.
     pub fn trust_registry(&mut self, url: &str) {
         if !self.config.is_registry_trusted(url) {
@@ -237,7 +251,7 @@ impl ConfigFile {
         }
     }
 
-    /// Save configuration to ~/.gdvm/config.toml.
+    /// Save configuration to config.toml in the config directory.
     pub fn save(&self) -> Result<()> {
         if self.state != ConfigFileState::Usable {
             return Err(terr!(
@@ -373,6 +387,11 @@ impl From<ConfigFile> for Config {
 
 fn write_managed(document: &mut DocumentMut, generated: &DocumentMut, key: ManagedKey) {
     match document_get(generated, key.as_str()) {
+        // Maps like `aliases` read better as their own table.
+        Some(item) if item.is_inline_table() => {
+            let table = item.clone().into_table().map(toml_edit::Item::Table);
+            document_set(document, key.as_str(), table.unwrap_or_else(|item| item));
+        }
         Some(item) => document_set(document, key.as_str(), item.clone()),
         None => document_remove(document, key.as_str()),
     }
@@ -15,14 +15,18 @@
 // You should have received a copy of the GNU General Public License along with
 // this program. If not, see <https://www.gnu.org/licenses/>.
 
+mod aliases;
 mod document;
 mod file;
 mod registries;
 mod schema;
 mod value;
 
+pub use aliases::validate_alias_name;
 pub use file::{ConfigFile, ConfigFileState, ConfigProblem};
 pub use registries::{RegistryConfig, validate_registry_name};
 pub use schema::{
-    Config, ConfigKey, DEFAULT_PRUNE_MAX_AGE_DAYS, GodotConfig, GodotLaunchMode, PruneConfig,
+    ByteSize, CacheConfig, CarrySettings, Config, ConfigKey, CrashesConfig,
+    DEFAULT_LOGS_MAX_AGE_DAYS, DEFAULT_PRUNE_MAX_AGE_DAYS, GodotConfig, GodotLaunchMode,
+    InstallConfig, LogsConfig, PinConfig, PruneConfig,
 };
@@ -16,6 +16,7 @@
 // this program. If not, see <https://www.gnu.org/licenses/>.
 
 use std::collections::HashMap;
+use std::path::PathBuf;
 
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
@@ -270,6 +271,10 @@ macro_rules! config_schema {
 /// for pruning, unless `prune.max-age-days` is configured.
 pub const DEFAULT_PRUNE_MAX_AGE_DAYS: u64 = 30;
 
+/// The default number of days the log of a detached launch is kept, unless
+/// `logs.max-age-days` is configured.
+pub const DEFAULT_LOGS_MAX_AGE_DAYS: u64 = 14;
+
 /// How Godot is launched by default.
 #[derive(
     Debug,
@@ -310,6 +315,137 @@ impl GodotLaunchMode {
 
 impl ConfigEnum for GodotLaunchMode {}
 
+/// Whether the editor settings of an older version are carried forward when a
+/// new major.minor version is first run.
+#[derive(
+    Debug,
+    Default,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Serialize,
+    Deserialize,
+    EnumString,
+    AsRefStr,
+    VariantNames,
+)]
+#[serde(rename_all = "kebab-case")]
+#[strum(serialize_all = "kebab-case")]
+pub enum CarrySettings {
+    /// Ask first, when there's a terminal to ask in.
+    #[default]
+    Ask,
+    /// Carry them forward without asking.
+    Always,
+    /// Never carry them forward.
+    Never,
+}
+
+impl ConfigEnum for CarrySettings {}
+
+/// A size in bytes, written as a number with an optional binary unit, e.g.
+/// `512MiB` or `10G`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "ByteSizeRepr", into = "String")]
+pub struct ByteSize(pub u64);
+
+/// How a size can be written in the config file.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum ByteSizeRepr {
+    Bytes(u64),
+    Text(String),
+}
+
+impl ByteSize {
+    const UNITS: [(&'static str, u64); 5] = [
+        ("TiB", 1 << 40),
+        ("GiB", 1 << 30),
+        ("MiB", 1 << 20),
+        ("KiB", 1 << 10),
+        ("B", 1),
+    ];
+
+    /// The size in bytes.
+    pub const fn bytes(self) -> u64 {
+        self.0
+    }
+}
+
+impl std::str::FromStr for ByteSize {
+    type Err = String;
+
+    fn from_str(value: &str) -> std::result::Result<Self, Self::Err> {
+        let value = value.trim();
+        let split = value
+            .find(|c: char| !c.is_ascii_digit() && c != '.')
+            .unwrap_or(value.len());
+        let (number, unit) = value.split_at(split);
+
+        let multiplier: u64 = match unit.trim().to_ascii_lowercase().as_str() {
+            "" | "b" => 1,
+            "k" | "kb" | "kib" => 1 << 10,
+            "m" | "mb" | "mib" => 1 << 20,
+            "g" | "gb" | "gib" => 1 << 30,
+            "t" | "tb" | "tib" => 1 << 40,
+            _ => return Err(value.to_string()),
+        };
+
+        if let Ok(whole) = number.parse::<u64>() {
+            return whole
+                .checked_mul(multiplier)
+                .map(ByteSize)
+                .ok_or_else(|| value.to_string());
+        }
+        match number.parse::<f64>() {
+            Ok(fraction) if fraction.is_finite() && fraction >= 0.0 => {
+                Ok(ByteSize((fraction * multiplier as f64) as u64))
+            }
+            _ => Err(value.to_string()),
+        }
+    }
+}
+
+impl std::fmt::Display for ByteSize {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (unit, size) = Self::UNITS
+            .iter()
+            .find(|(_, size)| self.0 != 0 && self.0.is_multiple_of(*size))
+            .unwrap_or(&("B", 1));
+        write!(f, "{}{unit}", self.0 / size)
+    }
+}
+
+impl TryFrom<ByteSizeRepr> for ByteSize {
+    type Error = String;
+
+    fn try_from(repr: ByteSizeRepr) -> std::result::Result<Self, Self::Error> {
+        match repr {
+            ByteSizeRepr::Bytes(bytes) => Ok(ByteSize(bytes)),
+            ByteSizeRepr::Text(text) => text.parse(),
+        }
+    }
+}
+
+impl From<ByteSize> for String {
+    fn from(size: ByteSize) -> Self {
+        size.to_string()
+    }
+}
+
+impl ConfigValue for ByteSize {
+    fn parse_config_value(key: &str, value: &str) -> Result<Self> {
+        value
+            .parse()
+            .map_err(|_| terr!("error-config-invalid-size", key = key, value = value).into())
+    }
+
+    fn to_config_string(&self) -> String {
+        self.to_string()
+    }
+}
+
 config_schema! {
     tables {
         /// Settings for launching Godot.
@@ -318,6 +454,53 @@ config_schema! {
             GodotLaunchMode = "launch-mode" => launch_mode: GodotLaunchMode,
                 sensitive = false,
                 default = GodotLaunchMode::PlatformDefault;
+            /// Whether to carry editor settings forward to a new version.
+            GodotCarrySettings = "carry-settings" => carry_settings: CarrySettings,
+                sensitive = false,
+                default = CarrySettings::Ask;
+        }
+
+        /// Settings for the download cache.
+        "cache" => cache: CacheConfig {
+            /// Maximum total size of cached archives. When exceeded, the least
+            /// recently used archives are removed after each install.
+            CacheMaxSize = "max-size" => max_size: ByteSize, sensitive = false;
+        }
+
+        /// Settings for crash records of attached Godot runs.
+        "crashes" => crashes: CrashesConfig {
+            /// Whether to copy Godot's output through gdvm so that its last
+            /// lines can be saved with a crash record.
+            CrashesCaptureOutput = "capture-output" => capture_output: bool,
+                sensitive = false,
+                default = false;
+        }
+
+        /// Settings for installing Godot.
+        "install" => install: InstallConfig {
+            /// Whether to share identical files between installs after each
+            /// install.
+            InstallDedupe = "dedupe" => dedupe: bool, sensitive = false,
+                default = false;
+            /// Read-only, system-wide install store consulted before the
+            /// user's own installs.
+            InstallSystemStore = "system-store" => system_store: PathBuf,
+                sensitive = false;
+        }
+
+        /// Settings for the logs of detached launches.
+        "logs" => logs: LogsConfig {
+            /// Maximum age, in days, of a launch log before it's removed.
+            LogsMaxAgeDays = "max-age-days" => max_age_days: u64, sensitive = false,
+                default = DEFAULT_LOGS_MAX_AGE_DAYS;
+        }
+
+        /// Settings for finding a project's pinned version.
+        "pin" => pin: PinConfig {
+            /// Whether versions written for other version managers in
+            /// `.godot-version`, `.tool-versions` and `mise.toml` count as pins.
+            PinToolFiles = "tool-files" => tool_files: bool, sensitive = false,
+                default = true;
         }
 
         /// Settings for `gdvm prune`.
@@ -336,6 +519,9 @@ config_schema! {
         /// Base URLs of unofficial registries the user has confirmed they
         /// trust.
         TrustedRegistries = "trusted-registries" => trusted_registries: Vec<String>;
+
+        /// Version specs keyed by alias name.
+        Aliases = "aliases" => aliases: HashMap<String, String>;
     }
 }
 
@@ -413,6 +599,42 @@ mod tests {
         assert!("console-attached".parse::<GodotLaunchMode>().is_err());
     }
 
+    #[test]
+    fn test_byte_sizes_parse_and_render() {
+        for (value, bytes) in [
+            ("1024", 1024),
+            ("512MiB", 512 << 20),
+            ("512 mb", 512 << 20),
+            ("10G", 10 << 30),
+            ("1.5GiB", 3 << 29),
+            ("2t", 2 << 40),
+        ] {
+            assert_eq!(
+                value.parse::<ByteSize>().unwrap(),
+                ByteSize(bytes),
+                "{value}"
+            );
+        }
+
+        assert_eq!(ByteSize(10 << 30).to_string(), "10GiB");
+        assert_eq!(ByteSize(1536 << 20).to_string(), "1536MiB");
+        assert_eq!(ByteSize(1000).to_string(), "1000B");
+        assert_eq!(ByteSize(0).to_string(), "0B");
+
+        assert!("ten gigs".parse::<ByteSize>().is_err());
+        assert!("-1G".parse::<ByteSize>().is_err());
+        assert!("".parse::<ByteSize>().is_err());
+    }
+
+    #[test]
+    fn test_byte_size_reads_strings_and_numbers_from_file() {
+        let cfg: Config = toml::from_str("[cache]\nmax-size = \"5GiB\"\n").unwrap();
+        assert_eq!(cfg.cache.max_size, Some(ByteSize(5 << 30)));
+
+        let cfg: Config = toml::from_str("[cache]\nmax-size = 4096\n").unwrap();
+        assert_eq!(cfg.cache.max_size, Some(ByteSize(4096)));
+    }
+
     #[test]
     fn test_managed_keys_cover_every_setting() {
         for &key in ConfigKey::ALL {
@@ -422,6 +644,7 @@ mod tests {
 
         assert!(ManagedKey::ALL.contains(&ManagedKey::Registries));
         assert!(ManagedKey::ALL.contains(&ManagedKey::TrustedRegistries));
+        assert!(ManagedKey::ALL.contains(&ManagedKey::Aliases));
     }
 
     #[test]
@@ -16,6 +16,7 @@
 // this program. If not, see <https://www.gnu.org/licenses/>.
 
 use std::collections::HashMap;
+use std::path::PathBuf;
 
 use anyhow::Result;
 use serde::Serialize;
@@ -64,6 +65,37 @@ impl ConfigValue for u64 {
     }
 }
 
+impl ConfigValue for bool {
+    fn parse_config_value(key: &str, value: &str) -> Result<Self> {
+        value
+            .parse()
+            .map_err(|_| terr!("error-config-invalid-bool", key = key, value = value).into())
+    }
+
+    fn to_config_string(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl ConfigValue for PathBuf {
+    fn parse_config_value(key: &str, value: &str) -> Result<Self> {
+        PathBuf::from(value).check_config_value(key, ValueSource::Cli)
+    }
+
+    fn check_config_value(self, key: &str, _source: ValueSource) -> Result<Self> {
+        if self.is_absolute() {
+            Ok(self)
+        } else {
+            let value = self.display().to_string();
+            Err(terr!("error-config-invalid-path", key = key, value = value).into())
+        }
+    }
+
+    fn to_config_string(&self) -> String {
+        self.display().to_string()
+    }
+}
+
 impl<T> ConfigValue for T
 where
     T: ConfigEnum + Clone + std::fmt::Debug + Serialize + DeserializeOwned,
@@ -129,6 +161,38 @@ mod tests {
         assert!(u64::parse_config_value(key, "").is_err());
     }
 
+    #[test]
+    fn test_bools_parse_and_render() {
+        let key = ConfigKey::InstallDedupe.as_str();
+
+        assert!(bool::parse_config_value(key, "true").unwrap());
+        assert!(!bool::parse_config_value(key, "false").unwrap());
+        assert_eq!(true.to_config_string(), "true");
+
+        assert!(bool::parse_config_value(key, "yes").is_err());
+        assert!(bool::parse_config_value(key, "").is_err());
+    }
+
+    #[test]
+    fn test_paths_must_be_absolute() {
+        let key = ConfigKey::InstallSystemStore.as_str();
+        let absolute = std::env::temp_dir().join("gdvm");
+        let absolute_str = absolute.display().to_string();
+
+        assert_eq!(
+            PathBuf::parse_config_value(key, &absolute_str).unwrap(),
+            absolute
+        );
+        assert_eq!(absolute.to_config_string(), absolute_str);
+
+        assert!(PathBuf::parse_config_value(key, "relative/store").is_err());
+        assert!(
+            PathBuf::from("relative")
+                .check_config_value(key, ValueSource::File)
+                .is_err()
+        );
+    }
+
     #[test]
     fn test_values_are_taken_as_given_by_default() {
         let key = ConfigKey::PruneMaxAgeDays.as_str();
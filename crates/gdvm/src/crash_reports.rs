@@ -0,0 +1,215 @@
+// SPDX-FileCopyrightText: Copyright (C) 2024 Adaline Simonian
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// This file is part of gdvm.
+//
+// gdvm is free software: you can redistribute it and/or modify it under the
+// terms of the GNU General Public License as published by the Free Software
+// Foundation, either version 3 of the License, or (at your option) any later
+// version.
+//
+// gdvm is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR
+// A PARTICULAR PURPOSE. See the GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with
+// this program. If not, see <https://www.gnu.org/licenses/>.
+
+use std::collections::VecDeque;
+use std::fs;
+use std::io::{self, Read, Write};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::thread::JoinHandle;
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+/// How many of the last lines of output are kept with a crash record.
+pub const OUTPUT_TAIL_LINES: usize = 200;
+
+/// What is known about a crashed Godot run, saved so it can go into a bug
+/// report.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CrashRecord {
+    /// When the crash happened, in ISO 8601.
+    pub time: String,
+    /// The exact Godot version, e.g. `4.3-stable`.
+    pub version: String,
+    pub variant: String,
+    /// Base URL of the registry the build came from.
+    pub registry: String,
+    /// The signal or exception the process ended with.
+    pub reason: String,
+    pub exit_code: i32,
+    pub executable: PathBuf,
+    pub args: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project: Option<PathBuf>,
+    pub gdvm_version: String,
+    pub os: String,
+    pub arch: String,
+    /// The last lines Godot printed, when output capture is on.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub output: Vec<String>,
+}
+
+/// Save a crash record in `dir`. Returns the file it was saved to.
+pub fn save(dir: &Path, record: &CrashRecord) -> Result<PathBuf> {
+    fs::create_dir_all(dir)?;
+    let timestamp = jiff::Timestamp::now()
+        .strftime("%Y%m%dT%H%M%S%3fZ")
+        .to_string();
+    let path = dir.join(format!("{timestamp}.json"));
+    crate::fs_utils::atomic_write(&path, &serde_json::to_string_pretty(record)?)?;
+    Ok(path)
+}
+
+/// The crash records in `dir`, oldest first. Files that can't be read are
+/// skipped.
+pub fn list(dir: &Path) -> Vec<(PathBuf, CrashRecord)> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    let mut records: Vec<_> = entries
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+        .filter_map(|path| {
+            let record = serde_json::from_str(&fs::read_to_string(&path).ok()?).ok()?;
+            Some((path, record))
+        })
+        .collect();
+    records.sort_by(|(a, _), (b, _)| a.cmp(b));
+    records
+}
+
+/// Copies a child's output through to gdvm's own while keeping its last lines.
+pub struct OutputTail {
+    lines: Arc<Mutex<VecDeque<String>>>,
+    threads: Vec<JoinHandle<()>>,
+}
+
+impl OutputTail {
+    /// Start copying the child's piped stdout and stderr to gdvm's.
+    pub fn attach(child: &mut std::process::Child) -> Self {
+        let lines = Arc::new(Mutex::new(VecDeque::with_capacity(OUTPUT_TAIL_LINES)));
+        let mut threads = Vec::new();
+        if let Some(stdout) = child.stdout.take() {
+            let lines = Arc::clone(&lines);
+            threads.push(std::thread::spawn(move || {
+                tee(stdout, io::stdout(), &lines)
+            }));
+        }
+        if let Some(stderr) = child.stderr.take() {
+            let lines = Arc::clone(&lines);
+            threads.push(std::thread::spawn(move || {
+                tee(stderr, io::stderr(), &lines)
+            }));
+        }
+        Self { lines, threads }
+    }
+
+    /// Wait for the output to end and return its last lines.
+    pub fn finish(self) -> Vec<String> {
+        for thread in self.threads {
+            thread.join().ok();
+        }
+        let mut lines = self.lines.lock().unwrap_or_else(|e| e.into_inner());
+        lines.drain(..).collect()
+    }
+}
+
+/// Copy `reader` to `writer` as it arrives, keeping the last lines in `lines`.
+/// Reading goes on when writing fails, so the child never blocks on a full
+/// pipe.
+fn tee(mut reader: impl Read, mut writer: impl Write, lines: &Mutex<VecDeque<String>>) {
+    let mut buf = [0u8; 8192];
+    let mut partial = Vec::new();
+    let push = |line: &[u8]| {
+        let mut lines = lines.lock().unwrap_or_else(|e| e.into_inner());
+        if lines.len() == OUTPUT_TAIL_LINES {
+            lines.pop_front();
+        }
+        lines.push_back(String::from_utf8_lossy(line).trim_end().to_string());
+    };
+
+    loop {
+        let read = match reader.read(&mut buf) {
+            Ok(0) => break,
+            Ok(read) => read,
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+            Err(_) => break,
+        };
+        writer.write_all(&buf[..read]).ok();
+        writer.flush().ok();
+
+        partial.extend_from_slice(&buf[..read]);
+        while let Some(end) = partial.iter().position(|&b| b == b'\n') {
+            let line: Vec<u8> = partial.drain(..=end).collect();
+            push(&line);
+        }
+    }
+    if !partial.is_empty() {
+        push(&partial);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tempfile::TempDir;
+
+    use super::*;
+
+    fn record(version: &str) -> CrashRecord {
+        CrashRecord {
+            time: "2026-10-18T10:15:30.000Z".to_string(),
+            version: version.to_string(),
+            variant: "default".to_string(),
+            registry: "https://example.com/godot".to_string(),
+            reason: "SIGSEGV".to_string(),
+            exit_code: 139,
+            executable: PathBuf::from("/opt/godot/Godot"),
+            args: vec!["-e".to_string()],
+            project: None,
+            gdvm_version: "0.16.0".to_string(),
+            os: "linux".to_string(),
+            arch: "x86_64".to_string(),
+            output: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn saved_records_are_listed_oldest_first() {
+        let tmp = TempDir::new().unwrap();
+        let first = save(tmp.path(), &record("4.2-stable")).unwrap();
+        std::thread::sleep(std::time::Duration::from_millis(2));
+        let second = save(tmp.path(), &record("4.3-stable")).unwrap();
+        fs::write(tmp.path().join("broken.json"), "{").unwrap();
+
+        let records = list(tmp.path());
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].0, first);
+        assert_eq!(records[0].1.version, "4.2-stable");
+        assert_eq!(records[1].0, second);
+        assert!(list(&tmp.path().join("missing")).is_empty());
+    }
+
+    #[test]
+    fn tee_copies_output_and_keeps_the_last_lines() {
+        let input: String = (0..OUTPUT_TAIL_LINES + 5)
+            .map(|i| format!("line {i}\n"))
+            .chain(["no newline".to_string()])
+            .collect();
+        let lines = Mutex::new(VecDeque::new());
+        let mut copied = Vec::new();
+
+        tee(input.as_bytes(), &mut copied, &lines);
+
+        assert_eq!(copied, input.as_bytes());
+        let lines = lines.into_inner().unwrap();
+        assert_eq!(lines.len(), OUTPUT_TAIL_LINES);
+        assert_eq!(lines.front().map(String::as_str), Some("line 6"));
+        assert_eq!(lines.back().map(String::as_str), Some("no newline"));
+    }
+}
@@ -0,0 +1,223 @@
+// SPDX-FileCopyrightText: Copyright (C) 2024 Adaline Simonian
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// This file is part of gdvm.
+//
+// gdvm is free software: you can redistribute it and/or modify it under the
+// terms of the GNU General Public License as published by the Free Software
+// Foundation, either version 3 of the License, or (at your option) any later
+// version.
+//
+// gdvm is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR
+// A PARTICULAR PURPOSE. See the GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with
+// this program. If not, see <https://www.gnu.org/licenses/>.
+
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+/// Current schema version of the dedupe index file.
+pub const DEDUPE_SCHEMA_VERSION: u32 = 1;
+
+/// A set of identical files across installs that share one copy on disk.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SharedGroup {
+    /// Size of each file in bytes.
+    pub size: u64,
+    /// Paths of the files relative to the installs directory (e.g.
+    /// `official-abc123/default/4.3-stable/godot.pck`). The first member still
+    /// on disk is the one whose storage the others share.
+    pub members: Vec<String>,
+}
+
+/// The full on-disk dedupe state.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DedupeState {
+    /// Schema version.
+    pub schema: u32,
+    /// Shared file groups keyed by the SHA-256 of the file contents.
+    #[serde(default)]
+    pub groups: HashMap<String, SharedGroup>,
+}
+
+impl Default for DedupeState {
+    fn default() -> Self {
+        Self {
+            schema: DEDUPE_SCHEMA_VERSION,
+            groups: HashMap::new(),
+        }
+    }
+}
+
+impl DedupeState {
+    /// Drop every member that lives inside the install with the given key.
+    pub fn forget_install(&mut self, install_key: &str) {
+        let prefix = format!("{install_key}/");
+        for group in self.groups.values_mut() {
+            group.members.retain(|member| !member.starts_with(&prefix));
+        }
+        self.groups.retain(|_, group| group.members.len() > 1);
+    }
+
+    /// Drop members that no longer exist and groups that no longer share
+    /// anything.
+    pub fn retain_existing(&mut self, installs_dir: &Path) {
+        for group in self.groups.values_mut() {
+            group
+                .members
+                .retain(|member| installs_dir.join(member).is_file());
+        }
+        self.groups.retain(|_, group| group.members.len() > 1);
+    }
+
+    /// Absolute paths of the files whose storage is owned by another file, i.e.
+    /// every existing member of a group except the first.
+    pub fn shared_paths(&self, installs_dir: &Path) -> HashSet<PathBuf> {
+        let mut shared = HashSet::new();
+        for group in self.groups.values() {
+            let existing = group
+                .members
+                .iter()
+                .map(|member| installs_dir.join(member))
+                .filter(|path| path.is_file());
+            shared.extend(existing.skip(1));
+        }
+        shared
+    }
+}
+
+/// Loads, updates, and persists the dedupe index file.
+pub struct DedupeIndex {
+    path: PathBuf,
+    locks_dir: PathBuf,
+}
+
+impl DedupeIndex {
+    pub fn new(path: PathBuf, locks_dir: PathBuf) -> Self {
+        Self { path, locks_dir }
+    }
+
+    /// Load the state from disk, falling back to an empty state if the file is
+    /// missing or unparseable.
+    pub fn load(&self) -> Result<DedupeState> {
+        if !self.path.exists() {
+            return Ok(DedupeState::default());
+        }
+        let data = fs::read_to_string(&self.path)?;
+        match serde_json::from_str::<DedupeState>(&data) {
+            Ok(state) => Ok(state),
+            Err(_) => Ok(DedupeState::default()),
+        }
+    }
+
+    /// Persist the state to disk.
+    pub fn save(&self, state: &DedupeState) -> Result<()> {
+        let data = serde_json::to_string(state)?;
+        crate::fs_utils::atomic_write(&self.path, &data)
+    }
+
+    /// Update the state and persist to disk.
+    pub fn update<F>(&self, mutate: F) -> Result<()>
+    where
+        F: FnOnce(&mut DedupeState),
+    {
+        let _lock = crate::locks::Lock::acquire(&self.locks_dir, crate::locks::Resource::Dedupe)?;
+        let mut state = self.load()?;
+        state.schema = DEDUPE_SCHEMA_VERSION;
+        mutate(&mut state);
+        self.save(&state)
+    }
+
+    /// Forget every shared file inside the install with the given key.
+    pub fn forget_install(&self, install_key: &str) -> Result<()> {
+        if !self.path.exists() {
+            return Ok(());
+        }
+        self.update(|state| state.forget_install(install_key))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tempfile::TempDir;
+
+    use super::*;
+
+    fn group(members: &[&str]) -> SharedGroup {
+        SharedGroup {
+            size: 4,
+            members: members.iter().map(|m| m.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn forget_install_drops_members_and_lone_groups() {
+        let mut state = DedupeState::default();
+        state.groups.insert(
+            "a".into(),
+            group(&["x/default/4.3-stable/f", "x/default/4.4-stable/f"]),
+        );
+        state.groups.insert(
+            "b".into(),
+            group(&[
+                "x/default/4.3-stable/g",
+                "x/default/4.4-stable/g",
+                "x/default/4.5-stable/g",
+            ]),
+        );
+
+        state.forget_install("x/default/4.3-stable");
+
+        assert!(!state.groups.contains_key("a"));
+        assert_eq!(
+            state.groups["b"].members,
+            vec!["x/default/4.4-stable/g", "x/default/4.5-stable/g"]
+        );
+    }
+
+    #[test]
+    fn shared_paths_skips_first_existing_member() {
+        let tmp = TempDir::new().unwrap();
+        for dir in ["a", "b", "c"] {
+            fs::create_dir_all(tmp.path().join(dir)).unwrap();
+            fs::write(tmp.path().join(dir).join("f"), "data").unwrap();
+        }
+
+        let mut state = DedupeState::default();
+        state
+            .groups
+            .insert("h".into(), group(&["missing/f", "a/f", "b/f", "c/f"]));
+
+        let shared = state.shared_paths(tmp.path());
+        assert_eq!(
+            shared,
+            HashSet::from([tmp.path().join("b/f"), tmp.path().join("c/f")])
+        );
+
+        fs::remove_file(tmp.path().join("c/f")).unwrap();
+        state.retain_existing(tmp.path());
+        assert_eq!(state.groups["h"].members, vec!["a/f", "b/f"]);
+    }
+
+    #[test]
+    fn update_roundtrip_and_corrupt_file_loads_as_default() {
+        let tmp = TempDir::new().unwrap();
+        let index = DedupeIndex::new(tmp.path().join("dedupe.json"), tmp.path().join("locks"));
+
+        index
+            .update(|state| {
+                state.groups.insert("h".into(), group(&["a/f", "b/f"]));
+            })
+            .unwrap();
+        assert_eq!(index.load().unwrap().groups["h"].members.len(), 2);
+
+        fs::write(tmp.path().join("dedupe.json"), "not json").unwrap();
+        assert!(index.load().unwrap().groups.is_empty());
+    }
+}
@@ -0,0 +1,158 @@
+// SPDX-FileCopyrightText: Copyright (C) 2024 Adaline Simonian
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// This file is part of gdvm.
+//
+// gdvm is free software: you can redistribute it and/or modify it under the
+// terms of the GNU General Public License as published by the Free Software
+// Foundation, either version 3 of the License, or (at your option) any later
+// version.
+//
+// gdvm is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR
+// A PARTICULAR PURPOSE. See the GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with
+// this program. If not, see <https://www.gnu.org/licenses/>.
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use anyhow::Result;
+
+use crate::terr;
+
+/// The file variables for Godot are read from. `.env.<profile>` files layer
+/// over it.
+pub const DOTENV_FILE: &str = ".env";
+
+/// Environment variable that picks the `.env.<profile>` file when
+/// `--env-profile` isn't given, such as for the `godot` shims.
+pub const ENV_PROFILE_ENV_VAR: &str = "GDVM_ENV_PROFILE";
+
+/// A `.env` profile and where it was asked for.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EnvProfile {
+    /// Given with `--env-profile`. Its file must exist.
+    Given(String),
+    /// Set through [`ENV_PROFILE_ENV_VAR`], which applies to every project, so
+    /// projects without the profile's file run with `.env` alone.
+    FromEnv(String),
+}
+
+impl EnvProfile {
+    pub fn name(&self) -> &str {
+        match self {
+            Self::Given(name) | Self::FromEnv(name) => name,
+        }
+    }
+}
+
+/// The `.env` profile requested through the environment.
+pub fn profile_from_env() -> Option<EnvProfile> {
+    std::env::var(ENV_PROFILE_ENV_VAR)
+        .ok()
+        .filter(|profile| !profile.trim().is_empty())
+        .map(EnvProfile::FromEnv)
+}
+
+/// Variables from `.env` in `dir`, with those from `.env.<profile>` layered
+/// over them. A profile given on the command line must have its file.
+/// Variables already set in gdvm's environment are left out, so that Godot
+/// gets those unchanged.
+pub fn load(dir: &Path, profile: Option<&EnvProfile>) -> Result<Vec<(String, String)>> {
+    let mut vars = BTreeMap::new();
+    read(&dir.join(DOTENV_FILE), &mut vars);
+
+    if let Some(profile) = profile {
+        let path = dir.join(format!("{DOTENV_FILE}.{}", profile.name()));
+        if !path.is_file() && matches!(profile, EnvProfile::Given(_)) {
+            return Err(terr!(
+                "error-env-profile-not-found",
+                profile = profile.name(),
+                path = path.display().to_string()
+            )
+            .into());
+        }
+        read(&path, &mut vars);
+    }
+
+    Ok(vars
+        .into_iter()
+        .filter(|(key, _)| std::env::var_os(key).is_none())
+        .collect())
+}
+
+/// Add the variables of a `.env` file to `vars`. A missing file and lines that
+/// can't be parsed are skipped.
+fn read(path: &Path, vars: &mut BTreeMap<String, String>) {
+    let Ok(iter) = dotenvy::from_path_iter(path) else {
+        return;
+    };
+    vars.extend(iter.filter_map(|item| item.ok()));
+}
+
+/// Whether the variable `name` matches `pattern`, in which `*` stands for any
+/// run of characters.
+pub fn name_matches(pattern: &str, name: &str) -> bool {
+    let mut parts = pattern.split('*');
+    let Some(mut rest) = name.strip_prefix(parts.next().unwrap_or_default()) else {
+        return false;
+    };
+    let parts: Vec<&str> = parts.collect();
+    let Some((last, middle)) = parts.split_last() else {
+        return rest.is_empty();
+    };
+    for part in middle {
+        let Some(index) = rest.find(part) else {
+            return false;
+        };
+        rest = &rest[index + part.len()..];
+    }
+    rest.ends_with(last)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::*;
+
+    #[test]
+    fn profile_files_layer_over_dotenv() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(
+            dir.path().join(".env"),
+            "GDVM_TEST_DOTENV_A=base\nGDVM_TEST_DOTENV_B=base\n",
+        )
+        .unwrap();
+        fs::write(dir.path().join(".env.ci"), "GDVM_TEST_DOTENV_B=ci\n").unwrap();
+
+        let given = |name: &str| EnvProfile::Given(name.to_string());
+        let vars = load(dir.path(), Some(&given("ci"))).unwrap();
+        assert_eq!(
+            vars,
+            [
+                ("GDVM_TEST_DOTENV_A".to_string(), "base".to_string()),
+                ("GDVM_TEST_DOTENV_B".to_string(), "ci".to_string()),
+            ]
+        );
+        assert_eq!(load(dir.path(), None).unwrap()[1].1, "base");
+        assert!(load(dir.path(), Some(&given("missing"))).is_err());
+
+        // A profile from the environment may be missing from a project.
+        let from_env = EnvProfile::FromEnv("missing".to_string());
+        assert_eq!(load(dir.path(), Some(&from_env)).unwrap()[1].1, "base");
+    }
+
+    #[test]
+    fn patterns_match_whole_names() {
+        assert!(name_matches("API_URL", "API_URL"));
+        assert!(!name_matches("API_URL", "API_URL2"));
+        assert!(name_matches("GODOT_*", "GODOT_DEBUG"));
+        assert!(name_matches("*_SECRET", "STEAM_SECRET"));
+        assert!(name_matches("*TOKEN*", "MY_TOKEN_2"));
+        assert!(name_matches("A*B*C", "AxxBxxC"));
+        assert!(!name_matches("A*B*C", "AxxC"));
+        assert!(!name_matches("*_SECRET", "SECRET"));
+    }
+}
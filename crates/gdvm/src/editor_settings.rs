@@ -0,0 +1,198 @@
+// SPDX-FileCopyrightText: Copyright (C) 2024 Adaline Simonian
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// This file is part of gdvm.
+//
+// gdvm is free software: you can redistribute it and/or modify it under the
+// terms of the GNU General Public License as published by the Free Software
+// Foundation, either version 3 of the License, or (at your option) any later
+// version.
+//
+// gdvm is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR
+// A PARTICULAR PURPOSE. See the GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with
+// this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Godot's editor settings, and carrying them forward from one version to the
+//! next.
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+
+use crate::version::ResolvedVersion;
+
+/// The folders next to the editor settings that are carried forward with them.
+pub const CARRIED_DIRS: [&str; 3] = ["text_editor_themes", "script_templates", "feature_profiles"];
+
+/// The name of Godot's folder in the user's config directory.
+#[cfg(not(any(target_os = "windows", target_os = "macos")))]
+pub(crate) const GODOT_DIR_NAME: &str = "godot";
+#[cfg(any(target_os = "windows", target_os = "macos"))]
+pub(crate) const GODOT_DIR_NAME: &str = "Godot";
+
+/// The editor settings file of a version. Godot 4.3 and later keep one for
+/// each minor version, older versions one for each major version.
+pub fn settings_file_name(version: &ResolvedVersion) -> String {
+    if (version.major, version.minor) >= (4, 3) {
+        format!("editor_settings-{}.{}.tres", version.major, version.minor)
+    } else {
+        format!("editor_settings-{}.tres", version.major)
+    }
+}
+
+/// Where Godot keeps the editor settings shared by every version that isn't
+/// self-contained.
+pub fn shared_config_dir() -> Result<PathBuf> {
+    let home = crate::paths::get_home_dir()?;
+    let env_dir = |var: &str| {
+        std::env::var_os(var)
+            .map(PathBuf::from)
+            .filter(|dir| dir.is_absolute())
+    };
+
+    #[cfg(target_os = "windows")]
+    let root = env_dir("APPDATA").unwrap_or_else(|| home.join("AppData").join("Roaming"));
+    #[cfg(target_os = "macos")]
+    let root = env_dir("XDG_CONFIG_HOME")
+        .unwrap_or_else(|| home.join("Library").join("Application Support"));
+    #[cfg(not(any(target_os = "windows", target_os = "macos")))]
+    let root = env_dir("XDG_CONFIG_HOME").unwrap_or_else(|| home.join(".config"));
+
+    Ok(root.join(GODOT_DIR_NAME))
+}
+
+/// Where the Godot in `install_dir` keeps its editor settings. A self-contained
+/// install keeps them inside it, and a project with a data directory of its
+/// own keeps them there.
+pub fn config_dir(install_dir: &Path, project_data: Option<&Path>) -> Result<PathBuf> {
+    if crate::self_contained::is_enabled(install_dir) {
+        return Ok(crate::self_contained::editor_data(install_dir));
+    }
+    match project_data {
+        Some(data_dir) => {
+            Ok(crate::self_contained::project_config_home(data_dir).join(GODOT_DIR_NAME))
+        }
+        None => shared_config_dir(),
+    }
+}
+
+/// Copy the editor settings in `source` to `target`, along with the folders in
+/// `CARRIED_DIRS` when the two are different directories. Files that would be
+/// replaced are first copied into `backup`. Returns whether anything was
+/// backed up.
+pub fn carry(
+    (source, source_file): (&Path, &str),
+    (target, target_file): (&Path, &str),
+    backup: &Path,
+) -> io::Result<bool> {
+    let mut backed_up = false;
+    let mut copy = |from: &Path, relative: &Path| -> io::Result<()> {
+        let to = target.join(relative);
+        if to.is_file() {
+            if fs::read(&to)? == fs::read(from)? {
+                return Ok(());
+            }
+            let saved = backup.join(relative);
+            fs::create_dir_all(saved.parent().unwrap_or(backup))?;
+            fs::copy(&to, saved)?;
+            backed_up = true;
+        }
+        fs::create_dir_all(to.parent().unwrap_or(target))?;
+        fs::copy(from, to)?;
+        Ok(())
+    };
+
+    copy(&source.join(source_file), Path::new(target_file))?;
+    if source != target {
+        for dir in CARRIED_DIRS {
+            for file in files_under(&source.join(dir)) {
+                let relative = file.strip_prefix(source).unwrap_or(&file).to_path_buf();
+                copy(&file, &relative)?;
+            }
+        }
+    }
+    Ok(backed_up)
+}
+
+/// Every file under `dir`.
+fn files_under(dir: &Path) -> Vec<PathBuf> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    let mut files = Vec::new();
+    for entry in entries.flatten() {
+        match entry.file_type() {
+            Ok(file_type) if file_type.is_dir() => files.extend(files_under(&entry.path())),
+            Ok(file_type) if file_type.is_file() => files.push(entry.path()),
+            _ => {}
+        }
+    }
+    files
+}
+
+#[cfg(test)]
+mod tests {
+    use tempfile::TempDir;
+
+    use super::*;
+    use crate::version::VersionQuery;
+
+    fn version(s: &str) -> ResolvedVersion {
+        VersionQuery::from_install_str(s).unwrap().to_resolved()
+    }
+
+    #[test]
+    fn settings_are_kept_per_minor_version_from_4_3() {
+        assert_eq!(
+            settings_file_name(&version("3.6-stable")),
+            "editor_settings-3.tres"
+        );
+        assert_eq!(
+            settings_file_name(&version("4.2.2-stable")),
+            "editor_settings-4.tres"
+        );
+        assert_eq!(
+            settings_file_name(&version("4.3-stable")),
+            "editor_settings-4.3.tres"
+        );
+    }
+
+    #[test]
+    fn carry_copies_settings_and_folders_and_backs_up_what_it_replaces() {
+        let tmp = TempDir::new().unwrap();
+        let source = tmp.path().join("old");
+        let target = tmp.path().join("new");
+        let backup = tmp.path().join("backup");
+        fs::create_dir_all(source.join("script_templates/Node")).unwrap();
+        fs::create_dir_all(target.join("script_templates/Node")).unwrap();
+        fs::write(source.join("editor_settings-4.3.tres"), "old settings").unwrap();
+        fs::write(source.join("script_templates/Node/mine.gd"), "mine").unwrap();
+        fs::write(target.join("script_templates/Node/mine.gd"), "theirs").unwrap();
+
+        let backed_up = carry(
+            (&source, "editor_settings-4.3.tres"),
+            (&target, "editor_settings-4.4.tres"),
+            &backup,
+        )
+        .unwrap();
+
+        assert!(backed_up);
+        assert_eq!(
+            fs::read_to_string(target.join("editor_settings-4.4.tres")).unwrap(),
+            "old settings"
+        );
+        assert_eq!(
+            fs::read_to_string(target.join("script_templates/Node/mine.gd")).unwrap(),
+            "mine"
+        );
+        assert_eq!(
+            fs::read_to_string(backup.join("script_templates/Node/mine.gd")).unwrap(),
+            "theirs"
+        );
+    }
+}
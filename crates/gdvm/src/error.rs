@@ -45,6 +45,11 @@ pub const ERROR_CODES: &[(&str, &str)] = &[
     ("error-no-stable-releases-found", "GDVM1007"),
     ("no-default-set", "GDVM1008"),
     ("error-pin-version-not-found", "GDVM1009"),
+    ("error-invalid-version-range", "GDVM1010"),
+    ("error-lock-missing", "GDVM1011"),
+    ("error-lock-out-of-date", "GDVM1012"),
+    ("error-no-pinned-version", "GDVM1013"),
+    ("error-alias-unknown", "GDVM1014"),
     //
     // GDVM2xxx: registries, network, downloads.
     //
@@ -83,6 +88,8 @@ pub const ERROR_CODES: &[(&str, &str)] = &[
     ("error-publish-url-requires-integrity", "GDVM2032"),
     ("error-publish-archive-not-found", "GDVM2033"),
     ("registry-validate-failed", "GDVM2034"),
+    ("error-lock-hash-mismatch", "GDVM2035"),
+    ("error-lock-platform-missing", "GDVM2036"),
     //
     // GDVM3xxx: installation, archives, filesystem.
     //
@@ -104,6 +111,10 @@ pub const ERROR_CODES: &[(&str, &str)] = &[
     ("error-link-copy", "GDVM3015"),
     ("error-ensure-godot-binaries-failed", "GDVM3016"),
     ("godot-executable-not-found", "GDVM3017"),
+    ("error-system-store-not-set", "GDVM3018"),
+    ("error-system-install-read-only", "GDVM3019"),
+    ("error-install-running", "GDVM3020"),
+    ("error-self-contained-read-only", "GDVM3021"),
     //
     // GDVM4xxx: configuration.
     //
@@ -113,12 +124,22 @@ pub const ERROR_CODES: &[(&str, &str)] = &[
     ("error-config-invalid-number", "GDVM4003"),
     ("error-config-unusable-not-saving", "GDVM4004"),
     ("error-config-invalid-enum", "GDVM4005"),
+    ("error-config-invalid-bool", "GDVM4006"),
+    ("error-config-invalid-size", "GDVM4007"),
+    ("error-config-invalid-path", "GDVM4008"),
+    ("error-alias-invalid-name", "GDVM4009"),
     //
     // GDVM5xxx: running Godot and project detection.
     //
     ("error-starting-godot", "GDVM5000"),
     ("error-failed-reading-project-godot", "GDVM5001"),
     ("error-project-version-mismatch", "GDVM5002"),
+    ("error-exec-failed", "GDVM5003"),
+    ("error-profile-not-found", "GDVM5004"),
+    ("error-process-not-tracked", "GDVM5005"),
+    ("error-process-stop-failed", "GDVM5006"),
+    ("error-env-profile-not-found", "GDVM5007"),
+    ("error-process-unconfirmed", "GDVM5008"),
     //
     // GDVM6xxx: gdvm upgrade.
     //
@@ -15,9 +15,10 @@
 // You should have received a copy of the GNU General Public License along with
 // this program. If not, see <https://www.gnu.org/licenses/>.
 
+use std::collections::HashSet;
 use std::fs;
 use std::io::Write;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 use anyhow::Result;
 
@@ -80,6 +81,12 @@ pub fn byte_display_args(bytes: u64) -> (f64, &'static str) {
 
 /// Compute the approximate size of a file or directory in bytes.
 pub fn dir_size(path: &Path) -> u64 {
+    dir_size_excluding(path, &HashSet::new())
+}
+
+/// Compute the approximate size of a file or directory in bytes, not counting
+/// any file in `shared`, e.g. files whose storage is owned by another install.
+pub fn dir_size_excluding(path: &Path, shared: &HashSet<PathBuf>) -> u64 {
     let Ok(meta) = std::fs::symlink_metadata(path) else {
         return 0;
     };
@@ -89,6 +96,9 @@ pub fn dir_size(path: &Path) -> u64 {
     }
 
     if meta.is_file() {
+        if shared.contains(path) {
+            return 0;
+        }
         return meta.len();
     }
 
@@ -96,12 +106,114 @@ pub fn dir_size(path: &Path) -> u64 {
 
     if let Ok(entries) = std::fs::read_dir(path) {
         for entry in entries.flatten() {
-            total += dir_size(&entry.path());
+            total += dir_size_excluding(&entry.path(), shared);
         }
     }
     total
 }
 
+/// Check whether two paths refer to the same file on disk, e.g. because they
+/// are hard links to each other.
+pub fn same_file(a: &Path, b: &Path) -> bool {
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::MetadataExt;
+
+        match (fs::metadata(a), fs::metadata(b)) {
+            (Ok(a), Ok(b)) => a.dev() == b.dev() && a.ino() == b.ino(),
+            _ => false,
+        }
+    }
+    #[cfg(not(unix))]
+    {
+        match (fs::canonicalize(a), fs::canonicalize(b)) {
+            (Ok(a), Ok(b)) => a == b,
+            _ => false,
+        }
+    }
+}
+
+/// Replace `duplicate` with a file sharing its storage with `original`.
+///
+/// A copy-on-write clone (reflink) is preferred where the filesystem supports
+/// it, since the two files stay independent. Otherwise `duplicate` becomes a
+/// hard link to `original`. The replacement is made next to `duplicate` and
+/// renamed over it, so `duplicate` is never left missing.
+pub fn share_file(original: &Path, duplicate: &Path) -> Result<()> {
+    let parent = duplicate
+        .parent()
+        .ok_or_else(|| terr!("error-invalid-path", path = duplicate.display().to_string()))?;
+    let file_name = duplicate
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let tmp = parent.join(format!(".{file_name}.gdvm-share"));
+
+    if fs::symlink_metadata(&tmp).is_ok() {
+        fs::remove_file(&tmp)?;
+    }
+
+    let result = (|| -> Result<()> {
+        if reflink(original, &tmp).is_ok() {
+            fs::set_permissions(&tmp, fs::metadata(duplicate)?.permissions())?;
+        } else {
+            let _ = fs::remove_file(&tmp);
+            fs::hard_link(original, &tmp)?;
+        }
+        fs::rename(&tmp, duplicate)?;
+        Ok(())
+    })();
+
+    if result.is_err() {
+        let _ = fs::remove_file(&tmp);
+    }
+    result
+}
+
+/// Create `dst` as a copy-on-write clone of `src`.
+#[cfg(target_os = "linux")]
+fn reflink(src: &Path, dst: &Path) -> std::io::Result<()> {
+    use std::os::fd::AsRawFd;
+
+    let src_file = fs::File::open(src)?;
+    let dst_file = fs::OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(dst)?;
+
+    // SAFETY: Both descriptors are owned by open `File`s that outlive the call,
+    // and FICLONE takes the source descriptor as its only argument.
+    let ret = unsafe { libc::ioctl(dst_file.as_raw_fd(), libc::FICLONE, src_file.as_raw_fd()) };
+    if ret == -1 {
+        return Err(std::io::Error::last_os_error());
+    }
+    Ok(())
+}
+
+/// Create `dst` as a copy-on-write clone of `src`.
+#[cfg(target_os = "macos")]
+fn reflink(src: &Path, dst: &Path) -> std::io::Result<()> {
+    use std::ffi::CString;
+    use std::os::unix::ffi::OsStrExt;
+
+    let src = CString::new(src.as_os_str().as_bytes())?;
+    let dst = CString::new(dst.as_os_str().as_bytes())?;
+
+    // SAFETY: Both pointers come from `CString`s that are alive for the
+    // duration of the call.
+    let ret = unsafe { libc::clonefile(src.as_ptr(), dst.as_ptr(), 0) };
+    if ret == -1 {
+        return Err(std::io::Error::last_os_error());
+    }
+    Ok(())
+}
+
+/// Create `dst` as a copy-on-write clone of `src`.
+#[cfg(not(any(target_os = "linux", target_os = "macos")))]
+fn reflink(_src: &Path, _dst: &Path) -> std::io::Result<()> {
+    Err(std::io::ErrorKind::Unsupported.into())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -148,4 +260,38 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn dir_size_excluding_skips_shared_files() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let a = dir.path().join("a.bin");
+        let b = dir.path().join("b.bin");
+        fs::write(&a, [0u8; 100])?;
+        fs::write(&b, [0u8; 50])?;
+
+        assert_eq!(dir_size(dir.path()), 150);
+
+        let shared = HashSet::from([b.clone()]);
+        assert_eq!(dir_size_excluding(dir.path(), &shared), 100);
+
+        Ok(())
+    }
+
+    #[test]
+    fn share_file_keeps_contents_and_shares_storage() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let original = dir.path().join("original.bin");
+        let duplicate = dir.path().join("duplicate.bin");
+        fs::write(&original, b"same contents")?;
+        fs::write(&duplicate, b"same contents")?;
+
+        assert!(!same_file(&original, &duplicate));
+
+        share_file(&original, &duplicate)?;
+
+        assert_eq!(fs::read(&duplicate)?, b"same contents");
+        assert_eq!(fs::read_dir(dir.path())?.count(), 2);
+
+        Ok(())
+    }
 }
@@ -0,0 +1,135 @@
+// SPDX-FileCopyrightText: Copyright (C) 2024 Adaline Simonian
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// This file is part of gdvm.
+//
+// gdvm is free software: you can redistribute it and/or modify it under the
+// terms of the GNU General Public License as published by the Free Software
+// Foundation, either version 3 of the License, or (at your option) any later
+// version.
+//
+// gdvm is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR
+// A PARTICULAR PURPOSE. See the GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with
+// this program. If not, see <https://www.gnu.org/licenses/>.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+/// Name of the lock file written next to `gdvm.toml`.
+pub const LOCK_FILE: &str = "gdvm.lock";
+
+/// Environment variable that makes every command behave as if `--locked` was
+/// given, including the `godot` shims.
+pub const LOCKED_ENV_VAR: &str = "GDVM_LOCKED";
+
+/// Comment at the top of every written lock file.
+const HEADER: &str =
+    "# This file is generated by gdvm. Update it with `gdvm pin --update-lock`.\n\n";
+
+/// The gdvm lock file, recording the exact build a pin resolved to.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GdvmLock {
+    pub godot: GdvmLockGodot,
+    /// Preserve keys written by newer gdvm versions.
+    #[serde(flatten)]
+    pub extra: BTreeMap<String, toml::Value>,
+}
+
+/// The `[godot]` section of `gdvm.lock`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GdvmLockGodot {
+    /// The `[godot] version` of `gdvm.toml` that this lock was resolved from.
+    pub constraint: String,
+    /// The exact release, e.g. `4.3-stable`.
+    pub version: String,
+    /// The variant, e.g. `default` or `csharp`.
+    pub variant: String,
+    /// Normalized base URL of the registry the release comes from.
+    pub registry: String,
+    /// SHA-512 of the archive for each platform key, e.g. `linux-x86_64`.
+    #[serde(default)]
+    pub sha512: BTreeMap<String, String>,
+}
+
+/// Whether `--locked` behaviour was requested through the environment.
+pub fn locked_from_env() -> bool {
+    std::env::var(LOCKED_ENV_VAR)
+        .is_ok_and(|v| matches!(v.trim().to_ascii_lowercase().as_str(), "1" | "true"))
+}
+
+/// Serialize a `GdvmLock` to a TOML string.
+pub fn serialize_gdvm_lock(lock: &GdvmLock) -> String {
+    let body = toml::to_string(lock).expect("GdvmLock serialization should never fail");
+    format!("{HEADER}{body}")
+}
+
+/// Deserialize a `GdvmLock` from a TOML string.
+pub fn deserialize_gdvm_lock(contents: &str) -> Result<GdvmLock, toml::de::Error> {
+    toml::from_str(contents)
+}
+
+/// Read the lock file in `dir`, if there is one.
+pub fn read(dir: &Path) -> Result<Option<GdvmLock>> {
+    let path = dir.join(LOCK_FILE);
+    if !path.is_file() {
+        return Ok(None);
+    }
+    let contents = fs::read_to_string(&path)?;
+    Ok(Some(deserialize_gdvm_lock(&contents)?))
+}
+
+/// Write `lock` to the lock file in `dir`.
+pub fn write(dir: &Path, lock: &GdvmLock) -> Result<()> {
+    crate::fs_utils::atomic_write(&dir.join(LOCK_FILE), &serialize_gdvm_lock(lock))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> GdvmLock {
+        GdvmLock {
+            godot: GdvmLockGodot {
+                constraint: "csharp:4.3".to_string(),
+                version: "4.3-stable".to_string(),
+                variant: "csharp".to_string(),
+                registry: "https://registry.gdvm.io/v2".to_string(),
+                sha512: BTreeMap::from([
+                    ("linux-x86_64".to_string(), "aa".to_string()),
+                    ("windows-x86_64".to_string(), "bb".to_string()),
+                ]),
+            },
+            extra: BTreeMap::new(),
+        }
+    }
+
+    #[test]
+    fn test_gdvm_lock_roundtrip() {
+        let lock = sample();
+        let toml_str = serialize_gdvm_lock(&lock);
+        assert!(toml_str.starts_with("# This file is generated by gdvm."));
+        assert!(toml_str.contains("[godot.sha512]"));
+        assert_eq!(deserialize_gdvm_lock(&toml_str).unwrap(), lock);
+    }
+
+    #[test]
+    fn test_gdvm_lock_read_and_write() {
+        let tmp = tempfile::tempdir().unwrap();
+        assert!(read(tmp.path()).unwrap().is_none());
+
+        write(tmp.path(), &sample()).unwrap();
+        assert_eq!(read(tmp.path()).unwrap(), Some(sample()));
+    }
+
+    #[test]
+    fn test_deserialize_gdvm_lock_invalid() {
+        assert!(deserialize_gdvm_lock("[godot]\nversion = \"4.3-stable\"\n").is_err());
+    }
+}
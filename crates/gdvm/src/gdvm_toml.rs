@@ -16,9 +16,12 @@
 // this program. If not, see <https://www.gnu.org/licenses/>.
 
 use std::collections::{BTreeMap, HashMap};
+use std::path::PathBuf;
 
 use serde::{Deserialize, Serialize};
 
+use crate::config::GodotLaunchMode;
+
 /// The gdvm pin file.
 #[derive(Debug, Serialize, Deserialize)]
 pub struct GdvmToml {
@@ -28,6 +31,15 @@ pub struct GdvmToml {
     /// Registries defined in the pin file, keyed by alias.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub registries: Option<HashMap<String, GdvmTomlRegistry>>,
+    /// Launch settings used whenever the project's Godot runs.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub run: Option<GdvmTomlLaunch>,
+    /// Named launch profiles, picked with `gdvm run --profile <name>`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub profiles: Option<BTreeMap<String, GdvmTomlLaunch>>,
+    /// Which variables from the project's `.env` files reach Godot.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dotenv: Option<GdvmTomlDotenv>,
     /// Preserve keys written by newer gdvm versions.
     #[serde(flatten)]
     pub extra: BTreeMap<String, toml::Value>,
@@ -45,6 +57,84 @@ pub struct GdvmTomlRegistry {
     pub url: String,
 }
 
+/// The `[run]` section or a `[profiles.<name>]` entry of `gdvm.toml`.
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+pub struct GdvmTomlLaunch {
+    /// Arguments passed to Godot before any given on the command line.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub args: Vec<String>,
+    /// Environment variables set for Godot.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub env: BTreeMap<String, String>,
+    /// Whether Godot runs attached to the console or detached from it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub launch_mode: Option<GodotLaunchMode>,
+    /// The directory Godot starts in, relative to `gdvm.toml`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub working_dir: Option<PathBuf>,
+    /// Whether the project gets its own editor settings, caches and export
+    /// templates instead of sharing them with every other project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub self_contained: Option<bool>,
+}
+
+impl GdvmTomlLaunch {
+    /// Layer `profile` over these settings. Its arguments follow these ones,
+    /// its variables replace those of the same name, and its launch mode,
+    /// working directory and self-contained setting replace these when set.
+    pub fn merge(mut self, profile: &GdvmTomlLaunch) -> Self {
+        self.args.extend(profile.args.iter().cloned());
+        self.env
+            .extend(profile.env.iter().map(|(k, v)| (k.clone(), v.clone())));
+        self.launch_mode = profile.launch_mode.or(self.launch_mode);
+        self.self_contained = profile.self_contained.or(self.self_contained);
+        if profile.working_dir.is_some() {
+            self.working_dir.clone_from(&profile.working_dir);
+        }
+        self
+    }
+}
+
+/// The `[dotenv]` section of `gdvm.toml`. Names can use `*` as a wildcard.
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+pub struct GdvmTomlDotenv {
+    /// Only variables matching one of these are passed, when set.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pass: Option<Vec<String>>,
+    /// Variables matching one of these are never passed.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub block: Vec<String>,
+}
+
+impl GdvmTomlDotenv {
+    /// Whether the variable `name` from a `.env` file may be passed to Godot.
+    pub fn allows(&self, name: &str) -> bool {
+        let matches = |patterns: &[String]| {
+            patterns
+                .iter()
+                .any(|pattern| crate::dotenv::name_matches(pattern, name))
+        };
+        self.pass.as_deref().is_none_or(matches) && !matches(&self.block)
+    }
+}
+
+impl GdvmToml {
+    /// The launch settings for `profile`, layered over `[run]`, or just
+    /// `[run]` without a profile. Returns `None` if the profile isn't defined.
+    pub fn launch(&self, profile: Option<&str>) -> Option<GdvmTomlLaunch> {
+        let base = self.run.clone().unwrap_or_default();
+        match profile {
+            None => Some(base),
+            Some(name) => self
+                .profiles
+                .as_ref()?
+                .get(name)
+                .map(|profile| base.merge(profile)),
+        }
+    }
+}
+
 /// Serialize a `GdvmToml` to a TOML string.
 pub fn serialize_gdvm_toml(version_specifier: &str) -> String {
     let gdvm_toml = GdvmToml {
@@ -52,11 +142,29 @@ pub fn serialize_gdvm_toml(version_specifier: &str) -> String {
             version: version_specifier.to_string(),
         }),
         registries: None,
+        run: None,
+        profiles: None,
+        dotenv: None,
         extra: BTreeMap::new(),
     };
     toml::to_string(&gdvm_toml).expect("GdvmToml serialization should never fail")
 }
 
+/// Replace the pin in an existing `gdvm.toml`, keeping its other sections,
+/// comments and layout. Falls back to a fresh file when `existing` can't be
+/// parsed.
+pub fn update_gdvm_toml_pin(existing: &str, version_specifier: &str) -> String {
+    let Ok(mut document) = existing.parse::<toml_edit::DocumentMut>() else {
+        return serialize_gdvm_toml(version_specifier);
+    };
+    let godot = document["godot"].or_insert(toml_edit::table());
+    if !godot.is_table() {
+        *godot = toml_edit::table();
+    }
+    godot["version"] = toml_edit::value(version_specifier);
+    document.to_string()
+}
+
 /// Deserialize a `GdvmToml` from a TOML string.
 pub fn deserialize_gdvm_toml(contents: &str) -> Result<GdvmToml, toml::de::Error> {
     toml::from_str(contents)
@@ -132,6 +240,81 @@ mod tests {
         assert!(!toml_str.contains("registries"));
     }
 
+    #[test]
+    fn test_gdvm_toml_dotenv_pass_and_block() {
+        let input = r#"
+[dotenv]
+pass = ["GODOT_*", "API_URL"]
+block = ["*_SECRET"]
+"#;
+        let dotenv = deserialize_gdvm_toml(input).unwrap().dotenv.unwrap();
+        assert!(dotenv.allows("GODOT_LOG"));
+        assert!(dotenv.allows("API_URL"));
+        assert!(!dotenv.allows("HOME_DIR"));
+        assert!(!dotenv.allows("GODOT_SECRET"));
+
+        let block_only = GdvmTomlDotenv {
+            block: vec!["STEAM_API_KEY".to_string()],
+            ..Default::default()
+        };
+        assert!(block_only.allows("API_URL"));
+        assert!(!block_only.allows("STEAM_API_KEY"));
+    }
+
+    #[test]
+    fn test_gdvm_toml_launch_profiles() {
+        let input = r#"
+[run]
+args = ["--rendering-driver", "opengl3"]
+env = { GODOT_LOG = "1" }
+self-contained = true
+
+[profiles.headless-tests]
+args = ["--headless", "-s", "res://tests/run.gd"]
+env = { GODOT_LOG = "2", CI = "1" }
+launch-mode = "attached"
+working-dir = "game"
+self-contained = false
+"#;
+        let parsed = deserialize_gdvm_toml(input).unwrap();
+
+        let run = parsed.launch(None).unwrap();
+        assert_eq!(run.args, ["--rendering-driver", "opengl3"]);
+        assert_eq!(run.launch_mode, None);
+        assert_eq!(run.self_contained, Some(true));
+
+        let profile = parsed.launch(Some("headless-tests")).unwrap();
+        assert_eq!(
+            profile.args,
+            [
+                "--rendering-driver",
+                "opengl3",
+                "--headless",
+                "-s",
+                "res://tests/run.gd"
+            ]
+        );
+        assert_eq!(profile.env.get("GODOT_LOG").map(String::as_str), Some("2"));
+        assert_eq!(profile.env.get("CI").map(String::as_str), Some("1"));
+        assert_eq!(profile.launch_mode, Some(GodotLaunchMode::Attached));
+        assert_eq!(profile.working_dir, Some(PathBuf::from("game")));
+        assert_eq!(profile.self_contained, Some(false));
+
+        assert!(parsed.launch(Some("missing")).is_none());
+    }
+
+    #[test]
+    fn test_update_gdvm_toml_pin_keeps_other_sections() {
+        let input = "[godot]\nversion = \"4.2-stable\"\n\n[profiles.editor]\nargs = [\"-e\"]\n";
+        let updated = update_gdvm_toml_pin(input, "4.3-stable");
+        let parsed = deserialize_gdvm_toml(&updated).unwrap();
+        assert_eq!(parsed.godot.unwrap().version, "4.3-stable");
+        assert_eq!(parsed.profiles.unwrap()["editor"].args, ["-e"]);
+
+        let updated = update_gdvm_toml_pin("not valid toml {{{}", "4.3-stable");
+        assert_eq!(updated, serialize_gdvm_toml("4.3-stable"));
+    }
+
     #[test]
     fn test_deserialize_gdvm_toml_invalid() {
         let input = "not valid toml {{{}";
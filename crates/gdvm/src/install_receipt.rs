@@ -0,0 +1,235 @@
+// SPDX-FileCopyrightText: Copyright (C) 2024 Adaline Simonian
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// This file is part of gdvm.
+//
+// gdvm is free software: you can redistribute it and/or modify it under the
+// terms of the GNU General Public License as published by the Free Software
+// Foundation, either version 3 of the License, or (at your option) any later
+// version.
+//
+// gdvm is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR
+// A PARTICULAR PURPOSE. See the GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with
+// this program. If not, see <https://www.gnu.org/licenses/>.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+use crate::hash_utils::{ShaType, hash_reader};
+
+/// Name of the receipt file written into the root of every install.
+pub const RECEIPT_FILE: &str = ".gdvm-receipt.json";
+
+/// Current schema version of install receipts.
+pub const RECEIPT_SCHEMA_VERSION: u32 = 1;
+
+/// Prefix of manifest entries for symlinks, which record the link target
+/// instead of a hash.
+const SYMLINK_PREFIX: &str = "symlink:";
+
+/// Where an install came from and what it contained when it was installed.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InstallReceipt {
+    /// Schema version.
+    pub schema: u32,
+    /// Base URL of the registry the release was installed from.
+    pub registry_url: String,
+    /// The release version, e.g. `4.3-stable`.
+    pub version: String,
+    /// The variant, e.g. `default` or `csharp`.
+    pub variant: String,
+    /// SHA-512 of the archive the install was extracted from.
+    pub archive_sha512: String,
+    /// Unix timestamp of when the install was made.
+    pub installed_at: u64,
+    /// SHA-256 of every file keyed by its path relative to the install
+    /// directory, using `/` as the separator.
+    pub files: BTreeMap<String, String>,
+}
+
+/// Differences between an install and its receipt.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct ReceiptDiff {
+    /// Files whose contents changed.
+    pub modified: Vec<String>,
+    /// Files listed in the receipt that no longer exist.
+    pub missing: Vec<String>,
+    /// Files that exist but are not listed in the receipt.
+    pub added: Vec<String>,
+}
+
+impl ReceiptDiff {
+    /// True when the install matches its receipt.
+    pub fn is_clean(&self) -> bool {
+        self.modified.is_empty() && self.missing.is_empty() && self.added.is_empty()
+    }
+}
+
+impl InstallReceipt {
+    /// Build a receipt for the files currently in `install_dir`.
+    pub fn create(
+        install_dir: &Path,
+        registry_url: &str,
+        version: &str,
+        variant: &str,
+        archive_sha512: &str,
+    ) -> Result<Self> {
+        Ok(Self {
+            schema: RECEIPT_SCHEMA_VERSION,
+            registry_url: registry_url.to_string(),
+            version: version.to_string(),
+            variant: variant.to_string(),
+            archive_sha512: archive_sha512.to_lowercase(),
+            installed_at: crate::date_utils::now_unix_secs(),
+            files: hash_tree(install_dir)?,
+        })
+    }
+
+    /// Read the receipt of an install, if it has one.
+    pub fn read(install_dir: &Path) -> Result<Option<Self>> {
+        let path = install_dir.join(RECEIPT_FILE);
+        if !path.is_file() {
+            return Ok(None);
+        }
+        let data = fs::read_to_string(path)?;
+        Ok(Some(serde_json::from_str(&data)?))
+    }
+
+    /// Write the receipt into `install_dir`.
+    pub fn write(&self, install_dir: &Path) -> Result<()> {
+        let data = serde_json::to_string_pretty(self)?;
+        crate::fs_utils::atomic_write(&install_dir.join(RECEIPT_FILE), &data)
+    }
+
+    /// Hash the files in `install_dir` and compare them to the receipt.
+    pub fn verify(&self, install_dir: &Path) -> Result<ReceiptDiff> {
+        let current = hash_tree(install_dir)?;
+        let mut diff = ReceiptDiff::default();
+
+        for (path, hash) in &self.files {
+            match current.get(path) {
+                Some(current_hash) if current_hash == hash => {}
+                Some(_) => diff.modified.push(path.clone()),
+                None => diff.missing.push(path.clone()),
+            }
+        }
+        diff.added = current
+            .into_keys()
+            .filter(|path| !self.files.contains_key(path))
+            .collect();
+
+        Ok(diff)
+    }
+}
+
+/// Hash every file under `root`, skipping the receipt itself and the marker and
+/// data of a self-contained install.
+fn hash_tree(root: &Path) -> Result<BTreeMap<String, String>> {
+    let mut files = BTreeMap::new();
+    hash_dir(root, root, &mut files)?;
+    files.remove(RECEIPT_FILE);
+    Ok(files)
+}
+
+fn hash_dir(root: &Path, dir: &Path, files: &mut BTreeMap<String, String>) -> Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        if dir == root && crate::self_contained::is_install_entry(&entry.file_name()) {
+            continue;
+        }
+        let path = entry.path();
+        let file_type = entry.file_type()?;
+        let key = path
+            .strip_prefix(root)?
+            .to_string_lossy()
+            .replace('\\', "/");
+
+        if file_type.is_symlink() {
+            let target = fs::read_link(&path)?;
+            files.insert(key, format!("{SYMLINK_PREFIX}{}", target.to_string_lossy()));
+        } else if file_type.is_dir() {
+            hash_dir(root, &path, files)?;
+        } else {
+            let mut file = fs::File::open(&path)?;
+            files.insert(key, hash_reader(ShaType::Sha256, &mut file)?);
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use tempfile::TempDir;
+
+    use super::*;
+
+    fn install() -> (TempDir, InstallReceipt) {
+        let tmp = TempDir::new().unwrap();
+        fs::create_dir_all(tmp.path().join("data")).unwrap();
+        fs::write(tmp.path().join("Godot"), "binary").unwrap();
+        fs::write(tmp.path().join("data/a.txt"), "a").unwrap();
+        fs::write(tmp.path().join("data/b.txt"), "b").unwrap();
+
+        let receipt = InstallReceipt::create(
+            tmp.path(),
+            "https://example.com/",
+            "4.3-stable",
+            "default",
+            "ABC",
+        )
+        .unwrap();
+        receipt.write(tmp.path()).unwrap();
+        (tmp, receipt)
+    }
+
+    #[test]
+    fn receipt_roundtrip_lists_files_but_not_itself() {
+        let (tmp, receipt) = install();
+
+        let read = InstallReceipt::read(tmp.path()).unwrap().unwrap();
+        assert_eq!(read.archive_sha512, "abc");
+        assert_eq!(
+            read.files.keys().collect::<Vec<_>>(),
+            vec!["Godot", "data/a.txt", "data/b.txt"]
+        );
+        assert!(receipt.verify(tmp.path()).unwrap().is_clean());
+    }
+
+    #[test]
+    fn verify_reports_modified_missing_and_added_files() {
+        let (tmp, receipt) = install();
+        fs::write(tmp.path().join("Godot"), "tampered").unwrap();
+        fs::remove_file(tmp.path().join("data/a.txt")).unwrap();
+        fs::write(tmp.path().join("data/c.txt"), "c").unwrap();
+
+        let diff = receipt.verify(tmp.path()).unwrap();
+        assert_eq!(diff.modified, vec!["Godot"]);
+        assert_eq!(diff.missing, vec!["data/a.txt"]);
+        assert_eq!(diff.added, vec!["data/c.txt"]);
+        assert!(!diff.is_clean());
+    }
+
+    #[test]
+    fn verify_ignores_self_contained_editor_data() {
+        let (tmp, receipt) = install();
+        crate::self_contained::enable(tmp.path()).unwrap();
+        let data = crate::self_contained::editor_data(tmp.path());
+        fs::create_dir_all(&data).unwrap();
+        fs::write(data.join("editor_settings-4.tres"), "settings").unwrap();
+
+        assert!(receipt.verify(tmp.path()).unwrap().is_clean());
+    }
+
+    #[test]
+    fn missing_receipt_reads_as_none() {
+        let tmp = TempDir::new().unwrap();
+        assert!(InstallReceipt::read(tmp.path()).unwrap().is_none());
+    }
+}
@@ -0,0 +1,132 @@
+// SPDX-FileCopyrightText: Copyright (C) 2024 Adaline Simonian
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// This file is part of gdvm.
+//
+// gdvm is free software: you can redistribute it and/or modify it under the
+// terms of the GNU General Public License as published by the Free Software
+// Foundation, either version 3 of the License, or (at your option) any later
+// version.
+//
+// gdvm is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR
+// A PARTICULAR PURPOSE. See the GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with
+// this program. If not, see <https://www.gnu.org/licenses/>.
+
+use std::fs::{self, File};
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Extension of launch log files.
+const LOG_EXTENSION: &str = "log";
+
+/// The file name of a launch log. It starts with the launch time so names sort
+/// oldest first, followed by the version and the project's name.
+pub fn log_file_name(timestamp: &str, version: &str, project: Option<&str>) -> String {
+    format!(
+        "{timestamp}_{}_{}.{LOG_EXTENSION}",
+        file_name_part(version),
+        file_name_part(project.unwrap_or("no-project")),
+    )
+}
+
+/// Replace anything that isn't safe in a file name on every platform.
+pub(crate) fn file_name_part(value: &str) -> String {
+    value
+        .chars()
+        .map(|c| {
+            if c.is_alphanumeric() || matches!(c, '-' | '.' | '+') {
+                c
+            } else {
+                '-'
+            }
+        })
+        .collect()
+}
+
+/// Create the log file for a launch happening now in `dir`.
+pub fn create(dir: &Path, version: &str, project: Option<&str>) -> io::Result<(PathBuf, File)> {
+    fs::create_dir_all(dir)?;
+    let timestamp = jiff::Timestamp::now()
+        .strftime("%Y%m%dT%H%M%S%3fZ")
+        .to_string();
+    let path = dir.join(log_file_name(&timestamp, version, project));
+    let file = File::create(&path)?;
+    Ok((path, file))
+}
+
+/// The launch logs in `dir`, oldest first.
+pub fn list(dir: &Path) -> Vec<PathBuf> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    let mut logs: Vec<PathBuf> = entries
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| path.is_file() && path.extension().is_some_and(|ext| ext == LOG_EXTENSION))
+        .collect();
+    logs.sort();
+    logs
+}
+
+/// Remove the logs in `dir` last written at least `max_age_secs` before
+/// `now`. Returns how many were removed.
+pub fn remove_older_than(dir: &Path, max_age_secs: u64, now: u64) -> usize {
+    list(dir)
+        .into_iter()
+        .filter(|path| {
+            crate::date_utils::modified_unix_secs(path)
+                .is_some_and(|modified| crate::date_utils::age_secs(now, modified) >= max_age_secs)
+        })
+        .filter(|path| fs::remove_file(path).is_ok())
+        .count()
+}
+
+#[cfg(test)]
+mod tests {
+    use tempfile::TempDir;
+
+    use super::*;
+
+    #[test]
+    fn log_file_names_carry_version_and_project() {
+        assert_eq!(
+            log_file_name("20261018T101530000Z", "4.3.0-stable", Some("My Game")),
+            "20261018T101530000Z_4.3.0-stable_My-Game.log"
+        );
+        assert_eq!(
+            log_file_name("20261018T101530000Z", "mybuilds/csharp:4.3", None),
+            "20261018T101530000Z_mybuilds-csharp-4.3_no-project.log"
+        );
+    }
+
+    #[test]
+    fn lists_logs_oldest_first() {
+        let tmp = TempDir::new().unwrap();
+        for name in ["20261018T2_b.log", "20261018T1_a.log", "notes.txt"] {
+            fs::write(tmp.path().join(name), "").unwrap();
+        }
+
+        let names: Vec<_> = list(tmp.path())
+            .iter()
+            .map(|path| path.file_name().unwrap().to_string_lossy().into_owned())
+            .collect();
+        assert_eq!(names, ["20261018T1_a.log", "20261018T2_b.log"]);
+        assert!(list(&tmp.path().join("missing")).is_empty());
+    }
+
+    #[test]
+    fn removes_only_old_logs() {
+        let tmp = TempDir::new().unwrap();
+        let (path, _) = create(tmp.path(), "4.3.0-stable", Some("game")).unwrap();
+        let now = crate::date_utils::now_unix_secs();
+
+        assert_eq!(remove_older_than(tmp.path(), 60, now), 0);
+        assert!(path.exists());
+
+        assert_eq!(remove_older_than(tmp.path(), 60, now + 120), 1);
+        assert!(!path.exists());
+    }
+}
@@ -18,18 +18,26 @@
 pub mod app;
 pub mod artifact_cache;
 pub mod config;
+pub mod crash_reports;
 pub mod date_utils;
+pub mod dedupe_index;
+pub mod dotenv;
 pub mod download_utils;
+pub mod editor_settings;
 pub mod error;
 pub mod fs_utils;
+pub mod gdvm_lock;
 pub mod gdvm_toml;
 pub mod hash_utils;
 pub mod host;
 pub mod i18n;
+pub mod install_receipt;
+pub mod launch_logs;
 pub mod locks;
 pub mod metadata_cache;
 pub mod migrations;
 pub mod paths;
+pub mod pin_files;
 pub mod post_upgrade;
 pub mod process_utils;
 pub mod project_version_detector;
@@ -37,7 +45,9 @@ pub mod registry;
 pub mod registry_store;
 pub mod registry_version_resolver;
 pub mod releases;
+pub mod resolution_cache;
 pub mod run_version_resolver;
+pub mod self_contained;
 pub mod self_update;
 pub mod shims;
 pub mod ui;
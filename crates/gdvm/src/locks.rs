@@ -33,6 +33,8 @@ pub enum Resource<'a> {
     Defaults,
     /// Change to the usage tracking file.
     Usage,
+    /// Change to the index of files shared between installs.
+    Dedupe,
     /// Change to the config file.
     Config,
     /// Migrations of gdvm's local data.
@@ -51,6 +53,7 @@ impl Resource<'_> {
             Resource::Archive(file_name) => format!("archive:{file_name}"),
             Resource::Defaults => "defaults".to_string(),
             Resource::Usage => "usage".to_string(),
+            Resource::Dedupe => "dedupe".to_string(),
             Resource::Config => "config".to_string(),
             Resource::Migrations => "migrations".to_string(),
             Resource::PostUpgrade => "post-upgrade".to_string(),
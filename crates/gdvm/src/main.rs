@@ -46,41 +46,35 @@ async fn run() -> Result<i32> {
     let exe_name = std::env::var("GDVM_ALIAS").ok().unwrap_or_else(|| {
         std::env::current_exe()
             .ok()
-            .and_then(|p| p.file_stem().map(|s| s.to_string_lossy().to_string()))
+            .and_then(|p| {
+                p.file_name()
+                    .map(|s| gdvm::shims::alias_of(&s.to_string_lossy()))
+            })
             .unwrap_or_default()
-            .to_lowercase()
     });
 
     if exe_name.contains("godot") {
         // Forward all args (skip clap) and treat it like "gdvm run"
 
-        let gdvm = Gdvm::new().await?;
-
+        // The Windows console shim always attaches. Otherwise the default
+        // profile and the launch-mode setting decide.
         #[cfg(target_os = "windows")]
-        let console_mode =
-            exe_name.contains("console") || gdvm.config().godot.launch_mode().attaches_console();
+        let console_mode = exe_name.contains("console").then_some(true);
 
         #[cfg(not(target_os = "windows"))]
-        let console_mode = gdvm.config().godot.launch_mode().attaches_console();
+        let console_mode = None;
 
         // Pass all arguments to Godot
         let args: Vec<String> = std::env::args().skip(1).collect();
-        let env_version = std::env::var("GDVM_GODOT_VERSION")
-            .ok()
-            .filter(|v| !v.trim().is_empty());
-
-        match cli::sub_run_inner(cli::RunConfig {
-            gdvm: &gdvm,
-            version_input: env_version.as_ref(),
-            variant: None,
-            console: console_mode,
-            raw_args: &args,
-            force_on_mismatch: false,
-            include_pre: false,
-            assume_yes: false,
-        })
-        .await
-        {
+        // A versioned shim such as `godot4.3` picks its series over
+        // `GDVM_GODOT_VERSION`.
+        let env_version = gdvm::shims::alias_version_spec(&exe_name).or_else(|| {
+            std::env::var("GDVM_GODOT_VERSION")
+                .ok()
+                .filter(|v| !v.trim().is_empty())
+        });
+
+        match cli::sub_run_shim(env_version.as_ref(), console_mode, &args).await {
             Ok(code) => return Ok(code),
             Err(err) => {
                 gdvm::ui::report_error(&err);
@@ -95,10 +89,17 @@ async fn run() -> Result<i32> {
         }
     }
 
-    let matches = cli::build_cli().get_matches();
+    // Version arguments can name aliases, so they're read before parsing.
+    let aliases = ConfigFile::load()?.into_config().aliases;
+    let matches = cli::build_cli(&aliases).get_matches();
 
     if let Some(("completions", sub_m)) = matches.subcommand() {
-        cli::sub_completions(sub_m)?;
+        cli::sub_completions(sub_m, &aliases)?;
+        return Ok(0);
+    }
+
+    if let Some(("env", sub_m)) = matches.subcommand() {
+        cli::sub_env(sub_m).await?;
         return Ok(0);
     }
 
@@ -106,7 +107,12 @@ async fn run() -> Result<i32> {
         ConfigFile::suppress_problem_report();
     }
 
-    let gdvm = Gdvm::new().await?;
+    let mut gdvm = Gdvm::new().await?;
+    if let Some((_, sub_m)) = matches.subcommand()
+        && matches!(sub_m.try_get_one::<bool>("locked"), Ok(Some(true)))
+    {
+        gdvm.require_lock();
+    }
 
     let mut exit_code = 0;
 
@@ -116,19 +122,29 @@ async fn run() -> Result<i32> {
         Some(("diagnose", sub_m)) => cli::sub_diagnose(&gdvm, sub_m).await?,
         Some(("list", sub_m)) => cli::sub_list(&gdvm, sub_m)?,
         Some(("run", sub_m)) => exit_code = cli::sub_run(&gdvm, sub_m).await?,
+        Some(("exec", sub_m)) => exit_code = cli::sub_exec(&gdvm, sub_m).await?,
         Some(("show", sub_m)) => cli::sub_show(&gdvm, sub_m).await?,
         Some(("info", sub_m)) => cli::sub_info(&gdvm, sub_m).await?,
         Some(("cache-path", sub_m)) => cli::sub_cache_path(&gdvm, sub_m).await?,
         Some(("link", sub_m)) => cli::sub_link(&gdvm, sub_m).await?,
         Some(("remove", sub_m)) => cli::sub_remove(&gdvm, sub_m).await?,
+        Some(("verify", sub_m)) => exit_code = cli::sub_verify(&gdvm, sub_m).await?,
+        Some(("outdated", sub_m)) => exit_code = cli::sub_outdated(&gdvm, sub_m).await?,
+        Some(("update", sub_m)) => cli::sub_update(&gdvm, sub_m).await?,
         Some(("search", sub_m)) => cli::sub_search(&gdvm, sub_m).await?,
         Some(("clear-cache", _)) => cli::sub_clear_cache(&gdvm)?,
         Some(("refresh", _)) => cli::sub_refresh(&gdvm).await?,
+        Some(("logs", sub_m)) => cli::sub_logs(&gdvm, sub_m)?,
+        Some(("crashes", sub_m)) => cli::sub_crashes(&gdvm, sub_m)?,
+        Some(("ps", sub_m)) => cli::sub_ps(&gdvm, sub_m)?,
+        Some(("stop", sub_m)) => cli::sub_stop(&gdvm, sub_m)?,
         Some(("prune", sub_m)) => cli::sub_prune(&gdvm, sub_m)?,
+        Some(("dedupe", sub_m)) => cli::sub_dedupe(&gdvm, sub_m)?,
         Some(("use", sub_m)) => cli::sub_use(&gdvm, sub_m).await?,
         Some(("upgrade", sub_m)) => cli::sub_upgrade(&gdvm, sub_m).await?,
         Some(("pin", sub_m)) => cli::sub_pin(&gdvm, sub_m).await?,
         Some(("config", sub_m)) => cli::sub_config(sub_m)?,
+        Some(("alias", sub_m)) => cli::sub_alias(sub_m)?,
         Some(("registry", sub_m)) => cli::sub_registry(&gdvm, sub_m).await?,
         _ => {}
     }
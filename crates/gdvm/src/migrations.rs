@@ -21,7 +21,8 @@ use std::path::{Path, PathBuf};
 use anyhow::Result;
 
 use crate::i18n::I18n;
-use crate::{terr, ui};
+use crate::paths::Layout;
+use crate::{t, terr, ui};
 
 type MigrationFn = fn(&Path) -> Result<()>;
 
@@ -274,13 +275,12 @@ fn move_into(
 pub fn run_migrations(base_path: &Path) -> Result<()> {
     fs::create_dir_all(base_path)?;
 
-    let version_file = base_path.join("data_version");
-
-    let latest = MIGRATIONS.iter().map(|m| m.version).max().unwrap_or(0);
-    if read_data_version(&version_file)? >= latest {
+    if is_current(base_path)? {
         return Ok(());
     }
 
+    let version_file = base_path.join("data_version");
+
     // If there was a lock for migrations held by another process, wait for it
     // to finish and then re-read the version file to see if we still need to
     // run migrations.
@@ -306,6 +306,12 @@ pub fn run_migrations(base_path: &Path) -> Result<()> {
     Ok(())
 }
 
+/// Whether every migration has run on the data in `base_path`.
+pub fn is_current(base_path: &Path) -> Result<bool> {
+    let latest = MIGRATIONS.iter().map(|m| m.version).max().unwrap_or(0);
+    Ok(read_data_version(&base_path.join("data_version"))? >= latest)
+}
+
 fn read_data_version(path: &Path) -> Result<u32> {
     Ok(crate::fs_utils::read_marker_line(path)?
         .and_then(|line| line.parse::<u32>().ok())
@@ -381,6 +387,136 @@ fn finish_legacy_top(old: &Path, new_target: &Path) {
     }
 }
 
+/// Move an existing ~/.gdvm into the XDG layout. The config goes to the config
+/// directory, archives and release metadata to the cache directory, and
+/// everything else to the data directory.
+pub fn relocate(legacy: &Path, layout: &Layout) -> Result<()> {
+    let lock =
+        crate::locks::Lock::acquire(&legacy.join("locks"), crate::locks::Resource::Migrations)?;
+
+    let mut moved_any = false;
+    for entry in fs::read_dir(legacy)?.flatten() {
+        let name = entry.file_name();
+        let destination = match name.to_str() {
+            Some("locks") => continue,
+            Some("config.toml") => layout.config.join(&name),
+            Some("cache") => layout.archives.clone(),
+            Some("cache.json") => layout.cache_index.clone(),
+            _ => layout.data.join(&name),
+        };
+        move_merging(&entry.path(), &destination)?;
+        moved_any = true;
+    }
+
+    if moved_any {
+        repoint_symlinks(legacy, &layout.data)?;
+        ui::note(t!(
+            "relocated-to-xdg",
+            from = legacy.display().to_string(),
+            old_bin = legacy.join("bin").display().to_string(),
+            bin = layout.data.join("bin").display().to_string(),
+        ));
+    }
+
+    drop(lock);
+
+    // Only the locks should be left behind, unless something was in the way.
+    let leftovers = fs::read_dir(legacy)?
+        .flatten()
+        .any(|entry| entry.file_name() != "locks");
+    if leftovers {
+        ui::warn(t!(
+            "warning-relocate-leftovers",
+            path = legacy.display().to_string()
+        ));
+    } else {
+        let _ = fs::remove_dir_all(legacy);
+    }
+
+    Ok(())
+}
+
+/// Move `src` to `dst`. When both are directories, their contents are merged.
+/// Anything that already exists at the destination is left where it is.
+fn move_merging(src: &Path, dst: &Path) -> Result<()> {
+    let src_meta = fs::symlink_metadata(src)?;
+    if let Ok(dst_meta) = fs::symlink_metadata(dst) {
+        if src_meta.is_dir() && dst_meta.is_dir() {
+            for entry in fs::read_dir(src)?.flatten() {
+                move_merging(&entry.path(), &dst.join(entry.file_name()))?;
+            }
+            let _ = fs::remove_dir(src);
+        }
+        return Ok(());
+    }
+
+    if let Some(parent) = dst.parent() {
+        fs::create_dir_all(parent)?;
+    }
+
+    match fs::rename(src, dst) {
+        Ok(()) => Ok(()),
+        Err(e) if e.kind() == std::io::ErrorKind::CrossesDevices => {
+            copy_tree(src, dst)?;
+            if src_meta.is_dir() {
+                fs::remove_dir_all(src)?;
+            } else {
+                fs::remove_file(src)?;
+            }
+            Ok(())
+        }
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// Copy a file, symlink, or directory tree, keeping symlinks as they are.
+fn copy_tree(src: &Path, dst: &Path) -> Result<()> {
+    let meta = fs::symlink_metadata(src)?;
+    if meta.is_symlink() {
+        create_symlink(&fs::read_link(src)?, dst)?;
+    } else if meta.is_dir() {
+        fs::create_dir_all(dst)?;
+        for entry in fs::read_dir(src)?.flatten() {
+            copy_tree(&entry.path(), &dst.join(entry.file_name()))?;
+        }
+    } else {
+        fs::copy(src, dst)?;
+    }
+    Ok(())
+}
+
+/// Point symlinks that led into `from` at the same paths under `to`. This
+/// covers the current-version link, links left behind by earlier migrations,
+/// and links made with `gdvm link`.
+fn repoint_symlinks(from: &Path, to: &Path) -> Result<()> {
+    let mut links: Vec<PathBuf> = Vec::new();
+    for dir in [to.join("bin"), to.join("installs")] {
+        if let Ok(entries) = fs::read_dir(dir) {
+            links.extend(entries.flatten().map(|entry| entry.path()));
+        }
+    }
+
+    let usage = crate::usage_tracker::UsageTracker::new(to.join("usage.json"), to.join("locks"));
+    if let Ok(state) = usage.load() {
+        links.extend(state.links.keys().map(PathBuf::from));
+    }
+
+    for link in links {
+        let Ok(target) = fs::read_link(&link) else {
+            continue;
+        };
+        let Ok(rest) = target.strip_prefix(from) else {
+            continue;
+        };
+        if fs::remove_file(&link).is_err() {
+            fs::remove_dir(&link)?;
+        }
+        create_symlink(&to.join(rest), &link)?;
+    }
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use std::fs;
@@ -388,7 +524,8 @@ mod tests {
 
     use tempfile::TempDir;
 
-    use super::run_migrations;
+    use super::{relocate, run_migrations};
+    use crate::paths::Layout;
 
     fn make_version(base: &Path, rel: &str) {
         let dir = base.join("installs").join(rel);
@@ -587,4 +724,80 @@ mod tests {
             original
         );
     }
+
+    #[test]
+    fn relocate_moves_legacy_dir_into_xdg_layout() {
+        let tmp = TempDir::new().unwrap();
+        let legacy = tmp.path().join(".gdvm");
+        let layout = Layout::xdg(
+            &tmp.path().join("data"),
+            &tmp.path().join("config"),
+            &tmp.path().join("cache"),
+        );
+
+        make_version(&legacy, "store/default/4.4-stable");
+        fs::create_dir_all(legacy.join("cache")).unwrap();
+        fs::write(legacy.join("cache/archive.zip"), b"zip").unwrap();
+        fs::write(legacy.join("cache.json"), b"{}").unwrap();
+        fs::write(legacy.join("config.toml"), b"[prune]\n").unwrap();
+        fs::write(legacy.join("data_version"), b"5\n").unwrap();
+        fs::create_dir_all(legacy.join("locks")).unwrap();
+
+        #[cfg(target_family = "unix")]
+        {
+            fs::create_dir_all(legacy.join("bin")).unwrap();
+            std::os::unix::fs::symlink(
+                legacy.join("installs/store/default/4.4-stable"),
+                legacy.join("bin/current_godot"),
+            )
+            .unwrap();
+        }
+
+        assert!(layout.should_relocate(&legacy));
+        relocate(&legacy, &layout).unwrap();
+
+        assert!(!legacy.exists());
+        assert!(
+            layout
+                .data
+                .join("installs/store/default/4.4-stable/Godot_v_bin")
+                .is_file()
+        );
+        assert!(layout.data.join("data_version").is_file());
+        assert!(layout.archives.join("archive.zip").is_file());
+        assert!(layout.cache_index.is_file());
+        assert!(layout.config.join("config.toml").is_file());
+        assert!(!layout.should_relocate(&legacy));
+
+        #[cfg(target_family = "unix")]
+        assert_eq!(
+            fs::read_link(layout.data.join("bin/current_godot")).unwrap(),
+            layout.data.join("installs/store/default/4.4-stable")
+        );
+    }
+
+    #[test]
+    fn relocate_keeps_files_already_in_the_new_location() {
+        let tmp = TempDir::new().unwrap();
+        let legacy = tmp.path().join(".gdvm");
+        let layout = Layout::xdg(
+            &tmp.path().join("data"),
+            &tmp.path().join("config"),
+            &tmp.path().join("cache"),
+        );
+
+        make_version(&legacy, "store/default/4.3-stable");
+        fs::write(legacy.join("config.toml"), b"old").unwrap();
+        fs::create_dir_all(&layout.config).unwrap();
+        fs::write(layout.config.join("config.toml"), b"new").unwrap();
+        make_version(&layout.data, "store/default/4.4-stable");
+
+        relocate(&legacy, &layout).unwrap();
+
+        let installs = layout.data.join("installs/store/default");
+        assert!(installs.join("4.3-stable").is_dir());
+        assert!(installs.join("4.4-stable").is_dir());
+        assert_eq!(fs::read(layout.config.join("config.toml")).unwrap(), b"new");
+        assert_eq!(fs::read(legacy.join("config.toml")).unwrap(), b"old");
+    }
 }
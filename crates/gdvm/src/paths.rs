@@ -37,12 +37,140 @@ pub fn get_home_dir() -> Result<PathBuf> {
     Ok(base_dirs.home_dir().to_path_buf())
 }
 
-/// The gdvm data directory (~/.gdvm).
-pub fn gdvm_dir() -> Result<PathBuf> {
+/// Environment variable that puts all of gdvm's files under one directory,
+/// for portable setups.
+pub const HOME_ENV_VAR: &str = "GDVM_HOME";
+
+/// Environment variable that opts into the XDG base-directory layout.
+pub const XDG_ENV_VAR: &str = "GDVM_XDG";
+
+/// How gdvm's files are spread across directories.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LayoutKind {
+    /// Everything under a single directory.
+    Single,
+    /// The XDG base-directory layout.
+    Xdg,
+}
+
+/// Where gdvm keeps its files.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Layout {
+    pub kind: LayoutKind,
+    /// Installs and state. This is gdvm's base directory.
+    pub data: PathBuf,
+    /// Directory holding config.toml.
+    pub config: PathBuf,
+    /// Downloaded archives.
+    pub archives: PathBuf,
+    /// Release metadata cache.
+    pub cache_index: PathBuf,
+}
+
+impl Layout {
+    /// Everything under a single directory, as in ~/.gdvm.
+    pub fn single(base: PathBuf) -> Self {
+        Self {
+            kind: LayoutKind::Single,
+            config: base.clone(),
+            archives: base.join("cache"),
+            cache_index: base.join("cache.json"),
+            data: base,
+        }
+    }
+
+    /// The XDG base-directory layout.
+    pub fn xdg(data_home: &Path, config_home: &Path, cache_home: &Path) -> Self {
+        let cache = cache_home.join("gdvm");
+        Self {
+            kind: LayoutKind::Xdg,
+            data: data_home.join("gdvm"),
+            config: config_home.join("gdvm"),
+            archives: cache.join("archives"),
+            cache_index: cache.join("cache.json"),
+        }
+    }
+
+    /// The layout in use. `GDVM_HOME` overrides everything. On Linux and
+    /// other Unix-likes, the XDG layout is used when `GDVM_XDG` is set, or
+    /// when there is no ~/.gdvm but an XDG data directory exists.
+    pub fn detect() -> Result<Self> {
+        if let Some(home) = std::env::var_os(HOME_ENV_VAR).filter(|home| !home.is_empty()) {
+            return Ok(Self::single(PathBuf::from(home)));
+        }
+
+        let legacy = legacy_dir()?;
+
+        #[cfg(not(any(target_os = "windows", target_os = "macos")))]
+        {
+            let xdg = Self::xdg_from_env()?;
+            if xdg_requested() || (!legacy.exists() && xdg.data.is_dir()) {
+                return Ok(xdg);
+            }
+        }
+
+        Ok(Self::single(legacy))
+    }
+
+    /// The XDG layout for the current environment.
+    #[cfg(not(any(target_os = "windows", target_os = "macos")))]
+    fn xdg_from_env() -> Result<Self> {
+        let home = get_home_dir()?;
+        let dir = |var: &str, fallback: &str| {
+            std::env::var_os(var)
+                .map(PathBuf::from)
+                .filter(|dir| dir.is_absolute())
+                .unwrap_or_else(|| home.join(fallback))
+        };
+        Ok(Self::xdg(
+            &dir("XDG_DATA_HOME", ".local/share"),
+            &dir("XDG_CONFIG_HOME", ".config"),
+            &dir("XDG_CACHE_HOME", ".cache"),
+        ))
+    }
+
+    /// Whether ~/.gdvm should be moved into this layout. Only the XDG layout
+    /// takes over an existing ~/.gdvm.
+    pub fn should_relocate(&self, legacy: &Path) -> bool {
+        self.kind == LayoutKind::Xdg && legacy.is_dir()
+    }
+}
+
+/// Whether the user opted into the XDG layout.
+#[cfg(not(any(target_os = "windows", target_os = "macos")))]
+fn xdg_requested() -> bool {
+    std::env::var(XDG_ENV_VAR)
+        .is_ok_and(|value| matches!(value.trim().to_ascii_lowercase().as_str(), "1" | "true"))
+}
+
+/// The directory gdvm has traditionally used (~/.gdvm).
+pub fn legacy_dir() -> Result<PathBuf> {
     Ok(get_home_dir()?.join(".gdvm"))
 }
 
-/// Centralizes filesystem layout for GDVM under the user home directory.
+/// The gdvm data directory (~/.gdvm, `$XDG_DATA_HOME/gdvm`, or `$GDVM_HOME`).
+pub fn gdvm_dir() -> Result<PathBuf> {
+    Ok(Layout::detect()?.data)
+}
+
+/// The directory holding config.toml.
+pub fn config_dir() -> Result<PathBuf> {
+    Ok(Layout::detect()?.config)
+}
+
+/// Environment variable that sets the system-wide install store, taking
+/// precedence over the `install.system-store` config key.
+pub const SYSTEM_STORE_ENV_VAR: &str = "GDVM_SYSTEM_STORE";
+
+/// The system-wide install store directory, if one is set.
+pub fn system_store_dir(configured: Option<&Path>) -> Option<PathBuf> {
+    match std::env::var_os(SYSTEM_STORE_ENV_VAR) {
+        Some(dir) if !dir.is_empty() => Some(PathBuf::from(dir)),
+        _ => configured.map(Path::to_path_buf),
+    }
+}
+
+/// Centralizes filesystem layout for GDVM.
 pub struct GdvmPaths {
     base: PathBuf,
     installs: PathBuf,
@@ -50,22 +178,32 @@ pub struct GdvmPaths {
     cache_index: PathBuf,
     usage_index: PathBuf,
     bin_dir: PathBuf,
+    system_installs: Option<PathBuf>,
 }
 
 impl GdvmPaths {
-    /// Construct paths rooted at the GDVM base directory, ~/.gdvm, and ensure the base, installs,
+    /// Construct paths for the layout in use and ensure the base, installs,
     /// cache, and bin directories exist.
     pub fn new() -> Result<Self> {
-        let base = gdvm_dir()?;
+        Self::from_layout(&Layout::detect()?)
+    }
+
+    /// Construct paths for a layout and ensure the base, installs, cache, and
+    /// bin directories exist.
+    pub fn from_layout(layout: &Layout) -> Result<Self> {
+        let base = layout.data.clone();
         let installs = base.join("installs");
-        let cache_dir = base.join("cache");
-        let cache_index = base.join("cache.json");
+        let cache_dir = layout.archives.clone();
+        let cache_index = layout.cache_index.clone();
         let usage_index = base.join("usage.json");
         let bin_dir = base.join("bin");
 
         fs::create_dir_all(&installs)?;
         fs::create_dir_all(&cache_dir)?;
         fs::create_dir_all(&bin_dir)?;
+        if let Some(parent) = cache_index.parent() {
+            fs::create_dir_all(parent)?;
+        }
 
         Ok(Self {
             base,
@@ -74,9 +212,30 @@ impl GdvmPaths {
             cache_index,
             usage_index,
             bin_dir,
+            system_installs: None,
         })
     }
 
+    /// Paths rooted at a system-wide install store. Nothing is created until
+    /// something is installed into it.
+    pub fn system(base: PathBuf) -> Self {
+        Self {
+            installs: base.join("installs"),
+            cache_dir: base.join("cache"),
+            cache_index: base.join("cache.json"),
+            usage_index: base.join("usage.json"),
+            bin_dir: base.join("bin"),
+            base,
+            system_installs: None,
+        }
+    }
+
+    /// Consult the installs of a read-only system-wide store as well.
+    pub fn with_system_store(mut self, store: Option<PathBuf>) -> Self {
+        self.system_installs = store.map(|dir| dir.join("installs"));
+        self
+    }
+
     pub fn base(&self) -> &Path {
         &self.base
     }
@@ -85,6 +244,11 @@ impl GdvmPaths {
         &self.installs
     }
 
+    /// Installs directory of the system-wide store, if one is set.
+    pub fn system_installs(&self) -> Option<&Path> {
+        self.system_installs.as_deref()
+    }
+
     pub fn cache_dir(&self) -> &Path {
         &self.cache_dir
     }
@@ -105,6 +269,40 @@ impl GdvmPaths {
         self.base.join("locks")
     }
 
+    /// Data directories of projects that run Godot self-contained.
+    pub fn editor_data(&self) -> PathBuf {
+        self.base.join("editor-data")
+    }
+
+    /// Backups of editor settings replaced when carrying them forward.
+    pub fn settings_backups(&self) -> PathBuf {
+        self.base.join("settings-backups")
+    }
+
+    /// Records of Godot crashes.
+    pub fn crashes(&self) -> PathBuf {
+        self.base.join("crashes")
+    }
+
+    /// Logs of detached launches.
+    pub fn logs(&self) -> PathBuf {
+        self.base.join("logs")
+    }
+
+    pub fn dedupe_index(&self) -> PathBuf {
+        self.base.join("dedupe.json")
+    }
+
+    /// Cached resolutions of the `godot` shims.
+    pub fn resolution_cache(&self) -> PathBuf {
+        self.base.join("resolutions.json")
+    }
+
+    /// Cached exports of `gdvm env`.
+    pub fn env_cache(&self) -> PathBuf {
+        self.base.join("env.json")
+    }
+
     pub fn default_file(&self) -> PathBuf {
         self.base.join("default")
     }
@@ -115,24 +313,7 @@ impl GdvmPaths {
 
     #[cfg(test)]
     pub fn from_base_for_tests(base: PathBuf) -> Result<Self> {
-        let installs = base.join("installs");
-        let cache_dir = base.join("cache");
-        let cache_index = base.join("cache.json");
-        let usage_index = base.join("usage.json");
-        let bin_dir = base.join("bin");
-
-        fs::create_dir_all(&installs)?;
-        fs::create_dir_all(&cache_dir)?;
-        fs::create_dir_all(&bin_dir)?;
-
-        Ok(Self {
-            base,
-            installs,
-            cache_dir,
-            cache_index,
-            usage_index,
-            bin_dir,
-        })
+        Self::from_layout(&Layout::single(base))
     }
 }
 
@@ -155,6 +336,14 @@ mod tests {
         // Derived files live under base.
         assert_eq!(paths.default_file(), paths.base().join("default"));
         assert_eq!(paths.usage_index(), paths.base().join("usage.json"));
+        assert_eq!(paths.dedupe_index(), paths.base().join("dedupe.json"));
+        assert_eq!(paths.logs(), paths.base().join("logs"));
+        assert_eq!(paths.crashes(), paths.base().join("crashes"));
+        assert_eq!(paths.editor_data(), paths.base().join("editor-data"));
+        assert_eq!(
+            paths.settings_backups(),
+            paths.base().join("settings-backups")
+        );
         assert_eq!(
             paths.current_godot_symlink(),
             paths.bin_dir().join("current_godot")
@@ -162,4 +351,64 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn system_store_is_not_created_and_is_opt_in() -> Result<()> {
+        let tmp = TempDir::new()?;
+        let paths = GdvmPaths::from_base_for_tests(tmp.path().join("user"))?;
+        assert!(paths.system_installs().is_none());
+
+        let store = tmp.path().join("system");
+        let system = GdvmPaths::system(store.clone());
+        assert_eq!(system.installs(), store.join("installs"));
+        assert!(!store.exists());
+
+        let paths = paths.with_system_store(Some(store.clone()));
+        assert_eq!(
+            paths.system_installs(),
+            Some(store.join("installs").as_path())
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn layouts_place_config_cache_and_data() {
+        let single = Layout::single(PathBuf::from("/home/u/.gdvm"));
+        assert_eq!(single.kind, LayoutKind::Single);
+        assert_eq!(single.config, single.data);
+        assert_eq!(single.archives, Path::new("/home/u/.gdvm/cache"));
+        assert_eq!(single.cache_index, Path::new("/home/u/.gdvm/cache.json"));
+
+        let xdg = Layout::xdg(
+            Path::new("/home/u/.local/share"),
+            Path::new("/home/u/.config"),
+            Path::new("/home/u/.cache"),
+        );
+        assert_eq!(xdg.kind, LayoutKind::Xdg);
+        assert_eq!(xdg.data, Path::new("/home/u/.local/share/gdvm"));
+        assert_eq!(xdg.config, Path::new("/home/u/.config/gdvm"));
+        assert_eq!(xdg.archives, Path::new("/home/u/.cache/gdvm/archives"));
+        assert_eq!(xdg.cache_index, Path::new("/home/u/.cache/gdvm/cache.json"));
+    }
+
+    #[test]
+    fn only_the_xdg_layout_takes_over_the_legacy_dir() -> Result<()> {
+        let tmp = TempDir::new()?;
+        let legacy = tmp.path().join(".gdvm");
+
+        let xdg = Layout::xdg(
+            &tmp.path().join("data"),
+            &tmp.path().join("config"),
+            &tmp.path().join("cache"),
+        );
+        assert!(!xdg.should_relocate(&legacy), "nothing to move yet");
+
+        fs::create_dir_all(&legacy)?;
+        assert!(xdg.should_relocate(&legacy));
+        assert!(!Layout::single(legacy.clone()).should_relocate(&legacy));
+        assert!(!Layout::single(tmp.path().join("portable")).should_relocate(&legacy));
+
+        Ok(())
+    }
 }
@@ -0,0 +1,99 @@
+// SPDX-FileCopyrightText: Copyright (C) 2024 Adaline Simonian
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// This file is part of gdvm.
+//
+// gdvm is free software: you can redistribute it and/or modify it under the
+// terms of the GNU General Public License as published by the Free Software
+// Foundation, either version 3 of the License, or (at your option) any later
+// version.
+//
+// gdvm is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR
+// A PARTICULAR PURPOSE. See the GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with
+// this program. If not, see <https://www.gnu.org/licenses/>.
+
+/// Name of the file holding nothing but a Godot version.
+pub const GODOT_VERSION_FILE: &str = ".godot-version";
+
+/// Name of the asdf tool versions file.
+pub const TOOL_VERSIONS_FILE: &str = ".tool-versions";
+
+/// Name of the mise config file.
+pub const MISE_TOML_FILE: &str = "mise.toml";
+
+/// The tool name Godot goes by in `.tool-versions` and `mise.toml`.
+const TOOL_NAME: &str = "godot";
+
+/// The version in a `.godot-version` file: its first line that is neither
+/// blank nor a comment.
+pub fn parse_godot_version(contents: &str) -> Option<&str> {
+    contents
+        .lines()
+        .map(str::trim)
+        .find(|line| !line.is_empty() && !line.starts_with('#'))
+}
+
+/// The version on the `godot` line of a `.tool-versions` file. Only the first
+/// version is used when fallbacks follow it.
+pub fn parse_tool_versions(contents: &str) -> Option<&str> {
+    contents.lines().find_map(|line| {
+        let line = line.split('#').next().unwrap_or_default();
+        let mut fields = line.split_whitespace();
+        (fields.next() == Some(TOOL_NAME)).then(|| fields.next())?
+    })
+}
+
+/// The version of `godot` under `[tools]` in a `mise.toml` file, written as a
+/// string, a list whose first entry is used, or a table with a `version` key.
+pub fn parse_mise_toml(contents: &str) -> Option<String> {
+    let document: toml::Table = toml::from_str(contents).ok()?;
+    let tool = document.get("tools")?.as_table()?.get(TOOL_NAME)?;
+    let version = match tool {
+        toml::Value::Array(versions) => versions.first()?,
+        toml::Value::Table(options) => options.get("version")?,
+        version => version,
+    };
+    version.as_str().map(str::to_string)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn godot_version_skips_blank_lines_and_comments() {
+        assert_eq!(parse_godot_version("4.3-stable\n"), Some("4.3-stable"));
+        assert_eq!(
+            parse_godot_version("# pinned for CI\n\n  4.2.2  \n"),
+            Some("4.2.2")
+        );
+        assert_eq!(parse_godot_version("\n# nothing\n"), None);
+    }
+
+    #[test]
+    fn tool_versions_reads_the_godot_line() {
+        let contents = "nodejs 20.11.0\ngodot 4.3-stable 4.2-stable # fallback\npython 3.12\n";
+        assert_eq!(parse_tool_versions(contents), Some("4.3-stable"));
+        assert_eq!(parse_tool_versions("godot-mono 4.3\n"), None);
+        assert_eq!(parse_tool_versions("# godot 4.3\n"), None);
+        assert_eq!(parse_tool_versions("godot\n"), None);
+    }
+
+    #[test]
+    fn mise_toml_reads_every_tool_form() {
+        let string = "[tools]\nnode = \"20\"\ngodot = \"4.3\"\n";
+        assert_eq!(parse_mise_toml(string).as_deref(), Some("4.3"));
+
+        let list = "[tools]\ngodot = [\"4.3-stable\", \"4.2-stable\"]\n";
+        assert_eq!(parse_mise_toml(list).as_deref(), Some("4.3-stable"));
+
+        let table = "[tools.godot]\nversion = \"4.2.2\"\n";
+        assert_eq!(parse_mise_toml(table).as_deref(), Some("4.2.2"));
+
+        assert_eq!(parse_mise_toml("[tools]\nnode = \"20\"\n"), None);
+        assert_eq!(parse_mise_toml("not toml ["), None);
+    }
+}
@@ -20,6 +20,7 @@ use std::path::Path;
 use anyhow::Result;
 use semver::Version;
 
+use crate::config::ConfigFile;
 use crate::locks::{Lock, Resource};
 use crate::{migrations, terr};
 
@@ -56,8 +57,34 @@ const ACTIONS: &[PostUpgradeAction] = &[
         stage: Stage::BeforeMigrations,
         run: revoke_everyone_acl,
     },
+    PostUpgradeAction {
+        id: "sync-versioned-shims",
+        stage: Stage::AfterMigrations,
+        run: sync_versioned_shims,
+    },
 ];
 
+/// Write versioned shims such as `godot4.3` for the official installs, which
+/// installs made before gdvm kept these shims don't have yet.
+fn sync_versioned_shims(base_path: &Path) -> Result<()> {
+    let store = crate::registry::store_dir_name(crate::registry::OFFICIAL_BASE_URL);
+    // A broken config file is reported once gdvm loads it.
+    let configured = ConfigFile::load()
+        .ok()
+        .and_then(|file| file.into_config().install.system_store);
+    let system_store = crate::paths::system_store_dir(configured.as_deref());
+
+    let mut installed = Vec::new();
+    for base in [Some(base_path), system_store.as_deref()]
+        .into_iter()
+        .flatten()
+    {
+        let store_dir = base.join("installs").join(&store);
+        crate::app::collect_store_installs(&store_dir, &None, &mut installed);
+    }
+    crate::shims::sync_versioned(&base_path.join("bin"), &installed)
+}
+
 /// Remove the Everyone permissions the installer used to set on the binary on
 /// Windows.
 #[cfg(target_os = "windows")]
@@ -117,6 +144,15 @@ pub fn run(base_path: &Path) -> Result<()> {
     Ok(())
 }
 
+/// Whether the data in `base_path` was last used by this gdvm build and needs
+/// no migrations, so that `run` would do nothing.
+pub fn is_current(base_path: &Path) -> Result<bool> {
+    Ok(
+        read_version(base_path)?.as_ref() == Some(&current_version())
+            && migrations::is_current(base_path)?,
+    )
+}
+
 /// Get the version of the running gdvm build.
 fn current_version() -> Version {
     Version::parse(env!("CARGO_PKG_VERSION")).expect("crate version is valid semver")
@@ -170,6 +206,32 @@ mod tests {
         fs::write(base.join("data_version"), "1000\n").unwrap();
     }
 
+    #[test]
+    fn upgrade_writes_versioned_shims_for_existing_installs() {
+        let tmp = TempDir::new().unwrap();
+        let base = tmp.path();
+
+        skip_migrations(base);
+        write_version(base, &Version::parse("0.0.1").unwrap()).unwrap();
+        let store = crate::registry::store_dir_name(crate::registry::OFFICIAL_BASE_URL);
+        fs::create_dir_all(
+            base.join("installs")
+                .join(store)
+                .join("default")
+                .join("4.3-stable"),
+        )
+        .unwrap();
+
+        run(base).unwrap();
+
+        let name = if cfg!(target_os = "windows") {
+            "godot4.3.exe"
+        } else {
+            "godot4.3"
+        };
+        assert!(base.join("bin").join(name).is_file());
+    }
+
     #[test]
     fn upgrade_reinstalls_shims() {
         let tmp = TempDir::new().unwrap();
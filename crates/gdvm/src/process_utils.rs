@@ -15,8 +15,10 @@
 // You should have received a copy of the GNU General Public License along with
 // this program. If not, see <https://www.gnu.org/licenses/>.
 
+use std::fs::File;
 use std::io;
-use std::process::{Command, ExitStatus, Stdio};
+use std::path::Path;
+use std::process::{Child, Command, ExitStatus, Stdio};
 
 /// Get the exit code of a child process.
 pub fn child_exit_code(status: ExitStatus) -> i32 {
@@ -32,12 +34,214 @@ pub fn child_exit_code(status: ExitStatus) -> i32 {
     status.code().unwrap_or(1)
 }
 
-/// Spawn `command` detached from the current process.
-pub fn spawn_detached(command: &mut Command) -> io::Result<()> {
-    command
-        .stdin(Stdio::null())
-        .stdout(Stdio::null())
-        .stderr(Stdio::null());
+/// Exception statuses a Windows process ends with when it faults, as opposed
+/// to other error statuses such as `STATUS_CONTROL_C_EXIT`.
+#[cfg(target_family = "windows")]
+const FAULT_STATUSES: &[u32] = &[
+    0x8000_0003, // STATUS_BREAKPOINT
+    0xC000_0005, // STATUS_ACCESS_VIOLATION
+    0xC000_0006, // STATUS_IN_PAGE_ERROR
+    0xC000_001D, // STATUS_ILLEGAL_INSTRUCTION
+    0xC000_0025, // STATUS_NONCONTINUABLE_EXCEPTION
+    0xC000_008C, // STATUS_ARRAY_BOUNDS_EXCEEDED
+    0xC000_008D, // STATUS_FLOAT_DENORMAL_OPERAND
+    0xC000_008E, // STATUS_FLOAT_DIVIDE_BY_ZERO
+    0xC000_008F, // STATUS_FLOAT_INEXACT_RESULT
+    0xC000_0090, // STATUS_FLOAT_INVALID_OPERATION
+    0xC000_0091, // STATUS_FLOAT_OVERFLOW
+    0xC000_0092, // STATUS_FLOAT_STACK_CHECK
+    0xC000_0093, // STATUS_FLOAT_UNDERFLOW
+    0xC000_0094, // STATUS_INTEGER_DIVIDE_BY_ZERO
+    0xC000_0095, // STATUS_INTEGER_OVERFLOW
+    0xC000_0096, // STATUS_PRIVILEGED_INSTRUCTION
+    0xC000_00FD, // STATUS_STACK_OVERFLOW
+    0xC000_0374, // STATUS_HEAP_CORRUPTION
+    0xC000_0409, // STATUS_STACK_BUFFER_OVERRUN, also raised by abort()
+    0xC000_0417, // STATUS_INVALID_CRUNTIME_PARAMETER
+];
+
+/// Why a child process crashed, if it ended the way a crash does rather than by
+/// exiting or being asked to stop. On Unix this is a fault signal such as
+/// `SIGSEGV` or `SIGABRT`, and on Windows a fault status such as
+/// `0xC0000005`.
+pub fn crash_reason(status: ExitStatus) -> Option<String> {
+    #[cfg(target_family = "unix")]
+    {
+        use std::os::unix::process::ExitStatusExt;
+
+        let name = match status.signal()? {
+            libc::SIGSEGV => "SIGSEGV",
+            libc::SIGBUS => "SIGBUS",
+            libc::SIGILL => "SIGILL",
+            libc::SIGFPE => "SIGFPE",
+            libc::SIGABRT => "SIGABRT",
+            libc::SIGTRAP => "SIGTRAP",
+            libc::SIGSYS => "SIGSYS",
+            _ => return None,
+        };
+        Some(name.to_string())
+    }
+
+    #[cfg(target_family = "windows")]
+    {
+        let code = status.code()? as u32;
+        FAULT_STATUSES
+            .contains(&code)
+            .then(|| format!("0x{code:08X}"))
+    }
+}
+
+/// Whether the process with `pid` is running. On Linux it must also be running
+/// `executable`, so that a reused PID isn't mistaken for it.
+#[allow(unused_variables)]
+pub fn is_process_running(pid: u32, executable: &Path) -> bool {
+    #[cfg(target_family = "unix")]
+    {
+        let Ok(pid) = libc::pid_t::try_from(pid) else {
+            return false;
+        };
+        // SAFETY: Signal 0 sends nothing; it only checks that the process
+        // exists and may be signalled.
+        let exists = unsafe { libc::kill(pid, 0) } == 0
+            || io::Error::last_os_error().raw_os_error() == Some(libc::EPERM);
+
+        #[cfg(target_os = "linux")]
+        if exists && let Ok(running) = std::fs::read_link(format!("/proc/{pid}/exe")) {
+            return running == executable
+                || executable.canonicalize().is_ok_and(|exe| exe == running);
+        }
+
+        exists
+    }
+
+    #[cfg(target_family = "windows")]
+    {
+        use winapi::um::handleapi::CloseHandle;
+        use winapi::um::minwinbase::STILL_ACTIVE;
+        use winapi::um::processthreadsapi::{GetExitCodeProcess, OpenProcess};
+        use winapi::um::winnt::PROCESS_QUERY_LIMITED_INFORMATION;
+
+        // SAFETY: The handle is checked before use and closed once the exit
+        // code has been read.
+        unsafe {
+            let handle = OpenProcess(PROCESS_QUERY_LIMITED_INFORMATION, 0, pid);
+            if handle.is_null() {
+                return false;
+            }
+            let mut code = 0;
+            let read = GetExitCodeProcess(handle, &mut code) != 0;
+            CloseHandle(handle);
+            read && code == STILL_ACTIVE
+        }
+    }
+}
+
+/// When the process with `pid` started, for telling it apart from a later
+/// process that reuses the PID. The value is only meant to be compared with
+/// another reading on the same system. `None` if the process is gone, or if the
+/// start time can't be read on this platform.
+pub fn process_start_time(pid: u32) -> Option<u64> {
+    #[cfg(target_os = "linux")]
+    {
+        // The start time is the 22nd field, in clock ticks since boot. The
+        // command name before it may hold spaces, so fields are counted from
+        // the parenthesis that closes it.
+        let stat = std::fs::read_to_string(format!("/proc/{pid}/stat")).ok()?;
+        let (_, fields) = stat.rsplit_once(')')?;
+        fields.split_whitespace().nth(19)?.parse().ok()
+    }
+
+    #[cfg(target_os = "macos")]
+    {
+        let pid = libc::c_int::try_from(pid).ok()?;
+        // SAFETY: `proc_bsdinfo` is plain data, and `proc_pidinfo` writes at
+        // most `size` bytes into it.
+        unsafe {
+            let mut info: libc::proc_bsdinfo = std::mem::zeroed();
+            let size = std::mem::size_of::<libc::proc_bsdinfo>() as libc::c_int;
+            let read = libc::proc_pidinfo(
+                pid,
+                libc::PROC_PIDTBSDINFO,
+                0,
+                (&mut info as *mut libc::proc_bsdinfo).cast(),
+                size,
+            );
+            (read == size).then(|| info.pbi_start_tvsec * 1_000_000 + info.pbi_start_tvusec)
+        }
+    }
+
+    #[cfg(target_family = "windows")]
+    {
+        use winapi::shared::minwindef::FILETIME;
+        use winapi::um::handleapi::CloseHandle;
+        use winapi::um::processthreadsapi::{GetProcessTimes, OpenProcess};
+        use winapi::um::winnt::PROCESS_QUERY_LIMITED_INFORMATION;
+
+        // SAFETY: The handle is checked before use and closed once the times
+        // have been read.
+        unsafe {
+            let handle = OpenProcess(PROCESS_QUERY_LIMITED_INFORMATION, 0, pid);
+            if handle.is_null() {
+                return None;
+            }
+            let mut times: [FILETIME; 4] = std::mem::zeroed();
+            let [created, exited, kernel, user] = &mut times;
+            let read = GetProcessTimes(handle, created, exited, kernel, user) != 0;
+            CloseHandle(handle);
+            read.then(|| {
+                (u64::from(times[0].dwHighDateTime) << 32) | u64::from(times[0].dwLowDateTime)
+            })
+        }
+    }
+
+    #[cfg(not(any(target_os = "linux", target_os = "macos", target_family = "windows")))]
+    {
+        let _ = pid;
+        None
+    }
+}
+
+/// Ask the process with `pid` to exit. On Windows, it is terminated.
+pub fn terminate_process(pid: u32) -> io::Result<()> {
+    #[cfg(target_family = "unix")]
+    {
+        let pid =
+            libc::pid_t::try_from(pid).map_err(|_| io::Error::from(io::ErrorKind::InvalidInput))?;
+        // SAFETY: `kill` only takes plain integers.
+        if unsafe { libc::kill(pid, libc::SIGTERM) } == -1 {
+            return Err(io::Error::last_os_error());
+        }
+        Ok(())
+    }
+
+    #[cfg(target_family = "windows")]
+    {
+        use winapi::um::handleapi::CloseHandle;
+        use winapi::um::processthreadsapi::{OpenProcess, TerminateProcess};
+        use winapi::um::winnt::PROCESS_TERMINATE;
+
+        // SAFETY: The handle is checked before use and closed afterwards.
+        unsafe {
+            let handle = OpenProcess(PROCESS_TERMINATE, 0, pid);
+            if handle.is_null() {
+                return Err(io::Error::last_os_error());
+            }
+            let terminated = TerminateProcess(handle, 1) != 0;
+            let error = io::Error::last_os_error();
+            CloseHandle(handle);
+            if terminated { Ok(()) } else { Err(error) }
+        }
+    }
+}
+
+/// Spawn `command` detached from the current process. Its output goes to `log`
+/// if given, and is discarded otherwise.
+pub fn spawn_detached(command: &mut Command, log: Option<File>) -> io::Result<Child> {
+    let (stdout, stderr) = match log {
+        Some(log) => (Stdio::from(log.try_clone()?), Stdio::from(log)),
+        None => (Stdio::null(), Stdio::null()),
+    };
+    command.stdin(Stdio::null()).stdout(stdout).stderr(stderr);
 
     #[cfg(target_family = "windows")]
     {
@@ -48,7 +252,67 @@ pub fn spawn_detached(command: &mut Command) -> io::Result<()> {
         command.creation_flags(DETACHED_PROCESS);
     }
 
-    command.spawn()?;
+    command.spawn()
+}
 
-    Ok(())
+#[cfg(all(test, target_family = "unix"))]
+mod tests {
+    use super::*;
+
+    fn exit_of(script: &str) -> ExitStatus {
+        Command::new("sh").args(["-c", script]).status().unwrap()
+    }
+
+    #[test]
+    fn fault_signals_are_crashes() {
+        assert_eq!(
+            crash_reason(exit_of("kill -SEGV $$")).as_deref(),
+            Some("SIGSEGV")
+        );
+        assert_eq!(
+            crash_reason(exit_of("kill -ABRT $$")).as_deref(),
+            Some("SIGABRT")
+        );
+    }
+
+    #[test]
+    fn exits_and_requested_stops_are_not_crashes() {
+        assert_eq!(crash_reason(exit_of("exit 0")), None);
+        assert_eq!(crash_reason(exit_of("exit 3")), None);
+        assert_eq!(crash_reason(exit_of("kill -TERM $$")), None);
+    }
+
+    #[cfg(any(target_os = "linux", target_os = "macos"))]
+    #[test]
+    fn start_times_tell_processes_apart() {
+        let this = process_start_time(std::process::id());
+        assert!(this.is_some());
+        assert_eq!(process_start_time(std::process::id()), this);
+
+        let mut child = Command::new("sleep").arg("5").spawn().unwrap();
+        let started = process_start_time(child.id());
+        assert!(started.is_some());
+        child.kill().unwrap();
+        child.wait().unwrap();
+        assert_eq!(process_start_time(child.id()), None);
+    }
+}
+
+#[cfg(all(test, target_family = "windows"))]
+mod tests {
+    use std::os::windows::process::ExitStatusExt;
+
+    use super::*;
+
+    #[test]
+    fn only_fault_statuses_are_crashes() {
+        assert_eq!(
+            crash_reason(ExitStatus::from_raw(0xC000_0005)).as_deref(),
+            Some("0xC0000005")
+        );
+        // Ctrl+C and other error statuses aren't crashes.
+        assert_eq!(crash_reason(ExitStatus::from_raw(0xC000_013A)), None);
+        assert_eq!(crash_reason(ExitStatus::from_raw(0xC000_0135)), None);
+        assert_eq!(crash_reason(ExitStatus::from_raw(3)), None);
+    }
 }
@@ -22,16 +22,24 @@ use std::path::{Path, PathBuf};
 use crate::version::VersionQuery;
 use crate::{terr, ui};
 
-/// Parsed representation of a `project.godot` file.
+/// Parsed representation of a `project.godot` file, along with the engine
+/// version from the C# project file next to it, if any.
 pub struct ParsedProject {
     config_version: Option<u32>,
     features_version: Option<String>,
     has_dotnet: bool,
+    sdk_version: Option<VersionQuery>,
 }
 
 impl ParsedProject {
     /// Parse raw `project.godot` contents.
     pub fn parse_str(contents: &str) -> Self {
+        Self::parse_with_csproj(contents, None)
+    }
+
+    /// Parse raw `project.godot` contents and, for C# projects, the contents
+    /// of the `.csproj` file next to it.
+    pub fn parse_with_csproj(contents: &str, csproj: Option<&str>) -> Self {
         let config_version = parse_config_version(contents);
         // Check for [dotnet] section in project.godot
         let has_dotnet = contents.contains("[dotnet]");
@@ -46,15 +54,45 @@ impl ParsedProject {
             })
             .and_then(|line| parse_packed_string_array_for_version(&line));
 
+        let sdk_version = csproj.and_then(parse_csproj_sdk_version);
+
         Self {
             config_version,
             features_version,
             has_dotnet,
+            sdk_version,
         }
     }
 
-    /// Convert the parsed fields into a detected `VersionQuery`.
+    /// Convert the parsed fields into a detected `VersionQuery`. The exact
+    /// version from a Godot 4 C# project's SDK is preferred when it agrees
+    /// with `project.godot`.
     pub fn detected_version(&self) -> Option<VersionQuery> {
+        let project_version = self.project_version();
+        // Godot 3 projects (config_version 4) share one SDK version across
+        // several engine versions, so it doesn't tell which one they use.
+        let sdk_version = self
+            .sdk_version
+            .as_ref()
+            .filter(|_| self.config_version == Some(5));
+        match (project_version, sdk_version) {
+            (Some(project), Some(sdk)) if project.matches(sdk) => Some(sdk.clone()),
+            (None, Some(sdk)) => Some(sdk.clone()),
+            (project, _) => project,
+        }
+    }
+
+    /// Whether the C# project's SDK version agrees with `project.godot`.
+    fn sdk_agrees(&self) -> bool {
+        match (self.project_version(), &self.sdk_version) {
+            (Some(project), Some(sdk)) => project.matches(sdk),
+            (None, sdk) => sdk.is_some(),
+            (Some(_), None) => false,
+        }
+    }
+
+    /// The version `project.godot` itself declares.
+    fn project_version(&self) -> Option<VersionQuery> {
         // If the config_version is 4, then it's a Godot 3.x version.
         if self.config_version == Some(4) {
             return Some(VersionQuery {
@@ -63,6 +101,7 @@ impl ParsedProject {
                 patch: None,
                 subpatch: None,
                 release_type: None,
+                range: None,
             });
         }
 
@@ -72,9 +111,10 @@ impl ParsedProject {
     }
 
     /// Return the detected variant based on project contents. Returns
-    /// `Some("csharp")` if the project has a `[dotnet]` section.
+    /// `Some("csharp")` if the project has a `[dotnet]` section or uses a
+    /// Godot .NET SDK that agrees with `project.godot`.
     pub fn detected_variant(&self) -> Option<String> {
-        if self.has_dotnet {
+        if self.has_dotnet || self.sdk_agrees() {
             Some("csharp".to_string())
         } else {
             None
@@ -93,6 +133,7 @@ impl std::str::FromStr for ParsedProject {
 /// IO helper that finds `project.godot`, reads it, and yields the parsed representation.
 pub struct ProjectVersionProbe {
     contents: String,
+    csproj: Option<String>,
 }
 
 impl ProjectVersionProbe {
@@ -112,18 +153,22 @@ impl ProjectVersionProbe {
             }
         };
 
-        Some(Self { contents })
+        let csproj = project_file.parent().and_then(read_csproj);
+
+        Some(Self { contents, csproj })
     }
 
     /// Parse the loaded file contents into `ParsedProject` so version detection can run.
     pub fn parse(&self) -> ParsedProject {
-        ParsedProject::parse_str(&self.contents)
+        ParsedProject::parse_with_csproj(&self.contents, self.csproj.as_deref())
     }
 }
 
 /// Detect the Godot version by looking for a `project.godot` file in the
 /// directory tree that `path` belongs to, then parsing `[application]`
 /// → `config/features` → `PackedStringArray(...)` for any `x.x` or `x.x.x`.
+/// For C# projects, the exact version from the `.csproj` file's Godot .NET SDK
+/// is used instead when it agrees.
 ///
 /// Returns `None` if the file cannot be found, parsed, or no version
 /// is specified in `config/features`.
@@ -176,6 +221,56 @@ pub fn find_project_file(start_path: &Path) -> Option<PathBuf> {
     }
 }
 
+/// Read the first `.csproj` file in the project directory, by name. C#
+/// projects are optional, so any problem reading one is ignored.
+fn read_csproj(project_dir: &Path) -> Option<String> {
+    let mut csproj_files: Vec<PathBuf> = fs::read_dir(project_dir)
+        .ok()?
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.extension() == Some(OsStr::new("csproj")) && path.is_file())
+        .collect();
+    csproj_files.sort();
+    fs::read_to_string(csproj_files.first()?).ok()
+}
+
+/// Looks for `<Project Sdk="Godot.NET.Sdk/4.3.1-rc.2">` in a `.csproj` file and
+/// returns the engine version it names.
+fn parse_csproj_sdk_version(contents: &str) -> Option<VersionQuery> {
+    const SDK_PREFIX: &str = "Godot.NET.Sdk/";
+
+    let start = contents.find(SDK_PREFIX)? + SDK_PREFIX.len();
+    let rest = &contents[start..];
+    let end = rest.find(['"', '\'', ';'])?;
+
+    nuget_to_version_query(rest[..end].trim())
+}
+
+/// Convert a NuGet package version like `4.3.1-rc.2` into a `VersionQuery`
+/// using gdvm's release tags, e.g. `4.3.1-rc2`. Versions without a prerelease
+/// label are stable releases.
+fn nuget_to_version_query(version: &str) -> Option<VersionQuery> {
+    let (numbers, prerelease) = match version.split_once('-') {
+        Some((numbers, prerelease)) => (numbers, Some(prerelease)),
+        None => (version, None),
+    };
+
+    let release_type = match prerelease {
+        Some(label) => {
+            let tag = label.replace('.', "").to_lowercase();
+            if tag.is_empty() || !tag.chars().all(|c| c.is_ascii_alphanumeric()) {
+                return None;
+            }
+            tag
+        }
+        None => "stable".to_string(),
+    };
+
+    let mut query = parse_version_string(numbers)?;
+    query.patch.get_or_insert(0);
+    query.release_type = Some(release_type);
+    Some(query)
+}
+
 /// Given the full contents of `project.godot`, extract just the lines in the
 /// `[application]` section. Returns None if no `[application]` section is present.
 fn extract_application_section(contents: &str) -> Option<Vec<String>> {
@@ -293,6 +388,7 @@ fn parse_version_string(version: &str) -> Option<VersionQuery> {
         patch,
         subpatch: None,
         release_type: None,
+        range: None,
     })
 }
 
@@ -360,6 +456,62 @@ foo=bar
         assert!(parsed.detected_variant().is_none());
     }
 
+    #[test]
+    fn test_nuget_to_version_query() {
+        let gv = super::nuget_to_version_query("4.3.1-rc.2").unwrap();
+        assert_eq!(gv.to_remote_str().as_deref(), Some("4.3.1-rc2"));
+
+        let gv = super::nuget_to_version_query("4.2.0").unwrap();
+        assert_eq!(gv.to_remote_str().as_deref(), Some("4.2-stable"));
+
+        let gv = super::nuget_to_version_query("4.4.0-dev.7").unwrap();
+        assert_eq!(gv.to_remote_str().as_deref(), Some("4.4-dev7"));
+
+        assert!(super::nuget_to_version_query("4.3.0-").is_none());
+        assert!(super::nuget_to_version_query("latest").is_none());
+    }
+
+    #[test]
+    fn parsed_project_prefers_agreeing_csproj_sdk_version() {
+        let project = "config_version=5\n[dotnet]\n[application]\nconfig/features=PackedStringArray(\"4.3\", \"C#\")\n";
+        let csproj = r#"<Project Sdk="Godot.NET.Sdk/4.3.1-rc.2">
+  <PropertyGroup>
+    <TargetFramework>net8.0</TargetFramework>
+  </PropertyGroup>
+</Project>"#;
+
+        let parsed = super::ParsedProject::parse_with_csproj(project, Some(csproj));
+        let detected = parsed.detected_version().unwrap();
+        assert_eq!(detected.to_remote_str().as_deref(), Some("4.3.1-rc2"));
+        assert_eq!(parsed.detected_variant().as_deref(), Some("csharp"));
+
+        // A stale csproj that disagrees with project.godot is ignored.
+        let stale = csproj.replace("4.3.1-rc.2", "4.2.0");
+        let parsed = super::ParsedProject::parse_with_csproj(project, Some(&stale));
+        let detected = parsed.detected_version().unwrap();
+        assert_eq!(detected.to_remote_str().as_deref(), Some("4.3"));
+    }
+
+    #[test]
+    fn godot_3_csproj_sdk_version_is_not_the_engine_version() {
+        // Godot.NET.Sdk/3.3.0 is used by every engine from 3.3 to 3.5.
+        let csproj = r#"<Project Sdk="Godot.NET.Sdk/3.3.0">
+</Project>"#;
+
+        let project = "config_version=4\n[application]\nconfig/name=\"Game\"\n";
+        let parsed = super::ParsedProject::parse_with_csproj(project, Some(csproj));
+        let detected = parsed.detected_version().unwrap();
+        assert_eq!(detected.major, Some(3));
+        assert_eq!(detected.minor, None);
+        assert_eq!(parsed.detected_variant().as_deref(), Some("csharp"));
+
+        // A Godot 4 SDK in a Godot 3 project doesn't make it a C# project.
+        let stray = csproj.replace("3.3.0", "4.3.0");
+        let parsed = super::ParsedProject::parse_with_csproj(project, Some(&stray));
+        assert_eq!(parsed.detected_version().unwrap().major, Some(3));
+        assert_eq!(parsed.detected_variant(), None);
+    }
+
     #[test]
     fn parsed_project_reads_features_version_and_dotnet() {
         let contents = r#"
@@ -21,6 +21,7 @@ pub mod publish;
 
 use std::collections::HashMap;
 use std::path::PathBuf;
+use std::sync::OnceLock;
 
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
@@ -307,7 +308,8 @@ pub fn select_binary<'a>(
 
 /// A handle to a single registry.
 pub struct Registry {
-    client: reqwest::Client,
+    /// Built on first use, as setting up TLS takes a while.
+    client: OnceLock<reqwest::Client>,
     name: String,
     base_url: RegistryUrl,
 }
@@ -321,7 +323,7 @@ impl Registry {
     /// Construct a registry with the given name and base URL.
     pub fn new(name: &str, base_url: &str) -> Result<Self> {
         Ok(Self {
-            client: crate::download_utils::http_client()?,
+            client: OnceLock::new(),
             name: name.to_string(),
             base_url: RegistryUrl::parse(base_url)?,
         })
@@ -331,6 +333,14 @@ impl Registry {
         &self.name
     }
 
+    fn client(&self) -> Result<&reqwest::Client> {
+        if let Some(client) = self.client.get() {
+            return Ok(client);
+        }
+        let client = crate::download_utils::http_client()?;
+        Ok(self.client.get_or_init(|| client))
+    }
+
     /// A display string for the registry's base URL.
     pub fn base_url_display(&self) -> String {
         self.base_url.as_display()
@@ -348,7 +358,7 @@ impl Registry {
             RegistryUrl::Http(base) => {
                 crate::download_utils::ensure_url_scheme_allowed(base)?;
                 let url = format!("{base}/{}", rel.trim_start_matches('/'));
-                let resp = crate::download_utils::get_retrying(&self.client, &url, None).await?;
+                let resp = crate::download_utils::get_retrying(self.client()?, &url, None).await?;
                 if resp.status() == reqwest::StatusCode::NOT_FOUND {
                     return Ok(None);
                 }
@@ -56,6 +56,32 @@ pub struct WildcardSuggestion {
     pub newest: String,
 }
 
+/// Newer releases of a version, as reported by `gdvm outdated`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct NewerBuilds {
+    /// The newest stable release with the same major and minor version.
+    pub patch: Option<ResolvedVersion>,
+    /// The newest stable release with the same major and a higher minor version.
+    pub minor: Option<ResolvedVersion>,
+    /// The newest prerelease, if it is newer than every newer stable release.
+    pub prerelease: Option<ResolvedVersion>,
+}
+
+impl NewerBuilds {
+    /// Whether any of the builds is an update of `current`. Prereleases only
+    /// count when `current` is a prerelease itself.
+    pub fn has_updates(&self, current: &ResolvedVersion) -> bool {
+        self.patch.is_some()
+            || self.minor.is_some()
+            || (!current.is_stable() && self.prerelease.is_some())
+    }
+
+    /// Whether no newer build was found at all.
+    pub fn is_empty(&self) -> bool {
+        self.patch.is_none() && self.minor.is_none() && self.prerelease.is_none()
+    }
+}
+
 #[derive(Debug)]
 pub enum ResolveOutcome {
     Candidates(Vec<ResolvedVersion>),
@@ -265,6 +291,56 @@ impl<'a> RegistryVersionResolver<'a> {
         })
     }
 
+    /// Find newer releases of `current` that are available for the host.
+    /// Only the releases that would be reported are checked for the host,
+    /// since each check can fetch the release's metadata.
+    pub async fn newer_builds(
+        &self,
+        current: &ResolvedVersion,
+        variant: Option<&str>,
+        use_cache_only: bool,
+    ) -> Result<NewerBuilds> {
+        let releases = self.catalog.list_releases(None, use_cache_only).await?;
+        let mut newer = NewerBuilds::default();
+        let mut seen_stable = false;
+
+        // Releases are sorted newest first.
+        for gv in releases.into_iter().take_while(|gv| gv > current) {
+            if !gv.is_stable() {
+                if !seen_stable
+                    && newer.prerelease.is_none()
+                    && self.is_compatible(&gv, variant).await?
+                {
+                    newer.prerelease = Some(gv);
+                }
+                continue;
+            }
+
+            let slot = if gv.major != current.major {
+                None
+            } else if gv.minor == current.minor {
+                Some(&mut newer.patch)
+            } else {
+                Some(&mut newer.minor)
+            };
+            let wanted = slot.as_ref().is_some_and(|slot| slot.is_none());
+            // Otherwise, a stable release only matters as the one that hides
+            // older prereleases.
+            if !wanted && (seen_stable || newer.prerelease.is_some()) {
+                continue;
+            }
+            if !self.is_compatible(&gv, variant).await? {
+                continue;
+            }
+            seen_stable = true;
+            if let Some(slot) = slot {
+                slot.get_or_insert(gv);
+            }
+        }
+
+        Ok(newer)
+    }
+
     pub async fn latest_stable(&self) -> Result<ResolvedVersion> {
         let stable = VersionQuery {
             release_type: Some("stable".to_string()),
@@ -414,6 +490,44 @@ mod tests {
         (ReleaseCatalog::new(registry, cache_store), tmp)
     }
 
+    #[tokio::test]
+    async fn newer_builds_reports_patch_minor_and_prerelease() {
+        let (catalog, _tmp) = catalog_with_tags(&[
+            "4.2-stable",
+            "4.3-stable",
+            "4.3.1-stable",
+            "4.4-stable",
+            "4.5-stable",
+            "4.6-beta2",
+            "5.0-dev1",
+        ]);
+        let resolver = RegistryVersionResolver::new(&catalog, host());
+        let current = VersionQuery::from_install_str("4.3-stable")
+            .expect("query")
+            .to_resolved();
+
+        let newer = resolver
+            .newer_builds(&current, None, true)
+            .await
+            .expect("newer builds");
+        let tag = |gv: &Option<ResolvedVersion>| gv.as_ref().map(|gv| gv.to_remote_str());
+        assert_eq!(tag(&newer.patch).as_deref(), Some("4.3.1-stable"));
+        assert_eq!(tag(&newer.minor).as_deref(), Some("4.5-stable"));
+        assert_eq!(tag(&newer.prerelease).as_deref(), Some("5.0-dev1"));
+        assert!(newer.has_updates(&current));
+
+        let latest = VersionQuery::from_install_str("4.5-stable")
+            .expect("query")
+            .to_resolved();
+        let newer = resolver
+            .newer_builds(&latest, None, true)
+            .await
+            .expect("newer builds");
+        assert!(newer.patch.is_none() && newer.minor.is_none());
+        assert!(newer.prerelease.is_some());
+        assert!(!newer.has_updates(&latest));
+    }
+
     #[tokio::test]
     async fn wildcard_resolves_newest_of_release_type() {
         let (catalog, _tmp) = catalog_with_tags(&["4.7-dev1", "4.7-dev10", "4.7-dev9", "4.7-dev2"]);
@@ -496,6 +610,7 @@ mod tests {
             patch: None,
             subpatch: None,
             release_type: None,
+            range: None,
         };
 
         let resolved = resolver
@@ -516,6 +631,7 @@ mod tests {
             patch: None,
             subpatch: None,
             release_type: None,
+            range: None,
         };
 
         for variant in [None, Some("default")] {
@@ -538,6 +654,7 @@ mod tests {
             patch: None,
             subpatch: None,
             release_type: Some("stable".to_string()),
+            range: None,
         };
 
         let resolved = resolver
@@ -558,6 +675,7 @@ mod tests {
                 patch: None,
                 subpatch: None,
                 release_type: None,
+                range: None,
             },
             None,
             false,
@@ -577,6 +695,7 @@ mod tests {
             patch: None,
             subpatch: None,
             release_type: None,
+            range: None,
         };
 
         let installed = vec![
@@ -339,6 +339,7 @@ mod tests {
             patch: None,
             subpatch: None,
             release_type: None,
+            range: None,
         };
 
         let releases = catalog
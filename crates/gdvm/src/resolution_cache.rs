@@ -0,0 +1,224 @@
+// SPDX-FileCopyrightText: Copyright (C) 2024 Adaline Simonian
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// This file is part of gdvm.
+//
+// gdvm is free software: you can redistribute it and/or modify it under the
+// terms of the GNU General Public License as published by the Free Software
+// Foundation, either version 3 of the License, or (at your option) any later
+// version.
+//
+// gdvm is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR
+// A PARTICULAR PURPOSE. See the GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with
+// this program. If not, see <https://www.gnu.org/licenses/>.
+use std::collections::BTreeMap;
+use std::fs;
+use std::marker::PhantomData;
+use std::path::{Path, PathBuf};
+use std::time::UNIX_EPOCH;
+
+use anyhow::Result;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+use crate::date_utils::now_unix_secs;
+
+/// How long a cached resolution is used before resolving in full again, which
+/// also picks up new patch releases.
+pub const MAX_AGE_SECS: u64 = 24 * 60 * 60;
+
+/// How many resolutions are kept. The oldest are dropped first.
+const MAX_ENTRIES: usize = 64;
+
+/// A file changed this recently may change again without its modification
+/// time moving, so resolutions that depend on it aren't cached yet.
+const RACY_SECS: u64 = 2;
+
+/// Which install a `godot` shim resolved to.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CachedResolution {
+    /// The exact Godot version, e.g. `4.3-stable`.
+    pub version: String,
+    pub variant: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub registry: Option<String>,
+    /// The version `project.godot` asked for, when that's where the version
+    /// came from. The warning about it is shown on every run.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_version: Option<String>,
+}
+
+/// The state of a file or directory a resolution was read from.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Stamp {
+    path: PathBuf,
+    /// Seconds and nanoseconds since the Unix epoch, `None` when the path
+    /// doesn't exist.
+    modified: Option<(u64, u32)>,
+    len: u64,
+}
+
+impl Stamp {
+    pub fn of(path: &Path) -> Self {
+        let metadata = fs::metadata(path).ok();
+        Self {
+            path: path.to_path_buf(),
+            modified: metadata
+                .as_ref()
+                .and_then(|m| m.modified().ok())
+                .and_then(|time| time.duration_since(UNIX_EPOCH).ok())
+                .map(|since| (since.as_secs(), since.subsec_nanos())),
+            len: metadata.map_or(0, |m| m.len()),
+        }
+    }
+
+    fn is_current(&self) -> bool {
+        *self == Self::of(&self.path)
+    }
+
+    fn is_racy(&self, now: u64) -> bool {
+        self.modified
+            .is_some_and(|(secs, _)| secs + RACY_SECS >= now)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Entry<T> {
+    resolution: T,
+    recorded: u64,
+    stamps: Vec<Stamp>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct CacheFile<T> {
+    /// The gdvm version that wrote the cache. Any other version starts over.
+    gdvm_version: String,
+    entries: BTreeMap<String, Entry<T>>,
+}
+
+/// Resolutions, by default those of the `godot` shims, each valid while the
+/// files it was read from are unchanged.
+pub struct ResolutionCache<T = CachedResolution> {
+    path: PathBuf,
+    resolution: PhantomData<T>,
+}
+
+impl<T: Clone + Serialize + DeserializeOwned> ResolutionCache<T> {
+    pub fn new(path: PathBuf) -> Self {
+        Self {
+            path,
+            resolution: PhantomData,
+        }
+    }
+
+    /// The resolution cached under `key`, if it's recent and none of its
+    /// files changed since.
+    pub fn get(&self, key: &str) -> Option<T> {
+        let file = self.load()?;
+        let entry = file.entries.get(key)?;
+        let fresh = now_unix_secs().saturating_sub(entry.recorded) < MAX_AGE_SECS;
+        (fresh && entry.stamps.iter().all(Stamp::is_current)).then(|| entry.resolution.clone())
+    }
+
+    /// Cache a resolution under `key`. `stamps` must be taken before resolving,
+    /// so that a change made meanwhile invalidates it.
+    pub fn insert(&self, key: &str, resolution: T, stamps: Vec<Stamp>) -> Result<()> {
+        let now = now_unix_secs();
+        if stamps.iter().any(|stamp| stamp.is_racy(now)) {
+            return Ok(());
+        }
+
+        let mut file = self.load().unwrap_or_else(|| CacheFile {
+            gdvm_version: env!("CARGO_PKG_VERSION").to_string(),
+            entries: BTreeMap::new(),
+        });
+        file.entries.insert(
+            key.to_string(),
+            Entry {
+                resolution,
+                recorded: now,
+                stamps,
+            },
+        );
+        while file.entries.len() > MAX_ENTRIES {
+            let Some(oldest) = file
+                .entries
+                .iter()
+                .min_by_key(|(_, entry)| entry.recorded)
+                .map(|(key, _)| key.clone())
+            else {
+                break;
+            };
+            file.entries.remove(&oldest);
+        }
+
+        crate::fs_utils::atomic_write(&self.path, &serde_json::to_string(&file)?)
+    }
+
+    /// The cache file, unless it's missing, unreadable or from another gdvm
+    /// version.
+    fn load(&self) -> Option<CacheFile<T>> {
+        let file: CacheFile<T> =
+            serde_json::from_str(&fs::read_to_string(&self.path).ok()?).ok()?;
+        (file.gdvm_version == env!("CARGO_PKG_VERSION")).then_some(file)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::File;
+    use std::time::{Duration, SystemTime};
+
+    use super::*;
+
+    fn resolution() -> CachedResolution {
+        CachedResolution {
+            version: "4.3-stable".to_string(),
+            variant: "default".to_string(),
+            registry: None,
+            project_version: None,
+        }
+    }
+
+    fn write_old(path: &Path, contents: &str) {
+        fs::write(path, contents).unwrap();
+        File::options()
+            .write(true)
+            .open(path)
+            .unwrap()
+            .set_modified(SystemTime::now() - Duration::from_secs(60))
+            .unwrap();
+    }
+
+    #[test]
+    fn entries_last_until_a_stamped_file_changes() {
+        let dir = tempfile::tempdir().unwrap();
+        let pin = dir.path().join("gdvm.toml");
+        write_old(&pin, "[godot]\nversion = \"4.3\"\n");
+        let cache = ResolutionCache::new(dir.path().join("resolutions.json"));
+
+        let stamps = vec![Stamp::of(&pin), Stamp::of(&dir.path().join("gdvm.lock"))];
+        cache.insert("key", resolution(), stamps).unwrap();
+        assert_eq!(cache.get("key"), Some(resolution()));
+        assert_eq!(cache.get("other"), None);
+
+        write_old(&dir.path().join("gdvm.lock"), "");
+        assert_eq!(cache.get("key"), None);
+    }
+
+    #[test]
+    fn recently_changed_files_are_not_cached() {
+        let dir = tempfile::tempdir().unwrap();
+        let pin = dir.path().join("gdvm.toml");
+        fs::write(&pin, "[godot]\nversion = \"4.3\"\n").unwrap();
+        let cache = ResolutionCache::new(dir.path().join("resolutions.json"));
+
+        cache
+            .insert("key", resolution(), vec![Stamp::of(&pin)])
+            .unwrap();
+        assert_eq!(cache.get("key"), None);
+    }
+}
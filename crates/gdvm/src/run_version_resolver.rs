@@ -25,12 +25,14 @@ use crate::{t, terr, ui};
 
 #[async_trait(?Send)]
 pub trait RunVersionSource {
-    async fn get_pinned_version(&self) -> Option<QuerySelection>;
+    async fn get_pinned_version(&self) -> Result<Option<QuerySelection>>;
     async fn get_default(&self) -> Result<Option<ResolvedSelection>>;
     async fn determine_version<P: AsRef<Path> + Send + Sync>(
         &self,
         path: Option<P>,
     ) -> Option<(VersionQuery, Option<String>)>;
+    /// The project file `determine_version` reads for the same path.
+    async fn project_file<P: AsRef<Path> + Send + Sync>(&self, path: Option<P>) -> Option<PathBuf>;
     async fn auto_install_version<T>(
         &self,
         gv: &T,
@@ -95,6 +97,18 @@ pub enum RunSource {
     Default,
 }
 
+impl RunSource {
+    /// A stable name for the source, as used in JSON output.
+    pub const fn name(self) -> &'static str {
+        match self {
+            RunSource::Explicit => "explicit",
+            RunSource::Pin { .. } => "pin",
+            RunSource::Project => "project",
+            RunSource::Default => "default",
+        }
+    }
+}
+
 /// The result of selecting which source to use.
 #[derive(Debug, Clone)]
 pub struct RunSelection {
@@ -102,6 +116,47 @@ pub struct RunSelection {
     pub version: VersionQuery,
     pub variant: Option<String>,
     pub registry: Option<String>,
+    /// A `gdvm.lock` that was ignored because it disagrees with the pin.
+    pub stale_lock: Option<PathBuf>,
+    /// The file the version was pinned in, for pins.
+    pub pin_file: Option<PathBuf>,
+}
+
+/// What one source offered while explaining a resolution.
+#[derive(Debug, Clone)]
+pub struct SourceOffer {
+    pub version: VersionQuery,
+    pub variant: Option<String>,
+    pub registry: Option<String>,
+    /// The file the version was read from, if any.
+    pub file: Option<PathBuf>,
+}
+
+/// One source checked while explaining a resolution.
+#[derive(Debug, Clone)]
+pub struct SourceCheck {
+    pub source: RunSource,
+    /// `None` when the source had no version to offer.
+    pub offer: Option<SourceOffer>,
+}
+
+/// Every source checked for a resolution, in order, and how the selected one
+/// was completed.
+#[derive(Debug, Clone)]
+pub struct ResolutionTrace {
+    pub checks: Vec<SourceCheck>,
+    pub selection: Option<RunSelection>,
+    /// The source the variant was taken from, when the request didn't give one.
+    pub variant_inherited_from: Option<RunSource>,
+    /// The source the registry was taken from, when the request didn't give
+    /// one.
+    pub registry_inherited_from: Option<RunSource>,
+    /// The project's version, when it conflicts with the selected one.
+    pub project_mismatch: Option<VersionQuery>,
+    /// The `gdvm.lock` that narrowed the pinned version, if any.
+    pub lock_file: Option<PathBuf>,
+    /// A `gdvm.lock` that was ignored because it disagrees with the pin.
+    pub stale_lock: Option<PathBuf>,
 }
 
 impl<'a, S: RunVersionSource> RunVersionResolver<'a, S> {
@@ -117,14 +172,18 @@ impl<'a, S: RunVersionSource> RunVersionResolver<'a, S> {
                 version: version.clone(),
                 variant: request.variant.clone(),
                 registry: request.registry.clone(),
+                stale_lock: None,
+                pin_file: None,
             }));
         }
 
-        if let Some(selection) = self.source.get_pinned_version().await {
+        if let Some(selection) = self.source.get_pinned_version().await? {
             return Ok(Some(RunSelection {
                 source: RunSource::Pin {
                     gdvmrc_fallback: selection.gdvmrc_fallback,
                 },
+                stale_lock: selection.stale_lock,
+                pin_file: selection.pin_file,
                 version: selection.version,
                 variant: request.variant.clone().or(selection.variant),
                 registry: request.registry.clone().or(selection.registry),
@@ -140,6 +199,8 @@ impl<'a, S: RunVersionSource> RunVersionResolver<'a, S> {
                 version: project_version,
                 variant: request.variant.clone().or(project_variant),
                 registry: request.registry.clone(),
+                stale_lock: None,
+                pin_file: None,
             }));
         }
 
@@ -152,12 +213,119 @@ impl<'a, S: RunVersionSource> RunVersionResolver<'a, S> {
                     .clone()
                     .or_else(|| Some(selection.variant.as_str().to_string())),
                 registry: request.registry.clone().or(selection.registry),
+                stale_lock: None,
+                pin_file: None,
             }));
         }
 
         Ok(None)
     }
 
+    /// Check every source in order, not just the first that offers a version,
+    /// and record what each offered and how the selection was completed.
+    pub async fn explain(&self, request: &RunResolutionRequest<'_>) -> Result<ResolutionTrace> {
+        let selection = self.select(request).await?;
+        let path_bufs = request.path_bufs();
+
+        let explicit = request.explicit.clone().map(|version| SourceOffer {
+            version,
+            variant: request.variant.clone(),
+            registry: request.registry.clone(),
+            file: None,
+        });
+
+        let pin = self.source.get_pinned_version().await?;
+        let pin_source = RunSource::Pin {
+            gdvmrc_fallback: pin.as_ref().is_some_and(|pin| pin.gdvmrc_fallback),
+        };
+        let lock_file = pin.as_ref().and_then(|pin| pin.lock_file.clone());
+        let stale_lock = pin.as_ref().and_then(|pin| pin.stale_lock.clone());
+        let pin = pin.map(|pin| SourceOffer {
+            version: pin.version,
+            variant: pin.variant,
+            registry: pin.registry,
+            file: pin.pin_file,
+        });
+
+        let mut project = None;
+        for path in path_bufs.iter().map(Some).chain([None]) {
+            if let Some((version, variant)) = self.source.determine_version(path).await {
+                project = Some(SourceOffer {
+                    version,
+                    variant,
+                    registry: None,
+                    file: self.source.project_file(path).await,
+                });
+                break;
+            }
+        }
+
+        let default = self.source.get_default().await?.map(|default| SourceOffer {
+            version: default.version.into(),
+            variant: Some(default.variant.as_str().to_string()),
+            registry: default.registry,
+            file: None,
+        });
+
+        let selected_source = selection.as_ref().map(|selection| selection.source);
+        let inherited = |given: bool, taken: Option<&String>| {
+            selected_source.filter(|_| !given && taken.is_some())
+        };
+        let variant_inherited_from = inherited(
+            request.variant.is_some(),
+            selection.as_ref().and_then(|s| s.variant.as_ref()),
+        );
+        let registry_inherited_from = inherited(
+            request.registry.is_some(),
+            selection.as_ref().and_then(|s| s.registry.as_ref()),
+        );
+
+        // Mirrors the checks `resolve` makes before using an explicit or pinned
+        // version.
+        let project_mismatch = match &selection {
+            Some(selection)
+                if matches!(
+                    selection.source,
+                    RunSource::Explicit | RunSource::Pin { .. }
+                ) =>
+            {
+                project
+                    .as_ref()
+                    .map(|project| &project.version)
+                    .filter(|version| version.conflicts_with(&selection.version))
+                    .cloned()
+            }
+            _ => None,
+        };
+
+        Ok(ResolutionTrace {
+            checks: vec![
+                SourceCheck {
+                    source: RunSource::Explicit,
+                    offer: explicit,
+                },
+                SourceCheck {
+                    source: pin_source,
+                    offer: pin,
+                },
+                SourceCheck {
+                    source: RunSource::Project,
+                    offer: project,
+                },
+                SourceCheck {
+                    source: RunSource::Default,
+                    offer: default,
+                },
+            ],
+            selection,
+            variant_inherited_from,
+            registry_inherited_from,
+            project_mismatch,
+            lock_file,
+            stale_lock,
+        })
+    }
+
     /// Resolve the Godot version to use. Installs it if requested.
     pub async fn resolve(&self, request: RunResolutionRequest<'_>) -> Result<RunResolutionResult> {
         let Some(selection) = self.select(&request).await? else {
@@ -183,6 +351,12 @@ impl<'a, S: RunVersionSource> RunVersionResolver<'a, S> {
                 if gdvmrc_fallback {
                     ui::warn(t!("warning-gdvmrc-detected"));
                 }
+                if let Some(path) = &selection.stale_lock {
+                    ui::warn(t!(
+                        "warning-lock-out-of-date",
+                        path = path.display().to_string()
+                    ));
+                }
                 if warn_project_version_mismatch::<S, PathBuf>(
                     self.source,
                     &selection.version,
@@ -293,6 +467,8 @@ mod tests {
     struct FakeSource {
         pinned: Option<VersionQuery>,
         pin_registry: Option<String>,
+        pin_lock_file: Option<PathBuf>,
+        pin_stale_lock: Option<PathBuf>,
         project_versions: HashMap<String, VersionQuery>,
         default: Option<ResolvedVersion>,
         auto_result: Option<ResolvedVersion>,
@@ -303,6 +479,8 @@ mod tests {
             Self {
                 pinned: None,
                 pin_registry: None,
+                pin_lock_file: None,
+                pin_stale_lock: None,
                 project_versions: HashMap::new(),
                 default: None,
                 auto_result: None,
@@ -324,6 +502,15 @@ mod tests {
             self
         }
 
+        fn with_pin_lock(mut self, path: &str, stale: bool) -> Self {
+            if stale {
+                self.pin_stale_lock = Some(PathBuf::from(path));
+            } else {
+                self.pin_lock_file = Some(PathBuf::from(path));
+            }
+            self
+        }
+
         fn with_default(mut self, gv: ResolvedVersion) -> Self {
             self.default = Some(gv);
             self
@@ -337,13 +524,16 @@ mod tests {
 
     #[async_trait::async_trait(?Send)]
     impl RunVersionSource for FakeSource {
-        async fn get_pinned_version(&self) -> Option<QuerySelection> {
-            self.pinned.clone().map(|version| QuerySelection {
+        async fn get_pinned_version(&self) -> Result<Option<QuerySelection>> {
+            Ok(self.pinned.clone().map(|version| QuerySelection {
                 version,
                 variant: None,
                 registry: self.pin_registry.clone(),
                 gdvmrc_fallback: false,
-            })
+                stale_lock: self.pin_stale_lock.clone(),
+                lock_file: self.pin_lock_file.clone(),
+                pin_file: None,
+            }))
         }
 
         async fn get_default(&self) -> Result<Option<ResolvedSelection>> {
@@ -372,6 +562,13 @@ mod tests {
             }
         }
 
+        async fn project_file<P: AsRef<Path> + Send + Sync>(
+            &self,
+            _path: Option<P>,
+        ) -> Option<PathBuf> {
+            None
+        }
+
         async fn auto_install_version<T>(
             &self,
             gv: &T,
@@ -413,6 +610,7 @@ mod tests {
             patch: Some(0),
             subpatch: None,
             release_type: Some(release.to_string()),
+            range: None,
         }
     }
 
@@ -597,4 +795,105 @@ mod tests {
         );
         assert_eq!(selection.registry.as_deref(), Some("mybuilds"));
     }
+
+    #[tokio::test]
+    async fn explain_checks_every_source() {
+        let source = FakeSource::new()
+            .with_pinned(gv(4, 3, "stable"))
+            .with_pin_registry("mybuilds")
+            .with_project("<cwd>", gv(4, 2, "stable"))
+            .with_default(gvd(4, 4, "stable"));
+        let resolver = RunVersionResolver::new(&source);
+        let request = RunResolutionRequest {
+            explicit: None,
+            variant: None,
+            registry: None,
+            include_pre: false,
+            possible_paths: &[],
+            force_on_mismatch: false,
+            install_if_missing: false,
+        };
+
+        let trace = resolver.explain(&request).await.unwrap();
+        let offered: Vec<_> = trace
+            .checks
+            .iter()
+            .map(|check| (check.source.name(), check.offer.is_some()))
+            .collect();
+        assert_eq!(
+            offered,
+            [
+                ("explicit", false),
+                ("pin", true),
+                ("project", true),
+                ("default", true)
+            ]
+        );
+        assert_eq!(trace.selection.unwrap().version, gv(4, 3, "stable"));
+        assert_eq!(
+            trace.registry_inherited_from,
+            Some(RunSource::Pin {
+                gdvmrc_fallback: false
+            })
+        );
+        assert_eq!(trace.variant_inherited_from, None);
+        assert_eq!(trace.project_mismatch, Some(gv(4, 2, "stable")));
+    }
+
+    #[tokio::test]
+    async fn explain_keeps_the_requested_variant() {
+        let source = FakeSource::new()
+            .with_default(gvd(4, 4, "stable"))
+            .with_project("<cwd>", gv(4, 2, "stable"));
+        let resolver = RunVersionResolver::new(&source);
+        let request = RunResolutionRequest {
+            explicit: None,
+            variant: Some("csharp".to_string()),
+            registry: None,
+            include_pre: false,
+            possible_paths: &[],
+            force_on_mismatch: false,
+            install_if_missing: false,
+        };
+
+        let trace = resolver.explain(&request).await.unwrap();
+        let selection = trace.selection.unwrap();
+        assert_eq!(selection.source, RunSource::Project);
+        assert_eq!(selection.variant.as_deref(), Some("csharp"));
+        assert_eq!(trace.variant_inherited_from, None);
+        assert_eq!(trace.project_mismatch, None);
+    }
+
+    #[tokio::test]
+    async fn explain_records_the_lock() {
+        let request = RunResolutionRequest {
+            explicit: None,
+            variant: None,
+            registry: None,
+            include_pre: false,
+            possible_paths: &[],
+            force_on_mismatch: false,
+            install_if_missing: false,
+        };
+
+        let source = FakeSource::new()
+            .with_pinned(gv(4, 3, "stable"))
+            .with_pin_lock("gdvm.lock", false);
+        let trace = RunVersionResolver::new(&source)
+            .explain(&request)
+            .await
+            .unwrap();
+        assert_eq!(trace.lock_file, Some(PathBuf::from("gdvm.lock")));
+        assert_eq!(trace.stale_lock, None);
+
+        let source = FakeSource::new()
+            .with_pinned(gv(4, 3, "stable"))
+            .with_pin_lock("gdvm.lock", true);
+        let trace = RunVersionResolver::new(&source)
+            .explain(&request)
+            .await
+            .unwrap();
+        assert_eq!(trace.lock_file, None);
+        assert_eq!(trace.stale_lock, Some(PathBuf::from("gdvm.lock")));
+    }
 }
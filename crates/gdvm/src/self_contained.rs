@@ -0,0 +1,302 @@
+// SPDX-FileCopyrightText: Copyright (C) 2024 Adaline Simonian
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// This file is part of gdvm.
+//
+// gdvm is free software: you can redistribute it and/or modify it under the
+// terms of the GNU General Public License as published by the Free Software
+// Foundation, either version 3 of the License, or (at your option) any later
+// version.
+//
+// gdvm is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR
+// A PARTICULAR PURPOSE. See the GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with
+// this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Godot's self-contained mode, where the editor keeps its settings, caches
+//! and export templates apart from those of every other Godot.
+//!
+//! An install is made self-contained with Godot's own marker file, which makes
+//! it keep its data in a directory next to the executable. A project can't put
+//! a marker next to a shared executable, so its Godot is pointed at a data
+//! directory of its own through the XDG base directory variables instead. Godot
+//! reads those on Linux, the BSDs and macOS, but not on Windows.
+
+use std::ffi::OsStr;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+
+use crate::hash_utils::{ShaType, hash_reader};
+
+/// The marker file that makes the Godot next to it self-contained.
+pub const MARKER_FILE: &str = "._sc_";
+
+/// The other marker file name Godot accepts.
+const ALT_MARKER_FILE: &str = "_sc_";
+
+/// Where a self-contained Godot keeps its data, next to the marker.
+pub const EDITOR_DATA_DIR: &str = "editor_data";
+
+/// The file in a project's data directory naming the project it belongs to.
+const PROJECT_FILE: &str = ".project";
+
+/// True when the install in `install_dir` is self-contained.
+pub fn is_enabled(install_dir: &Path) -> bool {
+    install_dir.join(MARKER_FILE).is_file() || install_dir.join(ALT_MARKER_FILE).is_file()
+}
+
+/// Make the install in `install_dir` self-contained.
+pub fn enable(install_dir: &Path) -> io::Result<()> {
+    if is_enabled(install_dir) {
+        return Ok(());
+    }
+    fs::write(install_dir.join(MARKER_FILE), b"")
+}
+
+/// The data directory of a self-contained install.
+pub fn editor_data(install_dir: &Path) -> PathBuf {
+    install_dir.join(EDITOR_DATA_DIR)
+}
+
+/// True for the top-level entries of an install that belong to its
+/// self-contained mode rather than to the release.
+pub fn is_install_entry(name: &OsStr) -> bool {
+    name == MARKER_FILE || name == ALT_MARKER_FILE || name == EDITOR_DATA_DIR
+}
+
+/// Where the self-contained state of an install is kept while the install is
+/// replaced.
+fn set_aside_path(install_dir: &Path) -> PathBuf {
+    let name = install_dir
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_default();
+    install_dir.with_file_name(format!(".self-contained-{name}"))
+}
+
+/// Move the marker and data of a self-contained install out of it, so they
+/// survive a reinstall. Does nothing for other installs.
+pub fn set_aside(install_dir: &Path) -> io::Result<()> {
+    if !is_enabled(install_dir) {
+        return Ok(());
+    }
+    let aside = set_aside_path(install_dir);
+    fs::create_dir_all(&aside)?;
+    for entry in fs::read_dir(install_dir)?.flatten() {
+        let name = entry.file_name();
+        if is_install_entry(&name) && !aside.join(&name).exists() {
+            fs::rename(entry.path(), aside.join(&name))?;
+        }
+    }
+    Ok(())
+}
+
+/// Move state kept by `set_aside` into `target`, usually the new install in
+/// `install_dir` itself. State left behind by a reinstall that failed is
+/// picked up by the next one.
+pub fn restore(install_dir: &Path, target: &Path) -> io::Result<()> {
+    let aside = set_aside_path(install_dir);
+    if !aside.is_dir() {
+        return Ok(());
+    }
+    for entry in fs::read_dir(&aside)?.flatten() {
+        fs::rename(entry.path(), target.join(entry.file_name()))?;
+    }
+    fs::remove_dir(aside)
+}
+
+/// The data directory for `project` under `root`. It is named after the
+/// project's directory, with a hash of its path to tell apart projects of the
+/// same name.
+pub fn project_data_dir(root: &Path, project: &Path) -> PathBuf {
+    let path = project.to_string_lossy();
+    let digest = hash_reader(ShaType::Sha256, &mut path.as_bytes()).unwrap_or_default();
+    let name = crate::launch_logs::file_name_part(
+        &project
+            .file_name()
+            .map(|name| name.to_string_lossy())
+            .unwrap_or_default(),
+    );
+    root.join(format!("{name}-{}", &digest[..digest.len().min(12)]))
+}
+
+/// Create the data directory for `project` under `root`, recording which
+/// project it belongs to.
+pub fn prepare_project(root: &Path, project: &Path) -> Result<PathBuf> {
+    let dir = project_data_dir(root, project);
+    fs::create_dir_all(&dir)?;
+    let record = dir.join(PROJECT_FILE);
+    if crate::fs_utils::read_marker_line(&record)?.as_deref()
+        != Some(project.to_string_lossy().as_ref())
+    {
+        crate::fs_utils::write_marker_line(&record, &project.to_string_lossy())?;
+    }
+    Ok(dir)
+}
+
+/// The config home Godot is given for a project's data directory, which holds
+/// its editor settings.
+pub fn project_config_home(data_dir: &Path) -> PathBuf {
+    data_dir.join("config")
+}
+
+/// Whether Godot can be pointed at a project's data directory on this
+/// platform. On Windows it only reads `APPDATA`, which every program it starts
+/// would read as well.
+pub const PROJECT_DATA_SUPPORTED: bool = cfg!(not(target_os = "windows"));
+
+/// The XDG base directory variables, the directory each points to in a
+/// project's data directory, and the user's own directory when it isn't set.
+const XDG_HOMES: [(&str, &str, &str); 3] = [
+    ("XDG_CONFIG_HOME", "config", ".config"),
+    ("XDG_DATA_HOME", "data", ".local/share"),
+    ("XDG_CACHE_HOME", "cache", ".cache"),
+];
+
+/// The environment variables that point Godot at a project's data directory.
+pub fn project_env(data_dir: &Path) -> [(&'static str, PathBuf); 3] {
+    XDG_HOMES.map(|(var, name, _)| (var, data_dir.join(name)))
+}
+
+/// Link everything in the user's own XDG directories except Godot's folder into
+/// the project's, so that programs Godot starts, such as an external script
+/// editor, still find their files through the variables they inherit. Entries
+/// already there are left alone.
+#[cfg(target_family = "unix")]
+pub fn link_user_dirs(data_dir: &Path) -> Result<()> {
+    let home = crate::paths::get_home_dir()?;
+    for (var, name, fallback) in XDG_HOMES {
+        let user_dir = std::env::var_os(var)
+            .map(PathBuf::from)
+            .filter(|dir| dir.is_absolute())
+            .unwrap_or_else(|| home.join(fallback));
+        link_entries(&user_dir, &data_dir.join(name))?;
+    }
+    Ok(())
+}
+
+/// Link the entries of `user_dir` other than Godot's folder into `project_dir`.
+#[cfg(target_family = "unix")]
+fn link_entries(user_dir: &Path, project_dir: &Path) -> io::Result<()> {
+    fs::create_dir_all(project_dir)?;
+    let Ok(entries) = fs::read_dir(user_dir) else {
+        return Ok(());
+    };
+    for entry in entries.flatten() {
+        let link = project_dir.join(entry.file_name());
+        if entry.file_name() == crate::editor_settings::GODOT_DIR_NAME
+            || link.symlink_metadata().is_ok()
+        {
+            continue;
+        }
+        std::os::unix::fs::symlink(entry.path(), link)?;
+    }
+    Ok(())
+}
+
+/// The project data directories under `root` whose project no longer exists,
+/// with the path of that project.
+pub fn orphaned_projects(root: &Path) -> Vec<(PathBuf, PathBuf)> {
+    let Ok(entries) = fs::read_dir(root) else {
+        return Vec::new();
+    };
+    let mut orphans: Vec<(PathBuf, PathBuf)> = entries
+        .flatten()
+        .filter(|entry| entry.file_type().is_ok_and(|ft| ft.is_dir()))
+        .filter_map(|entry| {
+            let project = crate::fs_utils::read_marker_line(&entry.path().join(PROJECT_FILE))
+                .ok()
+                .flatten()
+                .map(PathBuf::from)?;
+            (!project.is_dir()).then(|| (entry.path(), project))
+        })
+        .collect();
+    orphans.sort();
+    orphans
+}
+
+#[cfg(test)]
+mod tests {
+    use tempfile::TempDir;
+
+    use super::*;
+
+    #[test]
+    fn reinstall_keeps_the_marker_and_editor_data() {
+        let tmp = TempDir::new().unwrap();
+        let install = tmp.path().join("4.3-stable");
+        fs::create_dir_all(editor_data(&install)).unwrap();
+        fs::write(install.join("Godot"), "old").unwrap();
+        fs::write(editor_data(&install).join("editor_settings-4.tres"), "x").unwrap();
+        enable(&install).unwrap();
+
+        set_aside(&install).unwrap();
+        fs::remove_dir_all(&install).unwrap();
+        let staging = tmp.path().join(".staging-4.3-stable");
+        fs::create_dir_all(&staging).unwrap();
+        fs::rename(&staging, &install).unwrap();
+        restore(&install, &install).unwrap();
+
+        assert!(is_enabled(&install));
+        assert!(
+            editor_data(&install)
+                .join("editor_settings-4.tres")
+                .is_file()
+        );
+        assert_eq!(fs::read_dir(tmp.path()).unwrap().count(), 1);
+    }
+
+    #[cfg(target_family = "unix")]
+    #[test]
+    fn project_dirs_link_the_users_entries_but_not_godots() {
+        let tmp = TempDir::new().unwrap();
+        let user = tmp.path().join("user");
+        let project = tmp.path().join("project");
+        for name in ["code", crate::editor_settings::GODOT_DIR_NAME, "nvim"] {
+            fs::create_dir_all(user.join(name)).unwrap();
+        }
+        fs::create_dir_all(project.join("nvim")).unwrap();
+
+        link_entries(&user, &project).unwrap();
+
+        assert_eq!(
+            fs::read_link(project.join("code")).unwrap(),
+            user.join("code")
+        );
+        assert!(
+            !project
+                .join(crate::editor_settings::GODOT_DIR_NAME)
+                .exists()
+        );
+        assert!(!project.join("nvim").is_symlink());
+    }
+
+    #[test]
+    fn project_data_is_pruned_once_the_project_is_gone() {
+        let tmp = TempDir::new().unwrap();
+        let root = tmp.path().join("editor-data");
+        let kept = tmp.path().join("kept");
+        let gone = tmp.path().join("gone");
+        fs::create_dir_all(&kept).unwrap();
+        fs::create_dir_all(&gone).unwrap();
+
+        let kept_dir = prepare_project(&root, &kept).unwrap();
+        let gone_dir = prepare_project(&root, &gone).unwrap();
+        assert_ne!(kept_dir, gone_dir);
+        assert!(
+            kept_dir
+                .file_name()
+                .unwrap()
+                .to_string_lossy()
+                .starts_with("kept-")
+        );
+
+        fs::remove_dir_all(&gone).unwrap();
+        assert_eq!(orphaned_projects(&root), vec![(gone_dir, gone)]);
+    }
+}
@@ -15,6 +15,7 @@
 // You should have received a copy of the GNU General Public License along with
 // this program. If not, see <https://www.gnu.org/licenses/>.
 
+use std::collections::BTreeSet;
 use std::fs;
 #[cfg(target_family = "unix")]
 use std::os::unix::fs::PermissionsExt;
@@ -22,6 +23,7 @@ use std::path::Path;
 
 use anyhow::Result;
 
+use crate::app::InstalledVersion;
 use crate::terr;
 
 #[cfg(target_os = "windows")]
@@ -53,6 +55,105 @@ pub fn ensure(base_path: &Path) -> Result<()> {
     Ok(())
 }
 
+/// Keep a versioned shim such as `godot4`, `godot4.3` or `godot-csharp4.3` in
+/// `bin_dir` for each series of the stable official installs, and remove the
+/// ones whose series is no longer installed.
+pub fn sync_versioned(bin_dir: &Path, installed: &[InstalledVersion]) -> Result<()> {
+    let wanted: BTreeSet<String> = installed
+        .iter()
+        .filter(|i| {
+            i.version.is_stable()
+                && crate::registry::normalize_registry(i.registry.as_deref()).is_none()
+        })
+        .flat_map(|i| {
+            let prefix = if i.variant.is_default() {
+                "godot".to_string()
+            } else {
+                format!("godot-{}", i.variant.as_str())
+            };
+            [
+                format!("{prefix}{}", i.version.major),
+                format!("{prefix}{}.{}", i.version.major, i.version.minor),
+            ]
+        })
+        .filter(|alias| alias_version_spec(alias).is_some())
+        .flat_map(|alias| {
+            if cfg!(target_os = "windows") {
+                vec![format!("{alias}.exe"), format!("{alias}_console.exe")]
+            } else {
+                vec![alias]
+            }
+        })
+        .collect();
+
+    let result = (|| -> std::io::Result<()> {
+        fs::create_dir_all(bin_dir)?;
+
+        for entry in fs::read_dir(bin_dir)? {
+            let entry = entry?;
+            let name = entry.file_name().to_string_lossy().to_string();
+            if !wanted.contains(&name) && alias_version_spec(&alias_of(&name)).is_some() {
+                fs::remove_file(entry.path())?;
+            }
+        }
+
+        for name in &wanted {
+            write_bytes_if_different(GDVM_SHIM, &bin_dir.join(name), Some(0o755))?;
+        }
+
+        Ok(())
+    })();
+
+    result.map_err(|err| {
+        terr!("error-ensure-godot-binaries-failed")
+            .with_source(err)
+            .into()
+    })
+}
+
+/// The alias a shim passes on in `GDVM_ALIAS`: its lowercase file name
+/// without the `.exe` extension.
+pub fn alias_of(file_name: &str) -> String {
+    let name = file_name.to_lowercase();
+    match name.strip_suffix(".exe") {
+        Some(stem) => stem.to_string(),
+        None => name,
+    }
+}
+
+/// The version spec of a versioned shim alias, e.g. `4.3` for `godot4.3` and
+/// `csharp:4` for `godot-csharp4`. `None` for the generic shims and anything
+/// else.
+pub fn alias_version_spec(alias: &str) -> Option<String> {
+    let alias = alias.strip_suffix("_console").unwrap_or(alias);
+    let rest = alias.strip_prefix("godot")?;
+    let (variant, version) = match rest.strip_prefix('-') {
+        Some(rest) => {
+            let split = rest.find(|c: char| c.is_ascii_digit())?;
+            let (variant, version) = rest.split_at(split);
+            if !variant.chars().all(|c| c.is_ascii_alphabetic()) || variant.is_empty() {
+                return None;
+            }
+            (Some(variant), version)
+        }
+        None => (None, rest),
+    };
+
+    let parts: Vec<&str> = version.split('.').collect();
+    if parts.len() > 2
+        || parts
+            .iter()
+            .any(|part| part.is_empty() || !part.chars().all(|c| c.is_ascii_digit()))
+    {
+        return None;
+    }
+
+    Some(match variant {
+        Some(variant) => format!("{variant}:{version}"),
+        None => version.to_string(),
+    })
+}
+
 /// Write `bytes` to `dest` only if the current contents at `dest` are different.
 fn write_bytes_if_different(bytes: &[u8], dest: &Path, perm: Option<u32>) -> std::io::Result<()> {
     #[cfg(not(target_family = "unix"))]
@@ -99,3 +200,30 @@ fn write_bytes_if_different(bytes: &[u8], dest: &Path, perm: Option<u32>) -> std
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn versioned_aliases_map_to_version_specs() {
+        assert_eq!(alias_version_spec("godot4").as_deref(), Some("4"));
+        assert_eq!(alias_version_spec("godot4.3").as_deref(), Some("4.3"));
+        assert_eq!(
+            alias_version_spec("godot-csharp4.3_console").as_deref(),
+            Some("csharp:4.3")
+        );
+        assert_eq!(alias_of("Godot4.3_console.exe"), "godot4.3_console");
+
+        for alias in [
+            "godot",
+            "godot_console",
+            "godot4.3.1",
+            "godot-4",
+            "godot4.",
+            "gdvm",
+        ] {
+            assert_eq!(alias_version_spec(alias), None, "{alias}");
+        }
+    }
+}
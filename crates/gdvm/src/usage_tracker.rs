@@ -40,6 +40,9 @@ pub struct ArchiveUsage {
 pub struct InstallUsage {
     /// Unix timestamp of the most recent use.
     pub last_used: u64,
+    /// Unix timestamp of the first time gdvm launched Godot from the install.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub first_launched: Option<u64>,
 }
 
 /// A link that gdvm created on the user's behalf, pointing into an install.
@@ -51,6 +54,41 @@ pub struct LinkRecord {
     pub last_used: u64,
 }
 
+/// A Godot process that gdvm started. The record may outlive the process.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProcessRecord {
+    /// The install subpath the process runs from.
+    pub install_key: String,
+    /// The executable the process was started from.
+    pub executable: PathBuf,
+    /// The project directory Godot was started for, if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project: Option<PathBuf>,
+    /// Unix timestamp when the process was started.
+    pub started: u64,
+    /// The system's start time for the process, which tells it apart from a
+    /// later process that reuses the PID. Missing where it can't be read.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<u64>,
+}
+
+impl ProcessRecord {
+    /// Whether the process with this PID is still the one that was recorded.
+    pub fn is_running(&self, pid: u32) -> bool {
+        crate::process_utils::is_process_running(pid, &self.executable)
+            && self
+                .start_time
+                .is_none_or(|time| crate::process_utils::process_start_time(pid) == Some(time))
+    }
+
+    /// Whether the process with this PID is known to be the one that was
+    /// recorded, rather than possibly another that reused the PID.
+    pub fn is_confirmed(&self, pid: u32) -> bool {
+        self.start_time
+            .is_some_and(|time| crate::process_utils::process_start_time(pid) == Some(time))
+    }
+}
+
 /// The full on-disk usage state.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct UsageState {
@@ -65,6 +103,9 @@ pub struct UsageState {
     /// Links keyed by the absolute link path.
     #[serde(default)]
     pub links: HashMap<String, LinkRecord>,
+    /// Godot processes keyed by PID.
+    #[serde(default)]
+    pub processes: HashMap<u32, ProcessRecord>,
 }
 
 impl Default for UsageState {
@@ -74,10 +115,26 @@ impl Default for UsageState {
             archives: HashMap::new(),
             installs: HashMap::new(),
             links: HashMap::new(),
+            processes: HashMap::new(),
         }
     }
 }
 
+impl UsageState {
+    /// Mark an install as used at `now`, keeping the rest of its record.
+    fn touch_install(&mut self, install_key: &str, now: u64) -> &mut InstallUsage {
+        let usage = self
+            .installs
+            .entry(install_key.to_string())
+            .or_insert(InstallUsage {
+                last_used: now,
+                first_launched: None,
+            });
+        usage.last_used = now;
+        usage
+    }
+}
+
 /// Loads, updates, and persists the usage state file.
 pub struct UsageTracker {
     path: PathBuf,
@@ -136,23 +193,42 @@ impl UsageTracker {
 
     /// Record that the install with the given subpath key was used now.
     pub fn record_install(&self, install_key: &str) -> Result<()> {
+        let now = now_unix_secs();
+        self.update(|state| {
+            state.touch_install(install_key, now);
+        })
+    }
+
+    /// Record that gdvm launched Godot from the install with the given subpath
+    /// key now.
+    pub fn record_launch(&self, install_key: &str) -> Result<()> {
         let now = now_unix_secs();
         self.update(|state| {
             state
-                .installs
-                .insert(install_key.to_string(), InstallUsage { last_used: now });
+                .touch_install(install_key, now)
+                .first_launched
+                .get_or_insert(now);
         })
     }
 
+    /// The keys of the installs gdvm has launched Godot from.
+    pub fn launched_installs(&self) -> Result<Vec<String>> {
+        Ok(self
+            .load()?
+            .installs
+            .into_iter()
+            .filter(|(_, usage)| usage.first_launched.is_some())
+            .map(|(key, _)| key)
+            .collect())
+    }
+
     /// Record that a link at `link_path` pointing into `install_key` was made.
     pub fn record_link(&self, link_path: &Path, install_key: &str) -> Result<()> {
         let now = now_unix_secs();
         let key = link_key(link_path);
         let install_key = install_key.to_string();
         self.update(|state| {
-            state
-                .installs
-                .insert(install_key.clone(), InstallUsage { last_used: now });
+            state.touch_install(&install_key, now);
             state.links.insert(
                 key,
                 LinkRecord {
@@ -163,6 +239,45 @@ impl UsageTracker {
         })
     }
 
+    /// Record that gdvm started a Godot process, and forget processes that have
+    /// since exited.
+    pub fn record_process(&self, pid: u32, record: ProcessRecord) -> Result<()> {
+        self.update(|state| {
+            state.processes.retain(|pid, rec| rec.is_running(*pid));
+            state.processes.insert(pid, record);
+        })
+    }
+
+    /// Forget a process, once it has exited or been stopped.
+    pub fn forget_process(&self, pid: u32) -> Result<()> {
+        self.update(|state| {
+            state.processes.remove(&pid);
+        })
+    }
+
+    /// The recorded processes that are still running, oldest first.
+    pub fn running_processes(&self) -> Result<Vec<(u32, ProcessRecord)>> {
+        let mut running: Vec<_> = self
+            .load()?
+            .processes
+            .into_iter()
+            .filter(|(pid, rec)| rec.is_running(*pid))
+            .collect();
+        running.sort_by_key(|(pid, rec)| (rec.started, *pid));
+        Ok(running)
+    }
+
+    /// The PIDs of running processes started from the install with the given
+    /// subpath key.
+    pub fn processes_using(&self, install_key: &str) -> Result<Vec<u32>> {
+        Ok(self
+            .running_processes()?
+            .into_iter()
+            .filter(|(_, rec)| rec.install_key == install_key)
+            .map(|(pid, _)| pid)
+            .collect())
+    }
+
     /// Forget a single install and any links recorded against it.
     pub fn forget_install(&self, install_key: &str) -> Result<()> {
         self.update(|state| {
@@ -242,6 +357,40 @@ mod tests {
         assert_eq!(rec.install_key, "official-x/default/4.3-stable");
     }
 
+    #[test]
+    fn running_processes_skips_exited_ones() {
+        let (_tmp, t) = tracker();
+        let record = |executable: PathBuf| ProcessRecord {
+            install_key: "k".to_string(),
+            executable,
+            project: None,
+            started: now_unix_secs(),
+            start_time: None,
+        };
+        let this = std::process::id();
+        t.record_process(this, record(std::env::current_exe().unwrap()))
+            .unwrap();
+        t.record_process(u32::MAX, record(PathBuf::from("gone")))
+            .unwrap();
+
+        let running = t.running_processes().unwrap();
+        assert_eq!(running.len(), 1);
+        assert_eq!(running[0].0, this);
+        assert_eq!(t.processes_using("k").unwrap(), [this]);
+        assert!(t.processes_using("other").unwrap().is_empty());
+
+        t.forget_process(this).unwrap();
+        assert!(t.running_processes().unwrap().is_empty());
+
+        // A process that reused the PID has another start time.
+        let reused = ProcessRecord {
+            start_time: Some(u64::MAX),
+            ..record(std::env::current_exe().unwrap())
+        };
+        assert!(!reused.is_running(this));
+        assert!(!reused.is_confirmed(this));
+    }
+
     #[test]
     fn forget_install_removes_links() {
         let (_tmp, t) = tracker();
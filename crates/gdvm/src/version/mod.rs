@@ -16,18 +16,20 @@
 // this program. If not, see <https://www.gnu.org/licenses/>.
 
 mod query;
+mod range;
 mod resolved;
 mod selection;
 mod spec;
 mod variant;
 
 pub use query::VersionQuery;
+pub use range::VersionRange;
 pub use resolved::{ResolvedVersion, cmp_versions_newest_first, split_release_tag};
 pub use selection::{
     QuerySelection, ResolvedSelection, display_version, install_dir_subpath, legacy_pinned_str,
     parse_pinned_str, pinned_str,
 };
-pub use spec::{VersionSpec, VersionTarget, validate_version_spec};
+pub use spec::{VersionSpec, VersionTarget, expand_alias, validate_version_spec};
 pub use variant::Variant;
 
 #[cfg(test)]
@@ -17,16 +17,19 @@
 
 use std::fmt;
 
+use super::range::VersionRange;
 use super::resolved::ResolvedVersion;
 use crate::terr;
 
-#[derive(Debug, Default)]
+#[derive(Debug, Default, PartialEq, Eq)]
 pub struct VersionQuery {
     pub major: Option<u32>,
     pub minor: Option<u32>,
     pub patch: Option<u32>,
     pub subpatch: Option<u32>,
     pub release_type: Option<String>,
+    /// Comparator range the version must also satisfy, e.g. `>=4.2, <4.5`.
+    pub range: Option<VersionRange>,
 }
 
 impl From<ResolvedVersion> for VersionQuery {
@@ -49,6 +52,7 @@ impl Clone for VersionQuery {
             patch: self.patch,
             subpatch: self.subpatch,
             release_type: self.release_type.clone(),
+            range: self.range.clone(),
         }
     }
 }
@@ -69,8 +73,15 @@ impl VersionQuery {
         Self::parse_version_and_pre_release(clean)
     }
 
-    /// Parse a remote version tag, e.g. "4.1-stable", "3-rc1".
+    /// Parse a remote version tag, e.g. "4.1-stable", "3-rc1", or a range of
+    /// versions, e.g. ">=4.2, <4.5".
     pub fn from_remote_str(s: &str) -> Result<Self, anyhow::Error> {
+        if VersionRange::is_range(s) {
+            return Ok(VersionQuery {
+                range: Some(VersionRange::parse(s)?),
+                ..Default::default()
+            });
+        }
         Self::parse_version_and_pre_release(s)
     }
 
@@ -141,8 +152,11 @@ impl VersionQuery {
         Some(base)
     }
 
-    /// Convert to a display-friendly string, e.g. "4.1.1-rc1".
+    /// Convert to a display-friendly string, e.g. "4.1.1-rc1" or ">=4.2, <4.5".
     pub fn to_display_str(&self) -> Option<String> {
+        if let Some(range) = &self.range {
+            return Some(range.to_string());
+        }
         self.to_remote_str()
     }
 
@@ -201,6 +215,7 @@ impl VersionQuery {
             patch,
             subpatch,
             release_type: pre_release,
+            range: None,
         })
     }
 
@@ -255,6 +270,11 @@ impl VersionQuery {
     {
         let other: VersionQuery = other.clone().into();
 
+        if let Some(range) = &self.range
+            && !range.matches(&other.to_resolved())
+        {
+            return false;
+        }
         if let Some(major) = self.major
             && other.major.is_some_and(|x| x != major)
         {
@@ -543,6 +563,7 @@ mod tests {
             patch: None,
             subpatch: None,
             release_type: None,
+            range: None,
         };
 
         let requested = VersionQuery::from_match_str("3.2").unwrap();
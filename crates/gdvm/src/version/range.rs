@@ -0,0 +1,283 @@
+// SPDX-FileCopyrightText: Copyright (C) 2024 Adaline Simonian
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// This file is part of gdvm.
+//
+// gdvm is free software: you can redistribute it and/or modify it under the
+// terms of the GNU General Public License as published by the Free Software
+// Foundation, either version 3 of the License, or (at your option) any later
+// version.
+//
+// gdvm is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR
+// A PARTICULAR PURPOSE. See the GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with
+// this program. If not, see <https://www.gnu.org/licenses/>.
+
+use std::fmt;
+
+use super::query::VersionQuery;
+use super::resolved::ResolvedVersion;
+use crate::terr;
+
+/// A comparator range such as `>=4.2, <4.5`, `~4.3`, or `^4`.
+///
+/// Bounds without a release tag compare version numbers only, so `<4.5`
+/// excludes every 4.5 pre-release as well, and `>=4.2` includes 4.2 previews.
+/// Bounds with a release tag, like `>=4.3-rc1`, also order by release.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VersionRange {
+    comparators: Vec<Comparator>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Op {
+    Exact,
+    Greater,
+    GreaterEq,
+    Less,
+    LessEq,
+    Tilde,
+    Caret,
+}
+
+impl Op {
+    fn as_str(self) -> &'static str {
+        match self {
+            Op::Exact => "=",
+            Op::Greater => ">",
+            Op::GreaterEq => ">=",
+            Op::Less => "<",
+            Op::LessEq => "<=",
+            Op::Tilde => "~",
+            Op::Caret => "^",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Comparator {
+    op: Op,
+    version: VersionQuery,
+}
+
+/// Numeric components of a version, with missing components as zero.
+type Numbers = (u32, u32, u32, u32);
+
+fn numbers(v: &ResolvedVersion) -> Numbers {
+    (v.major, v.minor, v.patch, v.subpatch)
+}
+
+impl Comparator {
+    fn parse(input: &str, range: &str) -> Result<Self, anyhow::Error> {
+        let invalid = || terr!("error-invalid-version-range", input = range);
+
+        let input = input.trim();
+        let (op, rest) = [
+            (">=", Op::GreaterEq),
+            ("<=", Op::LessEq),
+            (">", Op::Greater),
+            ("<", Op::Less),
+            ("=", Op::Exact),
+            ("~", Op::Tilde),
+            ("^", Op::Caret),
+        ]
+        .into_iter()
+        .find_map(|(prefix, op)| input.strip_prefix(prefix).map(|rest| (op, rest)))
+        .unwrap_or((Op::Exact, input));
+
+        let version = VersionQuery::from_remote_str(rest.trim()).map_err(|_| invalid())?;
+        if version.major.is_none() || (op != Op::Exact && version.has_release_wildcard()) {
+            return Err(invalid().into());
+        }
+
+        Ok(Self { op, version })
+    }
+
+    /// The lowest version numbers the comparator's version stands for.
+    fn floor(&self) -> Numbers {
+        let v = &self.version;
+        (
+            v.major.unwrap_or(0),
+            v.minor.unwrap_or(0),
+            v.patch.unwrap_or(0),
+            v.subpatch.unwrap_or(0),
+        )
+    }
+
+    /// The version numbers just past everything the comparator's version
+    /// stands for, e.g. 4.3 for `4.2` and 4.2.2 for `4.2.1`.
+    fn ceiling(&self) -> Numbers {
+        let v = &self.version;
+        let major = v.major.unwrap_or(0);
+        match (v.minor, v.patch, v.subpatch) {
+            (None, _, _) => (major + 1, 0, 0, 0),
+            (Some(minor), None, _) => (major, minor + 1, 0, 0),
+            (Some(minor), Some(patch), None) => (major, minor, patch + 1, 0),
+            (Some(minor), Some(patch), Some(subpatch)) => (major, minor, patch, subpatch + 1),
+        }
+    }
+
+    /// The comparator's version as a release, when it has a release tag.
+    fn release(&self) -> Option<ResolvedVersion> {
+        self.version
+            .release_type
+            .is_some()
+            .then(|| self.version.to_resolved())
+    }
+
+    /// Whether `v` is at or above the comparator's version.
+    fn above_floor(&self, v: &ResolvedVersion) -> bool {
+        match self.release() {
+            Some(release) => *v >= release,
+            None => numbers(v) >= self.floor(),
+        }
+    }
+
+    /// The upper bound of `~` and `^` comparators.
+    fn compatible_ceiling(&self) -> Numbers {
+        let v = &self.version;
+        let (major, minor, patch, _) = self.floor();
+        match self.op {
+            Op::Tilde if v.minor.is_some() => (major, minor + 1, 0, 0),
+            Op::Caret if major == 0 && v.minor.is_some() => {
+                if minor > 0 || v.patch.is_none() {
+                    (0, minor + 1, 0, 0)
+                } else {
+                    (0, 0, patch + 1, 0)
+                }
+            }
+            _ => (major + 1, 0, 0, 0),
+        }
+    }
+
+    fn matches(&self, v: &ResolvedVersion) -> bool {
+        match self.op {
+            Op::Exact => self.version.matches(v),
+            Op::GreaterEq => self.above_floor(v),
+            Op::Greater => match self.release() {
+                Some(release) => *v > release,
+                None => numbers(v) >= self.ceiling(),
+            },
+            Op::Less => match self.release() {
+                Some(release) => *v < release,
+                None => numbers(v) < self.floor(),
+            },
+            Op::LessEq => match self.release() {
+                Some(release) => *v <= release,
+                None => numbers(v) < self.ceiling(),
+            },
+            Op::Tilde | Op::Caret => self.above_floor(v) && numbers(v) < self.compatible_ceiling(),
+        }
+    }
+}
+
+impl VersionRange {
+    /// Whether a version string is written as a range rather than a pattern.
+    pub fn is_range(input: &str) -> bool {
+        input.contains(',') || input.trim_start().starts_with(['<', '>', '=', '~', '^'])
+    }
+
+    /// Parse a comma-separated list of comparators.
+    pub fn parse(input: &str) -> Result<Self, anyhow::Error> {
+        let comparators = input
+            .split(',')
+            .map(|part| Comparator::parse(part, input))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self { comparators })
+    }
+
+    /// Whether `v` satisfies every comparator.
+    pub fn matches(&self, v: &ResolvedVersion) -> bool {
+        self.comparators.iter().all(|c| c.matches(v))
+    }
+}
+
+impl fmt::Display for VersionRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, comparator) in self.comparators.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            write!(f, "{}{}", comparator.op.as_str(), comparator.version)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn v(tag: &str) -> ResolvedVersion {
+        VersionQuery::from_remote_str(tag).unwrap().to_resolved()
+    }
+
+    fn range(input: &str) -> VersionRange {
+        VersionRange::parse(input).unwrap()
+    }
+
+    #[test]
+    fn bounded_range_compares_version_numbers() {
+        let r = range(">=4.2, <4.5");
+        assert!(r.matches(&v("4.2-stable")));
+        assert!(r.matches(&v("4.2-rc1")));
+        assert!(r.matches(&v("4.4.1-stable")));
+        assert!(!r.matches(&v("4.5-stable")));
+        assert!(!r.matches(&v("4.5-beta2")));
+        assert!(!r.matches(&v("4.1.4-stable")));
+    }
+
+    #[test]
+    fn partial_versions_cover_every_patch() {
+        assert!(range("<=4.4").matches(&v("4.4.1-stable")));
+        assert!(!range("<=4.4").matches(&v("4.5-stable")));
+        assert!(!range(">4.2").matches(&v("4.2.2-stable")));
+        assert!(range(">4.2").matches(&v("4.3-stable")));
+        assert!(range("=4.3").matches(&v("4.3.1-stable")));
+        assert!(!range("=4.3").matches(&v("4.4-stable")));
+    }
+
+    #[test]
+    fn tilde_and_caret_ranges() {
+        assert!(range("~4.3").matches(&v("4.3.1-stable")));
+        assert!(!range("~4.3").matches(&v("4.4-stable")));
+        assert!(range("~4").matches(&v("4.4-stable")));
+        assert!(range("^4").matches(&v("4.5-stable")));
+        assert!(!range("^4").matches(&v("5.0-stable")));
+        assert!(!range("^4").matches(&v("3.6-stable")));
+        assert!(range("^4.3").matches(&v("4.6-stable")));
+        assert!(!range("^4.3").matches(&v("4.2-stable")));
+    }
+
+    #[test]
+    fn release_tags_order_bounds_by_release() {
+        let r = range(">=4.3-rc1");
+        assert!(r.matches(&v("4.3-rc2")));
+        assert!(r.matches(&v("4.3-stable")));
+        assert!(!r.matches(&v("4.3-beta3")));
+
+        assert!(range("<4.3-stable").matches(&v("4.3-rc1")));
+        assert!(!range("<=4.3-rc1").matches(&v("4.3-stable")));
+    }
+
+    #[test]
+    fn detects_and_rejects_ranges() {
+        assert!(VersionRange::is_range(">=4.2, <4.5"));
+        assert!(VersionRange::is_range("^4"));
+        assert!(!VersionRange::is_range("4.3"));
+        assert!(!VersionRange::is_range("4.3-rc*"));
+
+        assert!(VersionRange::parse(">=").is_err());
+        assert!(VersionRange::parse(">=4.2,").is_err());
+        assert!(VersionRange::parse(">=four").is_err());
+        assert!(VersionRange::parse(">=4.2-rc*").is_err());
+    }
+
+    #[test]
+    fn displays_normalized_comparators() {
+        assert_eq!(range(">=4.2,<4.5").to_string(), ">=4.2, <4.5");
+        assert_eq!(range("^4").to_string(), "^4");
+    }
+}
@@ -121,6 +121,7 @@ impl ResolvedVersion {
             patch: Some(self.patch),
             subpatch: Some(self.subpatch),
             release_type: Some(self.release_type.clone()),
+            range: None,
         }
     }
 }
@@ -15,6 +15,8 @@
 // You should have received a copy of the GNU General Public License along with
 // this program. If not, see <https://www.gnu.org/licenses/>.
 
+use std::path::PathBuf;
+
 use super::query::VersionQuery;
 use super::resolved::ResolvedVersion;
 use super::variant::Variant;
@@ -35,6 +37,13 @@ pub struct QuerySelection {
     pub variant: Option<String>,
     pub registry: Option<String>,
     pub gdvmrc_fallback: bool,
+    /// A `gdvm.lock` next to the pin that was ignored because it no longer
+    /// agrees with it.
+    pub stale_lock: Option<PathBuf>,
+    /// The `gdvm.lock` next to the pin that narrowed the version, if any.
+    pub lock_file: Option<PathBuf>,
+    /// The file the version was pinned in, if it came from a pin.
+    pub pin_file: Option<PathBuf>,
 }
 
 /// Get the resolved version string for display, with any optional components
@@ -15,6 +15,9 @@
 // You should have received a copy of the GNU General Public License along with
 // this program. If not, see <https://www.gnu.org/licenses/>.
 
+use std::collections::HashMap;
+use std::fmt;
+
 use super::query::VersionQuery;
 use crate::terr;
 
@@ -42,6 +45,29 @@ pub enum VersionTarget {
     Pattern(VersionQuery),
 }
 
+impl VersionTarget {
+    /// The version query this target matches.
+    pub fn to_query(&self) -> VersionQuery {
+        match self {
+            VersionTarget::Keyword(kw) if kw == "stable" => VersionQuery {
+                release_type: Some("stable".to_string()),
+                ..Default::default()
+            },
+            VersionTarget::Keyword(_) => VersionQuery::default(),
+            VersionTarget::Pattern(gv) => gv.clone(),
+        }
+    }
+}
+
+impl fmt::Display for VersionTarget {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            VersionTarget::Keyword(kw) => f.write_str(kw),
+            VersionTarget::Pattern(gv) => f.write_str(&gv.to_display_str().unwrap_or_default()),
+        }
+    }
+}
+
 impl VersionSpec {
     /// Parse a specifier string using the grammar `[registry/][variant:]version_or_keyword`.
     pub fn parse(input: &str) -> Result<Self, anyhow::Error> {
@@ -85,6 +111,21 @@ impl VersionSpec {
     }
 }
 
+/// The spec `input` stands for: the spec of the alias it names, or `input`
+/// itself. Names prefixed with `@` must be aliases.
+pub fn expand_alias<'a>(
+    input: &'a str,
+    aliases: &'a HashMap<String, String>,
+) -> Result<&'a str, anyhow::Error> {
+    if let Some(name) = input.strip_prefix('@') {
+        return match aliases.get(name) {
+            Some(spec) => Ok(spec),
+            None => Err(terr!("error-alias-unknown", name = name).into()),
+        };
+    }
+    Ok(aliases.get(input).map_or(input, String::as_str))
+}
+
 /// Returns true if the string is a known client-side keyword.
 fn is_keyword(s: &str) -> bool {
     matches!(s, "stable" | "latest")
@@ -112,9 +153,28 @@ mod tests {
         assert!(validate_version_spec("latest").is_ok());
         assert!(validate_version_spec("csharp:4.4").is_ok());
         assert!(validate_version_spec("4.1.1-rc1").is_ok());
+        assert!(validate_version_spec(">=4.2, <4.5").is_ok());
+        assert!(validate_version_spec("csharp:^4").is_ok());
+        assert!(validate_version_spec(">=4.2, banana").is_err());
         assert!(validate_version_spec("not-a-version").is_err());
     }
 
+    #[test]
+    fn test_expand_alias() {
+        let aliases = HashMap::from([("work".to_string(), "studio/csharp:4.3.1-rc2".to_string())]);
+
+        for input in ["@work", "work"] {
+            assert_eq!(
+                expand_alias(input, &aliases).unwrap(),
+                "studio/csharp:4.3.1-rc2"
+            );
+        }
+
+        assert!(expand_alias("@home", &aliases).is_err());
+        assert_eq!(expand_alias("home", &aliases).unwrap(), "home");
+        assert_eq!(expand_alias("4.3", &aliases).unwrap(), "4.3");
+    }
+
     #[test]
     fn test_version_spec_parse() {
         // Simple version.
@@ -0,0 +1,97 @@
+// SPDX-FileCopyrightText: Copyright (C) 2024 Adaline Simonian
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// This file is part of gdvm.
+//
+// gdvm is free software: you can redistribute it and/or modify it under the
+// terms of the GNU General Public License as published by the Free Software
+// Foundation, either version 3 of the License, or (at your option) any later
+// version.
+//
+// gdvm is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR
+// A PARTICULAR PURPOSE. See the GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with
+// this program. If not, see <https://www.gnu.org/licenses/>.
+#![cfg(feature = "integration-tests")]
+
+use std::fs;
+
+use gdvm::app::LaunchOptions;
+use gdvm::registry::OFFICIAL_BASE_URL;
+use gdvm::usage_tracker::InstallUsage;
+use gdvm::version::Variant;
+use serial_test::serial;
+
+mod common;
+use common::{TestHome, gdvm, now_secs, resolved};
+
+#[tokio::test]
+#[serial]
+async fn first_run_of_a_new_minor_version_carries_settings_forward() {
+    let env = TestHome::with_project();
+    let mgr = gdvm().await;
+    let variant = Variant::default();
+
+    let mut data_dirs = Vec::new();
+    for version in ["4.2.2-stable", "4.3-stable", "4.4-stable"] {
+        let (_, dir) = mgr
+            .library()
+            .install_dir(&resolved(version), &variant, None)
+            .unwrap();
+        fs::create_dir_all(&dir).unwrap();
+        gdvm::registry_store::upsert(
+            dir.parent().unwrap().parent().unwrap(),
+            OFFICIAL_BASE_URL,
+            None,
+            None,
+        )
+        .unwrap();
+        data_dirs.push(
+            mgr.library()
+                .make_self_contained(&resolved(version), &variant, None)
+                .unwrap(),
+        );
+    }
+    fs::create_dir_all(&data_dirs[0]).unwrap();
+    fs::write(data_dirs[0].join("editor_settings-4.tres"), "4.2").unwrap();
+    fs::create_dir_all(data_dirs[1].join("text_editor_themes")).unwrap();
+    fs::write(data_dirs[1].join("editor_settings-4.3.tres"), "4.3").unwrap();
+    fs::write(data_dirs[1].join("text_editor_themes/dark.tet"), "theme").unwrap();
+
+    let gv = resolved("4.4-stable");
+    let options = LaunchOptions::default();
+    let carry = mgr
+        .launcher()
+        .settings_to_carry(&gv, &variant, None, &options)
+        .unwrap()
+        .expect("the newest older version's settings are offered");
+    assert!(carry.from.starts_with("4.3"), "{}", carry.from);
+
+    assert_eq!(mgr.launcher().carry_settings(&carry).unwrap(), None);
+    assert_eq!(
+        fs::read_to_string(data_dirs[2].join("editor_settings-4.4.tres")).unwrap(),
+        "4.3"
+    );
+    assert!(data_dirs[2].join("text_editor_themes/dark.tet").is_file());
+
+    // Once gdvm has launched a 4.4, its settings are its own.
+    fs::remove_file(data_dirs[2].join("editor_settings-4.4.tres")).unwrap();
+    let key = mgr.library().install_key(&gv, &variant, None).unwrap();
+    let mut state = env.read_usage();
+    state.installs.insert(
+        key,
+        InstallUsage {
+            last_used: now_secs(),
+            first_launched: Some(now_secs()),
+        },
+    );
+    env.write_usage(&state);
+    assert!(
+        mgr.launcher()
+            .settings_to_carry(&gv, &variant, None, &options)
+            .unwrap()
+            .is_none()
+    );
+}
@@ -127,6 +127,21 @@ impl TestHome {
         dir
     }
 
+    /// Backdate the installs and the release metadata, so that the resolution
+    /// cache doesn't take them for files that may still be changing.
+    pub fn settle(&self) {
+        fn walk(path: &Path) {
+            backdate(path);
+            if let Ok(entries) = fs::read_dir(path) {
+                for entry in entries.flatten() {
+                    walk(&entry.path());
+                }
+            }
+        }
+        walk(&self.installs());
+        backdate(&self.gdvm_dir().join("cache.json"));
+    }
+
     /// Create a cached archive file with the given name and contents.
     pub fn make_cache_file(&self, name: &str, contents: &[u8]) -> PathBuf {
         let dir = self.cache();
@@ -201,6 +216,15 @@ pub fn resolved(install_str: &str) -> ResolvedVersion {
         .to_resolved()
 }
 
+/// Move a file's or directory's modification time a minute back, so that the
+/// resolution cache doesn't take it for one that may still be changing.
+pub fn backdate(path: &Path) {
+    fs::File::open(path)
+        .unwrap()
+        .set_modified(std::time::SystemTime::now() - std::time::Duration::from_secs(60))
+        .unwrap();
+}
+
 /// Get unix timestamp for now.
 pub fn now_secs() -> u64 {
     std::time::SystemTime::now()
@@ -0,0 +1,77 @@
+// SPDX-FileCopyrightText: Copyright (C) 2024 Adaline Simonian
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// This file is part of gdvm.
+//
+// gdvm is free software: you can redistribute it and/or modify it under the
+// terms of the GNU General Public License as published by the Free Software
+// Foundation, either version 3 of the License, or (at your option) any later
+// version.
+//
+// gdvm is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR
+// A PARTICULAR PURPOSE. See the GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with
+// this program. If not, see <https://www.gnu.org/licenses/>.
+#![cfg(feature = "integration-tests")]
+#![cfg(target_family = "unix")]
+
+use std::fs;
+use std::os::unix::fs::PermissionsExt;
+
+use gdvm::app::LaunchOptions;
+use gdvm::registry::OFFICIAL_BASE_URL;
+use gdvm::version::Variant;
+use serial_test::serial;
+
+mod common;
+use common::{TestHome, gdvm, resolved};
+
+#[tokio::test]
+#[serial]
+async fn attached_run_that_crashes_saves_a_crash_record() {
+    let env = TestHome::with_project();
+    fs::create_dir_all(env.gdvm_dir()).unwrap();
+    fs::write(
+        env.gdvm_dir().join("config.toml"),
+        "[crashes]\ncapture-output = true\n",
+    )
+    .unwrap();
+    let mgr = gdvm().await;
+    let gv = resolved("4.3-stable");
+    let variant = Variant::default();
+
+    let (_, dir) = mgr.library().install_dir(&gv, &variant, None).unwrap();
+    fs::create_dir_all(&dir).unwrap();
+    let exe = dir.join("Godot_v4.3-stable.exe");
+    fs::write(&exe, b"#!/bin/sh\necho \"loading $1\"\nkill -SEGV $$\n").unwrap();
+    fs::set_permissions(&exe, fs::Permissions::from_mode(0o755)).unwrap();
+    gdvm::registry_store::upsert(
+        dir.parent().unwrap().parent().unwrap(),
+        OFFICIAL_BASE_URL,
+        None,
+        None,
+    )
+    .unwrap();
+
+    let options = LaunchOptions {
+        console: true,
+        args: vec!["main.tscn".to_string()],
+        project: Some(env.project_dir().to_path_buf()),
+        ..Default::default()
+    };
+    let code = mgr.launcher().run(&gv, &variant, None, &options).unwrap();
+    assert_ne!(code, 0);
+
+    let crashes = mgr.launcher().crashes();
+    assert_eq!(crashes.len(), 1);
+    let (path, record) = &crashes[0];
+    assert!(path.starts_with(env.gdvm_dir().join("crashes")));
+    assert_eq!(record.version, "4.3-stable");
+    assert_eq!(record.reason, "SIGSEGV");
+    assert_eq!(record.registry, OFFICIAL_BASE_URL);
+    assert_eq!(record.args, ["main.tscn"]);
+    assert_eq!(record.project.as_deref(), Some(env.project_dir()));
+    assert_eq!(record.output, ["loading main.tscn"]);
+}
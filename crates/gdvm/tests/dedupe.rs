@@ -0,0 +1,120 @@
+// SPDX-FileCopyrightText: Copyright (C) 2024 Adaline Simonian
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// This file is part of gdvm.
+//
+// gdvm is free software: you can redistribute it and/or modify it under the
+// terms of the GNU General Public License as published by the Free Software
+// Foundation, either version 3 of the License, or (at your option) any later
+// version.
+//
+// gdvm is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR
+// A PARTICULAR PURPOSE. See the GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with
+// this program. If not, see <https://www.gnu.org/licenses/>.
+#![cfg(feature = "integration-tests")]
+
+use std::collections::HashMap;
+use std::fs;
+
+use gdvm::app::PruneOptions;
+use gdvm::usage_tracker::{InstallUsage, UsageState};
+use serial_test::serial;
+
+mod common;
+use common::{TestHome, gdvm, now_secs};
+
+const DAY: u64 = 24 * 60 * 60;
+
+/// Contents large enough to be worth sharing.
+fn pck_contents() -> Vec<u8> {
+    (0..64 * 1024).map(|i| (i % 251) as u8).collect()
+}
+
+#[tokio::test]
+#[serial]
+async fn dedupe_shares_identical_files_and_reports_real_sizes() {
+    let env = TestHome::new();
+
+    let a = env.make_install("store/default/4.3-stable");
+    let b = env.make_install("store/default/4.4-stable");
+    fs::write(a.join("Godot.pck"), pck_contents()).unwrap();
+    fs::write(b.join("Godot.pck"), pck_contents()).unwrap();
+
+    let mgr = gdvm().await;
+
+    let dry = mgr.deduper().dedupe(true).unwrap();
+    assert_eq!(dry.files, 1);
+    assert_eq!(dry.saved_bytes, pck_contents().len() as u64);
+    assert!(!env.gdvm_dir().join("dedupe.json").exists());
+
+    let size_before = mgr.deduper().install_size(&b);
+    let report = mgr.deduper().dedupe(false).unwrap();
+    assert_eq!(report.files, 1);
+    assert_eq!(fs::read(b.join("Godot.pck")).unwrap(), pck_contents());
+
+    // Only one install is charged for the shared file.
+    assert_eq!(mgr.deduper().install_size(&a), size_before);
+    assert_eq!(
+        mgr.deduper().install_size(&b),
+        size_before - pck_contents().len() as u64
+    );
+
+    // Running again finds nothing new.
+    assert_eq!(mgr.deduper().dedupe(false).unwrap().files, 0);
+}
+
+#[tokio::test]
+#[serial]
+async fn pruning_the_owning_install_keeps_the_shared_file() {
+    let env = TestHome::new();
+
+    let old_key = "store/default/4.3-stable";
+    let new_key = "store/default/4.4-stable";
+    let old = env.make_install(old_key);
+    let new = env.make_install(new_key);
+    fs::write(old.join("Godot.pck"), pck_contents()).unwrap();
+    fs::write(new.join("Godot.pck"), pck_contents()).unwrap();
+
+    let now = now_secs();
+    env.write_usage(&UsageState {
+        installs: HashMap::from([
+            (
+                old_key.to_string(),
+                InstallUsage {
+                    last_used: now - 40 * DAY,
+                    first_launched: None,
+                },
+            ),
+            (
+                new_key.to_string(),
+                InstallUsage {
+                    last_used: now,
+                    first_launched: None,
+                },
+            ),
+        ]),
+        ..UsageState::default()
+    });
+
+    let mgr = gdvm().await;
+    mgr.deduper().dedupe(false).unwrap();
+    let shared_size = mgr.deduper().install_size(&new);
+
+    let report = mgr
+        .pruner()
+        .prune(30 * DAY, PruneOptions::default())
+        .unwrap();
+
+    assert_eq!(report.installs.len(), 1);
+    assert!(!old.exists());
+    assert_eq!(fs::read(new.join("Godot.pck")).unwrap(), pck_contents());
+
+    // The remaining install now owns the file on its own.
+    assert_eq!(
+        mgr.deduper().install_size(&new),
+        shared_size + pck_contents().len() as u64
+    );
+}
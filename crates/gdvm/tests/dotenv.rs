@@ -0,0 +1,176 @@
+// SPDX-FileCopyrightText: Copyright (C) 2024 Adaline Simonian
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// This file is part of gdvm.
+//
+// gdvm is free software: you can redistribute it and/or modify it under the
+// terms of the GNU General Public License as published by the Free Software
+// Foundation, either version 3 of the License, or (at your option) any later
+// version.
+//
+// gdvm is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR
+// A PARTICULAR PURPOSE. See the GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with
+// this program. If not, see <https://www.gnu.org/licenses/>.
+
+#![cfg(feature = "integration-tests")]
+#![cfg(target_family = "unix")]
+
+use std::fs;
+use std::os::unix::fs::PermissionsExt;
+use std::path::Path;
+use std::process::Command;
+
+use gdvm::registry::OFFICIAL_BASE_URL;
+use gdvm::version::Variant;
+use serial_test::serial;
+
+mod common;
+use common::{TestHome, gdvm, resolved};
+
+/// Set up a project whose fake Godot 4.3 writes the variables it gets to
+/// `out.txt` and exits with 3. `GDVM_TEST_PROFILE` comes from launch profiles.
+async fn project(env: &TestHome, gdvm_toml: &str) {
+    let mgr = gdvm().await;
+    let (_, dir) = mgr
+        .library()
+        .install_dir(&resolved("4.3-stable"), &Variant::default(), None)
+        .unwrap();
+    fs::create_dir_all(&dir).unwrap();
+    let exe = dir.join("Godot_v4.3-stable.exe");
+    let out = env.project_dir().join("out.txt");
+    fs::write(
+        &exe,
+        format!(
+            "#!/bin/sh\nprintf '%s\\n%s\\n%s\\n' \"$GDVM_TEST_API_URL\" \"$GDVM_TEST_SECRET\" \"$GDVM_TEST_PROFILE\" > '{}'\nexit 3\n",
+            out.display()
+        ),
+    )
+    .unwrap();
+    fs::set_permissions(&exe, fs::Permissions::from_mode(0o755)).unwrap();
+    gdvm::registry_store::upsert(
+        dir.parent().unwrap().parent().unwrap(),
+        OFFICIAL_BASE_URL,
+        None,
+        None,
+    )
+    .unwrap();
+
+    fs::write(env.project_dir().join("project.godot"), "").unwrap();
+    fs::write(
+        env.project_dir().join(".env"),
+        "GDVM_TEST_API_URL=https://example.com\nGDVM_TEST_SECRET=hunter2\n",
+    )
+    .unwrap();
+    fs::write(
+        env.project_dir().join("gdvm.toml"),
+        format!("[godot]\nversion = \"~4.3\"\n\n{gdvm_toml}"),
+    )
+    .unwrap();
+}
+
+/// Run `gdvm run` with `args` from `cwd` and return the lines Godot wrote.
+fn run(env: &TestHome, cwd: &Path, args: &[&str]) -> Vec<String> {
+    let output = Command::new(env!("CARGO_BIN_EXE_gdvm"))
+        .arg("run")
+        .args(args)
+        .current_dir(cwd)
+        .env_remove("GDVM_TEST_API_URL")
+        .env_remove("GDVM_TEST_SECRET")
+        .env_remove("GDVM_TEST_PROFILE")
+        .output()
+        .expect("gdvm runs");
+    assert_eq!(output.status.code(), Some(3), "{output:?}");
+    fs::read_to_string(env.project_dir().join("out.txt"))
+        .unwrap()
+        .lines()
+        .map(str::to_string)
+        .collect()
+}
+
+#[tokio::test]
+#[serial]
+async fn dotenv_is_read_from_the_project_root() {
+    let env = TestHome::with_project();
+    project(&env, "").await;
+
+    // From a folder inside the project.
+    let scenes = env.project_dir().join("scenes");
+    fs::create_dir_all(&scenes).unwrap();
+    assert_eq!(
+        run(&env, &scenes, &[]),
+        ["https://example.com", "hunter2", ""]
+    );
+
+    // From outside the project, with the project given by `--path`.
+    let project_dir = env.project_dir().display().to_string();
+    let outside = env.path().to_path_buf();
+    assert_eq!(
+        run(&env, &outside, &["~4.3", "--", "--path", &project_dir]),
+        ["https://example.com", "hunter2", ""]
+    );
+}
+
+#[tokio::test]
+#[serial]
+async fn dotenv_rules_decide_what_reaches_godot() {
+    let env = TestHome::with_project();
+    let cwd = env.project_dir().to_path_buf();
+
+    project(&env, "[dotenv]\nblock = [\"*_SECRET\"]\n").await;
+    assert_eq!(run(&env, &cwd, &[]), ["https://example.com", "", ""]);
+
+    project(&env, "[dotenv]\npass = [\"*_SECRET\"]\n").await;
+    assert_eq!(run(&env, &cwd, &[]), ["", "hunter2", ""]);
+}
+
+#[tokio::test]
+#[serial]
+async fn launch_profiles_come_from_the_same_project_as_dotenv() {
+    let env = TestHome::with_project();
+    project(
+        &env,
+        "[dotenv]\nblock = [\"*_SECRET\"]\n\n[profiles.ci]\nenv = { GDVM_TEST_PROFILE = \"ci\" }\n",
+    )
+    .await;
+
+    let project_dir = env.project_dir().display().to_string();
+    let outside = env.path().to_path_buf();
+    assert_eq!(
+        run(
+            &env,
+            &outside,
+            &["~4.3", "--profile", "ci", "--", "--path", &project_dir]
+        ),
+        ["https://example.com", "", "ci"]
+    );
+}
+
+#[tokio::test]
+#[serial]
+async fn exec_passes_dotenv_through_the_rules() {
+    let env = TestHome::with_project();
+    project(&env, "[dotenv]\nblock = [\"*_SECRET\"]\n").await;
+
+    let out = env.project_dir().join("exec.txt");
+    let script = format!(
+        "printf '%s\\n%s\\n' \"$GDVM_TEST_API_URL\" \"$GDVM_TEST_SECRET\" > '{}'",
+        out.display()
+    );
+    let output = Command::new(env!("CARGO_BIN_EXE_gdvm"))
+        .args(["exec", "--", "sh", "-c", &script])
+        .env_remove("GDVM_TEST_API_URL")
+        .env_remove("GDVM_TEST_SECRET")
+        .output()
+        .expect("gdvm runs");
+    assert!(output.status.success(), "{output:?}");
+
+    let written = fs::read_to_string(out).unwrap();
+    assert_eq!(
+        written.lines().collect::<Vec<_>>(),
+        ["https://example.com", ""],
+        "passed variables reach the command and blocked ones don't"
+    );
+}
@@ -0,0 +1,88 @@
+// SPDX-FileCopyrightText: Copyright (C) 2024 Adaline Simonian
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// This file is part of gdvm.
+//
+// gdvm is free software: you can redistribute it and/or modify it under the
+// terms of the GNU General Public License as published by the Free Software
+// Foundation, either version 3 of the License, or (at your option) any later
+// version.
+//
+// gdvm is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR
+// A PARTICULAR PURPOSE. See the GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with
+// this program. If not, see <https://www.gnu.org/licenses/>.
+
+#![cfg(feature = "integration-tests")]
+
+use std::fs;
+use std::process::Command;
+
+use gdvm::app::Gdvm;
+use gdvm::registry::OFFICIAL_BASE_URL;
+use gdvm::version::Variant;
+use serial_test::serial;
+
+mod common;
+use common::{TestHome, backdate, gdvm, resolved};
+
+/// Create an install the way `gdvm install` lays it out.
+fn install(env: &TestHome, mgr: &Gdvm, version: &str) {
+    let key = mgr
+        .library()
+        .install_key(&resolved(version), &Variant::default(), None)
+        .unwrap();
+    let dir = env.make_install(&key);
+    // A name the executable lookup matches on every platform.
+    fs::write(dir.join(format!("Godot_v{version}.exe")), b"fake-binary").unwrap();
+    gdvm::registry_store::upsert(
+        dir.parent().unwrap().parent().unwrap(),
+        OFFICIAL_BASE_URL,
+        None,
+        None,
+    )
+    .unwrap();
+}
+
+/// Pin `version` and make everything the resolution depends on look settled.
+fn pin(env: &TestHome, version: &str) {
+    let pin = env.project_dir().join("gdvm.toml");
+    fs::write(&pin, format!("[godot]\nversion = \"{version}\"\n")).unwrap();
+    backdate(&pin);
+    env.settle();
+}
+
+fn export() -> String {
+    let output = Command::new(env!("CARGO_BIN_EXE_gdvm"))
+        .args(["env", "--shell", "bash", "--export"])
+        .output()
+        .expect("gdvm runs");
+    assert!(output.status.success(), "env succeeds: {output:?}");
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[tokio::test]
+#[serial]
+async fn env_exports_are_cached_until_the_pin_changes() {
+    let env = TestHome::with_project();
+    let mgr = gdvm().await;
+    install(&env, &mgr, "4.3-stable");
+    install(&env, &mgr, "4.4-stable");
+
+    pin(&env, "4.3-stable");
+    let exports = export();
+    assert!(exports.contains("export GODOT_VERSION='4.3"), "{exports}");
+    assert!(
+        exports.contains("export GDVM_RESOLVED_SOURCE='pin'"),
+        "{exports}"
+    );
+    let cached = fs::read_to_string(env.gdvm_dir().join("env.json")).unwrap();
+    assert!(cached.contains("4.3"), "the exports are cached: {cached}");
+    assert_eq!(export(), exports);
+
+    pin(&env, "4.4-stable");
+    let exports = export();
+    assert!(exports.contains("export GODOT_VERSION='4.4"), "{exports}");
+}
@@ -0,0 +1,78 @@
+// SPDX-FileCopyrightText: Copyright (C) 2024 Adaline Simonian
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// This file is part of gdvm.
+//
+// gdvm is free software: you can redistribute it and/or modify it under the
+// terms of the GNU General Public License as published by the Free Software
+// Foundation, either version 3 of the License, or (at your option) any later
+// version.
+//
+// gdvm is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR
+// A PARTICULAR PURPOSE. See the GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with
+// this program. If not, see <https://www.gnu.org/licenses/>.
+#![cfg(feature = "integration-tests")]
+#![cfg(target_family = "unix")]
+
+use std::fs;
+use std::os::unix::fs::PermissionsExt;
+
+use gdvm::registry::OFFICIAL_BASE_URL;
+use gdvm::version::Variant;
+use serial_test::serial;
+
+mod common;
+use common::{TestHome, gdvm, resolved};
+
+#[tokio::test]
+#[serial]
+async fn exec_puts_godot_on_path_and_forwards_the_exit_code() {
+    let env = TestHome::with_project();
+    let mgr = gdvm().await;
+    let gv = resolved("4.3-stable");
+    let variant = Variant::default();
+
+    let (_, dir) = mgr.library().install_dir(&gv, &variant, None).unwrap();
+    fs::create_dir_all(&dir).unwrap();
+    let exe = dir.join("Godot_v4.3-stable.exe");
+    fs::write(&exe, b"#!/bin/sh\n").unwrap();
+    fs::set_permissions(&exe, fs::Permissions::from_mode(0o755)).unwrap();
+    gdvm::registry_store::upsert(
+        dir.parent().unwrap().parent().unwrap(),
+        OFFICIAL_BASE_URL,
+        None,
+        None,
+    )
+    .unwrap();
+
+    let out = env.project_dir().join("out.txt");
+    let script = format!(
+        "printf '%s\\n%s\\n%s\\n' \"$GODOT\" \"$GODOT_VERSION\" \"$(command -v godot)\" > '{}'; exit 3",
+        out.display()
+    );
+    let command = ["sh".to_string(), "-c".to_string(), script];
+
+    let code = mgr
+        .launcher()
+        .exec(&gv, &variant, None, &command, &[])
+        .unwrap();
+    assert_eq!(code, 3);
+
+    let written = fs::read_to_string(&out).unwrap();
+    let lines: Vec<&str> = written.lines().collect();
+    assert_eq!(lines[0], exe.display().to_string());
+    assert_eq!(
+        lines[1],
+        gdvm::version::display_version(&gv, &variant, None)
+    );
+
+    let on_path = std::path::Path::new(lines[2]);
+    assert_eq!(on_path.file_name().unwrap(), "godot");
+    assert!(
+        !on_path.exists(),
+        "the temporary directory is removed after the command exits"
+    );
+}
@@ -0,0 +1,131 @@
+// SPDX-FileCopyrightText: Copyright (C) 2024 Adaline Simonian
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// This file is part of gdvm.
+//
+// gdvm is free software: you can redistribute it and/or modify it under the
+// terms of the GNU General Public License as published by the Free Software
+// Foundation, either version 3 of the License, or (at your option) any later
+// version.
+//
+// gdvm is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR
+// A PARTICULAR PURPOSE. See the GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with
+// this program. If not, see <https://www.gnu.org/licenses/>.
+#![cfg(feature = "integration-tests")]
+
+use std::fs;
+use std::path::PathBuf;
+
+use gdvm::app::{Gdvm, PruneOptions};
+use gdvm::registry::OFFICIAL_BASE_URL;
+use gdvm::version::{Variant, VersionSpec};
+use serial_test::serial;
+
+mod common;
+use common::{TestHome, gdvm, resolved};
+
+/// Create an install the way `gdvm install` lays it out.
+fn install(env: &TestHome, mgr: &Gdvm, version: &str) -> PathBuf {
+    let key = mgr
+        .library()
+        .install_key(&resolved(version), &Variant::default(), None)
+        .unwrap();
+    let dir = env.make_install(&key);
+    gdvm::registry_store::upsert(
+        dir.parent().unwrap().parent().unwrap(),
+        OFFICIAL_BASE_URL,
+        None,
+        None,
+    )
+    .unwrap();
+    dir
+}
+
+fn float(mgr: &Gdvm, spec: &str) {
+    let target = VersionSpec::parse(spec).unwrap().target;
+    mgr.defaults()
+        .set_floating_default(&target, &Variant::default(), None)
+        .unwrap();
+}
+
+fn current_godot(env: &TestHome) -> PathBuf {
+    env.gdvm_dir().join("bin").join("current_godot")
+}
+
+fn default_version(mgr: &Gdvm) -> Option<String> {
+    mgr.defaults()
+        .get_default()
+        .unwrap()
+        .map(|default| default.version.to_remote_str())
+}
+
+#[tokio::test]
+#[serial]
+async fn floating_default_follows_newest_installed_match() {
+    let env = TestHome::new();
+    let mgr = gdvm().await;
+
+    install(&env, &mgr, "4.3-stable");
+    install(&env, &mgr, "4.4-stable");
+    float(&mgr, "4.3");
+    assert_eq!(default_version(&mgr).as_deref(), Some("4.3-stable"));
+
+    let newer = install(&env, &mgr, "4.3.1-stable");
+    assert_eq!(default_version(&mgr).as_deref(), Some("4.3.1-stable"));
+
+    mgr.defaults().refresh_floating_default().unwrap();
+    let link = fs::read_link(current_godot(&env)).unwrap();
+    assert_eq!(link, newer);
+}
+
+#[tokio::test]
+#[serial]
+async fn removing_the_current_match_keeps_the_default_floating() {
+    let env = TestHome::new();
+    let mgr = gdvm().await;
+
+    install(&env, &mgr, "4.3-stable");
+    install(&env, &mgr, "4.3.1-stable");
+    float(&mgr, "stable");
+
+    mgr.installer()
+        .remove(&resolved("4.3.1-stable"), &Variant::default(), None)
+        .unwrap();
+    assert_eq!(default_version(&mgr).as_deref(), Some("4.3-stable"));
+    assert!(mgr.defaults().get_floating_default().unwrap().is_some());
+
+    mgr.installer()
+        .remove(&resolved("4.3-stable"), &Variant::default(), None)
+        .unwrap();
+    assert_eq!(default_version(&mgr), None);
+    assert!(current_godot(&env).symlink_metadata().is_err());
+}
+
+#[tokio::test]
+#[serial]
+async fn prune_keeps_the_floating_defaults_current_match() {
+    let env = TestHome::new();
+    let mgr = gdvm().await;
+
+    let older = install(&env, &mgr, "4.3-stable");
+    let newer = install(&env, &mgr, "4.3.1-stable");
+    float(&mgr, "4.3");
+
+    mgr.pruner()
+        .prune(
+            0,
+            PruneOptions {
+                all: true,
+                force: true,
+                dry_run: false,
+                cache_max_bytes: None,
+            },
+        )
+        .unwrap();
+
+    assert!(newer.exists());
+    assert!(!older.exists());
+}
@@ -0,0 +1,123 @@
+// SPDX-FileCopyrightText: Copyright (C) 2024 Adaline Simonian
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// This file is part of gdvm.
+//
+// gdvm is free software: you can redistribute it and/or modify it under the
+// terms of the GNU General Public License as published by the Free Software
+// Foundation, either version 3 of the License, or (at your option) any later
+// version.
+//
+// gdvm is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR
+// A PARTICULAR PURPOSE. See the GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with
+// this program. If not, see <https://www.gnu.org/licenses/>.
+#![cfg(feature = "integration-tests")]
+
+use std::ffi::OsString;
+use std::path::Path;
+
+use gdvm::config::ConfigFile;
+use gdvm::paths::HOME_ENV_VAR;
+use serial_test::serial;
+use tempfile::TempDir;
+
+mod common;
+use common::{TestHome, gdvm};
+
+/// Sets environment variables for the duration of a test.
+struct EnvVars {
+    previous: Vec<(&'static str, Option<OsString>)>,
+}
+
+impl EnvVars {
+    fn set(vars: &[(&'static str, &Path)]) -> Self {
+        let previous = vars
+            .iter()
+            .map(|(key, value)| {
+                let previous = std::env::var_os(key);
+                unsafe {
+                    std::env::set_var(key, value);
+                }
+                (*key, previous)
+            })
+            .collect();
+        Self { previous }
+    }
+}
+
+impl Drop for EnvVars {
+    fn drop(&mut self) {
+        for (key, value) in &self.previous {
+            unsafe {
+                match value {
+                    Some(value) => std::env::set_var(key, value),
+                    None => std::env::remove_var(key),
+                }
+            }
+        }
+    }
+}
+
+#[tokio::test]
+#[serial]
+async fn gdvm_home_overrides_every_location() {
+    let env = TestHome::new();
+    let portable = TempDir::new().unwrap();
+    let _vars = EnvVars::set(&[(HOME_ENV_VAR, portable.path())]);
+
+    let mgr = gdvm().await;
+
+    assert_eq!(mgr.base_path(), portable.path());
+    assert!(portable.path().join("installs").is_dir());
+    assert!(portable.path().join("cache").is_dir());
+    assert_eq!(
+        ConfigFile::path().unwrap(),
+        portable.path().join("config.toml")
+    );
+    assert!(env.gdvm_dir().is_dir(), "~/.gdvm is left alone");
+}
+
+#[cfg(not(any(target_os = "windows", target_os = "macos")))]
+#[tokio::test]
+#[serial]
+async fn xdg_layout_moves_the_legacy_dir() {
+    let env = TestHome::new();
+    let xdg = TempDir::new().unwrap();
+    let (data, config, cache) = (
+        xdg.path().join("data"),
+        xdg.path().join("config"),
+        xdg.path().join("cache"),
+    );
+    std::fs::write(env.gdvm_dir().join("config.toml"), "[prune]\n").unwrap();
+    env.make_install("store/default/4.3-stable");
+
+    let _vars = EnvVars::set(&[
+        (gdvm::paths::XDG_ENV_VAR, Path::new("1")),
+        ("XDG_DATA_HOME", &data),
+        ("XDG_CONFIG_HOME", &config),
+        ("XDG_CACHE_HOME", &cache),
+    ]);
+
+    let mgr = gdvm().await;
+
+    assert!(!env.gdvm_dir().exists());
+    assert_eq!(mgr.base_path(), data.join("gdvm"));
+    assert!(
+        data.join("gdvm/installs/store/default/4.3-stable/Godot")
+            .is_file()
+    );
+    assert!(cache.join("gdvm/cache.json").is_file());
+    assert!(cache.join("gdvm/archives").is_dir());
+    assert_eq!(ConfigFile::path().unwrap(), config.join("gdvm/config.toml"));
+    assert!(ConfigFile::path().unwrap().is_file());
+    drop(mgr);
+
+    // Without the opt-in, the moved files are still found.
+    unsafe {
+        std::env::remove_var(gdvm::paths::XDG_ENV_VAR);
+    }
+    assert_eq!(gdvm().await.base_path(), data.join("gdvm"));
+}
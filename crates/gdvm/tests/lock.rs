@@ -0,0 +1,193 @@
+// SPDX-FileCopyrightText: Copyright (C) 2024 Adaline Simonian
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// This file is part of gdvm.
+//
+// gdvm is free software: you can redistribute it and/or modify it under the
+// terms of the GNU General Public License as published by the Free Software
+// Foundation, either version 3 of the License, or (at your option) any later
+// version.
+//
+// gdvm is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR
+// A PARTICULAR PURPOSE. See the GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with
+// this program. If not, see <https://www.gnu.org/licenses/>.
+
+#![cfg(feature = "integration-tests")]
+
+use std::fs;
+use std::path::PathBuf;
+
+use gdvm::app::{Gdvm, InstallOutcome};
+use gdvm::config::ConfigFile;
+use gdvm::gdvm_lock::{self, LOCK_FILE};
+use gdvm::registry::{self, publish};
+use gdvm::version::{Variant, VersionQuery};
+use serial_test::serial;
+use tempfile::TempDir;
+
+mod common;
+use common::{TestHome, host_platform, make_zip};
+
+/// Publish a local registry with a single stable build for the host platform,
+/// add it to the config as `localreg` and pin it loosely in the project.
+fn pin_local_registry(env: &TestHome) -> (String, String) {
+    let reg = TempDir::new().unwrap().keep().join("reg");
+    publish::init(&reg, Some("local")).unwrap();
+
+    let platform = host_platform();
+    let archive_src = reg.parent().unwrap().join("godot.zip");
+    make_zip(&archive_src, "Godot.test", b"a real-enough godot archive");
+    publish::add_build(
+        &reg,
+        &publish::AddBuild {
+            version: "4.4-stable".to_string(),
+            variant: None,
+            platform: platform.clone(),
+            file: Some(archive_src),
+            store: true,
+            url: None,
+            sha512: None,
+            size: None,
+        },
+    )
+    .unwrap();
+
+    let url = format!("file://{}", reg.display());
+    let mut config = ConfigFile::load().unwrap();
+    config.add_registry("localreg", &url).unwrap();
+    config.save().unwrap();
+
+    fs::write(
+        env.project_dir().join("gdvm.toml"),
+        "[godot]\nversion = \"localreg/4.4\"\n",
+    )
+    .unwrap();
+
+    (url, platform)
+}
+
+fn lock_path(env: &TestHome) -> PathBuf {
+    env.project_dir().join(LOCK_FILE)
+}
+
+#[tokio::test]
+#[serial]
+async fn update_lock_records_exact_build() {
+    let env = TestHome::with_project();
+    let (url, platform) = pin_local_registry(&env);
+    let gdvm = Gdvm::new().await.unwrap();
+
+    let (path, locked) = gdvm.defaults().update_lock(false).await.unwrap();
+    assert_eq!(path, lock_path(&env));
+    assert_eq!(locked.version.to_remote_str(), "4.4-stable");
+
+    let lock = gdvm_lock::read(env.project_dir()).unwrap().unwrap().godot;
+    assert_eq!(lock.constraint, "localreg/4.4");
+    assert_eq!(lock.version, "4.4-stable");
+    assert_eq!(lock.variant, "default");
+    assert_eq!(lock.registry, registry::normalize_url(&url));
+    assert!(lock.sha512.contains_key(&platform));
+
+    let pin = gdvm.defaults().get_locked_pin(true).unwrap().unwrap();
+    assert_eq!(
+        pin.version,
+        VersionQuery::from_install_str("4.4-stable").unwrap()
+    );
+    assert_eq!(pin.registry.as_deref(), Some("localreg"));
+}
+
+#[tokio::test]
+#[serial]
+async fn locked_requires_lock_to_agree_with_pin() {
+    let env = TestHome::with_project();
+    pin_local_registry(&env);
+    let gdvm = Gdvm::new().await.unwrap();
+
+    assert!(gdvm.defaults().get_locked_pin(true).is_err());
+    let pin = gdvm.defaults().get_locked_pin(false).unwrap().unwrap();
+    assert!(pin.stale_lock.is_none());
+
+    gdvm.defaults().update_lock(false).await.unwrap();
+    fs::write(
+        env.project_dir().join("gdvm.toml"),
+        "[godot]\nversion = \"localreg/4\"\n",
+    )
+    .unwrap();
+
+    assert!(gdvm.defaults().get_locked_pin(true).is_err());
+    let pin = gdvm.defaults().get_locked_pin(false).unwrap().unwrap();
+    assert_eq!(pin.stale_lock, Some(lock_path(&env)));
+    assert_eq!(pin.version, VersionQuery::from_remote_str("4").unwrap());
+}
+
+#[tokio::test]
+#[serial]
+async fn locked_install_fails_on_hash_mismatch() {
+    let env = TestHome::with_project();
+    let (_url, platform) = pin_local_registry(&env);
+    let mut gdvm = Gdvm::new().await.unwrap();
+    gdvm.defaults().update_lock(false).await.unwrap();
+
+    let mut lock = gdvm_lock::read(env.project_dir()).unwrap().unwrap();
+    lock.godot.sha512.insert(platform, "00".repeat(64));
+    gdvm_lock::write(env.project_dir(), &lock).unwrap();
+
+    let gv = VersionQuery::from_install_str("4.4-stable")
+        .unwrap()
+        .to_resolved();
+    gdvm.require_lock();
+    let result = gdvm
+        .installer()
+        .install(&gv, &Variant::default(), Some("localreg"), false, false)
+        .await;
+    assert!(
+        result.is_err(),
+        "a locked install must check the lock's hash"
+    );
+
+    let gdvm = Gdvm::new().await.unwrap();
+    let outcome = gdvm
+        .installer()
+        .install(&gv, &Variant::default(), Some("localreg"), false, false)
+        .await
+        .expect("an unlocked install only warns");
+    assert!(matches!(outcome, InstallOutcome::Installed));
+}
+
+#[tokio::test]
+#[serial]
+async fn unlocked_platforms_are_not_mismatches() {
+    let env = TestHome::with_project();
+    let (_url, platform) = pin_local_registry(&env);
+    let mut gdvm = Gdvm::new().await.unwrap();
+    gdvm.defaults().update_lock(false).await.unwrap();
+
+    let mut lock = gdvm_lock::read(env.project_dir()).unwrap().unwrap();
+    lock.godot.sha512.remove(&platform);
+    gdvm_lock::write(env.project_dir(), &lock).unwrap();
+
+    let gv = VersionQuery::from_install_str("4.4-stable")
+        .unwrap()
+        .to_resolved();
+    gdvm.require_lock();
+    let error = gdvm
+        .installer()
+        .install(&gv, &Variant::default(), Some("localreg"), false, false)
+        .await
+        .expect_err("a locked install can't check a platform the lock lacks");
+    let code = error
+        .downcast_ref::<gdvm::error::CodedError>()
+        .and_then(|coded| coded.code());
+    assert_eq!(code, gdvm::error::code_for("error-lock-platform-missing"));
+
+    let gdvm = Gdvm::new().await.unwrap();
+    let outcome = gdvm
+        .installer()
+        .install(&gv, &Variant::default(), Some("localreg"), false, false)
+        .await
+        .expect("an unlocked install goes ahead");
+    assert!(matches!(outcome, InstallOutcome::Installed));
+}
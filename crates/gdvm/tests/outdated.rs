@@ -0,0 +1,124 @@
+// SPDX-FileCopyrightText: Copyright (C) 2024 Adaline Simonian
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// This file is part of gdvm.
+//
+// gdvm is free software: you can redistribute it and/or modify it under the
+// terms of the GNU General Public License as published by the Free Software
+// Foundation, either version 3 of the License, or (at your option) any later
+// version.
+//
+// gdvm is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR
+// A PARTICULAR PURPOSE. See the GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with
+// this program. If not, see <https://www.gnu.org/licenses/>.
+
+#![cfg(feature = "integration-tests")]
+
+use std::process::Command;
+
+use gdvm::app::Gdvm;
+use gdvm::config::ConfigFile;
+use gdvm::registry::publish;
+use gdvm::version::Variant;
+use serial_test::serial;
+use tempfile::TempDir;
+
+mod common;
+use common::{TestHome, host_platform, make_zip, resolved};
+
+/// Publish a local registry with two patch releases of the 4.4 series and add
+/// it to the config as `localreg`.
+fn publish_local_registry() {
+    let reg = TempDir::new().unwrap().keep().join("reg");
+    publish::init(&reg, Some("local")).unwrap();
+
+    for version in ["4.4-stable", "4.4.1-stable"] {
+        let archive_src = reg.parent().unwrap().join(format!("{version}.zip"));
+        make_zip(&archive_src, "Godot.test", version.as_bytes());
+        publish::add_build(
+            &reg,
+            &publish::AddBuild {
+                version: version.to_string(),
+                variant: None,
+                platform: host_platform(),
+                file: Some(archive_src),
+                store: true,
+                url: None,
+                sha512: None,
+                size: None,
+            },
+        )
+        .unwrap();
+    }
+
+    let mut config = ConfigFile::load().unwrap();
+    config
+        .add_registry("localreg", &format!("file://{}", reg.display()))
+        .unwrap();
+    config.save().unwrap();
+}
+
+fn run_outdated() -> (Option<i32>, serde_json::Value) {
+    let output = Command::new(env!("CARGO_BIN_EXE_gdvm"))
+        .args(["outdated", "--format", "json", "--yes"])
+        .output()
+        .expect("gdvm runs");
+    let json = serde_json::from_slice(&output.stdout)
+        .unwrap_or_else(|_| panic!("outdated prints JSON: {output:?}"));
+    (output.status.code(), json)
+}
+
+#[tokio::test]
+#[serial]
+async fn outdated_reports_newer_patch_as_json_and_exits_non_zero() {
+    let _env = TestHome::new();
+    publish_local_registry();
+    let gdvm = Gdvm::new().await.unwrap();
+
+    gdvm.installer()
+        .install(
+            &resolved("4.4-stable"),
+            &Variant::default(),
+            Some("localreg"),
+            false,
+            false,
+        )
+        .await
+        .unwrap();
+
+    let (code, json) = run_outdated();
+    assert_eq!(code, Some(1));
+    let entry = &json[0];
+    assert_eq!(entry["version"], "4.4.0-stable");
+    assert_eq!(entry["registry"], "localreg");
+    assert_eq!(entry["sources"], serde_json::json!(["installed"]));
+    assert_eq!(entry["patch"], "4.4.1-stable");
+    assert_eq!(entry["outdated"], true);
+
+    gdvm.installer()
+        .install(
+            &resolved("4.4.1-stable"),
+            &Variant::default(),
+            Some("localreg"),
+            false,
+            false,
+        )
+        .await
+        .unwrap();
+    gdvm.installer()
+        .remove(
+            &resolved("4.4-stable"),
+            &Variant::default(),
+            Some("localreg"),
+        )
+        .unwrap();
+
+    let (code, json) = run_outdated();
+    assert_eq!(code, Some(0));
+    assert_eq!(json[0]["version"], "4.4.1-stable");
+    assert_eq!(json[0]["outdated"], false);
+    assert!(json[0].get("patch").is_none());
+}
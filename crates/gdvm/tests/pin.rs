@@ -14,6 +14,7 @@
 //
 // You should have received a copy of the GNU General Public License along with
 // this program. If not, see <https://www.gnu.org/licenses/>.
+#![cfg(feature = "integration-tests")]
 
 use std::fs;
 
@@ -183,3 +184,86 @@ async fn pin_then_get_roundtrips_variant() {
     assert_eq!(gv.minor, Some(3));
     assert_eq!(variant.as_deref(), Some("csharp"));
 }
+
+#[tokio::test]
+#[serial]
+async fn range_pin_picks_newest_installed_match() {
+    let env = TestHome::with_project();
+    let mgr = gdvm().await;
+
+    env.make_install("default/4.2.2-stable");
+    env.make_install("default/4.3-stable");
+    env.make_install("default/4.5-stable");
+    fs::write(
+        env.project_dir().join("gdvm.toml"),
+        "[godot]\nversion = \">=4.2, <4.5\"\n",
+    )
+    .unwrap();
+
+    let pinned = mgr
+        .defaults()
+        .get_pinned_version()
+        .expect("a pinned version");
+    assert!(pinned.version.range.is_some());
+
+    let installed = mgr
+        .library()
+        .resolve_installed_newest(&pinned.version, None, None)
+        .await
+        .unwrap();
+    assert_eq!(installed.version, resolved("4.3-stable"));
+}
+
+#[tokio::test]
+#[serial]
+async fn pin_keeps_launch_profiles() {
+    let env = TestHome::with_project();
+    let path = env.project_dir().join("gdvm.toml");
+    fs::write(
+        &path,
+        "# Shared launch settings.\n[godot]\nversion = \"4.2-stable\"\n\n[profiles.editor]\nargs = [\"-e\"]\n",
+    )
+    .unwrap();
+    let mgr = gdvm().await;
+
+    mgr.defaults()
+        .pin_version(&resolved("4.3-stable"), &Variant::default(), None, true)
+        .unwrap();
+
+    let toml = fs::read_to_string(&path).unwrap();
+    assert!(
+        toml.contains("version = \"default:4.3.0-stable\""),
+        "{toml}"
+    );
+    assert!(toml.contains("# Shared launch settings."), "{toml}");
+    assert!(toml.contains("[profiles.editor]"), "{toml}");
+}
+
+#[tokio::test]
+#[serial]
+async fn launch_profile_layers_over_run() {
+    let env = TestHome::with_project();
+    fs::write(
+        env.project_dir().join("gdvm.toml"),
+        "[run]\nargs = [\"--verbose\"]\n\n[profiles.headless-tests]\nargs = [\"--headless\"]\nlaunch-mode = \"attached\"\nworking-dir = \"game\"\n",
+    )
+    .unwrap();
+    let mgr = gdvm().await;
+
+    let run = mgr.defaults().launch_profile(None, None).unwrap();
+    assert_eq!(run.args, ["--verbose"]);
+    assert_eq!(run.working_dir, None);
+
+    let profile = mgr
+        .defaults()
+        .launch_profile(None, Some("headless-tests"))
+        .unwrap();
+    assert_eq!(profile.args, ["--verbose", "--headless"]);
+    assert_eq!(profile.working_dir, Some(env.project_dir().join("game")));
+
+    assert!(
+        mgr.defaults()
+            .launch_profile(None, Some("missing"))
+            .is_err()
+    );
+}
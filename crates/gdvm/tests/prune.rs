@@ -21,7 +21,8 @@ use std::collections::HashMap;
 use std::path::Path;
 
 use gdvm::app::PruneOptions;
-use gdvm::usage_tracker::{ArchiveUsage, InstallUsage, LinkRecord, UsageState};
+use gdvm::install_receipt::InstallReceipt;
+use gdvm::usage_tracker::{ArchiveUsage, InstallUsage, LinkRecord, ProcessRecord, UsageState};
 use gdvm::version::Variant;
 use serial_test::serial;
 
@@ -46,7 +47,15 @@ fn state_with(
     UsageState {
         installs: installs
             .iter()
-            .map(|(k, t)| (k.to_string(), InstallUsage { last_used: *t }))
+            .map(|(k, t)| {
+                (
+                    k.to_string(),
+                    InstallUsage {
+                        last_used: *t,
+                        first_launched: None,
+                    },
+                )
+            })
             .collect::<HashMap<_, _>>(),
         archives: archives
             .iter()
@@ -167,6 +176,7 @@ async fn prune_all_preserves_link_referenced_install() {
                 all: true,
                 force: false,
                 dry_run: false,
+                cache_max_bytes: None,
             },
         )
         .unwrap();
@@ -211,6 +221,7 @@ async fn prune_force_removes_link_referenced_install() {
                 all: false,
                 force: true,
                 dry_run: false,
+                cache_max_bytes: None,
             },
         )
         .unwrap();
@@ -253,6 +264,7 @@ async fn prune_dry_run_changes_nothing() {
                 all: false,
                 force: false,
                 dry_run: true,
+                cache_max_bytes: None,
             },
         )
         .unwrap();
@@ -298,6 +310,7 @@ async fn prune_all_force_removes_everything() {
                 all: true,
                 force: true,
                 dry_run: false,
+                cache_max_bytes: None,
             },
         )
         .unwrap();
@@ -357,6 +370,7 @@ async fn prune_never_removes_the_default_install() {
                 all: true,
                 force: true,
                 dry_run: false,
+                cache_max_bytes: None,
             },
         )
         .unwrap();
@@ -383,3 +397,228 @@ async fn prune_never_removes_the_default_install() {
         "default install usage record must be retained"
     );
 }
+
+#[tokio::test]
+#[serial]
+async fn prune_trims_cache_to_max_size_keeping_default_archive() {
+    let env = TestHome::new();
+
+    let mgr = gdvm().await;
+
+    let default_gv = resolved("4.3-stable");
+    let default_dir = env.make_install(
+        &mgr.library()
+            .install_key(&default_gv, &Variant::default(), None)
+            .unwrap(),
+    );
+    mgr.defaults()
+        .set_default(&default_gv, &Variant::default(), None)
+        .unwrap();
+
+    // The default install came from the oldest archive.
+    let default_sha = "d".repeat(128);
+    InstallReceipt::create(
+        &default_dir,
+        "https://example.com/",
+        "4.3-stable",
+        "default",
+        &default_sha,
+    )
+    .unwrap()
+    .write(&default_dir)
+    .unwrap();
+    let default_archive = format!("{}.zip", "d".repeat(16));
+
+    for name in [default_archive.as_str(), "a.zip", "b.zip", "c.zip"] {
+        env.make_cache_file(name, &[0u8; 100]);
+    }
+
+    let now = now_secs();
+    env.write_usage(&state_with(
+        &[],
+        &[
+            (default_archive.as_str(), now - 4 * DAY),
+            ("a.zip", now - 3 * DAY),
+            ("b.zip", now - 2 * DAY),
+            ("c.zip", now - DAY),
+        ],
+        &[],
+    ));
+
+    let opts = PruneOptions {
+        cache_max_bytes: Some(250),
+        ..PruneOptions::default()
+    };
+
+    let dry = mgr
+        .pruner()
+        .prune(
+            30 * DAY,
+            PruneOptions {
+                dry_run: true,
+                ..opts
+            },
+        )
+        .unwrap();
+    let labels: Vec<_> = dry.archives.iter().map(|a| a.label.as_str()).collect();
+    assert_eq!(labels, vec!["a.zip", "b.zip"]);
+    assert!(
+        env.cache().join("a.zip").exists(),
+        "dry run removes nothing"
+    );
+
+    mgr.pruner().prune(30 * DAY, opts).unwrap();
+
+    assert!(env.cache().join(&default_archive).exists());
+    assert!(!env.cache().join("a.zip").exists());
+    assert!(!env.cache().join("b.zip").exists());
+    assert!(env.cache().join("c.zip").exists());
+    assert!(!env.read_usage().archives.contains_key("a.zip"));
+}
+
+/// A cached archive holding empty files with the given names.
+fn make_cache_zip(env: &TestHome, name: &str, entries: &[&str]) {
+    let mut zip = zip::ZipWriter::new(std::io::Cursor::new(Vec::new()));
+    for entry in entries {
+        zip.start_file(*entry, zip::write::SimpleFileOptions::default())
+            .unwrap();
+    }
+    env.make_cache_file(name, &zip.finish().unwrap().into_inner());
+}
+
+#[tokio::test]
+#[serial]
+async fn prune_keeps_default_archive_of_install_without_receipt() {
+    let env = TestHome::new();
+
+    let mgr = gdvm().await;
+
+    let default_gv = resolved("4.3-stable");
+    env.make_install(
+        &mgr.library()
+            .install_key(&default_gv, &Variant::default(), None)
+            .unwrap(),
+    );
+    mgr.defaults()
+        .set_default(&default_gv, &Variant::default(), None)
+        .unwrap();
+
+    make_cache_zip(&env, "default.zip", &["Godot_v4.3-stable_linux.x86_64"]);
+    make_cache_zip(
+        &env,
+        "csharp.zip",
+        &["Godot_v4.3-stable_mono_linux_x86_64/Godot_v4.3-stable_mono_linux.x86_64"],
+    );
+    make_cache_zip(&env, "other.zip", &["Godot_v4.3.1-stable_linux.x86_64"]);
+
+    mgr.pruner()
+        .prune(
+            30 * DAY,
+            PruneOptions {
+                cache_max_bytes: Some(1),
+                ..PruneOptions::default()
+            },
+        )
+        .unwrap();
+
+    assert!(env.cache().join("default.zip").exists());
+    assert!(!env.cache().join("csharp.zip").exists());
+    assert!(!env.cache().join("other.zip").exists());
+}
+
+#[tokio::test]
+#[serial]
+async fn prune_never_removes_an_install_godot_is_running_from() {
+    let env = TestHome::new();
+
+    let key = "store/default/4.3-stable";
+    let dir = env.make_install(key);
+    let mut state = state_with(&[(key, 0)], &[], &[]);
+    // The test process stands in for a running Godot.
+    state.processes.insert(
+        std::process::id(),
+        ProcessRecord {
+            install_key: key.to_string(),
+            executable: std::env::current_exe().unwrap(),
+            project: None,
+            started: now_secs(),
+            start_time: None,
+        },
+    );
+    env.write_usage(&state);
+
+    let mgr = gdvm().await;
+    mgr.pruner()
+        .prune(
+            30 * DAY,
+            PruneOptions {
+                all: true,
+                force: true,
+                dry_run: false,
+                cache_max_bytes: None,
+            },
+        )
+        .unwrap();
+
+    assert!(dir.exists(), "an install in use must never be pruned");
+}
+
+#[tokio::test]
+#[serial]
+async fn prune_keeps_self_contained_editor_data_unless_forced() {
+    let env = TestHome::new();
+
+    let key = "store/default/4.3-stable";
+    let dir = env.make_install(key);
+    gdvm::self_contained::enable(&dir).unwrap();
+    std::fs::create_dir_all(gdvm::self_contained::editor_data(&dir)).unwrap();
+    env.write_usage(&state_with(&[(key, 0)], &[], &[]));
+
+    let opts = PruneOptions {
+        all: false,
+        force: false,
+        dry_run: false,
+        cache_max_bytes: None,
+    };
+    let mgr = gdvm().await;
+    let report = mgr.pruner().prune(30 * DAY, opts).unwrap();
+    assert!(report.installs.is_empty());
+    assert!(dir.exists(), "editor data is only pruned when forced");
+
+    let report = mgr
+        .pruner()
+        .prune(
+            30 * DAY,
+            PruneOptions {
+                force: true,
+                ..opts
+            },
+        )
+        .unwrap();
+    assert_eq!(report.installs.len(), 1);
+    assert!(!dir.exists());
+}
+
+#[tokio::test]
+#[serial]
+async fn prune_removes_editor_data_of_projects_that_are_gone() {
+    let env = TestHome::new();
+    let root = env.gdvm_dir().join("editor-data");
+    let kept = env.path().join("kept");
+    let gone = env.path().join("gone");
+    std::fs::create_dir_all(&kept).unwrap();
+    std::fs::create_dir_all(&gone).unwrap();
+    let kept_data = gdvm::self_contained::prepare_project(&root, &kept).unwrap();
+    let gone_data = gdvm::self_contained::prepare_project(&root, &gone).unwrap();
+    std::fs::remove_dir_all(&gone).unwrap();
+
+    let mgr = gdvm().await;
+    let report = mgr
+        .pruner()
+        .prune(30 * DAY, PruneOptions::default())
+        .unwrap();
+
+    assert_eq!(report.editor_data.len(), 1);
+    assert!(kept_data.exists());
+    assert!(!gone_data.exists());
+}
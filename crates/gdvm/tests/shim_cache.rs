@@ -0,0 +1,118 @@
+// SPDX-FileCopyrightText: Copyright (C) 2024 Adaline Simonian
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// This file is part of gdvm.
+//
+// gdvm is free software: you can redistribute it and/or modify it under the
+// terms of the GNU General Public License as published by the Free Software
+// Foundation, either version 3 of the License, or (at your option) any later
+// version.
+//
+// gdvm is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR
+// A PARTICULAR PURPOSE. See the GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with
+// this program. If not, see <https://www.gnu.org/licenses/>.
+
+#![cfg(feature = "integration-tests")]
+#![cfg(target_family = "unix")]
+
+use std::fs;
+use std::os::unix::fs::PermissionsExt;
+use std::path::Path;
+use std::process::Command;
+
+use gdvm::app::Gdvm;
+use gdvm::registry::OFFICIAL_BASE_URL;
+use gdvm::version::Variant;
+use serial_test::serial;
+
+mod common;
+use common::{TestHome, backdate, gdvm, resolved};
+
+/// Install a fake Godot that records its version in `out.txt` and exits with 3.
+fn install(env: &TestHome, mgr: &Gdvm, version: &str) {
+    let (_, dir) = mgr
+        .library()
+        .install_dir(&resolved(version), &Variant::default(), None)
+        .unwrap();
+    fs::create_dir_all(&dir).unwrap();
+    let exe = dir.join(format!("Godot_v{version}.exe"));
+    let out = env.project_dir().join("out.txt");
+    fs::write(
+        &exe,
+        format!("#!/bin/sh\necho {version} > '{}'\nexit 3\n", out.display()),
+    )
+    .unwrap();
+    fs::set_permissions(&exe, fs::Permissions::from_mode(0o755)).unwrap();
+    gdvm::registry_store::upsert(
+        dir.parent().unwrap().parent().unwrap(),
+        OFFICIAL_BASE_URL,
+        None,
+        None,
+    )
+    .unwrap();
+}
+
+/// Pin the 4.3 series in the project, with a settled modification time.
+fn pin(env: &TestHome) {
+    let pin = env.project_dir().join("gdvm.toml");
+    fs::write(
+        &pin,
+        "[godot]\nversion = \"~4.3\"\n\n[run]\nlaunch-mode = \"attached\"\n",
+    )
+    .unwrap();
+    backdate(&pin);
+}
+
+/// Run the `godot` shim and return the version of Godot it launched.
+fn run_shim(env: &TestHome) -> String {
+    let output = Command::new(env!("CARGO_BIN_EXE_gdvm"))
+        .env("GDVM_ALIAS", "godot")
+        .output()
+        .expect("the shim runs");
+    assert_eq!(output.status.code(), Some(3), "{output:?}");
+    let out = env.project_dir().join("out.txt");
+    let version = fs::read_to_string(&out).unwrap().trim().to_string();
+    fs::remove_file(out).unwrap();
+    version
+}
+
+/// Point every cached resolution at `version`, keeping its stamps.
+fn rewrite_cached_version(path: &Path, version: &str) {
+    let mut cache: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(path).unwrap()).unwrap();
+    let entries = cache["entries"].as_object_mut().unwrap();
+    assert!(!entries.is_empty(), "the resolution is cached");
+    for entry in entries.values_mut() {
+        entry["resolution"]["version"] = version.into();
+    }
+    fs::write(path, cache.to_string()).unwrap();
+}
+
+#[tokio::test]
+#[serial]
+async fn shim_uses_the_cache_until_a_pin_changes_or_the_install_goes() {
+    let env = TestHome::with_project();
+    let mgr = gdvm().await;
+    install(&env, &mgr, "4.3-stable");
+    install(&env, &mgr, "4.4-stable");
+    pin(&env);
+    env.settle();
+    let cache = env.gdvm_dir().join("resolutions.json");
+
+    assert_eq!(run_shim(&env), "4.3-stable");
+
+    // A cache hit launches whatever the cache says, without resolving again.
+    rewrite_cached_version(&cache, "4.4-stable");
+    assert_eq!(run_shim(&env), "4.4-stable");
+
+    // Editing the pin invalidates the cached resolution.
+    pin(&env);
+    assert_eq!(run_shim(&env), "4.3-stable");
+
+    // A cached install that's gone falls back to resolving in full.
+    rewrite_cached_version(&cache, "4.2-stable");
+    assert_eq!(run_shim(&env), "4.3-stable");
+}
@@ -0,0 +1,116 @@
+// SPDX-FileCopyrightText: Copyright (C) 2024 Adaline Simonian
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// This file is part of gdvm.
+//
+// gdvm is free software: you can redistribute it and/or modify it under the
+// terms of the GNU General Public License as published by the Free Software
+// Foundation, either version 3 of the License, or (at your option) any later
+// version.
+//
+// gdvm is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR
+// A PARTICULAR PURPOSE. See the GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with
+// this program. If not, see <https://www.gnu.org/licenses/>.
+#![cfg(feature = "integration-tests")]
+
+use std::fs;
+
+use gdvm::registry::OFFICIAL_BASE_URL;
+use gdvm::version::Variant;
+use serial_test::serial;
+
+mod common;
+use common::{TestHome, gdvm, resolved};
+
+fn shim(env: &TestHome, alias: &str) -> std::path::PathBuf {
+    let name = if cfg!(target_os = "windows") {
+        format!("{alias}.exe")
+    } else {
+        alias.to_string()
+    };
+    env.gdvm_dir().join("bin").join(name)
+}
+
+#[tokio::test]
+#[serial]
+async fn versioned_shims_follow_the_installed_series() {
+    let env = TestHome::new();
+    let mgr = gdvm().await;
+
+    for (version, variant) in [
+        ("4.3-stable", Variant::default()),
+        ("4.4-stable", Variant::default()),
+        ("4.3-stable", Variant::from_option(Some("csharp"))),
+        ("4.5-beta1", Variant::default()),
+    ] {
+        let (_, dir) = mgr
+            .library()
+            .install_dir(&resolved(version), &variant, None)
+            .unwrap();
+        fs::create_dir_all(&dir).unwrap();
+        gdvm::registry_store::upsert(
+            dir.parent().unwrap().parent().unwrap(),
+            OFFICIAL_BASE_URL,
+            None,
+            None,
+        )
+        .unwrap();
+    }
+
+    mgr.library().sync_version_shims();
+    for alias in [
+        "godot4",
+        "godot4.3",
+        "godot4.4",
+        "godot-csharp4",
+        "godot-csharp4.3",
+    ] {
+        assert!(shim(&env, alias).is_file(), "{alias}");
+    }
+    // Pre-releases don't get a series of their own.
+    assert!(!shim(&env, "godot4.5").exists());
+
+    mgr.installer()
+        .remove(&resolved("4.3-stable"), &Variant::default(), None)
+        .unwrap();
+    assert!(!shim(&env, "godot4.3").exists());
+    assert!(shim(&env, "godot4").is_file());
+    assert!(shim(&env, "godot-csharp4.3").is_file());
+}
+
+#[tokio::test]
+#[serial]
+async fn resolution_stamps_change_with_the_files_resolution_reads() {
+    let env = TestHome::with_project();
+    let pin = env.project_dir().join("gdvm.toml");
+    fs::write(&pin, "[godot]\nversion = \"4.3\"\n").unwrap();
+    let mgr = gdvm().await;
+    let start = [std::env::current_dir().unwrap()];
+    let stamps = || mgr.defaults().resolution_stamps(&start).unwrap();
+
+    let before = stamps();
+    assert_eq!(stamps(), before);
+
+    fs::write(&pin, "[godot]\nversion = \"4.3.1\"\n").unwrap();
+    let edited = stamps();
+    assert_ne!(edited, before);
+
+    fs::write(env.project_dir().join("gdvm.lock"), "").unwrap();
+    let locked = stamps();
+    assert_ne!(locked, edited);
+
+    env.make_install("store/default/4.3-stable");
+    let installed = stamps();
+    assert_ne!(installed, locked);
+
+    // Another install in the same store and variant.
+    env.make_install("store/default/4.4-stable");
+    let added = stamps();
+    assert_ne!(added, installed);
+
+    fs::remove_dir_all(env.installs().join("store/default/4.3-stable")).unwrap();
+    assert_ne!(stamps(), added);
+}
@@ -0,0 +1,56 @@
+// SPDX-FileCopyrightText: Copyright (C) 2024 Adaline Simonian
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// This file is part of gdvm.
+//
+// gdvm is free software: you can redistribute it and/or modify it under the
+// terms of the GNU General Public License as published by the Free Software
+// Foundation, either version 3 of the License, or (at your option) any later
+// version.
+//
+// gdvm is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR
+// A PARTICULAR PURPOSE. See the GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with
+// this program. If not, see <https://www.gnu.org/licenses/>.
+
+#![cfg(feature = "integration-tests")]
+
+use std::fs;
+use std::process::Command;
+
+use serial_test::serial;
+
+mod common;
+use common::TestHome;
+
+#[test]
+#[serial]
+fn show_explain_prints_json_trace_when_the_project_conflicts() {
+    let env = TestHome::with_project();
+    fs::write(
+        env.project_dir().join("gdvm.toml"),
+        "[godot]\nversion = \"4.3-stable\"\n",
+    )
+    .unwrap();
+    fs::write(
+        env.project_dir().join("project.godot"),
+        "[application]\nconfig/features=PackedStringArray(\"4.2\")\n",
+    )
+    .unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_gdvm"))
+        .args(["show", "--explain", "--format", "json"])
+        .output()
+        .expect("gdvm runs");
+
+    assert!(
+        !output.status.success(),
+        "the mismatch should fail: {output:?}"
+    );
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout)
+        .unwrap_or_else(|_| panic!("show prints JSON: {output:?}"));
+    assert_eq!(json["explain"]["selected"], "pin");
+    assert_eq!(json["explain"]["project_mismatch"], "4.2");
+}
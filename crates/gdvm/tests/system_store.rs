@@ -0,0 +1,210 @@
+// SPDX-FileCopyrightText: Copyright (C) 2024 Adaline Simonian
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// This file is part of gdvm.
+//
+// gdvm is free software: you can redistribute it and/or modify it under the
+// terms of the GNU General Public License as published by the Free Software
+// Foundation, either version 3 of the License, or (at your option) any later
+// version.
+//
+// gdvm is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR
+// A PARTICULAR PURPOSE. See the GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with
+// this program. If not, see <https://www.gnu.org/licenses/>.
+#![cfg(feature = "integration-tests")]
+
+use std::fs;
+use std::path::Path;
+
+use gdvm::app::InstallOutcome;
+use gdvm::config::ConfigFile;
+use gdvm::paths::SYSTEM_STORE_ENV_VAR;
+use gdvm::registry::{OFFICIAL_BASE_URL, publish};
+use gdvm::version::Variant;
+use serial_test::serial;
+use tempfile::TempDir;
+
+mod common;
+use common::{TestHome, gdvm, host_platform, make_zip, resolved};
+
+/// Sets the system store for the duration of a test.
+struct SystemStore {
+    dir: TempDir,
+}
+
+impl SystemStore {
+    fn new() -> Self {
+        let dir = TempDir::new().unwrap();
+        unsafe {
+            std::env::set_var(SYSTEM_STORE_ENV_VAR, dir.path());
+        }
+        Self { dir }
+    }
+
+    fn path(&self) -> &Path {
+        self.dir.path()
+    }
+}
+
+impl Drop for SystemStore {
+    fn drop(&mut self) {
+        unsafe {
+            std::env::remove_var(SYSTEM_STORE_ENV_VAR);
+        }
+    }
+}
+
+/// Create a fake install with a Godot executable every platform recognizes.
+fn fake_install(dir: &Path) {
+    fs::create_dir_all(dir).unwrap();
+    fs::write(dir.join("Godot_v4.3-stable.exe"), b"fake-binary").unwrap();
+    gdvm::registry_store::upsert(
+        dir.parent().unwrap().parent().unwrap(),
+        OFFICIAL_BASE_URL,
+        None,
+        None,
+    )
+    .unwrap();
+}
+
+#[tokio::test]
+#[serial]
+async fn system_store_installs_are_used_but_not_removed() {
+    let env = TestHome::new();
+    let store = SystemStore::new();
+    let mgr = gdvm().await;
+    let gv = resolved("4.3-stable");
+    let variant = Variant::default();
+
+    let (key, dir) = mgr
+        .system_library()
+        .unwrap()
+        .install_dir(&gv, &variant, None)
+        .unwrap();
+    assert!(dir.starts_with(store.path()));
+    fake_install(&dir);
+
+    let installed = mgr.library().list_installed().unwrap();
+    assert_eq!(installed.len(), 1);
+    assert_eq!(installed[0].version, gv);
+
+    let exe = mgr
+        .library()
+        .get_executable_path(&gv, &variant, None, false)
+        .unwrap();
+    assert!(exe.starts_with(&dir));
+
+    let outcome = mgr
+        .installer()
+        .install(&gv, &variant, None, false, false)
+        .await
+        .unwrap();
+    assert!(matches!(outcome, InstallOutcome::AlreadyInstalled));
+    assert!(!env.installs().join(&key).exists());
+
+    assert!(mgr.installer().remove(&gv, &variant, None).is_err());
+    assert!(dir.exists(), "user removal leaves the system store alone");
+
+    mgr.system_installer()
+        .unwrap()
+        .remove(&gv, &variant, None)
+        .unwrap();
+    assert!(!dir.exists());
+    assert!(mgr.library().list_installed().unwrap().is_empty());
+}
+
+#[tokio::test]
+#[serial]
+async fn user_and_system_copies_are_listed_once() {
+    let env = TestHome::new();
+    let _store = SystemStore::new();
+    let mgr = gdvm().await;
+    let gv = resolved("4.3-stable");
+    let variant = Variant::default();
+
+    let (key, system_dir) = mgr
+        .system_library()
+        .unwrap()
+        .install_dir(&gv, &variant, None)
+        .unwrap();
+    fake_install(&system_dir);
+    fake_install(&env.installs().join(&key));
+
+    assert_eq!(mgr.library().list_installed().unwrap().len(), 1);
+    let (_, dir) = mgr.library().install_dir(&gv, &variant, None).unwrap();
+    assert_eq!(dir, system_dir, "the system store is consulted first");
+}
+
+#[tokio::test]
+#[serial]
+async fn system_installs_keep_their_own_usage_and_archives() {
+    let env = TestHome::new();
+    let store = SystemStore::new();
+
+    let reg = TempDir::new().unwrap().keep().join("reg");
+    publish::init(&reg, Some("local")).unwrap();
+    let archive_src = reg.parent().unwrap().join("godot.zip");
+    make_zip(&archive_src, "Godot.test", b"a real-enough godot archive");
+    publish::add_build(
+        &reg,
+        &publish::AddBuild {
+            version: "4.4-stable".to_string(),
+            variant: None,
+            platform: host_platform(),
+            file: Some(archive_src),
+            store: true,
+            url: None,
+            sha512: None,
+            size: None,
+        },
+    )
+    .unwrap();
+    let mut config = ConfigFile::load().unwrap();
+    config
+        .add_registry("localreg", &format!("file://{}", reg.display()))
+        .unwrap();
+    config.save().unwrap();
+
+    let mgr = gdvm().await;
+    mgr.system_installer()
+        .unwrap()
+        .install(
+            &resolved("4.4-stable"),
+            &Variant::default(),
+            Some("localreg"),
+            false,
+            false,
+        )
+        .await
+        .unwrap();
+
+    let system_usage = fs::read_to_string(store.path().join("usage.json")).unwrap();
+    assert!(system_usage.contains("4.4-stable"), "{system_usage}");
+    assert!(
+        fs::read_to_string(env.usage_path()).map_or(true, |usage| !usage.contains("4.4-stable")),
+        "the installing user's usage index is left alone"
+    );
+    assert!(
+        fs::read_dir(store.path().join("cache"))
+            .unwrap()
+            .next()
+            .is_some()
+    );
+    assert!(
+        fs::read_dir(env.cache()).map_or(true, |mut entries| entries.next().is_none()),
+        "the archive is cached in the system store"
+    );
+}
+
+#[tokio::test]
+#[serial]
+async fn system_commands_need_a_system_store() {
+    let _env = TestHome::new();
+    let mgr = gdvm().await;
+
+    assert!(mgr.system_library().is_err());
+    assert!(mgr.system_installer().is_err());
+}
@@ -0,0 +1,145 @@
+// SPDX-FileCopyrightText: Copyright (C) 2024 Adaline Simonian
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// This file is part of gdvm.
+//
+// gdvm is free software: you can redistribute it and/or modify it under the
+// terms of the GNU General Public License as published by the Free Software
+// Foundation, either version 3 of the License, or (at your option) any later
+// version.
+//
+// gdvm is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR
+// A PARTICULAR PURPOSE. See the GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with
+// this program. If not, see <https://www.gnu.org/licenses/>.
+
+#![cfg(feature = "integration-tests")]
+
+use std::fs;
+
+use gdvm::config::{ConfigFile, ConfigKey};
+use serial_test::serial;
+
+mod common;
+use common::{TestHome, gdvm};
+
+#[tokio::test]
+#[serial]
+async fn get_pinned_reads_tool_files() {
+    let env = TestHome::with_project();
+    let mgr = gdvm().await;
+
+    fs::write(
+        env.project_dir().join("mise.toml"),
+        "[tools]\ngodot = \"4.1\"\n",
+    )
+    .unwrap();
+    let pinned = mgr
+        .defaults()
+        .get_pinned_version()
+        .expect("a pinned version");
+    assert_eq!(pinned.version.minor, Some(1));
+    assert_eq!(pinned.pin_file, Some(env.project_dir().join("mise.toml")));
+
+    fs::write(
+        env.project_dir().join(".tool-versions"),
+        "nodejs 20\ngodot 4.2-stable\n",
+    )
+    .unwrap();
+    let pinned = mgr
+        .defaults()
+        .get_pinned_version()
+        .expect("a pinned version");
+    assert_eq!(
+        pinned.version.minor,
+        Some(2),
+        ".tool-versions must win over mise.toml"
+    );
+
+    fs::write(env.project_dir().join(".godot-version"), "4.3\n").unwrap();
+    let pinned = mgr
+        .defaults()
+        .get_pinned_version()
+        .expect("a pinned version");
+    assert_eq!(
+        pinned.version.minor,
+        Some(3),
+        ".godot-version must win over .tool-versions"
+    );
+    assert!(!pinned.gdvmrc_fallback);
+
+    fs::write(
+        env.project_dir().join("gdvm.toml"),
+        "[godot]\nversion = \"4.4-stable\"\n",
+    )
+    .unwrap();
+    let pinned = mgr
+        .defaults()
+        .get_pinned_version()
+        .expect("a pinned version");
+    assert_eq!(
+        pinned.version.minor,
+        Some(4),
+        "gdvm.toml must win over tool files"
+    );
+}
+
+#[tokio::test]
+#[serial]
+async fn nearer_tool_file_wins_over_parent_gdvm_toml() {
+    let env = TestHome::with_project();
+    let mgr = gdvm().await;
+
+    fs::write(
+        env.project_dir().join("gdvm.toml"),
+        "[godot]\nversion = \"4.4-stable\"\n",
+    )
+    .unwrap();
+    let nested = env.project_dir().join("game");
+    fs::create_dir_all(&nested).unwrap();
+    fs::write(nested.join(".godot-version"), "4.2-stable\n").unwrap();
+    std::env::set_current_dir(&nested).unwrap();
+
+    let pinned = mgr
+        .defaults()
+        .get_pinned_version()
+        .expect("a pinned version");
+    assert_eq!(pinned.version.minor, Some(2));
+    assert_eq!(pinned.pin_file, Some(nested.join(".godot-version")));
+}
+
+#[tokio::test]
+#[serial]
+async fn tool_files_can_be_turned_off() {
+    let env = TestHome::with_project();
+    ConfigFile::modify(|file| file.set_value(ConfigKey::PinToolFiles, "false")).unwrap();
+    let mgr = gdvm().await;
+
+    fs::write(env.project_dir().join(".godot-version"), "4.3\n").unwrap();
+    assert!(mgr.defaults().get_pinned_version().is_none());
+
+    fs::write(env.project_dir().join(".gdvmrc"), "4.2.0-stable").unwrap();
+    let pinned = mgr
+        .defaults()
+        .get_pinned_version()
+        .expect("a pinned version");
+    assert_eq!(pinned.version.minor, Some(2));
+}
+
+#[tokio::test]
+#[serial]
+async fn tool_file_pins_ignore_gdvm_lock() {
+    let env = TestHome::with_project();
+    let mgr = gdvm().await;
+
+    fs::write(env.project_dir().join(".godot-version"), "4.3\n").unwrap();
+    let pinned = mgr
+        .defaults()
+        .get_locked_pin(true)
+        .unwrap()
+        .expect("a pinned version");
+    assert_eq!(pinned.version.minor, Some(3));
+    assert!(pinned.stale_lock.is_none());
+}
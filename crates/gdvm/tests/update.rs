@@ -0,0 +1,175 @@
+// SPDX-FileCopyrightText: Copyright (C) 2024 Adaline Simonian
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// This file is part of gdvm.
+//
+// gdvm is free software: you can redistribute it and/or modify it under the
+// terms of the GNU General Public License as published by the Free Software
+// Foundation, either version 3 of the License, or (at your option) any later
+// version.
+//
+// gdvm is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR
+// A PARTICULAR PURPOSE. See the GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with
+// this program. If not, see <https://www.gnu.org/licenses/>.
+#![cfg(feature = "integration-tests")]
+
+use gdvm::app::Gdvm;
+use gdvm::config::ConfigFile;
+use gdvm::registry::publish;
+use gdvm::version::{Variant, VersionQuery};
+use serial_test::serial;
+use tempfile::TempDir;
+
+mod common;
+use common::{TestHome, host_platform, make_zip, resolved};
+
+/// Publish a local registry with two patch releases of the 4.4 series and add
+/// it to the config as `localreg`.
+fn publish_local_registry() {
+    let reg = TempDir::new().unwrap().keep().join("reg");
+    publish::init(&reg, Some("local")).unwrap();
+
+    for version in ["4.4-stable", "4.4.1-stable"] {
+        let archive_src = reg.parent().unwrap().join(format!("{version}.zip"));
+        make_zip(&archive_src, "Godot.test", version.as_bytes());
+        publish::add_build(
+            &reg,
+            &publish::AddBuild {
+                version: version.to_string(),
+                variant: None,
+                platform: host_platform(),
+                file: Some(archive_src),
+                store: true,
+                url: None,
+                sha512: None,
+                size: None,
+            },
+        )
+        .unwrap();
+    }
+
+    let mut config = ConfigFile::load().unwrap();
+    config
+        .add_registry("localreg", &format!("file://{}", reg.display()))
+        .unwrap();
+    config.save().unwrap();
+}
+
+#[tokio::test]
+#[serial]
+async fn plans_newest_patch_for_installed_series() {
+    let _env = TestHome::new();
+    publish_local_registry();
+    let gdvm = Gdvm::new().await.unwrap();
+
+    gdvm.installer()
+        .install(
+            &resolved("4.4-stable"),
+            &Variant::default(),
+            Some("localreg"),
+            false,
+            false,
+        )
+        .await
+        .unwrap();
+
+    let updates = gdvm.library().plan_updates(None, None, None).await.unwrap();
+    assert_eq!(updates.len(), 1);
+    assert_eq!(updates[0].latest, resolved("4.4.1-stable"));
+    assert_eq!(updates[0].replaced, vec![resolved("4.4-stable")]);
+    assert_eq!(updates[0].registry.as_deref(), Some("localreg"));
+
+    let other_series = VersionQuery::from_remote_str("4.3").unwrap();
+    let updates = gdvm
+        .library()
+        .plan_updates(Some(&other_series), None, None)
+        .await
+        .unwrap();
+    assert!(updates.is_empty());
+
+    gdvm.installer()
+        .install(
+            &resolved("4.4.1-stable"),
+            &Variant::default(),
+            Some("localreg"),
+            false,
+            false,
+        )
+        .await
+        .unwrap();
+    // The older install is still replaced once the latest one is in place.
+    let updates = gdvm.library().plan_updates(None, None, None).await.unwrap();
+    assert_eq!(updates.len(), 1);
+    assert_eq!(updates[0].latest, resolved("4.4.1-stable"));
+    assert_eq!(updates[0].replaced, vec![resolved("4.4-stable")]);
+
+    gdvm.installer()
+        .remove(
+            &resolved("4.4-stable"),
+            &Variant::default(),
+            Some("localreg"),
+        )
+        .unwrap();
+    let updates = gdvm.library().plan_updates(None, None, None).await.unwrap();
+    assert!(updates.is_empty());
+}
+
+#[tokio::test]
+#[serial]
+async fn unreachable_registries_fail_the_plan() {
+    let _env = TestHome::new();
+    let url = format!(
+        "file://{}",
+        TempDir::new().unwrap().path().join("reg").display()
+    );
+    let mut config = ConfigFile::load().unwrap();
+    config.add_registry("gonereg", &url).unwrap();
+    config.save().unwrap();
+    let gdvm = Gdvm::new().await.unwrap();
+
+    let (_, dir) = gdvm
+        .library()
+        .install_dir(
+            &resolved("4.4-stable"),
+            &Variant::default(),
+            Some("gonereg"),
+        )
+        .unwrap();
+    std::fs::create_dir_all(&dir).unwrap();
+    gdvm::registry_store::upsert(dir.parent().unwrap().parent().unwrap(), &url, None, None)
+        .unwrap();
+
+    // Not being able to reach the registry isn't the same as being up to date.
+    assert!(gdvm.library().plan_updates(None, None, None).await.is_err());
+}
+
+#[tokio::test]
+#[serial]
+async fn recorded_links_are_listed_per_install() {
+    let env = TestHome::with_project();
+    env.make_install("default/4.3-stable");
+    env.make_install("default/4.3.1-stable");
+    let gdvm = Gdvm::new().await.unwrap();
+    let library = gdvm.library();
+
+    let variant = Variant::default();
+    let old_key = library
+        .install_key(&resolved("4.3-stable"), &variant, None)
+        .unwrap();
+    let link = env.project_dir().join("godot");
+    library.record_link(&link, &old_key).unwrap();
+
+    let links = library
+        .recorded_links(&resolved("4.3-stable"), &variant, None)
+        .unwrap();
+    assert_eq!(links, vec![link]);
+    assert!(
+        library
+            .recorded_links(&resolved("4.3.1-stable"), &variant, None)
+            .unwrap()
+            .is_empty()
+    );
+}
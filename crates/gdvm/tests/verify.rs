@@ -0,0 +1,65 @@
+// SPDX-FileCopyrightText: Copyright (C) 2024 Adaline Simonian
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// This file is part of gdvm.
+//
+// gdvm is free software: you can redistribute it and/or modify it under the
+// terms of the GNU General Public License as published by the Free Software
+// Foundation, either version 3 of the License, or (at your option) any later
+// version.
+//
+// gdvm is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR
+// A PARTICULAR PURPOSE. See the GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with
+// this program. If not, see <https://www.gnu.org/licenses/>.
+#![cfg(feature = "integration-tests")]
+
+use std::fs;
+
+use gdvm::install_receipt::InstallReceipt;
+use gdvm::version::Variant;
+use serial_test::serial;
+
+mod common;
+use common::{TestHome, gdvm, resolved};
+
+#[tokio::test]
+#[serial]
+async fn verify_compares_install_with_its_receipt() {
+    let env = TestHome::new();
+    let mgr = gdvm().await;
+
+    let (_, dir) = mgr
+        .library()
+        .install_dir(&resolved("4.3-stable"), &Variant::default(), None)
+        .unwrap();
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(dir.join("Godot"), b"fake-binary").unwrap();
+
+    let check = || {
+        mgr.library()
+            .verify(&resolved("4.3-stable"), &Variant::default(), None)
+            .unwrap()
+    };
+
+    assert!(check().is_none(), "installs without a receipt are skipped");
+
+    InstallReceipt::create(&dir, "https://example.com/", "4.3-stable", "default", "abc")
+        .unwrap()
+        .write(&dir)
+        .unwrap();
+    let (receipt, diff) = check().unwrap();
+    assert_eq!(receipt.archive_sha512, "abc");
+    assert!(diff.is_clean());
+
+    fs::write(dir.join("Godot"), b"tampered").unwrap();
+    fs::write(dir.join("extra.txt"), b"extra").unwrap();
+    let (_, diff) = check().unwrap();
+    assert_eq!(diff.modified, vec!["Godot"]);
+    assert_eq!(diff.added, vec!["extra.txt"]);
+    assert!(diff.missing.is_empty());
+
+    drop(env);
+}
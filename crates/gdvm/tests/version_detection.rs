@@ -48,3 +48,42 @@ foo=bar
     assert_eq!(gv.major, Some(4));
     assert_eq!(gv.minor, Some(2));
 }
+
+#[test]
+fn test_detect_godot_version_from_csproj() {
+    let dir = tempdir().unwrap();
+    std::fs::write(
+        dir.path().join("project.godot"),
+        "config_version=5\n[dotnet]\n[application]\nconfig/features=PackedStringArray(\"4.3\", \"C#\")\n",
+    )
+    .unwrap();
+    std::fs::write(
+        dir.path().join("Game.csproj"),
+        "<Project Sdk=\"Godot.NET.Sdk/4.3.1-rc.2\">\n</Project>\n",
+    )
+    .unwrap();
+
+    let (gv, variant) = detect_godot_version_in_path(dir.path()).unwrap();
+    assert_eq!(gv.to_remote_str().as_deref(), Some("4.3.1-rc2"));
+    assert_eq!(variant.as_deref(), Some("csharp"));
+}
+
+#[test]
+fn test_godot_3_csproj_only_sets_the_variant() {
+    let dir = tempdir().unwrap();
+    std::fs::write(
+        dir.path().join("project.godot"),
+        "config_version=4\n[application]\nconfig/name=\"Game\"\n",
+    )
+    .unwrap();
+    std::fs::write(
+        dir.path().join("Game.csproj"),
+        "<Project Sdk=\"Godot.NET.Sdk/3.3.0\">\n</Project>\n",
+    )
+    .unwrap();
+
+    let (gv, variant) = detect_godot_version_in_path(dir.path()).unwrap();
+    assert_eq!(gv.major, Some(3));
+    assert_eq!(gv.minor, None);
+    assert_eq!(variant.as_deref(), Some("csharp"));
+}
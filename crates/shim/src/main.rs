@@ -21,11 +21,14 @@ use std::process::Command;
 fn main() {
     let exe = std::env::current_exe().expect("failed to get current exe");
 
-    let exe_stem = exe
-        .file_stem()
+    // Only strip `.exe`, versioned shims such as `godot4.3` have a dot of
+    // their own.
+    let exe_name = exe
+        .file_name()
         .and_then(|s| s.to_str())
         .unwrap_or("")
         .to_lowercase();
+    let exe_stem = exe_name.strip_suffix(".exe").unwrap_or(&exe_name);
 
     let gdvm_name = if cfg!(target_os = "windows") {
         "gdvm.exe"
@@ -40,7 +43,7 @@ fn main() {
 
     let mut cmd = Command::new(gdvm_path);
 
-    cmd.env("GDVM_ALIAS", &exe_stem);
+    cmd.env("GDVM_ALIAS", exe_stem);
     cmd.args(std::env::args().skip(1));
 
     #[cfg(unix)]